@@ -148,6 +148,15 @@ fn convert_display(input: taffy::style::Display) -> taffy_03::style::Display {
         taffy::style::Display::Flex => taffy_03::style::Display::Flex,
         taffy::style::Display::Grid => taffy_03::style::Display::Grid,
         taffy::style::Display::Block => panic!("Block layout not implemented in taffy 0.3"),
+        taffy::style::Display::Contents => panic!("Display::Contents not implemented in taffy 0.3"),
+        taffy::style::Display::Inline | taffy::style::Display::InlineBlock => {
+            panic!("Inline layout not implemented in taffy 0.3")
+        }
+        taffy::style::Display::Table
+        | taffy::style::Display::TableRowGroup
+        | taffy::style::Display::TableRow
+        | taffy::style::Display::TableCell
+        | taffy::style::Display::TableCaption => panic!("Table layout not implemented in taffy 0.3"),
     }
 }
 
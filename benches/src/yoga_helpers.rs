@@ -192,6 +192,13 @@ fn apply_taffy_style(node: &mut yg::Node, style: &tf::Style) {
         tf::Display::Flex => yg::Display::Flex,
         tf::Display::Grid => panic!("Yoga does not support CSS Grid layout"),
         tf::Display::Block => panic!("Yoga does not support CSS Block layout"),
+        tf::Display::Contents => panic!("Yoga does not support display: contents"),
+        tf::Display::Inline | tf::Display::InlineBlock => panic!("Yoga does not support inline layout"),
+        tf::Display::Table
+        | tf::Display::TableRowGroup
+        | tf::Display::TableRow
+        | tf::Display::TableCell
+        | tf::Display::TableCaption => panic!("Yoga does not support table layout"),
     });
 
     // box_sizing
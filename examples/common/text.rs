@@ -22,8 +22,8 @@ pub fn text_measure_function(
     text_context: &TextContext,
     font_metrics: &FontMetrics,
 ) -> taffy::geometry::Size<f32> {
-    use taffy::geometry::AbsoluteAxis;
-    use taffy::prelude::*;
+    use taffy::geometry::{AbsoluteAxis, Size};
+    use taffy::style::AvailableSpace;
 
     let inline_axis = match text_context.writing_mode {
         WritingMode::Horizontal => AbsoluteAxis::Horizontal,
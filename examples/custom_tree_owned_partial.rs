@@ -152,6 +152,7 @@ impl taffy::LayoutPartialTree for Node {
 
     fn compute_child_layout(&mut self, node_id: NodeId, inputs: taffy::tree::LayoutInput) -> taffy::tree::LayoutOutput {
         compute_cached_layout(self, node_id, inputs, |parent, node_id, inputs| {
+            let relative_length_basis = parent.get_relative_length_basis(node_id);
             let node = parent.node_from_id_mut(node_id);
             let font_metrics = FontMetrics { char_width: 10.0, char_height: 10.0 };
 
@@ -161,6 +162,7 @@ impl taffy::LayoutPartialTree for Node {
                 NodeKind::Text => compute_leaf_layout(
                     inputs,
                     &node.style,
+                    relative_length_basis,
                     |_val, _basis| 0.0,
                     |known_dimensions, available_space| {
                         text_measure_function(
@@ -174,6 +176,7 @@ impl taffy::LayoutPartialTree for Node {
                 NodeKind::Image => compute_leaf_layout(
                     inputs,
                     &node.style,
+                    relative_length_basis,
                     |_val, _basis| 0.0,
                     |known_dimensions, _available_space| {
                         image_measure_function(known_dimensions, node.image_data.as_ref().unwrap())
@@ -232,6 +235,8 @@ impl taffy::LayoutFlexboxContainer for Node {
 }
 
 impl taffy::LayoutGridContainer for Node {
+    type CustomIdent = taffy::DefaultCheapStr;
+
     type GridContainerStyle<'a>
         = &'a Style
     where
@@ -7,8 +7,8 @@ use common::text::{text_measure_function, FontMetrics, TextContext, WritingMode,
 use taffy::tree::Cache;
 use taffy::util::print_tree;
 use taffy::{
-    compute_cached_layout, compute_flexbox_layout, compute_grid_layout, compute_leaf_layout, compute_root_layout,
-    prelude::*, round_layout, CacheTree,
+    compute_cached_layout, compute_flexbox_layout, compute_grid_layout, compute_leaf_layout,
+    compute_root_layout_with_positions, prelude::*, round_layout, CacheTree,
 };
 
 #[derive(Debug, Copy, Clone)]
@@ -81,7 +81,7 @@ impl Node {
 
     pub fn compute_layout(&mut self, available_space: Size<AvailableSpace>, use_rounding: bool) {
         let root_node_id = unsafe { self.as_id() };
-        compute_root_layout(&mut StatelessLayoutTree, root_node_id, available_space);
+        compute_root_layout_with_positions(&mut StatelessLayoutTree, root_node_id, available_space, None);
         if use_rounding {
             round_layout(&mut StatelessLayoutTree, root_node_id)
         }
@@ -149,6 +149,7 @@ impl LayoutPartialTree for StatelessLayoutTree {
 
     fn compute_child_layout(&mut self, node_id: NodeId, inputs: taffy::tree::LayoutInput) -> taffy::tree::LayoutOutput {
         compute_cached_layout(self, node_id, inputs, |tree, node_id, inputs| {
+            let relative_length_basis = tree.get_relative_length_basis(node_id);
             let node = unsafe { node_from_id_mut(node_id) };
             let font_metrics = FontMetrics { char_width: 10.0, char_height: 10.0 };
 
@@ -158,6 +159,7 @@ impl LayoutPartialTree for StatelessLayoutTree {
                 NodeKind::Text => compute_leaf_layout(
                     inputs,
                     &node.style,
+                    relative_length_basis,
                     |val, basis| tree.resolve_calc_value(val, basis),
                     |known_dimensions, available_space| {
                         text_measure_function(
@@ -171,6 +173,7 @@ impl LayoutPartialTree for StatelessLayoutTree {
                 NodeKind::Image => compute_leaf_layout(
                     inputs,
                     &node.style,
+                    relative_length_basis,
                     |val, basis| tree.resolve_calc_value(val, basis),
                     |known_dimensions, _available_space| {
                         image_measure_function(known_dimensions, node.image_data.as_ref().unwrap())
@@ -229,6 +232,8 @@ impl taffy::LayoutFlexboxContainer for StatelessLayoutTree {
 }
 
 impl taffy::LayoutGridContainer for StatelessLayoutTree {
+    type CustomIdent = taffy::DefaultCheapStr;
+
     type GridContainerStyle<'a>
         = &'a Style
     where
@@ -6,8 +6,8 @@ use common::image::{image_measure_function, ImageContext};
 use common::text::{text_measure_function, FontMetrics, TextContext, WritingMode, LOREM_IPSUM};
 use taffy::util::print_tree;
 use taffy::{
-    compute_cached_layout, compute_flexbox_layout, compute_grid_layout, compute_leaf_layout, compute_root_layout,
-    prelude::*, round_layout, Cache, CacheTree,
+    compute_cached_layout, compute_flexbox_layout, compute_grid_layout, compute_leaf_layout,
+    compute_root_layout_with_positions, prelude::*, round_layout, Cache, CacheTree,
 };
 
 #[derive(Debug, Copy, Clone)]
@@ -101,7 +101,7 @@ impl Tree {
     }
 
     pub fn compute_layout(&mut self, root: usize, available_space: Size<AvailableSpace>, use_rounding: bool) {
-        compute_root_layout(self, NodeId::from(root), available_space);
+        compute_root_layout_with_positions(self, NodeId::from(root), available_space, None);
         if use_rounding {
             round_layout(self, NodeId::from(root))
         }
@@ -158,6 +158,7 @@ impl taffy::LayoutPartialTree for Tree {
 
     fn compute_child_layout(&mut self, node_id: NodeId, inputs: taffy::tree::LayoutInput) -> taffy::tree::LayoutOutput {
         compute_cached_layout(self, node_id, inputs, |tree, node_id, inputs| {
+            let relative_length_basis = tree.get_relative_length_basis(node_id);
             let node = &mut tree.nodes[usize::from(node_id)];
             let font_metrics = FontMetrics { char_width: 10.0, char_height: 10.0 };
 
@@ -167,6 +168,7 @@ impl taffy::LayoutPartialTree for Tree {
                 NodeKind::Text => compute_leaf_layout(
                     inputs,
                     &node.style,
+                    relative_length_basis,
                     |_val, _basis| 0.0,
                     |known_dimensions, available_space| {
                         text_measure_function(
@@ -180,6 +182,7 @@ impl taffy::LayoutPartialTree for Tree {
                 NodeKind::Image => compute_leaf_layout(
                     inputs,
                     &node.style,
+                    relative_length_basis,
                     |_val, _basis| 0.0,
                     |known_dimensions, _available_space| {
                         image_measure_function(known_dimensions, node.image_data.as_ref().unwrap())
@@ -238,6 +241,8 @@ impl taffy::LayoutFlexboxContainer for Tree {
 }
 
 impl taffy::LayoutGridContainer for Tree {
+    type CustomIdent = taffy::DefaultCheapStr;
+
     type GridContainerStyle<'a>
         = &'a Style
     where
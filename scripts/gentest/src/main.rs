@@ -390,6 +390,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             "none" => quote!(display: taffy::style::Display::None,),
             "block" => quote!(display: taffy::style::Display::Block,),
             "grid" => quote!(display: taffy::style::Display::Grid,),
+            "contents" => quote!(display: taffy::style::Display::Contents,),
             _ => quote!(display: taffy::style::Display::Flex,),
         },
         _ => quote!(),
@@ -406,6 +407,7 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let position = match style["position"] {
         Value::String(ref value) => match value.as_ref() {
             "absolute" => quote!(position: taffy::style::Position::Absolute,),
+            "static" => quote!(position: taffy::style::Position::Static,),
             _ => quote!(),
         },
         _ => quote!(),
@@ -413,8 +415,17 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
 
     let direction = match style["direction"] {
         Value::String(ref value) => match value.as_ref() {
-            "rtl" => quote!(direction: taffy::style::Direction::RTL,),
-            "ltr" => quote!(direction: taffy::style::Direction::LTR,),
+            "rtl" => quote!(direction: taffy::style::Direction::Rtl,),
+            "ltr" => quote!(direction: taffy::style::Direction::Ltr,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
+
+    let writing_mode_style = match style["writingMode"] {
+        Value::String(ref value) => match value.as_ref() {
+            "vertical-rl" => quote!(writing_mode: taffy::style::WritingMode::VerticalRl,),
+            "vertical-lr" => quote!(writing_mode: taffy::style::WritingMode::VerticalLr,),
             _ => quote!(),
         },
         _ => quote!(),
@@ -456,12 +467,25 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         let overflow_x = overflow_x.unwrap_or(quote!(taffy::style::Overflow::Visible));
         let overflow_y = overflow_y.unwrap_or(quote!(taffy::style::Overflow::Visible));
         let overflow = quote!(overflow: taffy::geometry::Point { x: #overflow_x, y: #overflow_y },);
-        let scrollbar_width = quote_number_prop("scrollbar_width", style, |value: f32| quote!(#value));
+        let scrollbar_width = quote_number_prop(
+            "scrollbar_width",
+            style,
+            |value: f32| quote!(taffy::geometry::Size { width: #value, height: #value }),
+        );
         (overflow, scrollbar_width)
     } else {
         (quote!(), quote!())
     };
 
+    let scrollbar_gutter = match style["scrollbarGutter"] {
+        Value::String(ref value) => match value.as_ref() {
+            "stable" => quote!(scrollbar_gutter: taffy::style::ScrollbarGutter::Stable,),
+            "stable both-edges" => quote!(scrollbar_gutter: taffy::style::ScrollbarGutter::StableBothEdges,),
+            _ => quote!(),
+        },
+        _ => quote!(),
+    };
+
     let text_align = match style["textAlign"] {
         Value::String(ref value) => match value.as_ref() {
             "-webkit-left" => quote!(text_align: taffy::style::TextAlign::LegacyLeft,),
@@ -645,12 +669,14 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         #display
         #box_sizing
         #direction
+        #writing_mode_style
         #position
         #text_align
         #flex_direction
         #flex_wrap
         #overflow
         #scrollbar_width
+        #scrollbar_gutter
         #align_items
         #align_self
         #justify_items
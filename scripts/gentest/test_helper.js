@@ -219,6 +219,7 @@ function describeElement(e) {
       overflowX: parseEnum(e.style.overflowX),
       overflowY: parseEnum(e.style.overflowY),
       scrollbarWidth: getScrollBarWidth(),
+      scrollbarGutter: parseEnum(e.style.scrollbarGutter),
 
       alignItems: parseEnum(e.style.alignItems),
       alignSelf: parseEnum(e.style.alignSelf),
@@ -1,9 +1,23 @@
-//! Computes the CSS block layout algorithm in the case that the block container being laid out contains only block-level boxes
+//! Computes the CSS block layout algorithm. Runs of inline-level children are wrapped in anonymous block boxes, whose
+//! contents are laid out in lines by the [inline layout algorithm](super::inline).
+//!
+//! Floated children are placed by the [float context](super::float) of the container, and the container's other
+//! content flows around them. Every block container contains its own floats, and floats do not intrude into nested
+//! block containers: block-level children which are beside a float are narrowed to fit beside it (as if they
+//! established a new block formatting context), and floats inside a run of inline-level content are placed at the top
+//! of the run.
+use core::ops::Range;
+
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AvailableSpace, CoreStyle, LengthPercentageAuto, Overflow, Position};
+use crate::style::{
+    AvailableSpace, Clear, CoreStyle, Dimension, Direction, DisplayOutside, Float, LengthPercentageAuto, Overflow,
+    Position, Visibility,
+};
 use crate::style_helpers::TaffyMaxContent;
-use crate::tree::{CollapsibleMarginSet, Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
-use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId};
+use crate::tree::{
+    CollapsibleMarginSet, ContainingBlock, Layout, LayoutInput, LayoutOutput, RequestedAxis, RunMode, SizingMode,
+};
+use crate::tree::{LayoutPartialTreeExt, NodeId};
 use crate::util::debug::debug_log;
 use crate::util::sys::f32_max;
 use crate::util::sys::Vec;
@@ -11,8 +25,17 @@ use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{BlockContainerStyle, BlockItemStyle, BoxGenerationMode, BoxSizing, LayoutBlockContainer, TextAlign};
 
+use super::common::containing_block::{perform_static_child_layouts, AbsoluteContainingBlock, StaticChild};
 #[cfg(feature = "content_size")]
 use super::common::content_size::compute_content_size_contribution;
+use super::common::scrollbars::{compute_with_scrollbars, Scrollbars};
+use super::common::sizing_keywords::{compute_with_sizing_keywords, SizingKeywords};
+use super::common::writing_mode::compute_with_transposed_axes;
+use super::float::FloatContext;
+use super::inline::{compute_inline_layout, InlineLayout};
+use super::multicol::{self, ColumnBreaks, ColumnGeometry};
+#[cfg(feature = "detailed_layout_info")]
+use super::multicol::{DetailedMulticolInfo, DetailedMulticolItemInfo};
 
 /// Per-child data that is accumulated and modified over the course of the layout algorithm
 struct BlockItem {
@@ -25,6 +48,18 @@ struct BlockItem {
 
     /// Items that are tables don't have stretch sizing applied to them
     is_table: bool,
+    /// Items whose width is an intrinsic sizing keyword (such as max-content) are sized by their content rather than
+    /// stretched to fill the container
+    has_intrinsic_width: bool,
+    /// Whether the item is block-level or inline-level
+    display_outside: DisplayOutside,
+    /// For anonymous block boxes which wrap a run of inline-level children, the range of the run within the
+    /// container's list of inline-level children. `None` for items which correspond to a child of the container.
+    inline_run: Option<Range<usize>>,
+    /// Which side of the container the item is floated to. Absolutely positioned items are never floated.
+    float: Float,
+    /// Which earlier floats the item is placed below
+    clear: Clear,
 
     /// The base size of this item
     size: Size<Option<f32>>,
@@ -34,9 +69,10 @@ struct BlockItem {
     max_size: Size<Option<f32>>,
 
     /// The overflow style of the item
+    #[cfg_attr(not(feature = "content_size"), allow(dead_code))]
     overflow: Point<Overflow>,
-    /// The width of the item's scrollbars (if it has scrollbars)
-    scrollbar_width: f32,
+    /// The visibility style of the item
+    visibility: Visibility,
 
     /// The position style of the item
     position: Position,
@@ -54,49 +90,77 @@ struct BlockItem {
     /// The computed border box size of this item
     computed_size: Size<f32>,
     /// The computed "static position" of this item. The static position is the position
-    /// taking into account padding, border, margins, and scrollbar_gutters but not inset
+    /// taking into account padding, border, margins, and scrollbar_gutters but not inset.
+    /// In right-to-left containers the x coordinate is that of the inline-start (right) edge.
     static_position: Point<f32>,
     /// Whether margins can be collapsed through this item
     can_be_collapsed_through: bool,
+
+    /// The top and bottom edges of the item's border box in the unfragmented flow of the container's content (before
+    /// the item is moved into its column, in multi-column containers)
+    flow_extent: Line<f32>,
+    /// For anonymous block boxes in multi-column containers, the top and bottom edges of each of the box's lines in the
+    /// unfragmented flow of the container's content
+    line_extents: Vec<Line<f32>>,
+    /// In multi-column containers, the offset by which the item is moved from its position in the unfragmented flow
+    /// into its column
+    column_offset: Point<f32>,
+    /// For anonymous block boxes in multi-column containers, the offset by which each of the box's lines is moved
+    /// into its column
+    line_column_offsets: Vec<Point<f32>>,
 }
 
-/// Computes the layout of [`LayoutPartialTree`] according to the block layout algorithm
+/// Computes the layout of [`LayoutPartialTree`](crate::LayoutPartialTree) according to the block layout algorithm
 pub fn compute_block_layout(
     tree: &mut impl LayoutBlockContainer,
     node_id: NodeId,
     inputs: LayoutInput,
 ) -> LayoutOutput {
+    let style = tree.get_block_container_style(node_id);
+    let writing_mode = style.writing_mode();
+    let sizing_keywords = SizingKeywords::new(&style, inputs.parent_size, |val, basis| tree.calc(node_id, val, basis));
+    drop(style);
+
+    compute_with_sizing_keywords(sizing_keywords, inputs, |inputs| match writing_mode.is_vertical() {
+        true => compute_with_transposed_axes(tree, node_id, inputs, writing_mode, compute_horizontal_tb),
+        false => compute_horizontal_tb(tree, node_id, inputs),
+    })
+}
+
+/// Computes the layout of [`LayoutBlockContainer`] according to the block layout algorithm, treating the x axis as the inline axis
+fn compute_horizontal_tb(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, run_mode, .. } = inputs;
     let style = tree.get_block_container_style(node_id);
 
     // Pull these out earlier to avoid borrowing issues
     let aspect_ratio = style.aspect_ratio();
-    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
-    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
+    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
     let padding_border_size = (padding + border).sum_axes();
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
 
     let min_size = style
         .min_size()
-        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let max_size = style
         .max_size()
-        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let clamped_style_size = if inputs.sizing_mode == SizingMode::InherentSize {
         style
             .size()
-            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment)
             .maybe_clamp(min_size, max_size)
     } else {
         Size::NONE
     };
+    let scrollbars = Scrollbars::new(&style, parent_size.width, |val, basis| tree.calc(node_id, val, basis));
 
     drop(style);
 
@@ -118,13 +182,25 @@ pub fn compute_block_layout(
     }
 
     debug_log!("BLOCK");
-    compute_inner(tree, node_id, LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs })
+    let inputs = LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs };
+    compute_with_scrollbars(scrollbars, |scrollbar_gutter| compute_inner(tree, node_id, inputs, scrollbar_gutter))
 }
 
 /// Computes the layout of [`LayoutBlockContainer`] according to the block layout algorithm
-fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
+fn compute_inner(
+    tree: &mut impl LayoutBlockContainer,
+    node_id: NodeId,
+    inputs: LayoutInput,
+    scrollbar_gutter: Rect<f32>,
+) -> LayoutOutput {
     let LayoutInput {
-        known_dimensions, parent_size, available_space, run_mode, vertical_margins_are_collapsible, ..
+        known_dimensions,
+        parent_size,
+        available_space,
+        run_mode,
+        vertical_margins_are_collapsible,
+        containing_block,
+        ..
     } = inputs;
 
     let style = tree.get_block_container_style(node_id);
@@ -132,20 +208,11 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     let raw_border = style.border();
     let raw_margin = style.margin();
     let aspect_ratio = style.aspect_ratio();
-    let padding = raw_padding.resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
-    let border = raw_border.resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
-
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = {
-        let offsets = style.overflow().transpose().map(|overflow| match overflow {
-            Overflow::Scroll => style.scrollbar_width(),
-            _ => 0.0,
-        });
-        // TODO: make side configurable based on the `direction` property
-        Rect { top: 0.0, left: 0.0, right: offsets.x, bottom: offsets.y }
-    };
+    let direction = style.direction();
+    let position = style.position();
+    let padding = raw_padding.resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
+    let border = raw_border.resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
+
     let padding_border = padding + border;
     let padding_border_size = padding_border.sum_axes();
     let content_box_inset = padding_border + scrollbar_gutter;
@@ -155,40 +222,49 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
     let size = style
         .size()
-        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let min_size = style
         .min_size()
-        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let max_size = style
         .max_size()
-        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
 
+    // Multi-column containers establish an independent formatting context for their content
+    let is_multicol = style.is_multicol_container();
+    let column_count = style.column_count();
+    let column_width = style.column_width();
+    let column_gap = style.column_gap();
+
     // Determine margin collapsing behaviour
     let own_margins_collapse_with_children = Line {
         start: vertical_margins_are_collapsible.start
+            && !is_multicol
             && !style.overflow().x.is_scroll_container()
             && !style.overflow().y.is_scroll_container()
-            && style.position() == Position::Relative
+            && !style.position().is_absolutely_positioned()
             && padding.top == 0.0
             && border.top == 0.0,
         end: vertical_margins_are_collapsible.end
+            && !is_multicol
             && !style.overflow().x.is_scroll_container()
             && !style.overflow().y.is_scroll_container()
-            && style.position() == Position::Relative
+            && !style.position().is_absolutely_positioned()
             && padding.bottom == 0.0
             && border.bottom == 0.0
             && size.height.is_none(),
     };
     let has_styles_preventing_being_collapsed_through = !style.is_block()
+        || is_multicol
         || style.overflow().x.is_scroll_container()
         || style.overflow().y.is_scroll_container()
-        || style.position() == Position::Absolute
+        || style.position().is_absolutely_positioned()
         || padding.top > 0.0
         || padding.bottom > 0.0
         || border.top > 0.0
@@ -201,13 +277,27 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     drop(style);
 
     // 1. Generate items
-    let mut items = generate_item_list(tree, node_id, container_content_box_size);
+    let mut inline_children = Vec::new();
+    let items = generate_item_list(tree, node_id, container_content_box_size);
+    let mut items = wrap_inline_level_items(items, &mut inline_children);
 
     // 2. Compute container width
     let container_outer_width = known_dimensions.width.unwrap_or_else(|| {
         let available_width = available_space.width.maybe_sub(content_box_inset.horizontal_axis_sum());
-        let intrinsic_width = determine_content_based_container_width(tree, &items, available_width)
-            + content_box_inset.horizontal_axis_sum();
+        let content_based_width = |tree: &mut _, available_width| {
+            determine_content_based_container_width(tree, &items, &inline_children, available_width, direction)
+        };
+        let intrinsic_width = match is_multicol {
+            false => content_based_width(tree, available_width),
+            true => multicol::compute_intrinsic_width(
+                column_count,
+                column_width.maybe_resolve(None, |val, basis| tree.calc(node_id, val, basis)),
+                column_gap.resolve_or_zero(None, |val, basis| tree.calc(node_id, val, basis)),
+                content_based_width(tree, AvailableSpace::MinContent),
+                content_based_width(tree, AvailableSpace::MaxContent),
+                available_width,
+            ),
+        } + content_box_inset.horizontal_axis_sum();
         intrinsic_width.maybe_clamp(min_size.width, max_size.width).maybe_max(Some(padding_border_size.width))
     });
 
@@ -217,19 +307,87 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
     }
 
     // 3. Perform final item layout and return content height
-    let resolved_padding = raw_padding.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(val, basis));
-    let resolved_border = raw_border.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(val, basis));
+    let resolved_padding =
+        raw_padding.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(node_id, val, basis));
+    let resolved_border =
+        raw_border.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(node_id, val, basis));
     let resolved_content_box_inset = resolved_padding + resolved_border + scrollbar_gutter;
-    let (inflow_content_size, intrinsic_outer_height, first_child_top_margin_set, last_child_bottom_margin_set) =
-        perform_final_layout_on_in_flow_children(
+
+    // The content of multi-column containers is laid out in a single column, and then divided into columns
+    let column_geometry = is_multicol.then(|| {
+        let content_width = container_outer_width - resolved_content_box_inset.horizontal_axis_sum();
+        ColumnGeometry::resolve(
+            content_width,
+            column_count,
+            column_width.maybe_resolve(Some(content_width), |val, basis| tree.calc(node_id, val, basis)),
+            column_gap.resolve_or_zero(Some(content_width), |val, basis| tree.calc(node_id, val, basis)),
+        )
+    });
+    let flow_outer_width = match column_geometry {
+        Some(geometry) => geometry.width + content_box_inset.horizontal_axis_sum(),
+        None => container_outer_width,
+    };
+
+    let mut static_children = Vec::new();
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let (
+        mut inflow_content_size,
+        mut intrinsic_outer_height,
+        first_child_top_margin_set,
+        last_child_bottom_margin_set,
+        first_baseline,
+    ) = perform_final_layout_on_in_flow_children(
+        tree,
+        &mut items,
+        &inline_children,
+        flow_outer_width,
+        content_box_inset,
+        resolved_content_box_inset,
+        text_align,
+        direction,
+        own_margins_collapse_with_children,
+        is_multicol,
+        &mut static_children,
+    );
+
+    #[cfg_attr(not(feature = "detailed_layout_info"), allow(unused_variables))]
+    let column_breaks = column_geometry.map(|geometry| {
+        let max_column_height = known_dimensions
+            .height
+            .or(max_size.height)
+            .map(|height| height - resolved_content_box_inset.vertical_axis_sum());
+        let breaks = balance_columns(&items, resolved_content_box_inset.top, geometry, max_column_height);
+
+        // Move each item (or for anonymous block boxes, each line) into its column and lay the content out again
+        for item in items.iter_mut().filter(|item| !item.position.is_absolutely_positioned()) {
+            match item.inline_run {
+                Some(_) => {
+                    item.line_column_offsets = item
+                        .line_extents
+                        .iter()
+                        .map(|line| breaks.offset_of(line.start, &geometry, direction))
+                        .collect()
+                }
+                None => item.column_offset = breaks.offset_of(item.flow_extent.start, &geometry, direction),
+            }
+        }
+        static_children.clear();
+        (inflow_content_size, _, _, _, _) = perform_final_layout_on_in_flow_children(
             tree,
             &mut items,
-            container_outer_width,
+            &inline_children,
+            flow_outer_width,
             content_box_inset,
             resolved_content_box_inset,
             text_align,
+            direction,
             own_margins_collapse_with_children,
+            false,
+            &mut static_children,
         );
+        intrinsic_outer_height = breaks.height + resolved_content_box_inset.vertical_axis_sum();
+        breaks
+    });
     let container_outer_height = known_dimensions
         .height
         .unwrap_or(intrinsic_outer_height.maybe_clamp(min_size.height, max_size.height))
@@ -241,12 +399,23 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
         return LayoutOutput::from_outer_size(final_outer_size);
     }
 
-    // 4. Layout absolutely positioned children
-    let absolute_position_inset = resolved_border + scrollbar_gutter;
-    let absolute_position_area = final_outer_size - absolute_position_inset.sum_axes();
-    let absolute_position_offset = Point { x: absolute_position_inset.left, y: absolute_position_inset.top };
-    let absolute_content_size =
-        perform_absolute_layout_on_absolute_children(tree, &items, absolute_position_area, absolute_position_offset);
+    #[cfg(feature = "detailed_layout_info")]
+    if let (Some(geometry), Some(breaks)) = (column_geometry, &column_breaks) {
+        let detailed_multicol_info = detailed_multicol_info(tree, node_id, &items, geometry, breaks, direction);
+        tree.set_detailed_multicol_info(node_id, detailed_multicol_info);
+    }
+
+    // 4. Layout absolutely positioned children (and the absolutely positioned descendants of static children) once
+    // their containing block is known
+    let containing_block =
+        AbsoluteContainingBlock::resolve(containing_block, position, resolved_border + scrollbar_gutter);
+    perform_static_child_layouts(tree, &static_children, containing_block, final_outer_size);
+    let absolute_content_size = match containing_block {
+        Some(containing_block) => {
+            perform_absolute_layout_on_absolute_children(tree, &items, containing_block, final_outer_size, direction)
+        }
+        None => Size::ZERO,
+    };
 
     // 5. Perform hidden layout on hidden children
     let len = tree.child_count(node_id);
@@ -267,7 +436,7 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
 
     // 7. Determine whether this node can be collapsed through
     let all_in_flow_children_can_be_collapsed_through =
-        items.iter().all(|item| item.position == Position::Absolute || item.can_be_collapsed_through);
+        items.iter().all(|item| item.position.is_absolutely_positioned() || item.can_be_collapsed_through);
     let can_be_collapsed_through =
         !has_styles_preventing_being_collapsed_through && all_in_flow_children_can_be_collapsed_through;
 
@@ -278,18 +447,20 @@ fn compute_inner(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs:
         size: final_outer_size,
         #[cfg(feature = "content_size")]
         content_size,
-        first_baselines: Point::NONE,
+        scrollbar_size: Size::ZERO,
+        first_baselines: Point { x: None, y: first_baseline },
         top_margin: if own_margins_collapse_with_children.start {
             first_child_top_margin_set
         } else {
-            let margin_top = raw_margin.top.resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+            let margin_top =
+                raw_margin.top.resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
             CollapsibleMarginSet::from_margin(margin_top)
         },
         bottom_margin: if own_margins_collapse_with_children.end {
             last_child_bottom_margin_set
         } else {
             let margin_bottom =
-                raw_margin.bottom.resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+                raw_margin.bottom.resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
             CollapsibleMarginSet::from_margin(margin_bottom)
         },
         margins_can_collapse_through: can_be_collapsed_through,
@@ -309,8 +480,12 @@ fn generate_item_list(
         .enumerate()
         .map(|(order, (child_node_id, child_style))| {
             let aspect_ratio = child_style.aspect_ratio();
-            let padding = child_style.padding().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
-            let border = child_style.border().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
+            let padding = child_style
+                .padding()
+                .resolve_or_zero(node_inner_size, |val, basis| tree.calc(child_node_id, val, basis));
+            let border = child_style
+                .border()
+                .resolve_or_zero(node_inner_size, |val, basis| tree.calc(child_node_id, val, basis));
             let pb_sum = (padding + border).sum_axes();
             let box_sizing_adjustment =
                 if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
@@ -318,23 +493,31 @@ fn generate_item_list(
                 node_id: child_node_id,
                 order: order as u32,
                 is_table: child_style.is_table(),
+                has_intrinsic_width: child_style.size().width.is_intrinsic_sizing_keyword(),
+                display_outside: child_style.display_outside(),
+                inline_run: None,
+                float: match child_style.position().is_absolutely_positioned() {
+                    true => Float::None,
+                    false => child_style.float(),
+                },
+                clear: child_style.clear(),
                 size: child_style
                     .size()
-                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
+                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(child_node_id, val, basis))
                     .maybe_apply_aspect_ratio(aspect_ratio)
                     .maybe_add(box_sizing_adjustment),
                 min_size: child_style
                     .min_size()
-                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
+                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(child_node_id, val, basis))
                     .maybe_apply_aspect_ratio(aspect_ratio)
                     .maybe_add(box_sizing_adjustment),
                 max_size: child_style
                     .max_size()
-                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
+                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(child_node_id, val, basis))
                     .maybe_apply_aspect_ratio(aspect_ratio)
                     .maybe_add(box_sizing_adjustment),
                 overflow: child_style.overflow(),
-                scrollbar_width: child_style.scrollbar_width(),
+                visibility: child_style.visibility(),
                 position: child_style.position(),
                 inset: child_style.inset(),
                 margin: child_style.margin(),
@@ -346,28 +529,123 @@ fn generate_item_list(
                 computed_size: Size::zero(),
                 static_position: Point::zero(),
                 can_be_collapsed_through: false,
+                flow_extent: Line { start: 0.0, end: 0.0 },
+                line_extents: Vec::new(),
+                column_offset: Point::zero(),
+                line_column_offsets: Vec::new(),
             }
         })
         .collect()
 }
 
+/// Replace each run of consecutive in-flow inline-level items with an anonymous block box that wraps the run.
+/// The inline-level children of the anonymous boxes are appended to `inline_children`.
+///
+/// Absolutely positioned items within a run are moved after the anonymous box, so that their static position is
+/// below the run's lines. Floated items (which are always block-level) within a run are kept in the run, so that they
+/// are placed at the line on which they occur.
+#[inline]
+fn wrap_inline_level_items(items: Vec<BlockItem>, inline_children: &mut Vec<(NodeId, u32)>) -> Vec<BlockItem> {
+    let mut wrapped_items = Vec::new();
+    let mut deferred_items = Vec::new();
+    let mut run_start = None;
+    for item in items {
+        let is_out_of_flow = item.position.is_absolutely_positioned() || item.float != Float::None;
+        let is_inline_level = item.display_outside != DisplayOutside::Block && !is_out_of_flow;
+        if is_inline_level || (run_start.is_some() && item.float != Float::None) {
+            run_start.get_or_insert(inline_children.len());
+            inline_children.push((item.node_id, item.order));
+        } else if run_start.is_some() && item.position.is_absolutely_positioned() {
+            deferred_items.push(item);
+        } else {
+            if let Some(start) = run_start.take() {
+                wrapped_items.push(BlockItem::anonymous(inline_children, start));
+                wrapped_items.extend(core::mem::take(&mut deferred_items));
+            }
+            wrapped_items.push(item);
+        }
+    }
+    if let Some(start) = run_start {
+        wrapped_items.push(BlockItem::anonymous(inline_children, start));
+        wrapped_items.extend(core::mem::take(&mut deferred_items));
+    }
+    wrapped_items
+}
+
+impl BlockItem {
+    /// Create an anonymous block box which wraps the inline-level children from `start` to the end of `inline_children`
+    fn anonymous(inline_children: &[(NodeId, u32)], start: usize) -> Self {
+        let (node_id, order) = inline_children[start];
+        BlockItem {
+            node_id,
+            order,
+            is_table: false,
+            has_intrinsic_width: false,
+            display_outside: DisplayOutside::Block,
+            inline_run: Some(start..inline_children.len()),
+            float: Float::None,
+            clear: Clear::None,
+            size: Size::NONE,
+            min_size: Size::NONE,
+            max_size: Size::NONE,
+            overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
+            visibility: Visibility::Visible,
+            position: Position::Relative,
+            inset: Rect::auto(),
+            margin: Rect::zero(),
+            padding: Rect::zero(),
+            border: Rect::zero(),
+            padding_border_sum: Size::zero(),
+            computed_size: Size::zero(),
+            static_position: Point::zero(),
+            can_be_collapsed_through: false,
+            flow_extent: Line { start: 0.0, end: 0.0 },
+            line_extents: Vec::new(),
+            column_offset: Point::zero(),
+            line_column_offsets: Vec::new(),
+        }
+    }
+}
+
 /// Compute the content-based width in the case that the width of the container is not known
 #[inline]
 fn determine_content_based_container_width(
-    tree: &mut impl LayoutPartialTree,
+    tree: &mut impl LayoutBlockContainer,
     items: &[BlockItem],
+    inline_children: &[(NodeId, u32)],
     available_width: AvailableSpace,
+    direction: Direction,
 ) -> f32 {
     let available_space = Size { width: available_width, height: AvailableSpace::MinContent };
 
     let mut max_child_width = 0.0;
-    for item in items.iter().filter(|item| item.position != Position::Absolute) {
+    let mut max_float_width = 0.0;
+    let mut float_width_sum = 0.0;
+    for item in items.iter().filter(|item| !item.position.is_absolutely_positioned()) {
+        if let Some(inline_run) = item.inline_run.clone() {
+            let inline_layout = compute_inline_layout(
+                tree,
+                &inline_children[inline_run],
+                available_width,
+                None,
+                direction,
+                TextAlign::Auto,
+                None,
+            );
+            max_child_width = f32_max(max_child_width, inline_layout.width());
+            for width in inline_layout.float_widths() {
+                max_float_width = f32_max(max_float_width, width);
+                float_width_sum += width;
+            }
+            continue;
+        }
+
         let known_dimensions = item.size.maybe_clamp(item.min_size, item.max_size);
 
         let width = known_dimensions.width.unwrap_or_else(|| {
             let item_x_margin_sum = item
                 .margin
-                .resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis))
+                .resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(item.node_id, val, basis))
                 .horizontal_axis_sum();
             let size_and_baselines = tree.perform_child_layout(
                 item.node_id,
@@ -375,35 +653,65 @@ fn determine_content_based_container_width(
                 Size::NONE,
                 available_space.map_width(|w| w.maybe_sub(item_x_margin_sum)),
                 SizingMode::InherentSize,
-                Line::TRUE,
+                if item.float == Float::None { Line::TRUE } else { Line::FALSE },
             );
 
             size_and_baselines.size.width + item_x_margin_sum
         });
         let width = f32_max(width, item.padding_border_sum.width);
 
-        max_child_width = f32_max(max_child_width, width);
+        if item.float == Float::None {
+            max_child_width = f32_max(max_child_width, width);
+        } else {
+            max_float_width = f32_max(max_float_width, width);
+            float_width_sum += width;
+        }
     }
 
-    max_child_width
+    // Floats are placed beside each other and beside the other content, as far as the available space allows
+    match available_width {
+        AvailableSpace::MinContent => f32_max(max_child_width, max_float_width),
+        _ => f32_max(
+            f32_max(max_child_width, max_float_width),
+            (max_child_width + float_width_sum).maybe_min(available_width.into_option()),
+        ),
+    }
 }
 
-/// Compute each child's final size and position
+/// Compute each child's final size and position.
+///
+/// Each item is moved by its `column_offset` (and the lines of anonymous block boxes by their `line_column_offsets`).
+/// If `record_line_extents` is true then the extents of the lines of anonymous block boxes are recorded, so that the
+/// container's content can be divided into columns.
 #[inline]
+#[allow(clippy::too_many_arguments)]
 fn perform_final_layout_on_in_flow_children(
-    tree: &mut impl LayoutPartialTree,
+    tree: &mut impl LayoutBlockContainer,
     items: &mut [BlockItem],
+    inline_children: &[(NodeId, u32)],
     container_outer_width: f32,
     content_box_inset: Rect<f32>,
     resolved_content_box_inset: Rect<f32>,
     text_align: TextAlign,
+    direction: Direction,
     own_margins_collapse_with_children: Line<bool>,
-) -> (Size<f32>, f32, CollapsibleMarginSet, CollapsibleMarginSet) {
+    record_line_extents: bool,
+    static_children: &mut Vec<StaticChild>,
+) -> (Size<f32>, f32, CollapsibleMarginSet, CollapsibleMarginSet, Option<f32>) {
     // Resolve container_inner_width for sizing child nodes using initial content_box_inset
     let container_inner_width = container_outer_width - content_box_inset.horizontal_axis_sum();
     let parent_size = Size { width: Some(container_outer_width), height: None };
-    let available_space =
-        Size { width: AvailableSpace::Definite(container_inner_width), height: AvailableSpace::MinContent };
+
+    // The x coordinate of the inline-start edge of the content box
+    let content_box_inline_start = match direction {
+        Direction::Ltr => resolved_content_box_inset.left,
+        Direction::Rtl => container_outer_width - resolved_content_box_inset.right,
+    };
+
+    let mut floats = FloatContext::new(Line {
+        start: resolved_content_box_inset.left,
+        end: resolved_content_box_inset.left + container_inner_width,
+    });
 
     #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
     let mut inflow_content_size = Size::ZERO;
@@ -412,15 +720,77 @@ fn perform_final_layout_on_in_flow_children(
     let mut first_child_top_margin_set = CollapsibleMarginSet::ZERO;
     let mut active_collapsible_margin_set = CollapsibleMarginSet::ZERO;
     let mut is_collapsing_with_first_margin_set = true;
+    let mut first_baseline = None;
     for item in items.iter_mut() {
-        if item.position == Position::Absolute {
-            item.static_position = Point { x: resolved_content_box_inset.left, y: y_offset_for_absolute }
+        if item.position.is_absolutely_positioned() {
+            item.static_position = Point { x: content_box_inline_start, y: y_offset_for_absolute }
+        } else if item.float != Float::None {
+            // Floats do not take part in margin collapsing, so they are placed below any margins collapsed so far
+            // unless those margins collapse through the container
+            let y = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+                committed_y_offset
+            } else {
+                committed_y_offset + active_collapsible_margin_set.resolve()
+            };
+            #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+            let (location, item_layout) = perform_float_layout(
+                tree,
+                item,
+                &mut floats,
+                y,
+                container_outer_width,
+                container_inner_width,
+                direction,
+                static_children,
+            );
+
+            #[cfg(feature = "content_size")]
+            {
+                inflow_content_size = inflow_content_size.f32_max(compute_content_size_contribution(
+                    location,
+                    item_layout.size,
+                    item_layout.content_size,
+                    item.overflow,
+                ));
+            }
         } else {
-            let item_margin = item
-                .margin
-                .map(|margin| margin.resolve_to_option(container_outer_width, |val, basis| tree.calc(val, basis)));
+            let item_margin = item.margin.map(|margin| {
+                margin.resolve_to_option(container_outer_width, |val, basis| tree.calc(item.node_id, val, basis))
+            });
             let item_non_auto_margin = item_margin.map(|m| m.unwrap_or(0.0));
             let item_non_auto_x_margin_sum = item_non_auto_margin.horizontal_axis_sum();
+
+            // Block-level items are narrowed to fit beside the floats at their top edge. Items with a definite width
+            // which do not fit beside the floats are moved below them, as are items which clear the floats.
+            let mut item_inner_width = container_inner_width;
+            let mut item_inline_start = content_box_inline_start;
+            let mut clearance_y = None;
+            if !floats.is_empty() && item.inline_run.is_none() {
+                let mut y = committed_y_offset;
+                if !(is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start) {
+                    y += active_collapsible_margin_set.collapse_with_margin(item_non_auto_margin.top).resolve();
+                }
+                y = f32_max(y, floats.clearance_y(item.clear).unwrap_or(y));
+                let required_width = item.size.width.maybe_clamp(item.min_size.width, item.max_size.width);
+                let space = loop {
+                    let space = floats.available_space(y, y);
+                    let fits = required_width
+                        .map_or(true, |width| space.end - space.start >= width + item_non_auto_x_margin_sum);
+                    match floats.next_float_bottom(y, y) {
+                        Some(next_y) if !fits => y = next_y,
+                        _ => break space,
+                    }
+                };
+                item_inner_width = f32_max(space.end - space.start, 0.0);
+                item_inline_start = match direction {
+                    Direction::Ltr => space.start,
+                    Direction::Rtl => space.end,
+                };
+                clearance_y = Some(y);
+            }
+
+            let available_space =
+                Size { width: AvailableSpace::Definite(item_inner_width), height: AvailableSpace::MinContent };
             let known_dimensions = if item.is_table {
                 Size::NONE
             } else {
@@ -428,23 +798,56 @@ fn perform_final_layout_on_in_flow_children(
                     .map_width(|width| {
                         // TODO: Allow stretch-sizing to be conditional, as there are exceptions.
                         // e.g. Table children of blocks do not stretch fit
-                        Some(
-                            width
-                                .unwrap_or(container_inner_width - item_non_auto_x_margin_sum)
-                                .maybe_clamp(item.min_size.width, item.max_size.width),
-                        )
+                        let stretched_width =
+                            (!item.has_intrinsic_width).then_some(item_inner_width - item_non_auto_x_margin_sum);
+                        width.or(stretched_width).maybe_clamp(item.min_size.width, item.max_size.width)
                     })
                     .maybe_clamp(item.min_size, item.max_size)
             };
 
-            let item_layout = tree.perform_child_layout(
-                item.node_id,
+            // Anonymous block boxes are sized by the lines of their inline-level content, which flow around floats
+            let inline_layout: Option<InlineLayout> = item.inline_run.clone().map(|inline_run| {
+                let first_line_y = match is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start
+                {
+                    true => committed_y_offset,
+                    false => committed_y_offset + active_collapsible_margin_set.resolve(),
+                };
+                compute_inline_layout(
+                    tree,
+                    &inline_children[inline_run],
+                    AvailableSpace::Definite(container_inner_width),
+                    Some(container_inner_width),
+                    direction,
+                    text_align,
+                    Some((&mut floats, first_line_y)),
+                )
+            });
+            let item_inputs = LayoutInput {
                 known_dimensions,
                 parent_size,
-                available_space.map_width(|w| w.maybe_sub(item_non_auto_x_margin_sum)),
-                SizingMode::InherentSize,
-                Line::TRUE,
-            );
+                available_space: available_space.map_width(|w| w.maybe_sub(item_non_auto_x_margin_sum)),
+                sizing_mode: SizingMode::InherentSize,
+                axis: RequestedAxis::Both,
+                run_mode: RunMode::PerformLayout,
+                vertical_margins_are_collapsible: Line::TRUE,
+                containing_block: ContainingBlock::Deferred,
+            };
+            let item_layout = match &inline_layout {
+                Some(inline_layout) => {
+                    let size = Size { width: container_inner_width, height: inline_layout.height() };
+                    LayoutOutput {
+                        size,
+                        #[cfg(feature = "content_size")]
+                        content_size: size,
+                        scrollbar_size: Size::ZERO,
+                        first_baselines: Point { x: None, y: inline_layout.first_baseline() },
+                        top_margin: CollapsibleMarginSet::ZERO,
+                        bottom_margin: CollapsibleMarginSet::ZERO,
+                        margins_can_collapse_through: inline_layout.is_empty(),
+                    }
+                }
+                None => tree.compute_child_layout(item.node_id, item_inputs),
+            };
             let final_size = item_layout.size;
 
             let top_margin_set = item_layout.top_margin.collapse_with_margin(item_margin.top.unwrap_or(0.0));
@@ -453,7 +856,7 @@ fn perform_final_layout_on_in_flow_children(
             // Expand auto margins to fill available space
             // Note: Vertical auto-margins for relatively positioned block items simply resolve to 0.
             // See: https://www.w3.org/TR/CSS21/visudet.html#abs-non-replaced-width
-            let free_x_space = f32_max(0.0, container_inner_width - final_size.width - item_non_auto_x_margin_sum);
+            let free_x_space = f32_max(0.0, item_inner_width - final_size.width - item_non_auto_x_margin_sum);
             let x_axis_auto_margin_size = {
                 let auto_margin_count = item_margin.left.is_none() as u8 + item_margin.right.is_none() as u8;
                 if auto_margin_count > 0 {
@@ -469,80 +872,138 @@ fn perform_final_layout_on_in_flow_children(
                 bottom: bottom_margin_set.resolve(),
             };
 
-            // Resolve item inset
-            let inset = item.inset.zip_size(Size { width: container_inner_width, height: 0.0 }, |p, s| {
-                p.maybe_resolve(s, |val, basis| tree.calc(val, basis))
-            });
-            let inset_offset = Point {
-                x: inset.left.or(inset.right.map(|x| -x)).unwrap_or(0.0),
-                y: inset.top.or(inset.bottom.map(|x| -x)).unwrap_or(0.0),
-            };
+            let inset_offset = resolve_relative_offset(tree, item, container_inner_width, direction);
 
-            let y_margin_offset = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start {
+            let mut y_margin_offset = if is_collapsing_with_first_margin_set && own_margins_collapse_with_children.start
+            {
                 0.0
             } else {
                 active_collapsible_margin_set.collapse_with_margin(resolved_margin.top).resolve()
             };
 
+            // An item which would otherwise be placed above the floats it has to be placed below is given clearance,
+            // which separates its top margin from the margins above it
+            let has_clearance = matches!(clearance_y, Some(y) if committed_y_offset + y_margin_offset < y);
+            if let (true, Some(clearance_y)) = (has_clearance, clearance_y) {
+                y_margin_offset = clearance_y - committed_y_offset;
+            }
+
             item.computed_size = item_layout.size;
-            item.can_be_collapsed_through = item_layout.margins_can_collapse_through;
-            item.static_position = Point {
-                x: resolved_content_box_inset.left,
-                y: committed_y_offset + active_collapsible_margin_set.resolve(),
-            };
+            item.can_be_collapsed_through = item_layout.margins_can_collapse_through && !has_clearance;
+            item.static_position =
+                Point { x: content_box_inline_start, y: committed_y_offset + active_collapsible_margin_set.resolve() };
             let mut location = Point {
-                x: resolved_content_box_inset.left + inset_offset.x + resolved_margin.left,
+                x: match direction {
+                    Direction::Ltr => item_inline_start + inset_offset.x + resolved_margin.left,
+                    Direction::Rtl => item_inline_start + inset_offset.x - resolved_margin.right - final_size.width,
+                },
                 y: committed_y_offset + inset_offset.y + y_margin_offset,
             };
 
             // Apply alignment
             let item_outer_width = item_layout.size.width + resolved_margin.horizontal_axis_sum();
-            if item_outer_width < container_inner_width {
-                match text_align {
-                    TextAlign::Auto => {
-                        // Do nothing
+            if item_outer_width < item_inner_width {
+                let free_space = item_inner_width - item_outer_width;
+                match (text_align, direction) {
+                    (TextAlign::Auto, _) => {
+                        // Do nothing. Items are aligned to the inline-start edge by default.
+                    }
+                    (TextAlign::LegacyLeft, Direction::Ltr) | (TextAlign::LegacyRight, Direction::Rtl) => {
+                        // Do nothing. Already aligned to the requested edge.
                     }
-                    TextAlign::LegacyLeft => {
-                        // Do nothing. Left aligned by default.
+                    (TextAlign::LegacyLeft, Direction::Rtl) => location.x -= free_space,
+                    (TextAlign::LegacyRight, Direction::Ltr) => location.x += free_space,
+                    (TextAlign::LegacyCenter, Direction::Ltr) => location.x += free_space / 2.0,
+                    (TextAlign::LegacyCenter, Direction::Rtl) => location.x -= free_space / 2.0,
+                    (TextAlign::End | TextAlign::Left | TextAlign::Right | TextAlign::Center, _) => {
+                        // Do nothing. Only the legacy values align block-level children.
                     }
-                    TextAlign::LegacyRight => location.x += container_inner_width - item_outer_width,
-                    TextAlign::LegacyCenter => location.x += (container_inner_width - item_outer_width) / 2.0,
                 }
             }
 
-            let scrollbar_size = Size {
-                width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-                height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-            };
-
-            tree.set_unrounded_layout(
-                item.node_id,
-                &Layout {
-                    order: item.order,
-                    size: item_layout.size,
-                    #[cfg(feature = "content_size")]
-                    content_size: item_layout.content_size,
-                    scrollbar_size,
-                    location,
-                    padding: item.padding,
-                    border: item.border,
-                    margin: resolved_margin,
-                },
-            );
+            let flow_top = location.y - inset_offset.y;
+            item.flow_extent = Line { start: flow_top, end: flow_top + final_size.height };
+            location = location + item.column_offset;
+
+            if let Some(inline_layout) = inline_layout {
+                // Anonymous block boxes have no node of their own, so only the layouts of their contents are set
+                let origin = Point { x: resolved_content_box_inset.left, y: location.y };
+                inline_layout.perform_layout(
+                    tree,
+                    origin,
+                    container_inner_width,
+                    &item.line_column_offsets,
+                    static_children,
+                );
+                if record_line_extents {
+                    item.line_extents = inline_layout
+                        .line_extents()
+                        .map(|line| Line { start: location.y + line.start, end: location.y + line.end })
+                        .collect();
+                }
+                if first_baseline.is_none() {
+                    first_baseline = item_layout.first_baselines.y.map(|baseline| location.y + baseline);
+                }
+                #[cfg(feature = "content_size")]
+                {
+                    inflow_content_size =
+                        inflow_content_size.f32_max(inline_layout.float_content_size(&item.line_column_offsets));
+                }
+            } else {
+                if item.position == Position::Static {
+                    static_children.push(StaticChild {
+                        node_id: item.node_id,
+                        inputs: item_inputs,
+                        location,
+                        size: item_layout.size,
+                        inline_containing_block: None,
+                    });
+                }
+                tree.set_unrounded_layout(
+                    item.node_id,
+                    &Layout {
+                        order: item.order,
+                        size: item_layout.size,
+                        #[cfg(feature = "content_size")]
+                        content_size: item_layout.content_size,
+                        scrollbar_size: item_layout.scrollbar_size,
+                        location,
+                        padding: item.padding,
+                        border: item.border,
+                        margin: resolved_margin,
+                        visibility: item.visibility,
+                        ..Layout::new()
+                    },
+                );
+            }
 
             #[cfg(feature = "content_size")]
-            {
+            if item.line_column_offsets.is_empty() {
                 inflow_content_size = inflow_content_size.f32_max(compute_content_size_contribution(
                     location,
                     final_size,
                     item_layout.content_size,
                     item.overflow,
                 ));
+            } else {
+                // The lines of the anonymous block box have been moved into different columns
+                for (line, offset) in item.line_extents.iter().zip(&item.line_column_offsets) {
+                    let line_location = Point { x: location.x + offset.x, y: line.start + offset.y };
+                    let line_size = Size { width: final_size.width, height: line.end - line.start };
+                    inflow_content_size = inflow_content_size.f32_max(compute_content_size_contribution(
+                        line_location,
+                        line_size,
+                        line_size,
+                        item.overflow,
+                    ));
+                }
             }
 
             // Update first_child_top_margin_set
             if is_collapsing_with_first_margin_set {
-                if item.can_be_collapsed_through {
+                if has_clearance {
+                    is_collapsing_with_first_margin_set = false;
+                } else if item.can_be_collapsed_through {
                     first_child_top_margin_set = first_child_top_margin_set
                         .collapse_with_set(top_margin_set)
                         .collapse_with_set(bottom_margin_set);
@@ -571,8 +1032,161 @@ fn perform_final_layout_on_in_flow_children(
         if own_margins_collapse_with_children.end { 0.0 } else { last_child_bottom_margin_set.resolve() };
 
     committed_y_offset += resolved_content_box_inset.bottom + bottom_y_margin_offset;
+
+    // The container grows to contain its floats
+    if let Some(float_bottom) = floats.bottom() {
+        committed_y_offset = f32_max(committed_y_offset, float_bottom + resolved_content_box_inset.bottom);
+    }
+
     let content_height = f32_max(0.0, committed_y_offset);
-    (inflow_content_size, content_height, first_child_top_margin_set, last_child_bottom_margin_set)
+    (inflow_content_size, content_height, first_child_top_margin_set, last_child_bottom_margin_set, first_baseline)
+}
+
+/// Divide the in-flow content of a multi-column container into columns. Columns may be broken between block-level
+/// items and between the lines of anonymous block boxes.
+#[inline]
+fn balance_columns(
+    items: &[BlockItem],
+    flow_start: f32,
+    geometry: ColumnGeometry,
+    max_column_height: Option<f32>,
+) -> ColumnBreaks {
+    let mut units = Vec::new();
+    for item in items.iter().filter(|item| !item.position.is_absolutely_positioned() && item.float == Float::None) {
+        match item.inline_run {
+            Some(_) => units.extend(item.line_extents.iter().copied()),
+            None => units.push(item.flow_extent),
+        }
+    }
+    ColumnBreaks::balance(&units, flow_start, geometry.count, max_column_height)
+}
+
+/// Collect the column geometry of a multi-column container and the fragments of its block-level children
+#[cfg(feature = "detailed_layout_info")]
+#[inline]
+fn detailed_multicol_info(
+    tree: &impl LayoutBlockContainer,
+    node_id: NodeId,
+    items: &[BlockItem],
+    geometry: ColumnGeometry,
+    breaks: &ColumnBreaks,
+    direction: Direction,
+) -> DetailedMulticolInfo {
+    let item_info = |child: NodeId| {
+        let item = items.iter().find(|item| {
+            item.node_id == child && item.inline_run.is_none() && !item.position.is_absolutely_positioned()
+        });
+        DetailedMulticolItemInfo {
+            fragments: item.map_or_else(Vec::new, |item| {
+                breaks.child_fragments(item.computed_size.width, item.flow_extent, &geometry, direction)
+            }),
+        }
+    };
+    DetailedMulticolInfo {
+        column_count: geometry.count,
+        filled_column_count: breaks.column_count() as u16,
+        column_width: geometry.width,
+        column_gap: geometry.gap,
+        column_height: breaks.height,
+        items: tree.child_ids(node_id).map(item_info).collect(),
+    }
+}
+
+/// Resolve the offset of a relatively positioned item from its normal position
+#[inline]
+fn resolve_relative_offset(
+    tree: &impl LayoutBlockContainer,
+    item: &BlockItem,
+    container_inner_width: f32,
+    direction: Direction,
+) -> Point<f32> {
+    let inset = item.inset.zip_size(Size { width: container_inner_width, height: 0.0 }, |p, s| {
+        p.maybe_resolve(s, |val, basis| tree.calc(item.node_id, val, basis))
+    });
+    // The insets of sticky items constrain their position after layout rather than offsetting them, and the insets
+    // of static items are ignored
+    match item.position {
+        Position::Sticky | Position::Static => Point::ZERO,
+        _ => Point {
+            x: match direction {
+                Direction::Ltr => inset.left.or(inset.right.map(|x| -x)).unwrap_or(0.0),
+                Direction::Rtl => inset.right.map(|x| -x).or(inset.left).unwrap_or(0.0),
+            },
+            y: inset.top.or(inset.bottom.map(|x| -x)).unwrap_or(0.0),
+        },
+    }
+}
+
+/// Lay out a floated item and place it beside the container's earlier floats. The item is sized to fit its content,
+/// and its auto margins resolve to zero. Returns the location and layout output of the item.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+fn perform_float_layout(
+    tree: &mut impl LayoutBlockContainer,
+    item: &mut BlockItem,
+    floats: &mut FloatContext,
+    y: f32,
+    container_outer_width: f32,
+    container_inner_width: f32,
+    direction: Direction,
+    static_children: &mut Vec<StaticChild>,
+) -> (Point<f32>, LayoutOutput) {
+    let margin =
+        item.margin.resolve_or_zero(Some(container_outer_width), |val, basis| tree.calc(item.node_id, val, basis));
+    let inputs = LayoutInput {
+        known_dimensions: item.size.maybe_clamp(item.min_size, item.max_size),
+        parent_size: Size { width: Some(container_outer_width), height: None },
+        available_space: Size {
+            width: AvailableSpace::Definite(container_inner_width - margin.horizontal_axis_sum()),
+            height: AvailableSpace::MinContent,
+        },
+        sizing_mode: SizingMode::InherentSize,
+        axis: RequestedAxis::Both,
+        run_mode: RunMode::PerformLayout,
+        vertical_margins_are_collapsible: Line::FALSE,
+        containing_block: ContainingBlock::Deferred,
+    };
+    let item_layout = tree.compute_child_layout(item.node_id, inputs);
+
+    let margin_box_size = item_layout.size + margin.sum_axes();
+    let margin_box_location = floats.place(item.float, item.clear, margin_box_size, y);
+    let inset_offset = resolve_relative_offset(tree, item, container_inner_width, direction);
+    let flow_top = margin_box_location.y + margin.top;
+    item.flow_extent = Line { start: flow_top, end: flow_top + item_layout.size.height };
+    let location = Point { x: margin_box_location.x + margin.left + inset_offset.x, y: flow_top + inset_offset.y }
+        + item.column_offset;
+
+    item.computed_size = item_layout.size;
+    item.static_position = location;
+    item.can_be_collapsed_through = false;
+    if item.position == Position::Static {
+        static_children.push(StaticChild {
+            node_id: item.node_id,
+            inputs,
+            location,
+            size: item_layout.size,
+            inline_containing_block: None,
+        });
+    }
+
+    tree.set_unrounded_layout(
+        item.node_id,
+        &Layout {
+            order: item.order,
+            size: item_layout.size,
+            #[cfg(feature = "content_size")]
+            content_size: item_layout.content_size,
+            scrollbar_size: item_layout.scrollbar_size,
+            location,
+            padding: item.padding,
+            border: item.border,
+            margin,
+            visibility: item.visibility,
+            ..Layout::new()
+        },
+    );
+
+    (location, item_layout)
 }
 
 /// Perform absolute layout on all absolutely positioned children.
@@ -580,55 +1194,65 @@ fn perform_final_layout_on_in_flow_children(
 fn perform_absolute_layout_on_absolute_children(
     tree: &mut impl LayoutBlockContainer,
     items: &[BlockItem],
-    area_size: Size<f32>,
-    area_offset: Point<f32>,
+    containing_block: AbsoluteContainingBlock,
+    container_size: Size<f32>,
+    direction: Direction,
 ) -> Size<f32> {
+    let area_size = containing_block.size(container_size);
+    let area_offset = Point { x: containing_block.insets.left, y: containing_block.insets.top };
     let area_width = area_size.width;
     let area_height = area_size.height;
 
     #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
     let mut absolute_content_size = Size::ZERO;
 
-    for item in items.iter().filter(|item| item.position == Position::Absolute) {
+    for item in items.iter().filter(|item| item.position.is_absolutely_positioned()) {
         let child_style = tree.get_block_child_style(item.node_id);
 
         // Skip items that are display:none or are not position:absolute
-        if child_style.box_generation_mode() == BoxGenerationMode::None || child_style.position() != Position::Absolute
+        if child_style.box_generation_mode() == BoxGenerationMode::None
+            || !child_style.position().is_absolutely_positioned()
         {
             continue;
         }
 
         let aspect_ratio = child_style.aspect_ratio();
-        let margin =
-            child_style.margin().map(|margin| margin.resolve_to_option(area_width, |val, basis| tree.calc(val, basis)));
-        let padding = child_style.padding().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
-        let border = child_style.border().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
+        let has_intrinsic_size = child_style.size().map(Dimension::is_intrinsic_sizing_keyword);
+        let margin = child_style
+            .margin()
+            .map(|margin| margin.resolve_to_option(area_width, |val, basis| tree.calc(item.node_id, val, basis)));
+        let padding =
+            child_style.padding().resolve_or_zero(Some(area_width), |val, basis| tree.calc(item.node_id, val, basis));
+        let border =
+            child_style.border().resolve_or_zero(Some(area_width), |val, basis| tree.calc(item.node_id, val, basis));
         let padding_border_sum = (padding + border).sum_axes();
         let box_sizing_adjustment =
             if child_style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
 
         // Resolve inset
-        let left = child_style.inset().left.maybe_resolve(area_width, |val, basis| tree.calc(val, basis));
-        let right = child_style.inset().right.maybe_resolve(area_width, |val, basis| tree.calc(val, basis));
-        let top = child_style.inset().top.maybe_resolve(area_height, |val, basis| tree.calc(val, basis));
-        let bottom = child_style.inset().bottom.maybe_resolve(area_height, |val, basis| tree.calc(val, basis));
+        let left = child_style.inset().left.maybe_resolve(area_width, |val, basis| tree.calc(item.node_id, val, basis));
+        let right =
+            child_style.inset().right.maybe_resolve(area_width, |val, basis| tree.calc(item.node_id, val, basis));
+        let top = child_style.inset().top.maybe_resolve(area_height, |val, basis| tree.calc(item.node_id, val, basis));
+        let bottom =
+            child_style.inset().bottom.maybe_resolve(area_height, |val, basis| tree.calc(item.node_id, val, basis));
 
         // Compute known dimensions from min/max/inherent size styles
         let style_size = child_style
             .size()
-            .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(area_size, |val, basis| tree.calc(item.node_id, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
         let min_size = child_style
             .min_size()
-            .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(area_size, |val, basis| tree.calc(item.node_id, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment)
             .or(padding_border_sum.map(Some))
             .maybe_max(padding_border_sum);
         let max_size = child_style
             .max_size()
-            .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(area_size, |val, basis| tree.calc(item.node_id, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
         let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
@@ -638,7 +1262,9 @@ fn perform_absolute_layout_on_absolute_children(
         // Fill in width from left/right and reapply aspect ratio if:
         //   - Width is not already known
         //   - Item has both left and right inset properties set
-        if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
+        //   - Item's width is not an intrinsic sizing keyword
+        if let (None, Some(left), Some(right), false) = (known_dimensions.width, left, right, has_intrinsic_size.width)
+        {
             let new_width_raw = area_width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
             known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
             known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
@@ -647,7 +1273,10 @@ fn perform_absolute_layout_on_absolute_children(
         // Fill in height from top/bottom and reapply aspect ratio if:
         //   - Height is not already known
         //   - Item has both top and bottom inset properties set
-        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
+        //   - Item's height is not an intrinsic sizing keyword
+        if let (None, Some(top), Some(bottom), false) =
+            (known_dimensions.height, top, bottom, has_intrinsic_size.height)
+        {
             let new_height_raw = area_height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
             known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
             known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
@@ -661,7 +1290,12 @@ fn perform_absolute_layout_on_absolute_children(
                 width: AvailableSpace::Definite(area_width.maybe_clamp(min_size.width, max_size.width)),
                 height: AvailableSpace::Definite(area_height.maybe_clamp(min_size.height, max_size.height)),
             },
-            SizingMode::ContentSize,
+            // Intrinsic sizing keywords are resolved by the child itself
+            if has_intrinsic_size.width || has_intrinsic_size.height {
+                SizingMode::InherentSize
+            } else {
+                SizingMode::ContentSize
+            },
             Line::FALSE,
         );
         let measured_size = layout_output.size;
@@ -739,24 +1373,26 @@ fn perform_absolute_layout_on_absolute_children(
             bottom: margin.bottom.unwrap_or(auto_margin.bottom),
         };
 
+        // If the values are over-constrained, the inset on the inline-start side takes precedence
+        let left_position = left.map(|left| left + resolved_margin.left);
+        let right_position = right.map(|right| area_size.width - final_size.width - right - resolved_margin.right);
         let location = Point {
-            x: left
-                .map(|left| left + resolved_margin.left)
-                .or(right.map(|right| area_size.width - final_size.width - right - resolved_margin.right))
-                .maybe_add(area_offset.x)
-                .unwrap_or(item.static_position.x + resolved_margin.left),
+            x: match direction {
+                Direction::Ltr => left_position
+                    .or(right_position)
+                    .maybe_add(area_offset.x)
+                    .unwrap_or(item.static_position.x + resolved_margin.left),
+                Direction::Rtl => right_position
+                    .or(left_position)
+                    .maybe_add(area_offset.x)
+                    .unwrap_or(item.static_position.x - final_size.width - resolved_margin.right),
+            },
             y: top
                 .map(|top| top + resolved_margin.top)
                 .or(bottom.map(|bottom| area_size.height - final_size.height - bottom - resolved_margin.bottom))
                 .maybe_add(area_offset.y)
                 .unwrap_or(item.static_position.y + resolved_margin.top),
         };
-        // Note: axis intentionally switched here as scrollbars take up space in the opposite axis
-        // to the axis in which scrolling is enabled.
-        let scrollbar_size = Size {
-            width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-            height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-        };
 
         tree.set_unrounded_layout(
             item.node_id,
@@ -765,16 +1401,19 @@ fn perform_absolute_layout_on_absolute_children(
                 size: final_size,
                 #[cfg(feature = "content_size")]
                 content_size: layout_output.content_size,
-                scrollbar_size,
+                scrollbar_size: layout_output.scrollbar_size,
                 location,
                 padding,
                 border,
                 margin: resolved_margin,
+                visibility: item.visibility,
+                ..Layout::new()
             },
         );
 
+        // Only the containing block's content size includes the child
         #[cfg(feature = "content_size")]
-        {
+        if containing_block.is_own {
             absolute_content_size = absolute_content_size.f32_max(compute_content_size_contribution(
                 location,
                 final_size,
@@ -0,0 +1,126 @@
+//! Resolves the containing block of absolutely positioned boxes, which is the padding box of their nearest positioned
+//! ancestor (or of the root). A layout algorithm only has access to the children of the node that it is laying out,
+//! so the containing block of the absolutely positioned children of a `Position::Static` node is passed to it by its
+//! parent (see [`LayoutInput::containing_block`](crate::LayoutInput::containing_block)).
+use crate::geometry::{Point, Rect, Size};
+use crate::style::Position;
+use crate::tree::ContainingBlock;
+#[cfg(any(feature = "grid", feature = "block_layout"))]
+use crate::tree::{LayoutInput, LayoutPartialTree, NodeId};
+
+/// The containing block of the absolutely positioned children of the node being laid out
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AbsoluteContainingBlock {
+    /// The distance of each edge of the containing block inward from the corresponding edge of the node's border box
+    pub(crate) insets: Rect<f32>,
+    /// Whether the containing block is the node's own padding box. Absolutely positioned children only contribute to
+    /// the content size of their containing block.
+    #[cfg_attr(not(any(feature = "flexbox", feature = "grid", feature = "content_size")), allow(dead_code))]
+    pub(crate) is_own: bool,
+}
+
+impl AbsoluteContainingBlock {
+    /// Resolves the containing block of the absolutely positioned children of a node with the specified `position`,
+    /// given the containing block passed to the node and the insets of its own padding box from its border box.
+    ///
+    /// Returns `None` if the containing block belongs to an ancestor whose layout is not known yet, in which case the
+    /// node's absolutely positioned children should not be laid out.
+    pub(crate) fn resolve(
+        containing_block: ContainingBlock,
+        position: Position,
+        padding_box_insets: Rect<f32>,
+    ) -> Option<Self> {
+        match (position, containing_block) {
+            (Position::Static, ContainingBlock::Deferred) => None,
+            (Position::Static, ContainingBlock::Ancestor(insets)) => Some(Self { insets, is_own: false }),
+            _ => Some(Self { insets: padding_box_insets, is_own: true }),
+        }
+    }
+
+    /// The size of the containing block given the border-box size of the node
+    #[cfg(any(feature = "flexbox", feature = "block_layout"))]
+    #[inline(always)]
+    pub(crate) fn size(&self, container_size: Size<f32>) -> Size<f32> {
+        container_size - self.insets.sum_axes()
+    }
+
+    /// The edges of the containing block relative to the border box of the node, given its border-box size
+    #[inline(always)]
+    pub(crate) fn edges(&self, container_size: Size<f32>) -> Rect<f32> {
+        Rect {
+            left: self.insets.left,
+            right: container_size.width - self.insets.right,
+            top: self.insets.top,
+            bottom: container_size.height - self.insets.bottom,
+        }
+    }
+}
+
+/// The final layout of a `Position::Static` child, which is laid out again once the containing block of its absolutely
+/// positioned descendants is known
+#[cfg(any(feature = "grid", feature = "block_layout"))]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StaticChild {
+    /// The node of the child
+    pub(crate) node_id: NodeId,
+    /// The inputs of the child's final layout
+    pub(crate) inputs: LayoutInput,
+    /// The location of the child's border box relative to the border box of the container
+    pub(crate) location: Point<f32>,
+    /// The size of the child's border box
+    pub(crate) size: Size<f32>,
+    /// The edges of the padding box of a positioned inline box which contains the child (relative to the border box
+    /// of the container), which is the containing block instead of that of the container
+    pub(crate) inline_containing_block: Option<Rect<f32>>,
+}
+
+/// Lays out `children` again against the containing block of the absolutely positioned children of their container
+/// (if it is known), given the border-box size of the container
+#[cfg(any(feature = "grid", feature = "block_layout"))]
+pub(crate) fn perform_static_child_layouts(
+    tree: &mut impl LayoutPartialTree,
+    children: &[StaticChild],
+    containing_block: Option<AbsoluteContainingBlock>,
+    container_size: Size<f32>,
+) {
+    let Some(containing_block) = containing_block else { return };
+    let edges = containing_block.edges(container_size);
+    for child in children {
+        let edges = child.inline_containing_block.unwrap_or(edges);
+        let containing_block = ancestor_containing_block(edges, child.location, child.size);
+        tree.compute_child_layout(child.node_id, LayoutInput { containing_block, ..child.inputs });
+    }
+}
+
+/// The containing block to pass to a child with the specified `position`, border-box `size` and `location` relative to
+/// the border box of a container of size `container_size` whose absolutely positioned children have the containing
+/// block `containing_block`
+#[cfg(feature = "flexbox")]
+pub(crate) fn containing_block_for_child(
+    containing_block: Option<AbsoluteContainingBlock>,
+    position: Position,
+    container_size: Size<f32>,
+    location: Point<f32>,
+    size: Size<f32>,
+) -> ContainingBlock {
+    match containing_block {
+        // Positioned children are the containing block of their own absolutely positioned children, so are passed
+        // `Deferred` to avoid bypassing the layout cache
+        Some(containing_block) if position == Position::Static => {
+            ancestor_containing_block(containing_block.edges(container_size), location, size)
+        }
+        _ => ContainingBlock::Deferred,
+    }
+}
+
+/// The containing block with the specified `edges` as seen by a box with the specified `location` and `size` (all
+/// relative to the same container)
+#[inline(always)]
+pub(crate) fn ancestor_containing_block(edges: Rect<f32>, location: Point<f32>, size: Size<f32>) -> ContainingBlock {
+    ContainingBlock::Ancestor(Rect {
+        left: edges.left - location.x,
+        right: location.x + size.width - edges.right,
+        top: edges.top - location.y,
+        bottom: location.y + size.height - edges.bottom,
+    })
+}
@@ -2,6 +2,13 @@
 use crate::geometry::{Point, Size};
 use crate::style::Overflow;
 use crate::util::sys::f32_max;
+#[cfg(feature = "content_size")]
+use crate::{
+    geometry::Rect,
+    style::{Direction, WritingMode},
+    tree::Layout,
+    util::sys::f32_min,
+};
 
 #[inline(always)]
 /// Determine how much width/height a given node contributes to it's parent's content size
@@ -30,3 +37,116 @@ pub(crate) fn compute_content_size_contribution(
         Size::ZERO
     }
 }
+
+/// Determine the area of its parent's scrollable overflow rectangle which a node covers: its border box and, in the
+/// axes in which it doesn't clip its content, its own scrollable overflow rectangle. The edges of the returned
+/// rectangle are relative to the top-left corner of the parent's border box.
+#[cfg(feature = "content_size")]
+pub(crate) fn compute_scrollable_overflow_contribution(
+    location: Point<f32>,
+    layout: &Layout,
+    overflow: Point<Overflow>,
+) -> Rect<f32> {
+    // Boxes with zero area do not contribute their border box, but may still contribute their overflowing content
+    let border_box = match layout.size.has_non_zero_area() {
+        true => edges(location, layout.size),
+        false => Rect::zero(),
+    };
+    let overflow_rect = edges(
+        Point {
+            x: location.x + layout.scrollable_overflow_location.x,
+            y: location.y + layout.scrollable_overflow_location.y,
+        },
+        layout.scrollable_overflow_size,
+    );
+
+    let horizontal = match overflow.x {
+        Overflow::Visible => union(border_box, overflow_rect),
+        _ => border_box,
+    };
+    let vertical = match overflow.y {
+        Overflow::Visible => union(border_box, overflow_rect),
+        _ => border_box,
+    };
+    Rect { left: horizontal.left, right: horizontal.right, top: vertical.top, bottom: vertical.bottom }
+}
+
+/// Determine the scrollable overflow rectangle of a node, as the location and size stored in its [`Layout`], from the
+/// union of the `contributions` of the boxes which it is the containing block of and the union of the margin boxes of
+/// its in-flow children (`inflow_bounds`), both of which are relative to the top-left corner of the node's border box.
+///
+/// <https://www.w3.org/TR/css-overflow-3/#scrollable>
+#[cfg(feature = "content_size")]
+pub(crate) fn compute_scrollable_overflow(
+    layout: &Layout,
+    contributions: Rect<f32>,
+    inflow_bounds: Rect<f32>,
+    is_scroll_container: bool,
+    writing_mode: WritingMode,
+    direction: Direction,
+) -> (Point<f32>, Size<f32>) {
+    // The vertical scrollbar is placed on the inline-end side, which is the left side for right-to-left nodes
+    let (scrollbar_left, scrollbar_right) = match direction.is_rtl() {
+        true => (layout.scrollbar_size.width, 0.0),
+        false => (0.0, layout.scrollbar_size.width),
+    };
+    let padding_box = Rect {
+        left: layout.border.left + scrollbar_left,
+        right: layout.size.width - layout.border.right - scrollbar_right,
+        top: layout.border.top,
+        bottom: layout.size.height - layout.border.bottom - layout.scrollbar_size.height,
+    };
+    let mut overflow = union(padding_box, contributions);
+
+    // The in-flow content of a scroll container is followed by the container's padding on its end sides, so that the
+    // padding remains visible when the container is scrolled to the end of its content
+    if is_scroll_container && has_non_zero_area(inflow_bounds) {
+        let (left_is_end, top_is_end) = match writing_mode {
+            WritingMode::HorizontalTb => (direction.is_rtl(), false),
+            WritingMode::VerticalRl => (true, direction.is_rtl()),
+            WritingMode::VerticalLr => (false, direction.is_rtl()),
+        };
+        let padded_bounds = Rect {
+            left: inflow_bounds.left - if left_is_end { layout.padding.left } else { 0.0 },
+            right: inflow_bounds.right + if left_is_end { 0.0 } else { layout.padding.right },
+            top: inflow_bounds.top - if top_is_end { layout.padding.top } else { 0.0 },
+            bottom: inflow_bounds.bottom + if top_is_end { 0.0 } else { layout.padding.bottom },
+        };
+        overflow = union(overflow, padded_bounds);
+    }
+
+    (
+        Point { x: overflow.left, y: overflow.top },
+        Size { width: overflow.right - overflow.left, height: overflow.bottom - overflow.top },
+    )
+}
+
+/// The edges of the rectangle with the given top-left corner and size
+#[cfg(feature = "content_size")]
+#[inline(always)]
+pub(crate) fn edges(location: Point<f32>, size: Size<f32>) -> Rect<f32> {
+    Rect { left: location.x, right: location.x + size.width, top: location.y, bottom: location.y + size.height }
+}
+
+/// The smallest rectangle which contains both rectangles. Rectangles with zero area are ignored.
+#[cfg(feature = "content_size")]
+#[inline(always)]
+pub(crate) fn union(a: Rect<f32>, b: Rect<f32>) -> Rect<f32> {
+    match (has_non_zero_area(a), has_non_zero_area(b)) {
+        (_, false) => a,
+        (false, true) => b,
+        (true, true) => Rect {
+            left: f32_min(a.left, b.left),
+            right: f32_max(a.right, b.right),
+            top: f32_min(a.top, b.top),
+            bottom: f32_max(a.bottom, b.bottom),
+        },
+    }
+}
+
+/// Whether the rectangle with the given edges has a non-zero area
+#[cfg(feature = "content_size")]
+#[inline(always)]
+fn has_non_zero_area(rect: Rect<f32>) -> bool {
+    rect.right > rect.left && rect.bottom > rect.top
+}
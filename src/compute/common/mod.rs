@@ -1,5 +1,11 @@
 //! Generic code that is shared between multiple layout algorithms
 pub(crate) mod alignment;
 
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
+pub(crate) mod containing_block;
 #[cfg(feature = "content_size")]
 pub(crate) mod content_size;
+pub(crate) mod scrollbars;
+pub(crate) mod sizing_keywords;
+#[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
+pub(crate) mod writing_mode;
@@ -0,0 +1,115 @@
+//! Reserves space for the scrollbars of scroll containers. `Overflow::Scroll` nodes always reserve space for their
+//! scrollbars, whereas `Overflow::Auto` nodes only reserve space for a scrollbar if their content overflows them in the
+//! axis in which it scrolls. The `scrollbar_gutter` style additionally reserves space for the vertical scrollbar of
+//! scroll containers which do not (yet) have one.
+use crate::geometry::{Point, Rect, Size};
+use crate::style::{CoreStyle, Direction, Overflow, ScrollbarGutter};
+use crate::tree::LayoutOutput;
+use crate::util::ResolveOrZero;
+
+/// The styles of a node which determine the space that it reserves for its scrollbars
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Scrollbars {
+    /// The overflow style of the node
+    overflow: Point<Overflow>,
+    /// The thickness of the vertical (`width`) and horizontal (`height`) scrollbars
+    scrollbar_width: Size<f32>,
+    /// Whether space is reserved for the vertical scrollbar before the node has one
+    scrollbar_gutter: ScrollbarGutter,
+    /// The direction of the node, which determines which side the vertical scrollbar is placed on
+    direction: Direction,
+    /// The resolved border of the node
+    #[cfg_attr(not(feature = "content_size"), allow(dead_code))]
+    border: Rect<f32>,
+}
+
+impl Scrollbars {
+    /// Collect the scrollbar styles of a node
+    pub(crate) fn new(
+        style: &impl CoreStyle,
+        parent_width: Option<f32>,
+        resolve_calc_value: impl Fn(u64, f32) -> f32,
+    ) -> Self {
+        Scrollbars {
+            overflow: style.overflow(),
+            scrollbar_width: style.scrollbar_width(),
+            scrollbar_gutter: style.scrollbar_gutter(),
+            direction: style.direction(),
+            border: style.border().resolve_or_zero(parent_width, resolve_calc_value),
+        }
+    }
+
+    /// The size of the scrollbars which are present in each axis. The axes are switched (transposed) because a node that
+    /// scrolls vertically needs *horizontal* space to be reserved for its scrollbar.
+    #[inline(always)]
+    fn scrollbar_size(&self, has_scrollbar: Point<bool>) -> Size<f32> {
+        Size {
+            width: if has_scrollbar.y { self.scrollbar_width.width } else { 0.0 },
+            height: if has_scrollbar.x { self.scrollbar_width.height } else { 0.0 },
+        }
+    }
+
+    /// The space reserved for the scrollbars on each side of the node, given the scrollbars which are present
+    fn gutter(&self, has_scrollbar: Point<bool>) -> Rect<f32> {
+        let stable = self.scrollbar_gutter != ScrollbarGutter::Auto && self.overflow.y.is_scroll_container();
+        let vertical = if has_scrollbar.y || stable { self.scrollbar_width.width } else { 0.0 };
+        let horizontal = if has_scrollbar.x { self.scrollbar_width.height } else { 0.0 };
+        let both_edges = self.scrollbar_gutter == ScrollbarGutter::StableBothEdges && stable;
+
+        // The vertical scrollbar is placed on the inline-end side of the node
+        let (inline_start, inline_end) = (if both_edges { vertical } else { 0.0 }, vertical);
+        match self.direction {
+            Direction::Ltr => Rect { top: 0.0, left: inline_start, right: inline_end, bottom: horizontal },
+            Direction::Rtl => Rect { top: 0.0, left: inline_end, right: inline_start, bottom: horizontal },
+        }
+    }
+}
+
+/// Compute the layout of a node with `compute`, which is passed the space that the node should reserve for its
+/// scrollbars on each side, between its border and its padding.
+///
+/// The node is first laid out with scrollbars in the axes in which its `overflow` is `Overflow::Scroll`. If its
+/// `overflow` is `Overflow::Auto` in an axis and its content overflows it in that axis, then it is laid out again with
+/// a scrollbar in that axis (unless space had already been reserved for it by the `scrollbar_gutter` style). The size
+/// of the scrollbars is reported in the `scrollbar_size` of the output.
+///
+/// Overflow is detected using the content size of the output, so without the `content_size` feature `Overflow::Auto`
+/// nodes never have scrollbars.
+pub(crate) fn compute_with_scrollbars(
+    scrollbars: Scrollbars,
+    mut compute: impl FnMut(Rect<f32>) -> LayoutOutput,
+) -> LayoutOutput {
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut has_scrollbar = scrollbars.overflow.map(|overflow| overflow == Overflow::Scroll);
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut gutter = scrollbars.gutter(has_scrollbar);
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut output = compute(gutter);
+
+    // A scrollbar which appears in one axis reduces the space available to the content in the other axis, which may
+    // cause the content to overflow in that axis too. So the node is laid out at most once more per axis.
+    #[cfg(feature = "content_size")]
+    for _ in 0..2 {
+        let overflows = Point {
+            x: output.content_size.width + gutter.right + scrollbars.border.right > output.size.width,
+            y: output.content_size.height + gutter.bottom + scrollbars.border.bottom > output.size.height,
+        };
+        let appears = Point {
+            x: scrollbars.overflow.x == Overflow::Auto && !has_scrollbar.x && overflows.x,
+            y: scrollbars.overflow.y == Overflow::Auto && !has_scrollbar.y && overflows.y,
+        };
+        if !appears.x && !appears.y {
+            break;
+        }
+        has_scrollbar = Point { x: has_scrollbar.x || appears.x, y: has_scrollbar.y || appears.y };
+
+        // A scrollbar which appears within a stable gutter doesn't change the layout of the content
+        let new_gutter = scrollbars.gutter(has_scrollbar);
+        if new_gutter != gutter {
+            gutter = new_gutter;
+            output = compute(gutter);
+        }
+    }
+
+    LayoutOutput { scrollbar_size: scrollbars.scrollbar_size(has_scrollbar), ..output }
+}
@@ -0,0 +1,163 @@
+//! Resolves the intrinsic sizing keywords (`min-content`, `max-content`, `fit-content()` and `stretch`) in the `size`,
+//! `min_size` and `max_size` styles of a node. See <https://www.w3.org/TR/css-sizing-3/#sizing-values>
+//!
+//! Keywords resolve to `None` when the styles are resolved by [`MaybeResolve`], so by default they behave like `auto`.
+//! Each layout algorithm instead wraps its computation of a node's layout in [`compute_with_sizing_keywords`], which
+//! measures the node under the corresponding available space and passes the resulting sizes on as known dimensions.
+use crate::geometry::{AbsoluteAxis, Size};
+use crate::style::{AvailableSpace, BoxSizing, CompactLength, CoreStyle, Dimension};
+use crate::tree::{LayoutInput, LayoutOutput, RunMode, SizingMode};
+use crate::util::sys::{f32_max, f32_min};
+use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
+
+/// The size styles of a node which uses intrinsic sizing keywords, along with the values needed to resolve them
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SizingKeywords {
+    /// The preferred size of the node
+    size: Size<Dimension>,
+    /// The minimum size of the node
+    min_size: Size<Dimension>,
+    /// The maximum size of the node
+    max_size: Size<Dimension>,
+    /// The minimum size of the node in the axes which are not keywords, as a border-box size
+    resolved_min_size: Size<Option<f32>>,
+    /// The maximum size of the node in the axes which are not keywords, as a border-box size
+    resolved_max_size: Size<Option<f32>>,
+    /// The sum of the node's margins in each axis
+    margin_sum: Size<f32>,
+    /// The amount which is added to sizes specified by the node's styles to convert them into border-box sizes
+    box_sizing_adjustment: Size<f32>,
+}
+
+impl SizingKeywords {
+    /// Collect the size styles of a node, returning `None` if they do not contain any intrinsic sizing keywords
+    pub(crate) fn new(
+        style: &impl CoreStyle,
+        parent_size: Size<Option<f32>>,
+        resolve_calc_value: impl Fn(u64, f32) -> f32,
+    ) -> Option<Self> {
+        let (size, min_size, max_size) = (style.size(), style.min_size(), style.max_size());
+        let uses_keywords = [size, min_size, max_size]
+            .iter()
+            .any(|size| size.width.is_sizing_keyword() || size.height.is_sizing_keyword());
+        if !uses_keywords {
+            return None;
+        }
+
+        // Note: both horizontal and vertical percentage padding/borders/margins are resolved against the container's inline size
+        let padding = style.padding().resolve_or_zero(parent_size.width, &resolve_calc_value);
+        let border = style.border().resolve_or_zero(parent_size.width, &resolve_calc_value);
+        let box_sizing_adjustment =
+            if style.box_sizing() == BoxSizing::ContentBox { (padding + border).sum_axes() } else { Size::ZERO };
+
+        Some(SizingKeywords {
+            size,
+            min_size,
+            max_size,
+            resolved_min_size: min_size
+                .maybe_resolve(parent_size, &resolve_calc_value)
+                .maybe_add(box_sizing_adjustment),
+            resolved_max_size: max_size
+                .maybe_resolve(parent_size, &resolve_calc_value)
+                .maybe_add(box_sizing_adjustment),
+            margin_sum: style.margin().resolve_or_zero(parent_size.width, &resolve_calc_value).sum_axes(),
+            box_sizing_adjustment,
+        })
+    }
+
+    /// Resolve the keywords of the node in the specified axis to the border-box size which the node should have in that
+    /// axis, or `None` if its size in that axis is determined by the layout algorithm. A size which is already known in
+    /// that axis is only clamped by keyword minimum and maximum sizes.
+    fn resolve_axis(
+        &self,
+        axis: AbsoluteAxis,
+        inputs: LayoutInput,
+        compute: &mut impl FnMut(LayoutInput) -> LayoutOutput,
+    ) -> Option<f32> {
+        let known_size = inputs.known_dimensions.get_abs(axis);
+        let stretch_size =
+            inputs.available_space.get_abs(axis).into_option().map(|size| size - self.margin_sum.get_abs(axis));
+
+        // Measure the node's content under the specified available space in this axis
+        let mut measure = |available_space: AvailableSpace| {
+            let inputs = LayoutInput {
+                known_dimensions: with_axis(inputs.known_dimensions, axis, None),
+                available_space: with_axis(inputs.available_space, axis, available_space),
+                sizing_mode: SizingMode::ContentSize,
+                run_mode: RunMode::ComputeSize,
+                ..inputs
+            };
+            compute(inputs).size.get_abs(axis)
+        };
+        let mut resolve_keyword = |value: Dimension| {
+            let raw = value.into_raw();
+            match raw.tag() {
+                CompactLength::MIN_CONTENT_TAG => Some(measure(AvailableSpace::MinContent)),
+                CompactLength::MAX_CONTENT_TAG => Some(measure(AvailableSpace::MaxContent)),
+                CompactLength::FIT_CONTENT_PX_TAG | CompactLength::FIT_CONTENT_PERCENT_TAG => {
+                    let limit = match raw.tag() {
+                        CompactLength::FIT_CONTENT_PX_TAG => Some(raw.value()),
+                        _ => inputs.parent_size.get_abs(axis).map(|parent_size| parent_size * raw.value()),
+                    };
+                    let limit = limit.map(|limit| limit + self.box_sizing_adjustment.get_abs(axis)).or(stretch_size);
+                    let max_content_size = measure(AvailableSpace::MaxContent);
+                    Some(match limit {
+                        Some(limit) => f32_max(measure(AvailableSpace::MinContent), f32_min(max_content_size, limit)),
+                        None => max_content_size,
+                    })
+                }
+                CompactLength::STRETCH_TAG => stretch_size,
+                _ => None,
+            }
+        };
+
+        let min_size = resolve_keyword(self.min_size.get_abs(axis)).or(self.resolved_min_size.get_abs(axis));
+        let max_size = resolve_keyword(self.max_size.get_abs(axis)).or(self.resolved_max_size.get_abs(axis));
+        let size = self.size.get_abs(axis);
+        if known_size.is_none() && size.is_sizing_keyword() {
+            return resolve_keyword(size).map(|size| size.maybe_min(max_size).maybe_max(min_size));
+        }
+
+        // Clamp the size which the node would otherwise have by keyword minimum and maximum sizes
+        let is_min_or_max_keyword =
+            self.min_size.get_abs(axis).is_sizing_keyword() || self.max_size.get_abs(axis).is_sizing_keyword();
+        if is_min_or_max_keyword && (min_size.is_some() || max_size.is_some()) {
+            let size = known_size.unwrap_or_else(|| {
+                compute(LayoutInput { run_mode: RunMode::ComputeSize, ..inputs }).size.get_abs(axis)
+            });
+            let clamped_size = size.maybe_min(max_size).maybe_max(min_size);
+            return (known_size.is_some() || clamped_size != size).then_some(clamped_size);
+        }
+
+        known_size
+    }
+}
+
+/// Compute the layout of a node with `compute`, first resolving any intrinsic sizing keywords in its size styles to
+/// known dimensions. `compute` is also used to measure the node: it is called with [`SizingMode::ContentSize`] inputs,
+/// under which it must ignore the node's size styles.
+pub(crate) fn compute_with_sizing_keywords(
+    sizing_keywords: Option<SizingKeywords>,
+    inputs: LayoutInput,
+    mut compute: impl FnMut(LayoutInput) -> LayoutOutput,
+) -> LayoutOutput {
+    let Some(sizing_keywords) = sizing_keywords.filter(|_| inputs.sizing_mode == SizingMode::InherentSize) else {
+        return compute(inputs);
+    };
+
+    // The width is resolved first, as the node's height may depend on its width
+    let mut known_dimensions = inputs.known_dimensions;
+    for axis in [AbsoluteAxis::Horizontal, AbsoluteAxis::Vertical] {
+        let size = sizing_keywords.resolve_axis(axis, LayoutInput { known_dimensions, ..inputs }, &mut compute);
+        known_dimensions = with_axis(known_dimensions, axis, size);
+    }
+    compute(LayoutInput { known_dimensions, ..inputs })
+}
+
+/// Replace the component of `size` in the specified axis
+fn with_axis<T>(size: Size<T>, axis: AbsoluteAxis, value: T) -> Size<T> {
+    match axis {
+        AbsoluteAxis::Horizontal => Size { width: value, height: size.height },
+        AbsoluteAxis::Vertical => Size { width: size.width, height: value },
+    }
+}
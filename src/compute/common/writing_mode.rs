@@ -0,0 +1,808 @@
+//! Support for vertical writing modes.
+//!
+//! Taffy's layout algorithms are written in terms of a horizontal coordinate space in which the inline axis is
+//! the x axis and the block axis is the y axis. Nodes with a vertical `writing_mode` are laid out by running the
+//! same algorithms against a [`TransposedTree`]: a view of the tree in which the styles of the node and its children
+//! and the inputs and outputs of child layout calls are transposed so that the node's inline axis (which is
+//! physically vertical) becomes the x axis. The resulting child layouts are mapped back into physical coordinates
+//! once the node's final size is known.
+use crate::geometry::{Line, LogicalRect, Point, Rect, Size};
+use crate::style::{
+    BoxGenerationMode, BoxSizing, CoreStyle, Dimension, Direction, FontMetrics, LengthPercentage, LengthPercentageAuto,
+    Overflow, Position, PositionArea, ScrollbarGutter, Visibility, WritingMode,
+};
+use crate::tree::{
+    CollapsibleMarginSet, ContainingBlock, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, NodeId, RequestedAxis,
+    TraversePartialTree,
+};
+use crate::util::sys::Vec;
+
+#[cfg(all(feature = "grid", feature = "detailed_layout_info"))]
+use crate::compute::grid::DetailedGridInfo;
+#[cfg(feature = "grid")]
+use crate::compute::grid::SubgridTracks;
+#[cfg(all(feature = "block_layout", feature = "detailed_layout_info"))]
+use crate::compute::multicol::{DetailedMulticolInfo, MulticolFragment};
+#[cfg(feature = "grid")]
+use crate::geometry::{AbsoluteAxis, AbstractAxis};
+#[cfg(any(feature = "flexbox", feature = "grid"))]
+use crate::style::{AlignContent, AlignItems, AlignSelf, AlignmentSafety, JustifyContent};
+#[cfg(feature = "block_layout")]
+use crate::style::{BlockContainerStyle, BlockItemStyle, Clear, DisplayOutside, Float, TextAlign, VerticalAlign};
+#[cfg(feature = "table_layout")]
+use crate::style::{BorderCollapse, CaptionSide, TableChildStyle, TableContainerStyle, TableLayout, TablePart};
+#[cfg(feature = "flexbox")]
+use crate::style::{FlexDirection, FlexWrap, FlexboxContainerStyle, FlexboxItemStyle};
+#[cfg(feature = "grid")]
+use crate::style::{GridAutoFlow, GridContainerStyle, GridItemStyle, GridPlacement, GridTemplateArea};
+#[cfg(feature = "flexbox")]
+use crate::tree::LayoutFlexboxContainer;
+#[cfg(feature = "grid")]
+use crate::tree::LayoutGridContainer;
+#[cfg(feature = "table_layout")]
+use crate::tree::LayoutTableContainer;
+#[cfg(feature = "block_layout")]
+use crate::tree::{InlineFragment, LayoutBlockContainer, TextRunBreak};
+#[cfg(feature = "grid")]
+use crate::util::sys::GridTrackVec;
+
+/// Maps a physical rect into the transposed coordinate space of a node with the specified vertical writing mode
+#[inline(always)]
+fn transpose_rect<T>(rect: Rect<T>, writing_mode: WritingMode) -> Rect<T> {
+    // The direction is applied by the layout algorithms themselves, so the inline axis is always mapped from top to bottom
+    let logical = rect.to_logical(writing_mode, Direction::Ltr);
+    Rect { left: logical.inline_start, right: logical.inline_end, top: logical.block_start, bottom: logical.block_end }
+}
+
+/// Maps a rect in the transposed coordinate space of a node with the specified vertical writing mode back into physical space
+#[inline(always)]
+fn untranspose_rect<T>(rect: Rect<T>, writing_mode: WritingMode) -> Rect<T> {
+    LogicalRect { inline_start: rect.left, inline_end: rect.right, block_start: rect.top, block_end: rect.bottom }
+        .to_physical(writing_mode, Direction::Ltr)
+}
+
+/// Transposes the inputs to a layout call
+#[inline(always)]
+fn transpose_inputs(inputs: LayoutInput) -> LayoutInput {
+    LayoutInput {
+        known_dimensions: inputs.known_dimensions.transpose(),
+        parent_size: inputs.parent_size.transpose(),
+        available_space: inputs.available_space.transpose(),
+        axis: match inputs.axis {
+            RequestedAxis::Horizontal => RequestedAxis::Vertical,
+            RequestedAxis::Vertical => RequestedAxis::Horizontal,
+            RequestedAxis::Both => RequestedAxis::Both,
+        },
+        // Margins are never collapsed across a change of orientation
+        vertical_margins_are_collapsible: Line::FALSE,
+        ..inputs
+    }
+}
+
+/// Maps the insets of the containing block passed in a layout call with `map_rect`
+#[inline(always)]
+fn map_containing_block(inputs: LayoutInput, map_rect: impl FnOnce(Rect<f32>) -> Rect<f32>) -> LayoutInput {
+    let containing_block = match inputs.containing_block {
+        ContainingBlock::Ancestor(insets) => ContainingBlock::Ancestor(map_rect(insets)),
+        containing_block => containing_block,
+    };
+    LayoutInput { containing_block, ..inputs }
+}
+
+/// Transposes the output of a layout call
+#[inline(always)]
+fn transpose_output(output: LayoutOutput) -> LayoutOutput {
+    LayoutOutput {
+        size: output.size.transpose(),
+        #[cfg(feature = "content_size")]
+        content_size: output.content_size.transpose(),
+        scrollbar_size: output.scrollbar_size.transpose(),
+        first_baselines: output.first_baselines.transpose(),
+        // Margins are never collapsed across a change of orientation
+        top_margin: CollapsibleMarginSet::ZERO,
+        bottom_margin: CollapsibleMarginSet::ZERO,
+        margins_can_collapse_through: false,
+    }
+}
+
+/// Lays out `node` (which has the vertical `writing_mode` specified) by running `compute_function` in a transposed
+/// coordinate space. The layouts of the node's children are written back to the underlying tree in physical coordinates.
+pub(crate) fn compute_with_transposed_axes<'t, Tree: LayoutPartialTree>(
+    tree: &'t mut Tree,
+    node: NodeId,
+    inputs: LayoutInput,
+    writing_mode: WritingMode,
+    compute_function: impl FnOnce(&mut TransposedTree<'t, Tree>, NodeId, LayoutInput) -> LayoutOutput,
+) -> LayoutOutput {
+    let mut transposed_tree = TransposedTree { tree, writing_mode, layouts: Vec::new() };
+    let inputs = map_containing_block(transpose_inputs(inputs), |insets| transpose_rect(insets, writing_mode));
+    let output = compute_function(&mut transposed_tree, node, inputs);
+
+    // The transposed height of the node is its physical width
+    let container_width = output.size.height;
+    let TransposedTree { tree, layouts, .. } = transposed_tree;
+
+    // The descendants of inline boxes are positioned relative to their parent inline box rather than the node, so
+    // need mapping into the physical space of their parent
+    let mut parent_widths = Vec::new();
+    if layouts.len() > tree.child_count(node) {
+        for (parent, layout) in layouts.iter() {
+            for child in tree.child_ids(*parent) {
+                parent_widths.push((u64::from(child), layout.size.height));
+            }
+        }
+        parent_widths.sort_unstable_by_key(|entry| entry.0);
+    }
+
+    for (child, layout) in layouts {
+        let size = layout.size.transpose();
+        let container_width = match parent_widths.binary_search_by(|entry| entry.0.cmp(&u64::from(child))) {
+            Ok(index) => parent_widths[index].1,
+            Err(_) => container_width,
+        };
+        let x = match writing_mode {
+            WritingMode::VerticalRl => container_width - layout.location.y - size.width,
+            _ => layout.location.y,
+        };
+        let physical_layout = Layout {
+            order: layout.order,
+            location: Point { x, y: layout.location.x },
+            size,
+            #[cfg(feature = "content_size")]
+            content_size: layout.content_size.transpose(),
+            scrollbar_size: layout.scrollbar_size.transpose(),
+            border: untranspose_rect(layout.border, writing_mode),
+            padding: untranspose_rect(layout.padding, writing_mode),
+            margin: untranspose_rect(layout.margin, writing_mode),
+            visibility: layout.visibility,
+            ..Layout::new()
+        };
+        tree.set_unrounded_layout(child, &physical_layout);
+    }
+
+    transpose_output(output)
+}
+
+/// A view of a tree in which the layout of a node with a vertical writing mode can be computed using
+/// algorithms that assume a horizontal writing mode. See the module documentation for details.
+pub(crate) struct TransposedTree<'t, Tree: LayoutPartialTree> {
+    /// The underlying tree
+    tree: &'t mut Tree,
+    /// The writing mode of the node being laid out
+    writing_mode: WritingMode,
+    /// The layouts of the node's children (in transposed coordinates) which have been set by the layout algorithm
+    layouts: Vec<(NodeId, Layout)>,
+}
+
+impl<Tree: LayoutPartialTree> TraversePartialTree for TransposedTree<'_, Tree> {
+    type ChildIter<'a>
+        = Tree::ChildIter<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
+        self.tree.child_ids(parent_node_id)
+    }
+
+    #[inline(always)]
+    fn child_count(&self, parent_node_id: NodeId) -> usize {
+        self.tree.child_count(parent_node_id)
+    }
+
+    #[inline(always)]
+    fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
+        self.tree.get_child_id(parent_node_id, child_index)
+    }
+}
+
+impl<Tree: LayoutPartialTree> LayoutPartialTree for TransposedTree<'_, Tree> {
+    type CoreContainerStyle<'a>
+        = TransposedStyle<Tree::CoreContainerStyle<'a>>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_core_container_style(&self, node_id: NodeId) -> Self::CoreContainerStyle<'_> {
+        TransposedStyle::new(self.tree.get_core_container_style(node_id), self.writing_mode)
+    }
+
+    #[inline(always)]
+    fn resolve_calc_value(&self, val: u64, basis: f32) -> f32 {
+        self.tree.resolve_calc_value(val, basis)
+    }
+
+    #[inline(always)]
+    fn get_viewport_size(&self) -> Size<f32> {
+        // The viewport is not transposed: viewport-relative lengths are relative to its physical dimensions
+        self.tree.get_viewport_size()
+    }
+
+    #[inline(always)]
+    fn get_font_metrics(&self, node_id: NodeId) -> FontMetrics {
+        self.tree.get_font_metrics(node_id)
+    }
+
+    #[inline(always)]
+    fn get_root_font_size(&self) -> f32 {
+        self.tree.get_root_font_size()
+    }
+
+    #[inline(always)]
+    fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout) {
+        self.layouts.push((node_id, *layout));
+    }
+
+    #[inline(always)]
+    fn compute_child_layout(&mut self, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
+        // Children are laid out according to their own writing mode, so are passed physical inputs
+        let inputs =
+            map_containing_block(transpose_inputs(inputs), |insets| untranspose_rect(insets, self.writing_mode));
+        transpose_output(self.tree.compute_child_layout(node_id, inputs))
+    }
+}
+
+#[cfg(feature = "flexbox")]
+impl<Tree: LayoutFlexboxContainer> LayoutFlexboxContainer for TransposedTree<'_, Tree> {
+    type FlexboxContainerStyle<'a>
+        = TransposedStyle<Tree::FlexboxContainerStyle<'a>>
+    where
+        Self: 'a;
+    type FlexboxItemStyle<'a>
+        = TransposedStyle<Tree::FlexboxItemStyle<'a>>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_flexbox_container_style(&self, node_id: NodeId) -> Self::FlexboxContainerStyle<'_> {
+        TransposedStyle::new(self.tree.get_flexbox_container_style(node_id), self.writing_mode)
+    }
+
+    #[inline(always)]
+    fn get_flexbox_child_style(&self, child_node_id: NodeId) -> Self::FlexboxItemStyle<'_> {
+        TransposedStyle::new(self.tree.get_flexbox_child_style(child_node_id), self.writing_mode)
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<Tree: LayoutGridContainer> LayoutGridContainer for TransposedTree<'_, Tree> {
+    type CustomIdent = Tree::CustomIdent;
+    type GridContainerStyle<'a>
+        = TransposedStyle<Tree::GridContainerStyle<'a>>
+    where
+        Self: 'a;
+    type GridItemStyle<'a>
+        = TransposedStyle<Tree::GridItemStyle<'a>>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_grid_container_style(&self, node_id: NodeId) -> Self::GridContainerStyle<'_> {
+        TransposedStyle::new(self.tree.get_grid_container_style(node_id), self.writing_mode)
+    }
+
+    #[inline(always)]
+    fn get_grid_child_style(&self, child_node_id: NodeId) -> Self::GridItemStyle<'_> {
+        TransposedStyle::new(self.tree.get_grid_child_style(child_node_id), self.writing_mode)
+    }
+
+    #[cfg(feature = "detailed_layout_info")]
+    #[inline(always)]
+    fn set_detailed_grid_info(&mut self, node_id: NodeId, detailed_grid_info: DetailedGridInfo) {
+        // Rows and columns are flow-relative, so the detailed grid info does not need transposing
+        self.tree.set_detailed_grid_info(node_id, detailed_grid_info);
+    }
+
+    #[inline(always)]
+    fn get_subgrid_tracks(&self, node_id: NodeId, axis: AbstractAxis) -> Option<&SubgridTracks> {
+        self.tree.get_subgrid_tracks(node_id, axis)
+    }
+
+    #[inline(always)]
+    fn set_subgrid_tracks(&mut self, node_id: NodeId, axis: AbstractAxis, tracks: SubgridTracks) {
+        self.tree.set_subgrid_tracks(node_id, axis, tracks);
+    }
+}
+
+#[cfg(feature = "block_layout")]
+impl<Tree: LayoutBlockContainer> LayoutBlockContainer for TransposedTree<'_, Tree> {
+    type BlockContainerStyle<'a>
+        = TransposedStyle<Tree::BlockContainerStyle<'a>>
+    where
+        Self: 'a;
+    type BlockItemStyle<'a>
+        = TransposedStyle<Tree::BlockItemStyle<'a>>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_block_container_style(&self, node_id: NodeId) -> Self::BlockContainerStyle<'_> {
+        TransposedStyle::new(self.tree.get_block_container_style(node_id), self.writing_mode)
+    }
+
+    #[inline(always)]
+    fn get_block_child_style(&self, child_node_id: NodeId) -> Self::BlockItemStyle<'_> {
+        TransposedStyle::new(self.tree.get_block_child_style(child_node_id), self.writing_mode)
+    }
+
+    #[inline(always)]
+    fn break_text_run(
+        &mut self,
+        node_id: NodeId,
+        start: usize,
+        available_width: Option<f32>,
+        line_is_empty: bool,
+    ) -> Option<TextRunBreak> {
+        // Text is measured along the inline axis, which is the x axis of the transposed space
+        self.tree.break_text_run(node_id, start, available_width, line_is_empty)
+    }
+
+    fn set_inline_fragments(&mut self, node_id: NodeId, fragments: &[InlineFragment]) {
+        // Fragments are relative to the node, whose layout has already been set, so can be mapped into physical space
+        // without waiting for the final size of the container
+        let node_width = match self.layouts.iter().rev().find(|(node, _)| *node == node_id) {
+            Some((_, layout)) => layout.size.height,
+            None => 0.0,
+        };
+        let writing_mode = self.writing_mode;
+        let physical_fragments: Vec<InlineFragment> = fragments
+            .iter()
+            .map(|fragment| {
+                let size = fragment.size.transpose();
+                let x = match writing_mode {
+                    WritingMode::VerticalRl => node_width - fragment.location.y - size.width,
+                    _ => fragment.location.y,
+                };
+                InlineFragment { location: Point { x, y: fragment.location.x }, size, ..*fragment }
+            })
+            .collect();
+        self.tree.set_inline_fragments(node_id, &physical_fragments);
+    }
+
+    #[cfg(feature = "detailed_layout_info")]
+    fn set_detailed_multicol_info(&mut self, node_id: NodeId, mut detailed_multicol_info: DetailedMulticolInfo) {
+        // Column sizes are flow-relative, but fragments are positioned relative to their child, whose layout has
+        // already been set, so can be mapped into physical space in the same way as inline fragments
+        let writing_mode = self.writing_mode;
+        for (index, item) in detailed_multicol_info.items.iter_mut().enumerate() {
+            let child = self.tree.get_child_id(node_id, index);
+            let child_width = match self.layouts.iter().rev().find(|(node, _)| *node == child) {
+                Some((_, layout)) => layout.size.height,
+                None => 0.0,
+            };
+            let to_physical = |location: Point<f32>, size: Size<f32>| match writing_mode {
+                WritingMode::VerticalRl => Point { x: child_width - location.y - size.width, y: location.x },
+                _ => Point { x: location.y, y: location.x },
+            };
+            for fragment in item.fragments.iter_mut() {
+                let size = fragment.size.transpose();
+                *fragment = MulticolFragment {
+                    location: to_physical(fragment.location, size),
+                    size,
+                    source_location: to_physical(fragment.source_location, size),
+                    ..*fragment
+                };
+            }
+        }
+        self.tree.set_detailed_multicol_info(node_id, detailed_multicol_info);
+    }
+}
+
+#[cfg(feature = "table_layout")]
+impl<Tree: LayoutTableContainer> LayoutTableContainer for TransposedTree<'_, Tree> {
+    type TableContainerStyle<'a>
+        = TransposedStyle<Tree::TableContainerStyle<'a>>
+    where
+        Self: 'a;
+    type TableChildStyle<'a>
+        = TransposedStyle<Tree::TableChildStyle<'a>>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_table_container_style(&self, node_id: NodeId) -> Self::TableContainerStyle<'_> {
+        TransposedStyle::new(self.tree.get_table_container_style(node_id), self.writing_mode)
+    }
+
+    #[inline(always)]
+    fn get_table_child_style(&self, child_node_id: NodeId) -> Self::TableChildStyle<'_> {
+        TransposedStyle::new(self.tree.get_table_child_style(child_node_id), self.writing_mode)
+    }
+
+    fn offset_cell_content(&mut self, cell_node_id: NodeId, offset: Point<f32>) {
+        // The content of the cell is laid out in physical coordinates, so the offset is mapped into physical space
+        let physical_offset = match self.writing_mode {
+            WritingMode::VerticalRl => Point { x: -offset.y, y: offset.x },
+            _ => Point { x: offset.y, y: offset.x },
+        };
+        self.tree.offset_cell_content(cell_node_id, physical_offset);
+    }
+}
+
+/// A wrapper around a style which transposes the physical style properties into the coordinate space of a
+/// [`TransposedTree`]. Flow-relative properties (gaps, alignment, flex direction, grid templates and placement, etc)
+/// are passed through unchanged.
+pub(crate) struct TransposedStyle<S> {
+    /// The underlying style
+    style: S,
+    /// The writing mode of the node whose coordinate space the style is transposed into
+    writing_mode: WritingMode,
+}
+
+impl<S> TransposedStyle<S> {
+    /// Wrap a style
+    #[inline(always)]
+    fn new(style: S, writing_mode: WritingMode) -> Self {
+        Self { style, writing_mode }
+    }
+}
+
+impl<S: CoreStyle> CoreStyle for TransposedStyle<S> {
+    #[inline(always)]
+    fn box_generation_mode(&self) -> BoxGenerationMode {
+        self.style.box_generation_mode()
+    }
+    #[inline(always)]
+    fn is_block(&self) -> bool {
+        self.style.is_block()
+    }
+    #[inline(always)]
+    fn box_sizing(&self) -> BoxSizing {
+        self.style.box_sizing()
+    }
+    #[inline(always)]
+    fn direction(&self) -> Direction {
+        self.style.direction()
+    }
+    #[inline(always)]
+    fn visibility(&self) -> Visibility {
+        self.style.visibility()
+    }
+    #[inline(always)]
+    fn writing_mode(&self) -> WritingMode {
+        WritingMode::HorizontalTb
+    }
+    #[inline(always)]
+    fn overflow(&self) -> Point<Overflow> {
+        self.style.overflow().transpose()
+    }
+    #[inline(always)]
+    fn scrollbar_width(&self) -> Size<f32> {
+        self.style.scrollbar_width().transpose()
+    }
+    #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        self.style.scrollbar_gutter()
+    }
+    #[inline(always)]
+    fn position(&self) -> Position {
+        self.style.position()
+    }
+    #[inline(always)]
+    fn inset(&self) -> Rect<LengthPercentageAuto> {
+        transpose_rect(self.style.inset(), self.writing_mode)
+    }
+    #[inline(always)]
+    fn anchor_name(&self) -> Option<&str> {
+        self.style.anchor_name()
+    }
+    #[inline(always)]
+    fn position_anchor(&self) -> Option<&str> {
+        self.style.position_anchor()
+    }
+    #[inline(always)]
+    fn position_area(&self) -> Option<Point<PositionArea>> {
+        // The block axis of vertical-rl nodes runs from right to left
+        self.style.position_area().map(|area| match self.writing_mode {
+            WritingMode::VerticalRl => Point { x: area.y, y: area.x.flipped() },
+            _ => area.transpose(),
+        })
+    }
+    #[inline(always)]
+    fn size(&self) -> Size<Dimension> {
+        self.style.size().transpose()
+    }
+    #[inline(always)]
+    fn min_size(&self) -> Size<Dimension> {
+        self.style.min_size().transpose()
+    }
+    #[inline(always)]
+    fn max_size(&self) -> Size<Dimension> {
+        self.style.max_size().transpose()
+    }
+    #[inline(always)]
+    fn aspect_ratio(&self) -> Option<f32> {
+        self.style.aspect_ratio().map(|ratio| 1.0 / ratio)
+    }
+    #[inline(always)]
+    fn margin(&self) -> Rect<LengthPercentageAuto> {
+        transpose_rect(self.style.margin(), self.writing_mode)
+    }
+    #[inline(always)]
+    fn padding(&self) -> Rect<LengthPercentage> {
+        transpose_rect(self.style.padding(), self.writing_mode)
+    }
+    #[inline(always)]
+    fn border(&self) -> Rect<LengthPercentage> {
+        transpose_rect(self.style.border(), self.writing_mode)
+    }
+}
+
+#[cfg(feature = "flexbox")]
+impl<S: FlexboxContainerStyle> FlexboxContainerStyle for TransposedStyle<S> {
+    #[inline(always)]
+    fn flex_direction(&self) -> FlexDirection {
+        self.style.flex_direction()
+    }
+    #[inline(always)]
+    fn flex_wrap(&self) -> FlexWrap {
+        self.style.flex_wrap()
+    }
+    #[inline(always)]
+    fn gap(&self) -> Size<LengthPercentage> {
+        self.style.gap()
+    }
+    #[inline(always)]
+    fn align_content(&self) -> Option<AlignContent> {
+        self.style.align_content()
+    }
+    #[inline(always)]
+    fn align_content_safety(&self) -> AlignmentSafety {
+        self.style.align_content_safety()
+    }
+    #[inline(always)]
+    fn align_items(&self) -> Option<AlignItems> {
+        self.style.align_items()
+    }
+    #[inline(always)]
+    fn align_items_safety(&self) -> AlignmentSafety {
+        self.style.align_items_safety()
+    }
+    #[inline(always)]
+    fn justify_content(&self) -> Option<JustifyContent> {
+        self.style.justify_content()
+    }
+    #[inline(always)]
+    fn justify_content_safety(&self) -> AlignmentSafety {
+        self.style.justify_content_safety()
+    }
+}
+
+#[cfg(feature = "flexbox")]
+impl<S: FlexboxItemStyle> FlexboxItemStyle for TransposedStyle<S> {
+    #[inline(always)]
+    fn flex_basis(&self) -> Dimension {
+        self.style.flex_basis()
+    }
+    #[inline(always)]
+    fn flex_grow(&self) -> f32 {
+        self.style.flex_grow()
+    }
+    #[inline(always)]
+    fn flex_shrink(&self) -> f32 {
+        self.style.flex_shrink()
+    }
+    #[inline(always)]
+    fn align_self(&self) -> Option<AlignSelf> {
+        self.style.align_self()
+    }
+    #[inline(always)]
+    fn align_self_safety(&self) -> Option<AlignmentSafety> {
+        self.style.align_self_safety()
+    }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        self.style.order()
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<S: GridContainerStyle> GridContainerStyle for TransposedStyle<S> {
+    type CustomIdent = S::CustomIdent;
+    type TemplateTrackList<'a>
+        = S::TemplateTrackList<'a>
+    where
+        Self: 'a;
+    type AutoTrackList<'a>
+        = S::AutoTrackList<'a>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn grid_template_rows(&self) -> Self::TemplateTrackList<'_> {
+        self.style.grid_template_rows()
+    }
+    #[inline(always)]
+    fn grid_template_columns(&self) -> Self::TemplateTrackList<'_> {
+        self.style.grid_template_columns()
+    }
+    #[inline(always)]
+    fn grid_auto_rows(&self) -> Self::AutoTrackList<'_> {
+        self.style.grid_auto_rows()
+    }
+    #[inline(always)]
+    fn grid_auto_columns(&self) -> Self::AutoTrackList<'_> {
+        self.style.grid_auto_columns()
+    }
+    #[inline(always)]
+    fn grid_template_row_names(&self) -> &[GridTrackVec<Self::CustomIdent>] {
+        self.style.grid_template_row_names()
+    }
+    #[inline(always)]
+    fn grid_template_column_names(&self) -> &[GridTrackVec<Self::CustomIdent>] {
+        self.style.grid_template_column_names()
+    }
+    #[inline(always)]
+    fn grid_template_areas(&self) -> &[GridTemplateArea<Self::CustomIdent>] {
+        self.style.grid_template_areas()
+    }
+    #[inline(always)]
+    fn grid_auto_flow(&self) -> GridAutoFlow {
+        self.style.grid_auto_flow()
+    }
+    #[inline(always)]
+    fn gap(&self) -> Size<LengthPercentage> {
+        self.style.gap()
+    }
+    #[inline(always)]
+    fn align_content(&self) -> Option<AlignContent> {
+        self.style.align_content()
+    }
+    #[inline(always)]
+    fn align_content_safety(&self) -> AlignmentSafety {
+        self.style.align_content_safety()
+    }
+    #[inline(always)]
+    fn justify_content(&self) -> Option<JustifyContent> {
+        self.style.justify_content()
+    }
+    #[inline(always)]
+    fn justify_content_safety(&self) -> AlignmentSafety {
+        self.style.justify_content_safety()
+    }
+    #[inline(always)]
+    fn align_tracks(&self) -> Option<AlignContent> {
+        self.style.align_tracks()
+    }
+    #[inline(always)]
+    fn justify_tracks(&self) -> Option<JustifyContent> {
+        self.style.justify_tracks()
+    }
+    #[inline(always)]
+    fn align_items(&self) -> Option<AlignItems> {
+        self.style.align_items()
+    }
+    #[inline(always)]
+    fn align_items_safety(&self) -> AlignmentSafety {
+        self.style.align_items_safety()
+    }
+    #[inline(always)]
+    fn justify_items(&self) -> Option<AlignItems> {
+        self.style.justify_items()
+    }
+    #[inline(always)]
+    fn justify_items_safety(&self) -> AlignmentSafety {
+        self.style.justify_items_safety()
+    }
+}
+
+#[cfg(feature = "grid")]
+impl<S: GridItemStyle> GridItemStyle for TransposedStyle<S> {
+    type CustomIdent = S::CustomIdent;
+
+    #[inline(always)]
+    fn grid_row(&self) -> Line<GridPlacement<Self::CustomIdent>> {
+        self.style.grid_row()
+    }
+    #[inline(always)]
+    fn grid_column(&self) -> Line<GridPlacement<Self::CustomIdent>> {
+        self.style.grid_column()
+    }
+    #[inline(always)]
+    fn align_self(&self) -> Option<AlignSelf> {
+        self.style.align_self()
+    }
+    #[inline(always)]
+    fn align_self_safety(&self) -> Option<AlignmentSafety> {
+        self.style.align_self_safety()
+    }
+    #[inline(always)]
+    fn justify_self(&self) -> Option<AlignSelf> {
+        self.style.justify_self()
+    }
+    #[inline(always)]
+    fn justify_self_safety(&self) -> Option<AlignmentSafety> {
+        self.style.justify_self_safety()
+    }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        self.style.order()
+    }
+    #[inline(always)]
+    fn is_subgrid(&self, axis: AbsoluteAxis) -> bool {
+        // Rows and columns are flow-relative, so the axis does not need transposing
+        self.style.is_subgrid(axis)
+    }
+}
+
+#[cfg(feature = "block_layout")]
+impl<S: BlockContainerStyle> BlockContainerStyle for TransposedStyle<S> {
+    #[inline(always)]
+    fn text_align(&self) -> TextAlign {
+        self.style.text_align()
+    }
+    #[inline(always)]
+    fn column_count(&self) -> Option<u16> {
+        self.style.column_count()
+    }
+    #[inline(always)]
+    fn column_width(&self) -> Dimension {
+        self.style.column_width()
+    }
+    #[inline(always)]
+    fn column_gap(&self) -> LengthPercentage {
+        self.style.column_gap()
+    }
+}
+
+#[cfg(feature = "block_layout")]
+impl<S: BlockItemStyle> BlockItemStyle for TransposedStyle<S> {
+    #[inline(always)]
+    fn is_table(&self) -> bool {
+        self.style.is_table()
+    }
+    #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        self.style.display_outside()
+    }
+    #[inline(always)]
+    fn vertical_align(&self) -> VerticalAlign {
+        self.style.vertical_align()
+    }
+    #[inline(always)]
+    fn float(&self) -> Float {
+        // Floats are line-relative, so the side does not need transposing
+        self.style.float()
+    }
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        self.style.clear()
+    }
+}
+
+#[cfg(feature = "table_layout")]
+impl<S: TableContainerStyle> TableContainerStyle for TransposedStyle<S> {
+    #[inline(always)]
+    fn border_collapse(&self) -> BorderCollapse {
+        self.style.border_collapse()
+    }
+    #[inline(always)]
+    fn border_spacing(&self) -> Size<LengthPercentage> {
+        // Border spacing is specified in physical terms: horizontal spacing first, then vertical spacing
+        self.style.border_spacing().transpose()
+    }
+    #[inline(always)]
+    fn table_layout(&self) -> TableLayout {
+        self.style.table_layout()
+    }
+}
+
+#[cfg(feature = "table_layout")]
+impl<S: TableChildStyle> TableChildStyle for TransposedStyle<S> {
+    #[inline(always)]
+    fn table_part(&self) -> TablePart {
+        self.style.table_part()
+    }
+    #[inline(always)]
+    fn col_span(&self) -> u16 {
+        self.style.col_span()
+    }
+    #[inline(always)]
+    fn row_span(&self) -> u16 {
+        self.style.row_span()
+    }
+    #[inline(always)]
+    fn caption_side(&self) -> CaptionSide {
+        self.style.caption_side()
+    }
+}
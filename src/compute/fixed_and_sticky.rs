@@ -0,0 +1,837 @@
+//! Positions `Position::Fixed` and `Position::Sticky` nodes. Unlike other nodes, the position of these nodes depends on
+//! the viewport and on the scroll offsets of their ancestors, so they are positioned in a pass over the whole tree after
+//! layout has been computed. See [`compute_fixed_and_sticky_positions`].
+//!
+//! The same pass positions anchored nodes (whose position depends on the position of their anchor), as those depend on
+//! the layout of other parts of the tree. `Position::Absolute` nodes whose containing block is not their parent (because
+//! their parent is `Position::Static`) have already been positioned against their containing block by the layout
+//! algorithms, and are only kept in place as the nodes between them and their containing block are scrolled.
+//!
+//! With the `content_size` feature, the same pass also computes the scrollable overflow rectangle of each node, which
+//! depends on the final position of the node's descendants.
+#[cfg(feature = "content_size")]
+use crate::compute::common::content_size::{
+    compute_scrollable_overflow, compute_scrollable_overflow_contribution, edges, union,
+};
+use crate::geometry::{Line, Point, Rect, Size};
+use crate::style::{
+    AnchorSide, AnchorSize, AvailableSpace, BoxGenerationMode, BoxSizing, CoreStyle, Dimension, Direction,
+    LengthPercentageAuto, Position, PositionArea, PositionTryFallback,
+};
+use crate::tree::{Layout, LayoutPartialTree, LayoutPartialTreeExt, NodeId, RoundTree, SizingMode};
+use crate::util::sys::{f32_max, f32_min, Vec};
+use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
+
+/// An axis-aligned rectangle in the coordinate space of the viewport
+#[derive(Debug, Clone, Copy)]
+struct Area {
+    /// The position of the top-left corner of the rectangle
+    origin: Point<f32>,
+    /// The size of the rectangle
+    size: Size<f32>,
+}
+
+impl Area {
+    /// The start and end coordinates of the rectangle in the horizontal axis
+    fn horizontal(&self) -> Line<f32> {
+        Line { start: self.origin.x, end: self.origin.x + self.size.width }
+    }
+
+    /// The start and end coordinates of the rectangle in the vertical axis
+    fn vertical(&self) -> Line<f32> {
+        Line { start: self.origin.y, end: self.origin.y + self.size.height }
+    }
+}
+
+/// The state shared by the whole pass
+struct PositioningContext {
+    /// The viewport, which is the containing block of fixed nodes
+    viewport: Area,
+    /// The nodes with an `anchor_name` that have been visited so far, and their border boxes
+    anchors: Vec<(NodeId, Area)>,
+    /// The contributions to the scrollable overflow of their containing block of the absolute nodes which have been
+    /// visited so far and whose containing block is not their parent, in the coordinate space of the viewport. These
+    /// are removed as they are added to the scrollable overflow of their containing block.
+    #[cfg(feature = "content_size")]
+    pending_overflow: Vec<Rect<f32>>,
+}
+
+/// Positions the `Position::Fixed`, `Position::Sticky` and anchored descendants of the root node. This should be run
+/// after [`compute_root_layout`](crate::compute_root_layout) and before [`round_layout`](crate::round_layout), as
+/// [`compute_root_layout_with_positions`](crate::compute_root_layout_with_positions) does.
+///
+///   - Fixed nodes are laid out again using the viewport (of size `viewport_size`) as their containing block, and are
+///     given a `location` which places them at the same position in the viewport regardless of the position and scroll
+///     offsets of their ancestors. In an axis in which neither inset is set, they keep their static position.
+///     Auto margins of fixed nodes are treated as zero.
+///   - Absolute and fixed nodes whose `position_anchor` refers to the `anchor_name` of a node which comes before them in
+///     the tree are laid out again in the same way, regardless of their parent. Their `anchor()` insets and
+///     `anchor-size()` sizes are resolved against the border box of the anchor (the last such node, if several have the
+///     same name), and their `position_area` places them in a region of their containing block relative to the anchor.
+///     If an anchored node overflows its containing block, its `position_try_fallbacks` are tried in order, and the
+///     first one with which it fits is used.
+///   - Sticky nodes are offset to keep them within the scrollport of their nearest scroll container (or the viewport),
+///     deflated by their insets, for as long as they can stay within the content box of their parent. The applied
+///     offset is recorded in [`Layout::sticky_offset`](crate::Layout::sticky_offset).
+///   - With the `content_size` feature, the scrollable overflow rectangle of every node is computed from the final
+///     positions of its descendants, and recorded in [`Layout::scrollable_overflow_location`](crate::Layout::scrollable_overflow_location)
+///     and [`Layout::scrollable_overflow_size`](crate::Layout::scrollable_overflow_size).
+///
+/// Scroll offsets are read using [`LayoutPartialTree::get_scroll_offset`], and are clamped to the range through which each
+/// node can be scrolled. The clamped offsets are recorded in [`Layout::scroll_offset`](crate::Layout::scroll_offset), and
+/// the position of each node once its ancestors have been scrolled in
+/// [`Layout::scrolled_location`](crate::Layout::scrolled_location). Absolute nodes are not moved by the scroll offsets of
+/// the nodes between them and their containing block. Running this function again (for example
+/// after the scroll offsets have changed) replaces rather than accumulates the offsets that were previously applied.
+pub fn compute_fixed_and_sticky_positions(
+    tree: &mut (impl LayoutPartialTree + RoundTree),
+    root: NodeId,
+    viewport_size: Size<f32>,
+) {
+    let viewport = Area { origin: Point::ZERO, size: viewport_size };
+    let root_layout = *tree.get_unrounded_layout(root);
+    let root_location = root_layout.location;
+    tree.set_unrounded_layout(root, &Layout { scrolled_location: root_location, ..root_layout });
+    let mut context = PositioningContext {
+        viewport,
+        anchors: Vec::new(),
+        #[cfg(feature = "content_size")]
+        pending_overflow: Vec::new(),
+    };
+    // The root node is always a containing block, so the containing block passed in for it is never used
+    position_descendants(tree, root, root_location, true, viewport, viewport, Point::ZERO, &mut context);
+}
+
+/// Positions the fixed, sticky and absolute children of a node, and then those of its descendants, before computing the
+/// scrollable overflow rectangle of the node.
+///
+/// `origin` is the position of the node's border box in the viewport, `scrollport` is the scrollport of the nearest
+/// scroll container that the node is inside of (or the viewport), and `containing_block` is the padding box of the
+/// nearest positioned node that the node is inside of. `inherited_scroll` is the sum of the scroll offsets of the
+/// node's ancestors below that positioned node.
+#[allow(clippy::too_many_arguments)]
+fn position_descendants(
+    tree: &mut (impl LayoutPartialTree + RoundTree),
+    node: NodeId,
+    origin: Point<f32>,
+    is_root: bool,
+    scrollport: Area,
+    containing_block: Area,
+    inherited_scroll: Point<f32>,
+    context: &mut PositioningContext,
+) {
+    let layout = *tree.get_unrounded_layout(node);
+    let style = tree.get_core_container_style(node);
+    let is_scroll_container = style.overflow().x.is_scroll_container() || style.overflow().y.is_scroll_container();
+    let is_containing_block = is_root || style.position().is_positioned();
+    let direction = style.direction();
+    #[cfg(feature = "content_size")]
+    let writing_mode = style.writing_mode();
+    drop(style);
+
+    // The contributions of this node's children to its scrollable overflow (and those of its absolute descendants, for
+    // a containing block), and the bounds of the margin boxes of its in-flow children
+    #[cfg(feature = "content_size")]
+    let (mut overflow_contributions, mut inflow_bounds) = (Rect::zero(), Rect::zero());
+    #[cfg(feature = "content_size")]
+    let pending_overflow_start = context.pending_overflow.len();
+
+    // The vertical scrollbar is placed on the inline-end side, which is the left side for right-to-left nodes
+    let scrollbar_inset_left = if direction.is_rtl() { layout.scrollbar_size.width } else { 0.0 };
+
+    // The scroll offset of the root node scrolls the viewport, even if the root is not a scroll container
+    let scroll_offset = match is_root || is_scroll_container {
+        true => clamp_scroll_offset(tree.get_scroll_offset(node), &layout, !is_scroll_container, context.viewport),
+        false => Point::ZERO,
+    };
+    tree.set_unrounded_layout(node, &Layout { scroll_offset, ..layout });
+    let children_origin = Point { x: origin.x - scroll_offset.x, y: origin.y - scroll_offset.y };
+
+    // The absolutely positioned children of a static node were positioned against their containing block without
+    // scrolling, so they are moved back by the scroll offsets of the nodes below their containing block
+    let children_scroll = match is_containing_block {
+        true => Point::ZERO,
+        false => Point { x: inherited_scroll.x + scroll_offset.x, y: inherited_scroll.y + scroll_offset.y },
+    };
+    let absolute_children_origin =
+        Point { x: children_origin.x + children_scroll.x, y: children_origin.y + children_scroll.y };
+
+    // The scrollport of a scroll container is its padding box, excluding the scrollbars
+    let children_scrollport = match is_scroll_container {
+        true => Area {
+            origin: Point { x: origin.x + layout.border.left + scrollbar_inset_left, y: origin.y + layout.border.top },
+            size: Size {
+                width: layout.size.width - layout.border.horizontal_axis_sum() - layout.scrollbar_size.width,
+                height: layout.size.height - layout.border.vertical_axis_sum() - layout.scrollbar_size.height,
+            },
+        },
+        false => scrollport,
+    };
+
+    // The padding box of a positioned node is the containing block of its absolutely positioned descendants, and moves
+    // as the node is scrolled
+    let children_containing_block = match is_containing_block {
+        true => Area {
+            origin: Point {
+                x: children_origin.x + layout.border.left + scrollbar_inset_left,
+                y: children_origin.y + layout.border.top,
+            },
+            size: Size {
+                width: layout.size.width - layout.border.horizontal_axis_sum() - layout.scrollbar_size.width,
+                height: layout.size.height - layout.border.vertical_axis_sum() - layout.scrollbar_size.height,
+            },
+        },
+        false => containing_block,
+    };
+
+    // The content box of the node is the containing block of its sticky children, and moves as the node is scrolled
+    let content_box = Area {
+        origin: Point {
+            x: children_origin.x + layout.border.left + layout.padding.left + scrollbar_inset_left,
+            y: children_origin.y + layout.border.top + layout.padding.top,
+        },
+        size: Size {
+            width: layout.content_box_width() - layout.scrollbar_size.width,
+            height: layout.content_box_height() - layout.scrollbar_size.height,
+        },
+    };
+
+    for index in 0..tree.child_count(node) {
+        let child = tree.get_child_id(node, index);
+        let child_style = tree.get_core_container_style(child);
+        let box_generation_mode = child_style.box_generation_mode();
+        let position = child_style.position();
+        drop(child_style);
+
+        if box_generation_mode == BoxGenerationMode::None {
+            continue;
+        }
+
+        let anchor = match position.is_absolutely_positioned() {
+            true => find_anchor(tree, child, &context.anchors),
+            false => None,
+        };
+
+        match position {
+            Position::Fixed => {
+                layout_out_of_flow_node(tree, child, children_origin, direction, context.viewport, anchor)
+            }
+            Position::Absolute if anchor.is_some() => layout_out_of_flow_node(
+                tree,
+                child,
+                absolute_children_origin,
+                direction,
+                children_containing_block,
+                anchor,
+            ),
+            Position::Sticky => {
+                apply_sticky_offset(tree, child, children_origin, direction, content_box, children_scrollport)
+            }
+            Position::Relative | Position::Static | Position::Absolute => {}
+        }
+
+        let child_layout = *tree.get_unrounded_layout(child);
+        let parent_origin = if position == Position::Absolute { absolute_children_origin } else { children_origin };
+        let child_origin =
+            Point { x: parent_origin.x + child_layout.location.x, y: parent_origin.y + child_layout.location.y };
+        let scrolled_location = Point { x: child_origin.x - origin.x, y: child_origin.y - origin.y };
+        tree.set_unrounded_layout(child, &Layout { scrolled_location, ..child_layout });
+        // Anchors can be referred to by the nodes which come after them in the tree
+        if tree.get_core_container_style(child).anchor_name().is_some() {
+            context.anchors.push((child, Area { origin: child_origin, size: child_layout.size }));
+        }
+
+        // The descendants of a fixed node are not moved by the scroll offsets of the fixed node's ancestors
+        let child_scrollport = if position == Position::Fixed { context.viewport } else { children_scrollport };
+        position_descendants(
+            tree,
+            child,
+            child_origin,
+            false,
+            child_scrollport,
+            children_containing_block,
+            children_scroll,
+            context,
+        );
+
+        // Fixed nodes do not contribute to the scrollable overflow of their ancestors, and sticky nodes contribute from
+        // their position in the normal flow
+        #[cfg(feature = "content_size")]
+        {
+            let child_layout = *tree.get_unrounded_layout(child);
+            let location = Point {
+                x: child_layout.location.x - child_layout.sticky_offset.x,
+                y: child_layout.location.y - child_layout.sticky_offset.y,
+            };
+            let child_overflow = tree.get_core_container_style(child).overflow();
+            let contribution = compute_scrollable_overflow_contribution(location, &child_layout, child_overflow);
+            match position {
+                Position::Fixed => {}
+                Position::Absolute if !is_containing_block => context.pending_overflow.push(Rect {
+                    left: contribution.left + absolute_children_origin.x,
+                    right: contribution.right + absolute_children_origin.x,
+                    top: contribution.top + absolute_children_origin.y,
+                    bottom: contribution.bottom + absolute_children_origin.y,
+                }),
+                Position::Absolute => overflow_contributions = union(overflow_contributions, contribution),
+                Position::Relative | Position::Static | Position::Sticky => {
+                    overflow_contributions = union(overflow_contributions, contribution);
+                    let margin_box = edges(
+                        Point { x: location.x - child_layout.margin.left, y: location.y - child_layout.margin.top },
+                        Size {
+                            width: child_layout.size.width + child_layout.margin.horizontal_axis_sum(),
+                            height: child_layout.size.height + child_layout.margin.vertical_axis_sum(),
+                        },
+                    );
+                    inflow_bounds = union(inflow_bounds, margin_box);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "content_size")]
+    {
+        // A containing block takes over the contributions of the absolute descendants which it is the containing block of
+        if is_containing_block {
+            for pending in context.pending_overflow.drain(pending_overflow_start..) {
+                let contribution = Rect {
+                    left: pending.left - children_origin.x,
+                    right: pending.right - children_origin.x,
+                    top: pending.top - children_origin.y,
+                    bottom: pending.bottom - children_origin.y,
+                };
+                overflow_contributions = union(overflow_contributions, contribution);
+            }
+        }
+
+        // The content of a leaf (such as text) is only known from its content size
+        let layout = *tree.get_unrounded_layout(node);
+        if tree.child_count(node) == 0 {
+            overflow_contributions = union(overflow_contributions, edges(Point::ZERO, layout.content_size));
+        }
+
+        let (scrollable_overflow_location, scrollable_overflow_size) = compute_scrollable_overflow(
+            &layout,
+            overflow_contributions,
+            inflow_bounds,
+            is_scroll_container,
+            writing_mode,
+            direction,
+        );
+        tree.set_unrounded_layout(node, &Layout { scrollable_overflow_location, scrollable_overflow_size, ..layout });
+    }
+}
+
+/// Clamps the scroll offset of a scroll container (or of the root node) to the range through which its content can be
+/// scrolled. The root node scrolls the viewport, so unless it is a scroll container it can be scrolled until the end of
+/// its border box or of its content reaches the end of the viewport.
+///
+/// Without the `content_size` feature the extent of the content is not known, so offsets are only clamped to be
+/// non-negative.
+#[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+fn clamp_scroll_offset(
+    scroll_offset: Point<f32>,
+    layout: &Layout,
+    scrolls_viewport: bool,
+    viewport: Area,
+) -> Point<f32> {
+    #[cfg(feature = "content_size")]
+    let max_offset = match scrolls_viewport {
+        true => Point {
+            x: layout.location.x + f32_max(layout.size.width, layout.content_size.width) - viewport.size.width,
+            y: layout.location.y + f32_max(layout.size.height, layout.content_size.height) - viewport.size.height,
+        },
+        false => Point { x: layout.scroll_width(), y: layout.scroll_height() },
+    };
+    #[cfg(not(feature = "content_size"))]
+    let max_offset = Point { x: f32::INFINITY, y: f32::INFINITY };
+
+    Point {
+        x: f32_max(0.0, f32_min(scroll_offset.x, max_offset.x)),
+        y: f32_max(0.0, f32_min(scroll_offset.y, max_offset.y)),
+    }
+}
+
+/// Finds the border box of the anchor that a node refers to with its `position_anchor`: the last of the `anchors`
+/// visited so far whose `anchor_name` matches
+fn find_anchor(tree: &impl LayoutPartialTree, node: NodeId, anchors: &[(NodeId, Area)]) -> Option<Area> {
+    let style = tree.get_core_container_style(node);
+    let name = style.position_anchor()?;
+    anchors
+        .iter()
+        .rev()
+        .find(|(anchor, _)| tree.get_core_container_style(*anchor).anchor_name() == Some(name))
+        .map(|(_, area)| *area)
+}
+
+/// Lays out a fixed or absolute node against its containing block (the viewport for fixed nodes), and positions it
+/// relative to its parent such that it is placed at the same position within its containing block regardless of where
+/// its parent is.
+///
+/// `parent_origin` is the position in the viewport of the (scrolled) origin of the coordinate space of the node's parent.
+/// If the node is anchored, `anchor` is the border box of its anchor, and its position fallbacks are tried in order if
+/// it overflows its containing block.
+fn layout_out_of_flow_node(
+    tree: &mut (impl LayoutPartialTree + RoundTree),
+    node: NodeId,
+    parent_origin: Point<f32>,
+    direction: Direction,
+    containing_block: Area,
+    anchor: Option<Area>,
+) {
+    let previous_layout = *tree.get_unrounded_layout(node);
+    let style = tree.get_core_container_style(node);
+    let is_vertical = style.writing_mode().is_vertical();
+    let fallback_count = if anchor.is_some() { style.position_try_fallbacks().len() } else { 0 };
+    let base_option =
+        PositionOption { inset: style.inset(), margin: style.margin(), position_area: style.position_area() };
+    drop(style);
+
+    let mut layout = compute_out_of_flow_layout(
+        tree,
+        node,
+        &previous_layout,
+        parent_origin,
+        direction,
+        containing_block,
+        anchor,
+        base_option,
+    );
+
+    if fallback_count > 0 && overflows(&layout, parent_origin, containing_block) {
+        let mut fits = false;
+        for index in 0..fallback_count {
+            let fallback = tree.get_core_container_style(node).position_try_fallbacks()[index];
+            let option = base_option.with_fallback(fallback, is_vertical);
+            layout = compute_out_of_flow_layout(
+                tree,
+                node,
+                &previous_layout,
+                parent_origin,
+                direction,
+                containing_block,
+                anchor,
+                option,
+            );
+            if !overflows(&layout, parent_origin, containing_block) {
+                fits = true;
+                break;
+            }
+        }
+
+        // The node's own styles are used if none of the fallbacks fit. The node is laid out again so that the layout
+        // of its descendants matches.
+        if !fits {
+            layout = compute_out_of_flow_layout(
+                tree,
+                node,
+                &previous_layout,
+                parent_origin,
+                direction,
+                containing_block,
+                anchor,
+                base_option,
+            );
+        }
+    }
+
+    tree.set_unrounded_layout(node, &layout);
+}
+
+/// The styles of an out-of-flow node which are changed by its position fallbacks
+#[derive(Debug, Clone, Copy)]
+struct PositionOption {
+    /// The insets of the node
+    inset: Rect<LengthPercentageAuto>,
+    /// The margins of the node
+    margin: Rect<LengthPercentageAuto>,
+    /// The region of the containing block that the node is placed in
+    position_area: Option<Point<PositionArea>>,
+}
+
+impl PositionOption {
+    /// Apply a position fallback. The block axis of the node is horizontal if its writing mode is vertical.
+    fn with_fallback(self, fallback: PositionTryFallback, is_vertical: bool) -> Self {
+        match fallback {
+            PositionTryFallback::FlipBlock if is_vertical => self.flip_horizontal(),
+            PositionTryFallback::FlipBlock => self.flip_vertical(),
+            PositionTryFallback::FlipInline if is_vertical => self.flip_vertical(),
+            PositionTryFallback::FlipInline => self.flip_horizontal(),
+            PositionTryFallback::FlipBoth => self.flip_horizontal().flip_vertical(),
+            PositionTryFallback::PositionArea(position_area) => Self { position_area: Some(position_area), ..self },
+        }
+    }
+
+    /// Mirror the styles in the horizontal axis
+    fn flip_horizontal(self) -> Self {
+        Self {
+            inset: Rect {
+                left: flip_anchor_side(self.inset.right),
+                right: flip_anchor_side(self.inset.left),
+                ..self.inset
+            },
+            margin: Rect { left: self.margin.right, right: self.margin.left, ..self.margin },
+            position_area: self.position_area.map(|area| Point { x: area.x.flipped(), y: area.y }),
+        }
+    }
+
+    /// Mirror the styles in the vertical axis
+    fn flip_vertical(self) -> Self {
+        Self {
+            inset: Rect {
+                top: flip_anchor_side(self.inset.bottom),
+                bottom: flip_anchor_side(self.inset.top),
+                ..self.inset
+            },
+            margin: Rect { top: self.margin.bottom, bottom: self.margin.top, ..self.margin },
+            position_area: self.position_area.map(|area| Point { x: area.x, y: area.y.flipped() }),
+        }
+    }
+}
+
+/// Replaces an `anchor()` value with one which refers to the opposite side of the anchor
+fn flip_anchor_side(value: LengthPercentageAuto) -> LengthPercentageAuto {
+    match value.into_raw().anchor_side() {
+        Some(side) => LengthPercentageAuto::anchor(side.flipped()),
+        None => value,
+    }
+}
+
+/// Whether the margin box of an out-of-flow node overflows its containing block
+fn overflows(layout: &Layout, parent_origin: Point<f32>, containing_block: Area) -> bool {
+    let left = parent_origin.x + layout.location.x - layout.margin.left;
+    let top = parent_origin.y + layout.location.y - layout.margin.top;
+    let right = left + layout.margin.left + layout.size.width + layout.margin.right;
+    let bottom = top + layout.margin.top + layout.size.height + layout.margin.bottom;
+    let horizontal = containing_block.horizontal();
+    let vertical = containing_block.vertical();
+    left < horizontal.start || right > horizontal.end || top < vertical.start || bottom > vertical.end
+}
+
+/// Computes the layout of a fixed or absolute node with the given insets, margins and position area. See
+/// [`layout_out_of_flow_node`].
+#[allow(clippy::too_many_arguments)]
+fn compute_out_of_flow_layout(
+    tree: &mut (impl LayoutPartialTree + RoundTree),
+    node: NodeId,
+    previous_layout: &Layout,
+    parent_origin: Point<f32>,
+    direction: Direction,
+    containing_block: Area,
+    anchor: Option<Area>,
+    option: PositionOption,
+) -> Layout {
+    // The position area of an anchored node replaces its containing block, and the node is aligned towards its anchor
+    // in the axes in which it has no insets
+    let (containing_block, alignment) = match (anchor, option.position_area) {
+        (Some(anchor), Some(position_area)) => {
+            let horizontal = position_area_region(position_area.x, containing_block.horizontal(), anchor.horizontal());
+            let vertical = position_area_region(position_area.y, containing_block.vertical(), anchor.vertical());
+            let area = Area {
+                origin: Point { x: horizontal.start, y: vertical.start },
+                size: Size { width: horizontal.end - horizontal.start, height: vertical.end - vertical.start },
+            };
+            (
+                area,
+                Point {
+                    x: Some(position_area_alignment(position_area.x)),
+                    y: Some(position_area_alignment(position_area.y)),
+                },
+            )
+        }
+        _ => (containing_block, Point::NONE),
+    };
+    let area_size = containing_block.size;
+
+    let style = tree.get_core_container_style(node);
+    let aspect_ratio = style.aspect_ratio();
+    let has_intrinsic_size = style.size().map(Dimension::is_intrinsic_sizing_keyword);
+    let margin = option.margin.map(|margin| {
+        margin.resolve_to_option(area_size.width, |val, basis| tree.calc(node, val, basis)).unwrap_or(0.0)
+    });
+    let padding = style.padding().resolve_or_zero(Some(area_size.width), |val, basis| tree.calc(node, val, basis));
+    let border = style.border().resolve_or_zero(Some(area_size.width), |val, basis| tree.calc(node, val, basis));
+    let padding_border_sum = (padding + border).sum_axes();
+    let box_sizing_adjustment =
+        if style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+
+    let horizontal = containing_block.horizontal();
+    let vertical = containing_block.vertical();
+    let anchor_horizontal = anchor.map(|anchor| anchor.horizontal());
+    let anchor_vertical = anchor.map(|anchor| anchor.vertical());
+    let left = resolve_inset(tree, node, option.inset.left, horizontal, anchor_horizontal, true, true);
+    let right = resolve_inset(tree, node, option.inset.right, horizontal, anchor_horizontal, true, false);
+    let top = resolve_inset(tree, node, option.inset.top, vertical, anchor_vertical, false, true);
+    let bottom = resolve_inset(tree, node, option.inset.bottom, vertical, anchor_vertical, false, false);
+
+    let style_size = resolve_size(tree, node, style.size(), area_size, anchor)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let min_size = resolve_size(tree, node, style.min_size(), area_size, anchor)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment)
+        .or(padding_border_sum.map(Some))
+        .maybe_max(padding_border_sum);
+    let max_size = resolve_size(tree, node, style.max_size(), area_size, anchor)
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
+    drop(style);
+
+    // Stretch the node between its insets in each axis in which it has no size but both insets are set, unless its size
+    // in that axis is an intrinsic sizing keyword
+    if let (None, Some(left), Some(right), false) = (known_dimensions.width, left, right, has_intrinsic_size.width) {
+        known_dimensions.width = Some(f32_max(area_size.width - margin.horizontal_axis_sum() - left - right, 0.0));
+        known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+    }
+    if let (None, Some(top), Some(bottom), false) = (known_dimensions.height, top, bottom, has_intrinsic_size.height) {
+        known_dimensions.height = Some(f32_max(area_size.height - margin.vertical_axis_sum() - top - bottom, 0.0));
+        known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+    }
+
+    let layout_output = tree.perform_child_layout(
+        node,
+        known_dimensions,
+        area_size.map(Some),
+        Size {
+            width: AvailableSpace::Definite(area_size.width.maybe_clamp(min_size.width, max_size.width)),
+            height: AvailableSpace::Definite(area_size.height.maybe_clamp(min_size.height, max_size.height)),
+        },
+        // Intrinsic sizing keywords are resolved by the node itself
+        if has_intrinsic_size.width || has_intrinsic_size.height {
+            SizingMode::InherentSize
+        } else {
+            SizingMode::ContentSize
+        },
+        Line::FALSE,
+    );
+    let final_size = known_dimensions.unwrap_or(layout_output.size).maybe_clamp(min_size, max_size);
+
+    // The static position (which the parent has already computed) is kept in axes in which neither inset is set. The
+    // static position of a right-to-left node is at its right edge, so it is adjusted for any change in its width.
+    let static_position = Point {
+        x: match direction {
+            Direction::Ltr => previous_layout.location.x,
+            Direction::Rtl => previous_layout.location.x + previous_layout.size.width - final_size.width,
+        },
+        y: previous_layout.location.y,
+    };
+    let location = Point {
+        x: out_of_flow_position_in_axis(
+            Line { start: left, end: right },
+            direction == Direction::Ltr,
+            alignment.x,
+            horizontal,
+            Line { start: margin.left, end: margin.right },
+            final_size.width,
+            parent_origin.x,
+        )
+        .unwrap_or(static_position.x),
+        y: out_of_flow_position_in_axis(
+            Line { start: top, end: bottom },
+            true,
+            alignment.y,
+            vertical,
+            Line { start: margin.top, end: margin.bottom },
+            final_size.height,
+            parent_origin.y,
+        )
+        .unwrap_or(static_position.y),
+    };
+
+    Layout {
+        location,
+        size: final_size,
+        #[cfg(feature = "content_size")]
+        content_size: layout_output.content_size,
+        padding,
+        border,
+        margin,
+        ..*previous_layout
+    }
+}
+
+/// Resolves an inset of a fixed or absolute node in an axis in which its containing block spans `containing_block`.
+/// `anchor()` values are resolved to the distance between the corresponding edge of the containing block and the
+/// referenced side of the node's anchor (which spans `anchor`), and are treated as `auto` if the node has no anchor or if
+/// the side is in the other axis.
+fn resolve_inset(
+    tree: &impl LayoutPartialTree,
+    node: NodeId,
+    inset: LengthPercentageAuto,
+    containing_block: Line<f32>,
+    anchor: Option<Line<f32>>,
+    is_horizontal: bool,
+    is_start: bool,
+) -> Option<f32> {
+    let Some(side) = inset.into_raw().anchor_side() else {
+        let basis = containing_block.end - containing_block.start;
+        return inset.maybe_resolve(basis, |val, basis| tree.calc(node, val, basis));
+    };
+    let anchor = anchor?;
+    let position = match (side, is_horizontal) {
+        (AnchorSide::Center, _) => (anchor.start + anchor.end) / 2.0,
+        (AnchorSide::Left, true) | (AnchorSide::Top, false) => anchor.start,
+        (AnchorSide::Right, true) | (AnchorSide::Bottom, false) => anchor.end,
+        _ => return None,
+    };
+    Some(if is_start { position - containing_block.start } else { containing_block.end - position })
+}
+
+/// Resolves a size style of a fixed or absolute node. `anchor-size()` values are resolved to the size of the node's
+/// anchor, and are treated as `auto` if the node has no anchor.
+fn resolve_size(
+    tree: &impl LayoutPartialTree,
+    node: NodeId,
+    size: Size<Dimension>,
+    area_size: Size<f32>,
+    anchor: Option<Area>,
+) -> Size<Option<f32>> {
+    size.zip_map(area_size, |dimension, basis| match dimension.into_raw().anchor_dimension() {
+        Some(AnchorSize::Width) => anchor.map(|anchor| anchor.size.width),
+        Some(AnchorSize::Height) => anchor.map(|anchor| anchor.size.height),
+        None => dimension.maybe_resolve(basis, |val, basis| tree.calc(node, val, basis)),
+    })
+}
+
+/// The region of the containing block (which spans `containing_block`) that a node with the given position area is
+/// placed in, in one axis. The edges of the anchor (which spans `anchor`) divide the containing block into three tracks.
+fn position_area_region(position_area: PositionArea, containing_block: Line<f32>, anchor: Line<f32>) -> Line<f32> {
+    let (start, end) = match position_area {
+        PositionArea::Start => (containing_block.start, anchor.start),
+        PositionArea::Center => (anchor.start, anchor.end),
+        PositionArea::End => (anchor.end, containing_block.end),
+        PositionArea::SpanStart => (containing_block.start, anchor.end),
+        PositionArea::SpanEnd => (anchor.start, containing_block.end),
+        PositionArea::SpanAll => (containing_block.start, containing_block.end),
+    };
+    Line { start, end: f32_max(start, end) }
+}
+
+/// How a node with the given position area is aligned within its region in one axis, from `0.0` (aligned to the start of
+/// the region) to `1.0` (aligned to the end of the region). Nodes are aligned towards their anchor.
+fn position_area_alignment(position_area: PositionArea) -> f32 {
+    match position_area {
+        PositionArea::Start | PositionArea::SpanStart => 1.0,
+        PositionArea::Center | PositionArea::SpanAll => 0.5,
+        PositionArea::End | PositionArea::SpanEnd => 0.0,
+    }
+}
+
+/// Resolves the position of a fixed or absolute node in one axis, relative to the origin of its parent's coordinate
+/// space, from its insets in that axis. `start_wins` determines which inset takes precedence if both are set. If
+/// neither inset is set, the node is aligned within its containing block according to `alignment` (see
+/// [`position_area_alignment`]), or `None` is returned if there is no alignment.
+fn out_of_flow_position_in_axis(
+    inset: Line<Option<f32>>,
+    start_wins: bool,
+    alignment: Option<f32>,
+    containing_block: Line<f32>,
+    margin: Line<f32>,
+    size: f32,
+    parent_origin: f32,
+) -> Option<f32> {
+    let from_start = inset.start.map(|start| containing_block.start + start + margin.start);
+    let from_end = inset.end.map(|end| containing_block.end - end - margin.end - size);
+    let aligned = alignment.map(|alignment| {
+        let free_space = containing_block.end - containing_block.start - margin.start - margin.end - size;
+        containing_block.start + margin.start + free_space * alignment
+    });
+    let position = if start_wins { from_start.or(from_end) } else { from_end.or(from_start) };
+    position.or(aligned).map(|position| position - parent_origin)
+}
+
+/// Offsets a sticky node such that it stays within the scrollport of its nearest scroll container (deflated by the
+/// node's insets) for as long as it can do so without leaving the content box of its parent.
+fn apply_sticky_offset(
+    tree: &mut (impl LayoutPartialTree + RoundTree),
+    node: NodeId,
+    parent_origin: Point<f32>,
+    direction: Direction,
+    containing_block: Area,
+    scrollport: Area,
+) {
+    let layout = *tree.get_unrounded_layout(node);
+    let inset = tree.get_core_container_style(node).inset();
+    let inset = Rect {
+        left: inset.left.maybe_resolve(scrollport.size.width, |val, basis| tree.calc(node, val, basis)),
+        right: inset.right.maybe_resolve(scrollport.size.width, |val, basis| tree.calc(node, val, basis)),
+        top: inset.top.maybe_resolve(scrollport.size.height, |val, basis| tree.calc(node, val, basis)),
+        bottom: inset.bottom.maybe_resolve(scrollport.size.height, |val, basis| tree.calc(node, val, basis)),
+    };
+
+    // The offset that was applied by a previous run is removed to find the node's position in the normal flow
+    let normal_location =
+        Point { x: layout.location.x - layout.sticky_offset.x, y: layout.location.y - layout.sticky_offset.y };
+    let horizontal_view = scrollport.horizontal();
+    let vertical_view = scrollport.vertical();
+    let sticky_offset = Point {
+        x: sticky_offset_in_axis(
+            Line {
+                start: parent_origin.x + normal_location.x,
+                end: parent_origin.x + normal_location.x + layout.size.width,
+            },
+            Line { start: layout.margin.left, end: layout.margin.right },
+            containing_block.horizontal(),
+            Line {
+                start: inset.left.map(|left| horizontal_view.start + left),
+                end: inset.right.map(|right| horizontal_view.end - right),
+            },
+            direction == Direction::Ltr,
+        ),
+        y: sticky_offset_in_axis(
+            Line {
+                start: parent_origin.y + normal_location.y,
+                end: parent_origin.y + normal_location.y + layout.size.height,
+            },
+            Line { start: layout.margin.top, end: layout.margin.bottom },
+            containing_block.vertical(),
+            Line {
+                start: inset.top.map(|top| vertical_view.start + top),
+                end: inset.bottom.map(|bottom| vertical_view.end - bottom),
+            },
+            true,
+        ),
+    };
+
+    tree.set_unrounded_layout(
+        node,
+        &Layout {
+            location: Point { x: normal_location.x + sticky_offset.x, y: normal_location.y + sticky_offset.y },
+            sticky_offset,
+            ..layout
+        },
+    );
+}
+
+/// Computes the offset of a sticky node in one axis. `border_box` and `containing_block` are the start and end
+/// coordinates of the node's border box and of its containing block, and `sticky_view` is the (optional) start and
+/// end coordinates of the scrollport deflated by the node's insets. `start_wins` determines which edge of the sticky
+/// view takes precedence if the node cannot fit within it.
+fn sticky_offset_in_axis(
+    border_box: Line<f32>,
+    margin: Line<f32>,
+    containing_block: Line<f32>,
+    sticky_view: Line<Option<f32>>,
+    start_wins: bool,
+) -> f32 {
+    // Sticky nodes may move towards the end of their containing block until their end margin edge reaches it...
+    let apply_start_constraint = |offset: f32| match sticky_view.start {
+        Some(view_start) if border_box.start + offset < view_start => {
+            f32_min(view_start - border_box.start, f32_max(0.0, containing_block.end - (border_box.end + margin.end)))
+        }
+        _ => offset,
+    };
+    // ...and towards the start of their containing block until their start margin edge reaches it
+    let apply_end_constraint = |offset: f32| match sticky_view.end {
+        Some(view_end) if border_box.end + offset > view_end => {
+            f32_max(view_end - border_box.end, f32_min(0.0, containing_block.start - (border_box.start - margin.start)))
+        }
+        _ => offset,
+    };
+
+    match start_wins {
+        true => apply_start_constraint(apply_end_constraint(0.0)),
+        false => apply_end_constraint(apply_start_constraint(0.0)),
+    }
+}
@@ -2,12 +2,12 @@
 use crate::compute::common::alignment::compute_alignment_offset;
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::style::{
-    AlignContent, AlignItems, AlignSelf, AvailableSpace, FlexWrap, JustifyContent, LengthPercentageAuto, Overflow,
-    Position,
+    AlignContent, AlignItems, AlignSelf, AlignmentSafety, AvailableSpace, Dimension, FlexWrap, JustifyContent,
+    LengthPercentageAuto, Overflow, Position, Visibility,
 };
 use crate::style::{CoreStyle, FlexDirection, FlexboxContainerStyle, FlexboxItemStyle};
 use crate::style_helpers::{TaffyMaxContent, TaffyMinContent};
-use crate::tree::{Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
+use crate::tree::{ContainingBlock, Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
 use crate::tree::{LayoutFlexboxContainer, LayoutPartialTreeExt, NodeId};
 use crate::util::debug::debug_log;
 use crate::util::sys::{f32_max, new_vec_with_capacity, Vec};
@@ -16,16 +16,23 @@ use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{BoxGenerationMode, BoxSizing};
 
 use super::common::alignment::apply_alignment_fallback;
+use super::common::containing_block::{containing_block_for_child, AbsoluteContainingBlock};
 #[cfg(feature = "content_size")]
 use super::common::content_size::compute_content_size_contribution;
+use super::common::scrollbars::{compute_with_scrollbars, Scrollbars};
+use super::common::sizing_keywords::{compute_with_sizing_keywords, SizingKeywords};
+use super::common::writing_mode::compute_with_transposed_axes;
 
 /// The intermediate results of a flexbox calculation for a single item
 struct FlexItem {
     /// The identifier for the associated node
     node: NodeId,
 
-    /// The order of the node relative to it's siblings
+    /// The order of the node relative to it's siblings. This is always the document order
+    /// and is not affected by the `order` style (which instead determines the order of the `FlexItem`s)
     order: u32,
+    /// The `order` style of the item, which determines its position in the "order-modified document order"
+    order_style: i32,
 
     /// The base size of this item
     size: Size<Option<f32>>,
@@ -35,11 +42,15 @@ struct FlexItem {
     max_size: Size<Option<f32>>,
     /// The cross-alignment of this item
     align_self: AlignSelf,
+    /// How the item is aligned in the cross axis if it overflows its line
+    align_self_safety: AlignmentSafety,
 
+    /// The position style of the item
+    position: Position,
     /// The overflow style of the item
     overflow: Point<Overflow>,
-    /// The width of the scrollbars (if it has any)
-    scrollbar_width: f32,
+    /// The visibility style of the item
+    visibility: Visibility,
     /// The flex shrink style of the item
     flex_shrink: f32,
     /// The flex grow style of the item
@@ -124,8 +135,14 @@ struct AlgoConstants {
     is_column: bool,
     /// Is wrapping enabled (in either direction)
     is_wrap: bool,
-    /// Is the wrap direction inverted
-    is_wrap_reverse: bool,
+    /// Is the node's `direction` right-to-left
+    is_rtl: bool,
+    /// Are items laid out along the main axis in the opposite of its physical direction
+    /// (due to a `*-reverse` flex direction or a right-to-left row)
+    is_main_reverse: bool,
+    /// Are lines and items laid out along the cross axis in the opposite of its physical direction
+    /// (due to `wrap-reverse` or a right-to-left column)
+    is_cross_reverse: bool,
 
     /// The item's min_size style
     min_size: Size<Option<f32>>,
@@ -138,16 +155,24 @@ struct AlgoConstants {
     /// The space between the content box and the border box.
     /// This consists of padding + border + scrollbar_gutter.
     content_box_inset: Rect<f32>,
-    /// The size reserved for scrollbar gutters in each axis
-    scrollbar_gutter: Point<f32>,
+    /// The size reserved for scrollbar gutters on each side
+    scrollbar_gutter: Rect<f32>,
+    /// The containing block of the absolutely positioned children (`None` if it is not known yet)
+    containing_block: Option<AbsoluteContainingBlock>,
     /// The gap of this section
     gap: Size<f32>,
     /// The align_items property of this node
     align_items: AlignItems,
+    /// The align_items_safety property of this node
+    align_items_safety: AlignmentSafety,
     /// The align_content property of this node
     align_content: AlignContent,
+    /// The align_content_safety property of this node
+    align_content_safety: AlignmentSafety,
     /// The justify_content property of this node
     justify_content: Option<JustifyContent>,
+    /// The justify_content_safety property of this node
+    justify_content_safety: AlignmentSafety,
 
     /// The border-box size of the node being laid out (if known)
     node_outer_size: Size<Option<f32>>,
@@ -160,37 +185,83 @@ struct AlgoConstants {
     inner_container_size: Size<f32>,
 }
 
+impl AlgoConstants {
+    /// Resolves an item's `align_self` style against the container's `align_items` style
+    #[inline(always)]
+    fn resolve_align_self(&self, align_self: Option<AlignSelf>) -> AlignSelf {
+        let align_self = align_self.unwrap_or(self.align_items);
+        // `Start` and `End` alignment refer to the inline axis, which runs right-to-left in rtl nodes
+        match self.is_column && self.is_rtl {
+            true => align_self.with_start_and_end_swapped(),
+            false => align_self,
+        }
+    }
+
+    /// Resolves the `justify_content` alignment of a line (after fallback alignment has been applied to it)
+    #[inline(always)]
+    fn resolve_justify_content(&self, justify_content: JustifyContent) -> JustifyContent {
+        // `Start` and `End` alignment refer to the inline axis, which runs right-to-left in rtl nodes
+        match self.is_row && self.is_rtl {
+            true => justify_content.with_start_and_end_swapped(),
+            false => justify_content,
+        }
+    }
+
+    /// Resolves the `align_content` alignment of the lines (after fallback alignment has been applied to it)
+    #[inline(always)]
+    fn resolve_align_content(&self, align_content: AlignContent) -> AlignContent {
+        // `Start` and `End` alignment refer to the inline axis, which runs right-to-left in rtl nodes
+        match self.is_column && self.is_rtl {
+            true => align_content.with_start_and_end_swapped(),
+            false => align_content,
+        }
+    }
+}
+
 /// Computes the layout of a box according to the flexbox algorithm
 pub fn compute_flexbox_layout(
     tree: &mut impl LayoutFlexboxContainer,
     node: NodeId,
     inputs: LayoutInput,
 ) -> LayoutOutput {
+    let style = tree.get_flexbox_container_style(node);
+    let writing_mode = style.writing_mode();
+    let sizing_keywords = SizingKeywords::new(&style, inputs.parent_size, |val, basis| tree.calc(node, val, basis));
+    drop(style);
+
+    compute_with_sizing_keywords(sizing_keywords, inputs, |inputs| match writing_mode.is_vertical() {
+        true => compute_with_transposed_axes(tree, node, inputs, writing_mode, compute_horizontal_tb),
+        false => compute_horizontal_tb(tree, node, inputs),
+    })
+}
+
+/// Computes the layout of a box according to the flexbox algorithm, treating the x axis as the inline axis
+fn compute_horizontal_tb(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, run_mode, .. } = inputs;
     let style = tree.get_flexbox_container_style(node);
 
     // Pull these out earlier to avoid borrowing issues
     let aspect_ratio = style.aspect_ratio();
-    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
-    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node, val, basis));
+    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node, val, basis));
     let padding_border_sum = padding.sum_axes() + border.sum_axes();
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
 
     let min_size = style
         .min_size()
-        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let max_size = style
         .max_size()
-        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let clamped_style_size = if inputs.sizing_mode == SizingMode::InherentSize {
         style
             .size()
-            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(parent_size, |val, basis| tree.calc(node, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment)
             .maybe_clamp(min_size, max_size)
@@ -217,17 +288,38 @@ pub fn compute_flexbox_layout(
     }
 
     debug_log!("FLEX:", dbg:style.flex_direction());
+    let scrollbars = Scrollbars::new(&style, parent_size.width, |val, basis| tree.calc(node, val, basis));
     drop(style);
 
-    compute_preliminary(tree, node, LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs })
+    let inputs = LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs };
+    compute_with_scrollbars(scrollbars, |scrollbar_gutter| {
+        compute_preliminary(tree, node, inputs, scrollbar_gutter, None)
+    })
 }
 
 /// Compute a preliminary size for an item
-fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
-    let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
+///
+/// `scrollbar_gutter` is the space reserved for the container's scrollbars on each side. `strut_sizes` is `None` in the
+/// first layout round, and contains the strut size of each collapsed item in the second layout round (see step 10 below)
+fn compute_preliminary(
+    tree: &mut impl LayoutFlexboxContainer,
+    node: NodeId,
+    inputs: LayoutInput,
+    scrollbar_gutter: Rect<f32>,
+    strut_sizes: Option<&[(NodeId, f32)]>,
+) -> LayoutOutput {
+    let LayoutInput { known_dimensions, parent_size, available_space, run_mode, containing_block, .. } = inputs;
 
     // Define some general constants we will need for the remainder of the algorithm.
-    let mut constants = compute_constants(tree, tree.get_flexbox_container_style(node), known_dimensions, parent_size);
+    let mut constants = compute_constants(
+        tree,
+        node,
+        tree.get_flexbox_container_style(node),
+        known_dimensions,
+        parent_size,
+        scrollbar_gutter,
+        containing_block,
+    );
 
     // 9. Flex Layout Algorithm
 
@@ -237,6 +329,12 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     debug_log!("generate_anonymous_flex_items");
     let mut flex_items = generate_anonymous_flex_items(tree, node, &constants);
 
+    // In the second layout round, collapsed items are ignored entirely except for their struts
+    let collapsed_items = match strut_sizes {
+        Some(strut_sizes) => remove_collapsed_items(&mut flex_items, strut_sizes),
+        None => Vec::new(),
+    };
+
     // 9.2. Line Length Determination
 
     // 2. Determine the available main and cross space for the flex items
@@ -287,7 +385,7 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
         let new_gap = style
             .gap()
             .main(constants.dir)
-            .maybe_resolve(inner_container_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(inner_container_size, |val, basis| tree.calc(node, val, basis))
             .unwrap_or(0.0);
         constants.gap.set_main(constants.dir, new_gap);
     }
@@ -314,6 +412,7 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     // 8. Calculate the cross size of each flex line.
     debug_log!("calculate_cross_size");
     calculate_cross_size(&mut flex_lines, known_dimensions, &constants);
+    apply_collapsed_item_struts(&mut flex_lines, &collapsed_items);
 
     // 9. Handle 'align-content: stretch'.
     debug_log!("handle_align_content_stretch");
@@ -331,8 +430,21 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     //     that strut size.
     //
     //     Skip this step in the second layout round.
-
-    // TODO implement once (if ever) we support visibility:collapse
+    if strut_sizes.is_none() {
+        let strut_sizes: Vec<(NodeId, f32)> = flex_lines
+            .iter()
+            .flat_map(|line| {
+                line.items
+                    .iter()
+                    .filter(|item| item.visibility == Visibility::Collapse)
+                    .map(move |item| (item.node, line.cross_size))
+            })
+            .collect();
+        if !strut_sizes.is_empty() {
+            debug_log!("restart_layout_with_collapsed_items");
+            return compute_preliminary(tree, node, inputs, scrollbar_gutter, Some(&strut_sizes));
+        }
+    }
 
     // 11. Determine the used cross size of each flex item.
     debug_log!("determine_used_cross_size");
@@ -376,8 +488,14 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
     let len = tree.child_count(node);
     for order in 0..len {
         let child = tree.get_child_id(node, order);
-        if tree.get_flexbox_child_style(child).box_generation_mode() == BoxGenerationMode::None {
-            tree.set_unrounded_layout(child, &Layout::with_order(order as u32));
+        let child_style = tree.get_flexbox_child_style(child);
+        let is_hidden = child_style.box_generation_mode() == BoxGenerationMode::None;
+        let is_collapsed =
+            child_style.visibility() == Visibility::Collapse && !child_style.position().is_absolutely_positioned();
+        drop(child_style);
+        if is_hidden || is_collapsed {
+            let visibility = if is_collapsed { Visibility::Collapse } else { Visibility::Visible };
+            tree.set_unrounded_layout(child, &Layout { visibility, ..Layout::with_order(order as u32) });
             tree.perform_child_layout(
                 child,
                 Size::NONE,
@@ -416,20 +534,26 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
 #[inline]
 fn compute_constants(
     tree: &impl LayoutFlexboxContainer,
+    node: NodeId,
     style: impl FlexboxContainerStyle,
     known_dimensions: Size<Option<f32>>,
     parent_size: Size<Option<f32>>,
+    scrollbar_gutter: Rect<f32>,
+    containing_block: ContainingBlock,
 ) -> AlgoConstants {
     let dir = style.flex_direction();
     let is_row = dir.is_row();
     let is_column = dir.is_column();
     let is_wrap = matches!(style.flex_wrap(), FlexWrap::Wrap | FlexWrap::WrapReverse);
     let is_wrap_reverse = style.flex_wrap() == FlexWrap::WrapReverse;
+    let is_rtl = style.direction().is_rtl();
+    let is_main_reverse = dir.is_reverse() != (is_row && is_rtl);
+    let is_cross_reverse = is_wrap_reverse != (is_column && is_rtl);
 
     let aspect_ratio = style.aspect_ratio();
-    let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
-    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
-    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node, val, basis));
+    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node, val, basis));
+    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node, val, basis));
     let padding_border_sum = padding.sum_axes() + border.sum_axes();
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
@@ -438,21 +562,13 @@ fn compute_constants(
     let align_content = style.align_content().unwrap_or(AlignContent::Stretch);
     let justify_content = style.justify_content();
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow().transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width(),
-        _ => 0.0,
-    });
-    // TODO: make side configurable based on the `direction` property
-    let mut content_box_inset = padding + border;
-    content_box_inset.right += scrollbar_gutter.x;
-    content_box_inset.bottom += scrollbar_gutter.y;
+    let content_box_inset = padding + border + scrollbar_gutter;
+    let containing_block =
+        AbsoluteContainingBlock::resolve(containing_block, style.position(), border + scrollbar_gutter);
 
     let node_outer_size = known_dimensions;
     let node_inner_size = node_outer_size.maybe_sub(content_box_inset.sum_axes());
-    let gap = style.gap().resolve_or_zero(node_inner_size.or(Size::zero()), |val, basis| tree.calc(val, basis));
+    let gap = style.gap().resolve_or_zero(node_inner_size.or(Size::zero()), |val, basis| tree.calc(node, val, basis));
 
     let container_size = Size::zero();
     let inner_container_size = Size::zero();
@@ -462,15 +578,17 @@ fn compute_constants(
         is_row,
         is_column,
         is_wrap,
-        is_wrap_reverse,
+        is_rtl,
+        is_main_reverse,
+        is_cross_reverse,
         min_size: style
             .min_size()
-            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(parent_size, |val, basis| tree.calc(node, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment),
         max_size: style
             .max_size()
-            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(parent_size, |val, basis| tree.calc(node, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment),
         margin,
@@ -478,9 +596,13 @@ fn compute_constants(
         gap,
         content_box_inset,
         scrollbar_gutter,
+        containing_block,
         align_items,
+        align_items_safety: style.align_items_safety(),
         align_content,
+        align_content_safety: style.align_content_safety(),
         justify_content,
+        justify_content_safety: style.justify_content_safety(),
         node_outer_size,
         node_inner_size,
         container_size,
@@ -499,57 +621,79 @@ fn generate_anonymous_flex_items(
     node: NodeId,
     constants: &AlgoConstants,
 ) -> Vec<FlexItem> {
-    tree.child_ids(node)
+    let mut items: Vec<FlexItem> = tree
+        .child_ids(node)
         .enumerate()
         .map(|(index, child)| (index, child, tree.get_flexbox_child_style(child)))
-        .filter(|(_, _, style)| style.position() != Position::Absolute)
+        .filter(|(_, _, style)| !style.position().is_absolutely_positioned())
         .filter(|(_, _, style)| style.box_generation_mode() != BoxGenerationMode::None)
         .map(|(index, child, child_style)| {
             let aspect_ratio = child_style.aspect_ratio();
             let padding = child_style
                 .padding()
-                .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis));
+                .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(child, val, basis));
             let border = child_style
                 .border()
-                .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis));
+                .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(child, val, basis));
             let pb_sum = (padding + border).sum_axes();
             let box_sizing_adjustment =
                 if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
             FlexItem {
                 node: child,
                 order: index as u32,
+                order_style: child_style.order(),
                 size: child_style
                     .size()
-                    .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
+                    .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(child, val, basis))
                     .maybe_apply_aspect_ratio(aspect_ratio)
                     .maybe_add(box_sizing_adjustment),
                 min_size: child_style
                     .min_size()
-                    .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
+                    .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(child, val, basis))
                     .maybe_apply_aspect_ratio(aspect_ratio)
                     .maybe_add(box_sizing_adjustment),
                 max_size: child_style
                     .max_size()
-                    .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
+                    .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(child, val, basis))
                     .maybe_apply_aspect_ratio(aspect_ratio)
                     .maybe_add(box_sizing_adjustment),
 
-                inset: child_style
-                    .inset()
-                    .zip_size(constants.node_inner_size, |p, s| p.maybe_resolve(s, |val, basis| tree.calc(val, basis))),
+                inset: {
+                    let inset = child_style.inset().zip_size(constants.node_inner_size, |p, s| {
+                        p.maybe_resolve(s, |val, basis| tree.calc(child, val, basis))
+                    });
+                    // If both horizontal insets are set then the one on the inline-start side takes precedence.
+                    // The insets of sticky items constrain their position after layout rather than offsetting them,
+                    // and the insets of static items are ignored.
+                    match child_style.position() {
+                        Position::Sticky | Position::Static => {
+                            Rect { left: None, right: None, top: None, bottom: None }
+                        }
+                        _ if constants.is_rtl && inset.right.is_some() => Rect { left: None, ..inset },
+                        _ => inset,
+                    }
+                },
                 margin: child_style
                     .margin()
-                    .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis)),
+                    .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(child, val, basis)),
                 margin_is_auto: child_style.margin().map(LengthPercentageAuto::is_auto),
                 padding: child_style
                     .padding()
-                    .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis)),
+                    .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(child, val, basis)),
                 border: child_style
                     .border()
-                    .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis)),
-                align_self: child_style.align_self().unwrap_or(constants.align_items),
+                    .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(child, val, basis)),
+                align_self: match constants.resolve_align_self(child_style.align_self()) {
+                    // Stretching only applies to items whose cross size is auto, and otherwise behaves like flex-start
+                    AlignSelf::Stretch if child_style.size().cross(constants.dir).is_intrinsic_sizing_keyword() => {
+                        AlignSelf::FlexStart
+                    }
+                    align_self => align_self,
+                },
+                align_self_safety: child_style.align_self_safety().unwrap_or(constants.align_items_safety),
+                position: child_style.position(),
                 overflow: child_style.overflow(),
-                scrollbar_width: child_style.scrollbar_width(),
+                visibility: child_style.visibility(),
                 flex_grow: child_style.flex_grow(),
                 flex_shrink: child_style.flex_shrink(),
                 flex_basis: 0.0,
@@ -570,7 +714,18 @@ fn generate_anonymous_flex_items(
                 offset_cross: 0.0,
             }
         })
-        .collect()
+        .collect();
+
+    // Flex items are laid out in "order-modified document order". Items with an equal `order` retain their
+    // document order (which is still what is reported as the paint order).
+    // https://www.w3.org/TR/css-flexbox-1/#order-property
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    items.sort_by_key(|item| item.order_style);
+    // Stable sorting requires an allocator, so without one the document order is used to break ties instead
+    #[cfg(not(any(feature = "std", feature = "alloc")))]
+    items.sort_unstable_by_key(|item| (item.order_style, item.order));
+
+    items
 }
 
 /// Determine the available main and cross space for the flex items.
@@ -647,8 +802,6 @@ fn determine_flex_base_size(
     let dir = constants.dir;
 
     for child in flex_items.iter_mut() {
-        let child_style = tree.get_flexbox_child_style(child.node);
-
         // Parent size for child sizing
         let cross_axis_parent_size = constants.node_inner_size.cross(dir);
         let child_parent_size = Size::from_cross(dir, cross_axis_parent_size);
@@ -673,6 +826,25 @@ fn determine_flex_base_size(
             },
         };
 
+        // If the item's cross size is an intrinsic sizing keyword (such as max-content) then the item resolves the keyword
+        // itself, after which its cross size is treated as definite
+        let cross_size_is_intrinsic_keyword =
+            tree.get_flexbox_child_style(child.node).size().cross(dir).is_intrinsic_sizing_keyword();
+        if cross_size_is_intrinsic_keyword && child.size.cross(dir).is_none() {
+            let cross_size = tree.measure_child_size(
+                child.node,
+                child.size.with_cross(dir, None),
+                child_parent_size,
+                available_space.with_cross(dir, cross_axis_available_space),
+                SizingMode::InherentSize,
+                dir.cross_axis(),
+                Line::FALSE,
+            );
+            child.size.set_cross(dir, Some(cross_size));
+        }
+
+        let child_style = tree.get_flexbox_child_style(child.node);
+
         // Known dimensions for child sizing
         let child_known_dimensions = {
             let mut ckd = child.size.with_main(dir, None);
@@ -687,8 +859,10 @@ fn determine_flex_base_size(
 
         let container_width = constants.node_inner_size.main(dir);
         let box_sizing_adjustment = if child_style.box_sizing() == BoxSizing::ContentBox {
-            let padding = child_style.padding().resolve_or_zero(container_width, |val, basis| tree.calc(val, basis));
-            let border = child_style.border().resolve_or_zero(container_width, |val, basis| tree.calc(val, basis));
+            let padding =
+                child_style.padding().resolve_or_zero(container_width, |val, basis| tree.calc(child.node, val, basis));
+            let border =
+                child_style.border().resolve_or_zero(container_width, |val, basis| tree.calc(child.node, val, basis));
             (padding + border).sum_axes()
         } else {
             Size::ZERO
@@ -696,8 +870,10 @@ fn determine_flex_base_size(
         .main(dir);
         let flex_basis = child_style
             .flex_basis()
-            .maybe_resolve(container_width, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(container_width, |val, basis| tree.calc(child.node, val, basis))
             .maybe_add(box_sizing_adjustment);
+        let main_size_is_sizing_keyword =
+            child_style.flex_basis().is_auto() && child_style.size().main(dir).is_sizing_keyword();
 
         drop(child_style);
 
@@ -716,6 +892,21 @@ fn determine_flex_base_size(
                 break 'flex_basis flex_basis;
             };
 
+            // If the used flex basis is auto and the item's main size is a sizing keyword (such as max-content) then the
+            // item resolves the keyword itself when it is sized under the container's available space
+            if main_size_is_sizing_keyword {
+                let child_available_space = available_space.with_cross(dir, cross_axis_available_space);
+                break 'flex_basis tree.measure_child_size(
+                    child.node,
+                    child_known_dimensions,
+                    child_parent_size,
+                    child_available_space,
+                    SizingMode::InherentSize,
+                    dir.main_axis(),
+                    Line::FALSE,
+                );
+            }
+
             // C. If the used flex basis is content or depends on its available space,
             //    and the flex container is being sized under a min-content or max-content
             //    constraint (e.g. when performing automatic table layout [CSS21]),
@@ -731,7 +922,8 @@ fn determine_flex_base_size(
             //    for a box in an orthogonal flow [CSS3-WRITING-MODES]. The flex base size
             //    is the item’s max-content main size.
 
-            // TODO if/when vertical writing modes are supported
+            // This is also covered by the implementation of E below, as items in an orthogonal flow
+            // are sized by their own layout algorithm according to their own writing mode.
 
             // E. Otherwise, size the item into the available space using its used flex basis
             //    in place of its main size, treating a value of content as max-content.
@@ -1147,7 +1339,7 @@ fn determine_container_main_size(
 
     let outer_main_size = outer_main_size
         .maybe_clamp(constants.min_size.main(constants.dir), constants.max_size.main(constants.dir))
-        .max(main_content_box_inset - constants.scrollbar_gutter.main(constants.dir));
+        .max(main_content_box_inset - constants.scrollbar_gutter.main_axis_sum(constants.dir));
 
     // let outer_main_size = inner_main_size + constants.padding_border.main_axis_sum(constants.dir);
     let inner_main_size = f32_max(outer_main_size - main_content_box_inset, 0.0);
@@ -1415,8 +1607,8 @@ fn calculate_children_base_lines(
     constants: &AlgoConstants,
 ) {
     // Only compute baselines for flex rows because we only support baseline alignment in the cross axis
-    // where that axis is also the inline axis
-    // TODO: this may need revisiting if/when we support vertical writing modes
+    // where that axis is also the block axis. Containers with a vertical writing mode are laid out with
+    // their axes transposed, so in those containers this is also the case for flex rows.
     if !constants.is_row {
         return;
     }
@@ -1539,6 +1731,50 @@ fn calculate_cross_size(flex_lines: &mut [FlexLine], node_size: Size<Option<f32>
     }
 }
 
+/// Removes the collapsed items from the flex items for the second layout round, returning the position that each
+/// collapsed item had among the remaining items along with its strut size.
+///
+/// # [9.4. Cross Size Determination](https://www.w3.org/TR/css-flexbox-1/#cross-sizing)
+///
+/// - [**Collapse visibility:collapse items**](https://www.w3.org/TR/css-flexbox-1/#algo-visibility).
+#[inline]
+fn remove_collapsed_items(flex_items: &mut Vec<FlexItem>, strut_sizes: &[(NodeId, f32)]) -> Vec<(usize, f32)> {
+    let mut collapsed_items = Vec::new();
+    let mut position = 0;
+    for item in flex_items.iter() {
+        if item.visibility == Visibility::Collapse {
+            let strut_size = strut_sizes.iter().find(|(node, _)| *node == item.node).map_or(0.0, |(_, size)| *size);
+            collapsed_items.push((position, strut_size));
+        } else {
+            position += 1;
+        }
+    }
+    flex_items.retain(|item| item.visibility != Visibility::Collapse);
+    collapsed_items
+}
+
+/// Grows each flex line to the largest strut size among the collapsed items in that line.
+///
+/// # [9.4. Cross Size Determination](https://www.w3.org/TR/css-flexbox-1/#cross-sizing)
+///
+/// - [**Collapse visibility:collapse items**](https://www.w3.org/TR/css-flexbox-1/#algo-visibility). After calculating the cross size
+///   of the lines, if any line’s cross size is less than the largest strut size among all the collapsed items in the line,
+///   set its cross size to that strut size.
+#[inline]
+fn apply_collapsed_item_struts(flex_lines: &mut [FlexLine], collapsed_items: &[(usize, f32)]) {
+    for &(position, strut_size) in collapsed_items {
+        // Collapsed items have a zero main size, so each one is in the same line as the item that precedes it
+        let mut line_end = 0;
+        let line = flex_lines.iter_mut().find(|line| {
+            line_end += line.items.len();
+            line_end >= position
+        });
+        if let Some(line) = line {
+            line.cross_size = f32_max(line.cross_size, strut_size);
+        }
+    }
+}
+
 /// Handle 'align-content: stretch'.
 ///
 /// # [9.4. Cross Size Determination](https://www.w3.org/TR/css-flexbox-1/#cross-sizing)
@@ -1593,29 +1829,29 @@ fn determine_used_cross_size(
 
         for child in line.items.iter_mut() {
             let child_style = tree.get_flexbox_child_style(child.node);
+            let cross_size = child_style.size().cross(constants.dir);
             child.target_size.set_cross(
                 constants.dir,
-                if child.align_self == AlignSelf::Stretch
+                if (child.align_self == AlignSelf::Stretch && cross_size.is_auto() || cross_size.is_stretch())
                     && !child.margin_is_auto.cross_start(constants.dir)
                     && !child.margin_is_auto.cross_end(constants.dir)
-                    && child_style.size().cross(constants.dir).is_auto()
                 {
                     // For some reason this particular usage of max_width is an exception to the rule that max_width's transfer
                     // using the aspect_ratio (if set). Both Chrome and Firefox agree on this. And reading the spec, it seems like
                     // a reasonable interpretation. Although it seems to me that the spec *should* apply aspect_ratio here.
                     let padding = child_style
                         .padding()
-                        .resolve_or_zero(constants.node_inner_size, |val, basis| tree.calc(val, basis));
+                        .resolve_or_zero(constants.node_inner_size, |val, basis| tree.calc(child.node, val, basis));
                     let border = child_style
                         .border()
-                        .resolve_or_zero(constants.node_inner_size, |val, basis| tree.calc(val, basis));
+                        .resolve_or_zero(constants.node_inner_size, |val, basis| tree.calc(child.node, val, basis));
                     let pb_sum = (padding + border).sum_axes();
                     let box_sizing_adjustment =
                         if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
 
                     let max_size_ignoring_aspect_ratio = child_style
                         .max_size()
-                        .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(val, basis))
+                        .maybe_resolve(constants.node_inner_size, |val, basis| tree.calc(child.node, val, basis))
                         .maybe_add(box_sizing_adjustment);
 
                     (line_cross_size - child.margin.cross_axis_sum(constants.dir)).maybe_clamp(
@@ -1684,12 +1920,16 @@ fn distribute_remaining_free_space(flex_lines: &mut [FlexLine], constants: &Algo
             }
         } else {
             let num_items = line.items.len();
-            let layout_reverse = constants.dir.is_reverse();
+            let layout_reverse = constants.is_main_reverse;
             let gap = constants.gap.main(constants.dir);
-            let is_safe = false; // TODO: Implement safe alignment
+            let is_safe = constants.justify_content_safety.is_safe();
             let raw_justify_content_mode = constants.justify_content.unwrap_or(JustifyContent::FlexStart);
-            let justify_content_mode =
-                apply_alignment_fallback(free_space, num_items, raw_justify_content_mode, is_safe);
+            let justify_content_mode = constants.resolve_justify_content(apply_alignment_fallback(
+                free_space,
+                num_items,
+                raw_justify_content_mode,
+                is_safe,
+            ));
 
             let justify_item = |(i, child): (usize, &mut FlexItem)| {
                 child.offset_main =
@@ -1767,10 +2007,19 @@ fn align_flex_items_along_cross_axis(
     max_baseline: f32,
     constants: &AlgoConstants,
 ) -> f32 {
-    match child.align_self {
+    // Safe alignment falls back to `start` alignment if the item overflows its line
+    let is_safe_fallback = child.align_self_safety.is_safe()
+        && free_space < 0.0
+        && !matches!(child.align_self, AlignSelf::Baseline | AlignSelf::Stretch);
+    let align_self = match is_safe_fallback {
+        true => constants.resolve_align_self(Some(AlignSelf::Start)),
+        false => child.align_self,
+    };
+
+    match align_self {
         AlignSelf::Start => 0.0,
         AlignSelf::FlexStart => {
-            if constants.is_wrap_reverse {
+            if constants.is_cross_reverse {
                 free_space
             } else {
                 0.0
@@ -1778,7 +2027,7 @@ fn align_flex_items_along_cross_axis(
         }
         AlignSelf::End => free_space,
         AlignSelf::FlexEnd => {
-            if constants.is_wrap_reverse {
+            if constants.is_cross_reverse {
                 0.0
             } else {
                 free_space
@@ -1789,9 +2038,9 @@ fn align_flex_items_along_cross_axis(
             if constants.is_row {
                 max_baseline - child.baseline
             } else {
-                // Until we support vertical writing modes, baseline alignment only makes sense if
-                // the constants.direction is row, so we treat it as flex-start alignment in columns.
-                if constants.is_wrap_reverse {
+                // We only support baseline alignment where the cross axis is the block axis (flex rows),
+                // so we treat it as flex-start alignment in columns.
+                if constants.is_cross_reverse {
                     free_space
                 } else {
                     0.0
@@ -1799,7 +2048,7 @@ fn align_flex_items_along_cross_axis(
             }
         }
         AlignSelf::Stretch => {
-            if constants.is_wrap_reverse {
+            if constants.is_cross_reverse {
                 free_space
             } else {
                 0.0
@@ -1828,7 +2077,7 @@ fn determine_container_cross_size(
     let total_line_cross_size: f32 = flex_lines.iter().map(|line| line.cross_size).sum::<f32>();
 
     let padding_border_sum = constants.content_box_inset.cross_axis_sum(constants.dir);
-    let cross_scrollbar_gutter = constants.scrollbar_gutter.cross(constants.dir);
+    let cross_scrollbar_gutter = constants.scrollbar_gutter.cross_axis_sum(constants.dir);
     let min_cross_size = constants.min_size.cross(constants.dir);
     let max_cross_size = constants.max_size.cross(constants.dir);
     let outer_container_size = node_size
@@ -1855,16 +2104,27 @@ fn align_flex_lines_per_align_content(flex_lines: &mut [FlexLine], constants: &A
     let gap = constants.gap.cross(constants.dir);
     let total_cross_axis_gap = sum_axis_gaps(gap, num_lines);
     let free_space = constants.inner_container_size.cross(constants.dir) - total_cross_size - total_cross_axis_gap;
-    let is_safe = false; // TODO: Implement safe alignment
+    let is_safe = constants.align_content_safety.is_safe();
 
-    let align_content_mode = apply_alignment_fallback(free_space, num_lines, constants.align_content, is_safe);
+    let align_content_mode = constants.resolve_align_content(apply_alignment_fallback(
+        free_space,
+        num_lines,
+        constants.align_content,
+        is_safe,
+    ));
 
     let align_line = |(i, line): (usize, &mut FlexLine)| {
-        line.offset_cross =
-            compute_alignment_offset(free_space, num_lines, gap, align_content_mode, constants.is_wrap_reverse, i == 0);
+        line.offset_cross = compute_alignment_offset(
+            free_space,
+            num_lines,
+            gap,
+            align_content_mode,
+            constants.is_cross_reverse,
+            i == 0,
+        );
     };
 
-    if constants.is_wrap_reverse {
+    if constants.is_cross_reverse {
         flex_lines.iter_mut().rev().enumerate().for_each(align_line);
     } else {
         flex_lines.iter_mut().enumerate().for_each(align_line);
@@ -1882,15 +2142,33 @@ fn calculate_flex_item(
     #[cfg(feature = "content_size")] total_content_size: &mut Size<f32>,
     container_size: Size<f32>,
     node_inner_size: Size<Option<f32>>,
+    containing_block: Option<AbsoluteContainingBlock>,
     direction: FlexDirection,
 ) {
-    let layout_output = tree.perform_child_layout(
+    let offset_main = *total_offset_main
+        + item.offset_main
+        + item.margin.main_start(direction)
+        + (item.inset.main_start(direction).or(item.inset.main_end(direction).map(|pos| -pos)).unwrap_or(0.0));
+
+    let offset_cross = total_offset_cross
+        + item.offset_cross
+        + line_offset_cross
+        + item.margin.cross_start(direction)
+        + (item.inset.cross_start(direction).or(item.inset.cross_end(direction).map(|pos| -pos)).unwrap_or(0.0));
+
+    let location = match direction.is_row() {
+        true => Point { x: offset_main, y: offset_cross },
+        false => Point { x: offset_cross, y: offset_main },
+    };
+
+    let layout_output = tree.perform_child_layout_with_containing_block(
         item.node,
         item.target_size.map(|s| s.into()),
         node_inner_size,
         container_size.map(|s| s.into()),
         SizingMode::ContentSize,
         Line::FALSE,
+        containing_block_for_child(containing_block, item.position, container_size, location, item.target_size),
     );
     let LayoutOutput {
         size,
@@ -1899,17 +2177,6 @@ fn calculate_flex_item(
         ..
     } = layout_output;
 
-    let offset_main = *total_offset_main
-        + item.offset_main
-        + item.margin.main_start(direction)
-        + (item.inset.main_start(direction).or(item.inset.main_end(direction).map(|pos| -pos)).unwrap_or(0.0));
-
-    let offset_cross = total_offset_cross
-        + item.offset_cross
-        + line_offset_cross
-        + item.margin.cross_start(direction)
-        + (item.inset.cross_start(direction).or(item.inset.cross_end(direction).map(|pos| -pos)).unwrap_or(0.0));
-
     if direction.is_row() {
         let baseline_offset_cross = total_offset_cross + item.offset_cross + item.margin.cross_start(direction);
         let inner_baseline = layout_output.first_baselines.y.unwrap_or(size.height);
@@ -1920,15 +2187,6 @@ fn calculate_flex_item(
         item.baseline = baseline_offset_main + inner_baseline;
     }
 
-    let location = match direction.is_row() {
-        true => Point { x: offset_main, y: offset_cross },
-        false => Point { x: offset_cross, y: offset_main },
-    };
-    let scrollbar_size = Size {
-        width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-        height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-    };
-
     tree.set_unrounded_layout(
         item.node,
         &Layout {
@@ -1936,11 +2194,13 @@ fn calculate_flex_item(
             size,
             #[cfg(feature = "content_size")]
             content_size,
-            scrollbar_size,
+            scrollbar_size: layout_output.scrollbar_size,
             location,
             padding: item.padding,
             border: item.border,
             margin: item.margin,
+            visibility: item.visibility,
+            ..Layout::new()
         },
     );
 
@@ -1962,13 +2222,15 @@ fn calculate_layout_line(
     #[cfg(feature = "content_size")] content_size: &mut Size<f32>,
     container_size: Size<f32>,
     node_inner_size: Size<Option<f32>>,
+    containing_block: Option<AbsoluteContainingBlock>,
     padding_border: Rect<f32>,
     direction: FlexDirection,
+    is_main_reverse: bool,
 ) {
     let mut total_offset_main = padding_border.main_start(direction);
     let line_offset_cross = line.offset_cross;
 
-    if direction.is_reverse() {
+    if is_main_reverse {
         for item in line.items.iter_mut().rev() {
             calculate_flex_item(
                 tree,
@@ -1980,6 +2242,7 @@ fn calculate_layout_line(
                 content_size,
                 container_size,
                 node_inner_size,
+                containing_block,
                 direction,
             );
         }
@@ -1995,6 +2258,7 @@ fn calculate_layout_line(
                 content_size,
                 container_size,
                 node_inner_size,
+                containing_block,
                 direction,
             );
         }
@@ -2015,7 +2279,7 @@ fn final_layout_pass(
     #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
     let mut content_size = Size::ZERO;
 
-    if constants.is_wrap_reverse {
+    if constants.is_cross_reverse {
         for line in flex_lines.iter_mut().rev() {
             calculate_layout_line(
                 tree,
@@ -2025,8 +2289,10 @@ fn final_layout_pass(
                 &mut content_size,
                 constants.container_size,
                 constants.node_inner_size,
+                constants.containing_block,
                 constants.content_box_inset,
                 constants.dir,
+                constants.is_main_reverse,
             );
         }
     } else {
@@ -2039,14 +2305,17 @@ fn final_layout_pass(
                 &mut content_size,
                 constants.container_size,
                 constants.node_inner_size,
+                constants.containing_block,
                 constants.content_box_inset,
                 constants.dir,
+                constants.is_main_reverse,
             );
         }
     }
 
-    content_size.width += constants.content_box_inset.right - constants.border.right - constants.scrollbar_gutter.x;
-    content_size.height += constants.content_box_inset.bottom - constants.border.bottom - constants.scrollbar_gutter.y;
+    content_size.width += constants.content_box_inset.right - constants.border.right - constants.scrollbar_gutter.right;
+    content_size.height +=
+        constants.content_box_inset.bottom - constants.border.bottom - constants.scrollbar_gutter.bottom;
 
     content_size
 }
@@ -2058,10 +2327,16 @@ fn perform_absolute_layout_on_absolute_children(
     node: NodeId,
     constants: &AlgoConstants,
 ) -> Size<f32> {
-    let container_width = constants.container_size.width;
-    let container_height = constants.container_size.height;
-    let inset_relative_size =
-        constants.container_size - constants.border.sum_axes() - constants.scrollbar_gutter.into();
+    // Absolutely positioned children are laid out once the containing block is known
+    let Some(containing_block) = constants.containing_block else {
+        return Size::ZERO;
+    };
+    let inset_relative_size = containing_block.size(constants.container_size);
+    // Children whose containing block belongs to an ancestor are sized against it rather than the container
+    let (area_size, area_inner_size) = match containing_block.is_own {
+        true => (constants.container_size, constants.node_inner_size),
+        false => (inset_relative_size, inset_relative_size.map(Some)),
+    };
 
     #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
     let mut content_size = Size::ZERO;
@@ -2071,52 +2346,66 @@ fn perform_absolute_layout_on_absolute_children(
         let child_style = tree.get_flexbox_child_style(child);
 
         // Skip items that are display:none or are not position:absolute
-        if child_style.box_generation_mode() == BoxGenerationMode::None || child_style.position() != Position::Absolute
+        if child_style.box_generation_mode() == BoxGenerationMode::None
+            || !child_style.position().is_absolutely_positioned()
         {
             continue;
         }
 
+        #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
         let overflow = child_style.overflow();
-        let scrollbar_width = child_style.scrollbar_width();
+        let visibility = child_style.visibility();
         let aspect_ratio = child_style.aspect_ratio();
-        let align_self = child_style.align_self().unwrap_or(constants.align_items);
-        let margin = child_style
-            .margin()
-            .map(|margin| margin.resolve_to_option(inset_relative_size.width, |val, basis| tree.calc(val, basis)));
-        let padding =
-            child_style.padding().resolve_or_zero(Some(inset_relative_size.width), |val, basis| tree.calc(val, basis));
-        let border =
-            child_style.border().resolve_or_zero(Some(inset_relative_size.width), |val, basis| tree.calc(val, basis));
+        let has_intrinsic_size = child_style.size().map(Dimension::is_intrinsic_sizing_keyword);
+        let align_self = constants.resolve_align_self(child_style.align_self());
+        let margin = child_style.margin().map(|margin| {
+            margin.resolve_to_option(inset_relative_size.width, |val, basis| tree.calc(child, val, basis))
+        });
+        let padding = child_style
+            .padding()
+            .resolve_or_zero(Some(inset_relative_size.width), |val, basis| tree.calc(child, val, basis));
+        let border = child_style
+            .border()
+            .resolve_or_zero(Some(inset_relative_size.width), |val, basis| tree.calc(child, val, basis));
         let padding_border_sum = (padding + border).sum_axes();
         let box_sizing_adjustment =
             if child_style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
 
         // Resolve inset
-        // Insets are resolved against the container size minus border
-        let left =
-            child_style.inset().left.maybe_resolve(inset_relative_size.width, |val, basis| tree.calc(val, basis));
-        let right =
-            child_style.inset().right.maybe_resolve(inset_relative_size.width, |val, basis| tree.calc(val, basis));
-        let top = child_style.inset().top.maybe_resolve(inset_relative_size.height, |val, basis| tree.calc(val, basis));
-        let bottom =
-            child_style.inset().bottom.maybe_resolve(inset_relative_size.height, |val, basis| tree.calc(val, basis));
+        // Insets are resolved against the size of the containing block
+        let left = child_style
+            .inset()
+            .left
+            .maybe_resolve(inset_relative_size.width, |val, basis| tree.calc(child, val, basis));
+        let right = child_style
+            .inset()
+            .right
+            .maybe_resolve(inset_relative_size.width, |val, basis| tree.calc(child, val, basis));
+        let top = child_style
+            .inset()
+            .top
+            .maybe_resolve(inset_relative_size.height, |val, basis| tree.calc(child, val, basis));
+        let bottom = child_style
+            .inset()
+            .bottom
+            .maybe_resolve(inset_relative_size.height, |val, basis| tree.calc(child, val, basis));
 
         // Compute known dimensions from min/max/inherent size styles
         let style_size = child_style
             .size()
-            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(child, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
         let min_size = child_style
             .min_size()
-            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(child, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment)
             .or(padding_border_sum.map(Some))
             .maybe_max(padding_border_sum);
         let max_size = child_style
             .max_size()
-            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(child, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
         let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
@@ -2126,7 +2415,9 @@ fn perform_absolute_layout_on_absolute_children(
         // Fill in width from left/right and reapply aspect ratio if:
         //   - Width is not already known
         //   - Item has both left and right inset properties set
-        if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
+        //   - Item's width is not an intrinsic sizing keyword
+        if let (None, Some(left), Some(right), false) = (known_dimensions.width, left, right, has_intrinsic_size.width)
+        {
             let new_width_raw = inset_relative_size.width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
             known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
             known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
@@ -2135,7 +2426,10 @@ fn perform_absolute_layout_on_absolute_children(
         // Fill in height from top/bottom and reapply aspect ratio if:
         //   - Height is not already known
         //   - Item has both top and bottom inset properties set
-        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
+        //   - Item's height is not an intrinsic sizing keyword
+        if let (None, Some(top), Some(bottom), false) =
+            (known_dimensions.height, top, bottom, has_intrinsic_size.height)
+        {
             let new_height_raw =
                 inset_relative_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
             known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
@@ -2144,10 +2438,10 @@ fn perform_absolute_layout_on_absolute_children(
         let layout_output = tree.perform_child_layout(
             child,
             known_dimensions,
-            constants.node_inner_size,
+            area_inner_size,
             Size {
-                width: AvailableSpace::Definite(container_width.maybe_clamp(min_size.width, max_size.width)),
-                height: AvailableSpace::Definite(container_height.maybe_clamp(min_size.height, max_size.height)),
+                width: AvailableSpace::Definite(area_size.width.maybe_clamp(min_size.width, max_size.width)),
+                height: AvailableSpace::Definite(area_size.height.maybe_clamp(min_size.height, max_size.height)),
             },
             SizingMode::InherentSize,
             Line::FALSE,
@@ -2158,8 +2452,8 @@ fn perform_absolute_layout_on_absolute_children(
         let non_auto_margin = margin.map(|m| m.unwrap_or(0.0));
 
         let free_space = Size {
-            width: constants.container_size.width - final_size.width - non_auto_margin.horizontal_axis_sum(),
-            height: constants.container_size.height - final_size.height - non_auto_margin.vertical_axis_sum(),
+            width: area_size.width - final_size.width - non_auto_margin.horizontal_axis_sum(),
+            height: area_size.height - final_size.height - non_auto_margin.vertical_axis_sum(),
         }
         .f32_max(Size::ZERO);
 
@@ -2192,6 +2486,12 @@ fn perform_absolute_layout_on_absolute_children(
             }
         };
 
+        // If both horizontal insets are set then the one on the inline-start side determines the position
+        let (left, right) = match (constants.is_rtl, left, right) {
+            (true, Some(_), Some(_)) => (None, right),
+            _ => (left, right),
+        };
+
         // Determine flex-relative insets
         let (start_main, end_main) = if constants.is_row { (left, right) } else { (top, bottom) };
         let (start_cross, end_cross) = if constants.is_row { (top, bottom) } else { (left, right) };
@@ -2199,18 +2499,19 @@ fn perform_absolute_layout_on_absolute_children(
         // Apply main-axis alignment
         // let free_main_space = free_space.main(constants.dir) - resolved_margin.main_axis_sum(constants.dir);
         let offset_main = if let Some(start) = start_main {
-            start + constants.border.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
+            start + containing_block.insets.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
         } else if let Some(end) = end_main {
             constants.container_size.main(constants.dir)
-                - constants.border.main_end(constants.dir)
-                - constants.scrollbar_gutter.main(constants.dir)
+                - containing_block.insets.main_end(constants.dir)
                 - final_size.main(constants.dir)
                 - end
                 - resolved_margin.main_end(constants.dir)
         } else {
             // Stretch is an invalid value for justify_content in the flexbox algorithm, so we
             // treat it as if it wasn't set (and thus we default to FlexStart behaviour)
-            match (constants.justify_content.unwrap_or(JustifyContent::Start), constants.is_wrap_reverse) {
+            let justify_content =
+                constants.resolve_justify_content(constants.justify_content.unwrap_or(JustifyContent::FlexStart));
+            match (justify_content, constants.is_main_reverse) {
                 (JustifyContent::SpaceBetween, _)
                 | (JustifyContent::Start, _)
                 | (JustifyContent::Stretch, false)
@@ -2242,16 +2543,15 @@ fn perform_absolute_layout_on_absolute_children(
         // Apply cross-axis alignment
         // let free_cross_space = free_space.cross(constants.dir) - resolved_margin.cross_axis_sum(constants.dir);
         let offset_cross = if let Some(start) = start_cross {
-            start + constants.border.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
+            start + containing_block.insets.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
         } else if let Some(end) = end_cross {
             constants.container_size.cross(constants.dir)
-                - constants.border.cross_end(constants.dir)
-                - constants.scrollbar_gutter.cross(constants.dir)
+                - containing_block.insets.cross_end(constants.dir)
                 - final_size.cross(constants.dir)
                 - end
                 - resolved_margin.cross_end(constants.dir)
         } else {
-            match (align_self, constants.is_wrap_reverse) {
+            match (align_self, constants.is_cross_reverse) {
                 // Stretch alignment does not apply to absolutely positioned items
                 // See "Example 3" at https://www.w3.org/TR/css-flexbox-1/#abspos-items
                 // Note: Stretch should be FlexStart not Start when we support both
@@ -2284,10 +2584,6 @@ fn perform_absolute_layout_on_absolute_children(
             true => Point { x: offset_main, y: offset_cross },
             false => Point { x: offset_cross, y: offset_main },
         };
-        let scrollbar_size = Size {
-            width: if overflow.y == Overflow::Scroll { scrollbar_width } else { 0.0 },
-            height: if overflow.x == Overflow::Scroll { scrollbar_width } else { 0.0 },
-        };
         tree.set_unrounded_layout(
             child,
             &Layout {
@@ -2295,16 +2591,19 @@ fn perform_absolute_layout_on_absolute_children(
                 size: final_size,
                 #[cfg(feature = "content_size")]
                 content_size: layout_output.content_size,
-                scrollbar_size,
+                scrollbar_size: layout_output.scrollbar_size,
                 location,
                 padding,
                 border,
                 margin: resolved_margin,
+                visibility,
+                ..Layout::new()
             },
         );
 
+        // Only the containing block's content size includes the child
         #[cfg(feature = "content_size")]
-        {
+        if containing_block.is_own {
             let size_content_size_contribution = Size {
                 width: match overflow.x {
                     Overflow::Visible => f32_max(final_size.width, layout_output.content_size.width),
@@ -0,0 +1,114 @@
+//! Tracks the floats of a block container, and the space which they exclude from the container's other content.
+//!
+//! All coordinates are relative to the border box of the block container. Floats are positioned according to a
+//! simplified version of the rules in <https://www.w3.org/TR/CSS2/visuren.html#float-position>:
+//!   - The top of a float is not above the top of any earlier float, or above the position at which it occurs
+//!   - Left floats are placed as far left as possible, and right floats as far right as possible
+//!   - A float which does not fit beside the earlier floats at its position is moved down until it fits, or until
+//!     there are no more floats beside it
+use crate::geometry::{Line, Point, Rect, Size};
+use crate::style::{Clear, Float};
+use crate::util::sys::{f32_max, f32_min, Vec};
+
+/// A float which has been placed within its container
+#[derive(Copy, Clone)]
+struct PlacedFloat {
+    /// Which side the float is floated to
+    float: Float,
+    /// The edges of the float's margin box
+    rect: Rect<f32>,
+}
+
+impl PlacedFloat {
+    /// Whether the float is beside the band between `top` and `bottom`. If the band is empty, whether the float is
+    /// beside the line at `top`.
+    fn is_beside(&self, top: f32, bottom: f32) -> bool {
+        self.rect.bottom > top && (self.rect.top < bottom || self.rect.top <= top) && self.rect.top < self.rect.bottom
+    }
+}
+
+/// The floats of a block container
+pub(crate) struct FloatContext {
+    /// The floats which have been placed, in document order
+    floats: Vec<PlacedFloat>,
+    /// The left and right edges of the container's content box
+    content_box: Line<f32>,
+}
+
+impl FloatContext {
+    /// Create a context for a container whose content box spans the horizontal range `content_box`
+    pub(crate) fn new(content_box: Line<f32>) -> Self {
+        Self { floats: Vec::new(), content_box }
+    }
+
+    /// Whether any floats have been placed
+    pub(crate) fn is_empty(&self) -> bool {
+        self.floats.is_empty()
+    }
+
+    /// The left and right edges of the container's content box
+    pub(crate) fn content_box(&self) -> Line<f32> {
+        self.content_box
+    }
+
+    /// The horizontal range which is not excluded by floats in the band between `top` and `bottom`
+    pub(crate) fn available_space(&self, top: f32, bottom: f32) -> Line<f32> {
+        let mut space = self.content_box;
+        for placed in self.floats.iter().filter(|placed| placed.is_beside(top, bottom)) {
+            match placed.float {
+                Float::Left => space.start = f32_max(space.start, placed.rect.right),
+                Float::Right => space.end = f32_min(space.end, placed.rect.left),
+                Float::None => {}
+            }
+        }
+        space
+    }
+
+    /// The first bottom edge of the floats beside the band between `top` and `bottom`, which is the first position
+    /// below `top` at which more space may be available. `None` if there are no floats beside the band.
+    pub(crate) fn next_float_bottom(&self, top: f32, bottom: f32) -> Option<f32> {
+        self.floats
+            .iter()
+            .filter(|placed| placed.is_beside(top, bottom))
+            .map(|placed| placed.rect.bottom)
+            .reduce(f32_min)
+    }
+
+    /// The position below the floats which an item with the specified `clear` style must be placed below, if any
+    pub(crate) fn clearance_y(&self, clear: Clear) -> Option<f32> {
+        self.floats.iter().filter(|placed| clear.clears(placed.float)).map(|placed| placed.rect.bottom).reduce(f32_max)
+    }
+
+    /// The bottom edge of the lowest float, if any
+    pub(crate) fn bottom(&self) -> Option<f32> {
+        self.floats.iter().map(|placed| placed.rect.bottom).reduce(f32_max)
+    }
+
+    /// Place a float whose margin box has the specified `size`, which occurs at the position `y`. Returns the position
+    /// of the float's margin box.
+    pub(crate) fn place(&mut self, float: Float, clear: Clear, size: Size<f32>, y: f32) -> Point<f32> {
+        let mut y = f32_max(y, self.floats.last().map(|placed| placed.rect.top).unwrap_or(y));
+        if let Some(clearance_y) = self.clearance_y(clear) {
+            y = f32_max(y, clearance_y);
+        }
+
+        let space = loop {
+            let space = self.available_space(y, y + size.height);
+            if space.end - space.start >= size.width {
+                break space;
+            }
+            match self.next_float_bottom(y, y + size.height) {
+                Some(next_y) => y = next_y,
+                None => break space,
+            }
+        };
+
+        let x = match float {
+            Float::Right => space.end - size.width,
+            Float::Left | Float::None => space.start,
+        };
+        let rect = Rect { left: x, right: x + size.width, top: y, bottom: y + size.height };
+        self.floats.push(PlacedFloat { float, rect });
+        Point { x, y }
+    }
+}
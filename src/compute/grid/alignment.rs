@@ -1,10 +1,16 @@
 //! Alignment of tracks and final positioning of items
 use super::types::GridTrack;
 use crate::compute::common::alignment::{apply_alignment_fallback, compute_alignment_offset};
+use crate::compute::common::containing_block::StaticChild;
 use crate::geometry::{InBothAbsAxis, Line, Point, Rect, Size};
-use crate::style::{AlignContent, AlignItems, AlignSelf, AvailableSpace, CoreStyle, GridItemStyle, Overflow, Position};
-use crate::tree::{Layout, LayoutPartialTreeExt, NodeId, SizingMode};
-use crate::util::sys::f32_max;
+use crate::style::{
+    AlignContent, AlignItems, AlignSelf, AlignmentSafety, AvailableSpace, CoreStyle, Dimension, Direction,
+    GridItemStyle, Position,
+};
+use crate::tree::{
+    ContainingBlock, Layout, LayoutInput, LayoutPartialTreeExt, NodeId, RequestedAxis, RunMode, SizingMode,
+};
+use crate::util::sys::{f32_max, Vec};
 use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
 
 #[cfg(feature = "content_size")]
@@ -20,6 +26,7 @@ pub(super) fn align_tracks(
     border: Line<f32>,
     tracks: &mut [GridTrack],
     track_alignment_style: AlignContent,
+    track_alignment_safety: AlignmentSafety,
 ) {
     let used_size: f32 = tracks.iter().map(|track| track.base_size).sum();
     let free_space = grid_container_content_box_size - used_size;
@@ -32,7 +39,7 @@ pub(super) fn align_tracks(
     // simply pass zero here. Grid layout is never reversed.
     let gap = 0.0;
     let layout_is_reversed = false;
-    let is_safe = false; // TODO: Implement safe alignment
+    let is_safe = track_alignment_safety.is_safe();
     let track_alignment = apply_alignment_fallback(free_space, num_tracks, track_alignment_style, is_safe);
 
     // Compute offsets
@@ -56,57 +63,78 @@ pub(super) fn align_tracks(
 }
 
 /// Align and size a grid item into it's final position
+///
+/// In-flow items are shimmed by the `baseline_shim` computed during track sizing. Absolutely positioned items are
+/// instead aligned to the `shared_baselines` of the tracks in which their grid area starts (measured from the start
+/// of the grid area), if they are baseline-aligned.
+///
+/// The final layouts of `Position::Static` items are appended to `static_children`, so that they can be laid out again
+/// once the containing block of their absolutely positioned descendants is known.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn align_and_position_item(
     tree: &mut impl LayoutGridContainer,
     node: NodeId,
     order: u32,
     grid_area: Rect<f32>,
     container_alignment_styles: InBothAbsAxis<Option<AlignItems>>,
-    baseline_shim: f32,
-) -> (Size<f32>, f32, f32) {
+    container_alignment_safety: InBothAbsAxis<AlignmentSafety>,
+    direction: Direction,
+    baseline_shim: Point<f32>,
+    shared_baselines: Point<Option<f32>>,
+    static_children: &mut Vec<StaticChild>,
+) -> (Size<f32>, Point<f32>, Size<f32>) {
     let grid_area_size = Size { width: grid_area.right - grid_area.left, height: grid_area.bottom - grid_area.top };
 
     let style = tree.get_grid_child_style(node);
 
+    #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
     let overflow = style.overflow();
-    let scrollbar_width = style.scrollbar_width();
+    let visibility = style.visibility();
     let aspect_ratio = style.aspect_ratio();
     let justify_self = style.justify_self();
     let align_self = style.align_self();
+    let alignment_safety = InBothAbsAxis {
+        horizontal: style.justify_self_safety().unwrap_or(container_alignment_safety.horizontal),
+        vertical: style.align_self_safety().unwrap_or(container_alignment_safety.vertical),
+    };
 
     let position = style.position();
     let inset_horizontal = style
         .inset()
         .horizontal_components()
-        .map(|size| size.resolve_to_option(grid_area_size.width, |val, basis| tree.calc(val, basis)));
+        .map(|size| size.resolve_to_option(grid_area_size.width, |val, basis| tree.calc(node, val, basis)));
     let inset_vertical = style
         .inset()
         .vertical_components()
-        .map(|size| size.resolve_to_option(grid_area_size.height, |val, basis| tree.calc(val, basis)));
-    let padding =
-        style.padding().map(|p| p.resolve_or_zero(Some(grid_area_size.width), |val, basis| tree.calc(val, basis)));
+        .map(|size| size.resolve_to_option(grid_area_size.height, |val, basis| tree.calc(node, val, basis)));
+    let padding = style
+        .padding()
+        .map(|p| p.resolve_or_zero(Some(grid_area_size.width), |val, basis| tree.calc(node, val, basis)));
     let border =
-        style.border().map(|p| p.resolve_or_zero(Some(grid_area_size.width), |val, basis| tree.calc(val, basis)));
+        style.border().map(|p| p.resolve_or_zero(Some(grid_area_size.width), |val, basis| tree.calc(node, val, basis)));
     let padding_border_size = (padding + border).sum_axes();
 
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
 
+    // Items whose size is an intrinsic sizing keyword are sized by their content, and so are not stretched
+    let has_intrinsic_size = style.size().map(Dimension::is_intrinsic_sizing_keyword);
+    let is_stretch_size = style.size().map(Dimension::is_stretch);
     let inherent_size = style
         .size()
-        .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(grid_area_size, |val, basis| tree.calc(node, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let min_size = style
         .min_size()
-        .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(grid_area_size, |val, basis| tree.calc(node, val, basis))
         .maybe_add(box_sizing_adjustment)
         .or(padding_border_size.map(Some))
         .maybe_max(padding_border_size)
         .maybe_apply_aspect_ratio(aspect_ratio);
     let max_size = style
         .max_size()
-        .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(grid_area_size, |val, basis| tree.calc(node, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
 
@@ -116,14 +144,14 @@ pub(super) fn align_and_position_item(
     // See: https://www.w3.org/TR/css-grid-1/#grid-item-sizing
     let alignment_styles = InBothAbsAxis {
         horizontal: justify_self.or(container_alignment_styles.horizontal).unwrap_or_else(|| {
-            if inherent_size.width.is_some() {
+            if inherent_size.width.is_some() || has_intrinsic_size.width {
                 AlignSelf::Start
             } else {
                 AlignSelf::Stretch
             }
         }),
         vertical: align_self.or(container_alignment_styles.vertical).unwrap_or_else(|| {
-            if inherent_size.height.is_some() || aspect_ratio.is_some() {
+            if inherent_size.height.is_some() || has_intrinsic_size.height || aspect_ratio.is_some() {
                 AlignSelf::Start
             } else {
                 AlignSelf::Stretch
@@ -133,12 +161,13 @@ pub(super) fn align_and_position_item(
 
     // Note: This is not a bug. It is part of the CSS spec that both horizontal and vertical margins
     // resolve against the WIDTH of the grid area.
-    let margin =
-        style.margin().map(|margin| margin.resolve_to_option(grid_area_size.width, |val, basis| tree.calc(val, basis)));
+    let margin = style
+        .margin()
+        .map(|margin| margin.resolve_to_option(grid_area_size.width, |val, basis| tree.calc(node, val, basis)));
 
     let grid_area_minus_item_margins_size = Size {
-        width: grid_area_size.width.maybe_sub(margin.left).maybe_sub(margin.right),
-        height: grid_area_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - baseline_shim,
+        width: grid_area_size.width.maybe_sub(margin.left).maybe_sub(margin.right) - baseline_shim.x,
+        height: grid_area_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - baseline_shim.y,
     };
 
     // If node is absolutely positioned and width is not set explicitly, then deduce it
@@ -146,20 +175,22 @@ pub(super) fn align_and_position_item(
     let width = inherent_size.width.or_else(|| {
         // Apply width derived from both the left and right properties of an absolutely
         // positioned element being set
-        if position == Position::Absolute {
+        if position.is_absolutely_positioned() {
             if let (Some(left), Some(right)) = (inset_horizontal.start, inset_horizontal.end) {
                 return Some(f32_max(grid_area_minus_item_margins_size.width - left - right, 0.0));
             }
         }
 
         // Apply width based on stretch alignment if:
-        //  - Alignment style is "stretch"
+        //  - Alignment style is "stretch" (or the width is the stretch keyword)
+        //  - The width is not an intrinsic sizing keyword
         //  - The node is not absolutely positioned
         //  - The node does not have auto margins in this axis.
         if margin.left.is_some()
             && margin.right.is_some()
-            && alignment_styles.horizontal == AlignSelf::Stretch
-            && position != Position::Absolute
+            && (alignment_styles.horizontal == AlignSelf::Stretch || is_stretch_size.width)
+            && !has_intrinsic_size.width
+            && !position.is_absolutely_positioned()
         {
             return Some(grid_area_minus_item_margins_size.width);
         }
@@ -171,20 +202,22 @@ pub(super) fn align_and_position_item(
     let Size { width, height } = Size { width, height: inherent_size.height }.maybe_apply_aspect_ratio(aspect_ratio);
 
     let height = height.or_else(|| {
-        if position == Position::Absolute {
+        if position.is_absolutely_positioned() {
             if let (Some(top), Some(bottom)) = (inset_vertical.start, inset_vertical.end) {
                 return Some(f32_max(grid_area_minus_item_margins_size.height - top - bottom, 0.0));
             }
         }
 
         // Apply height based on stretch alignment if:
-        //  - Alignment style is "stretch"
+        //  - Alignment style is "stretch" (or the height is the stretch keyword)
+        //  - The height is not an intrinsic sizing keyword
         //  - The node is not absolutely positioned
         //  - The node does not have auto margins in this axis.
         if margin.top.is_some()
             && margin.bottom.is_some()
-            && alignment_styles.vertical == AlignSelf::Stretch
-            && position != Position::Absolute
+            && (alignment_styles.vertical == AlignSelf::Stretch || is_stretch_size.height)
+            && !has_intrinsic_size.height
+            && !position.is_absolutely_positioned()
         {
             return Some(grid_area_minus_item_margins_size.height);
         }
@@ -199,44 +232,105 @@ pub(super) fn align_and_position_item(
 
     // Layout node
     drop(style);
-    let layout_output = tree.perform_child_layout(
-        node,
-        Size { width, height },
-        grid_area_size.map(Option::Some),
-        grid_area_minus_item_margins_size.map(AvailableSpace::Definite),
-        SizingMode::InherentSize,
-        Line::FALSE,
-    );
+    let inputs = LayoutInput {
+        known_dimensions: Size { width, height },
+        parent_size: grid_area_size.map(Option::Some),
+        available_space: grid_area_minus_item_margins_size.map(AvailableSpace::Definite),
+        sizing_mode: SizingMode::InherentSize,
+        axis: RequestedAxis::Both,
+        run_mode: RunMode::PerformLayout,
+        vertical_margins_are_collapsible: Line::FALSE,
+        containing_block: ContainingBlock::Deferred,
+    };
+    let layout_output = tree.compute_child_layout(node, inputs);
 
     // Resolve final size
     let Size { width, height } = Size { width, height }.unwrap_or(layout_output.size).maybe_clamp(min_size, max_size);
 
-    let (x, x_margin) = align_item_within_area(
-        Line { start: grid_area.left, end: grid_area.right },
-        justify_self.unwrap_or(alignment_styles.horizontal),
-        width,
-        position,
-        inset_horizontal,
-        margin.horizontal_components(),
-        0.0,
-    );
+    // Shim a baseline-aligned absolutely positioned item so that its baseline lines up with the shared baseline.
+    // Insets take precedence over alignment, so items with an inset in an axis are not shimmed in that axis.
+    let baseline_shim = if position.is_absolutely_positioned() {
+        let first_baselines = layout_output.first_baselines;
+        let (inline_start_baseline, inline_start_margin) = match direction {
+            Direction::Ltr => (first_baselines.x.unwrap_or(width), margin.left),
+            Direction::Rtl => (width - first_baselines.x.unwrap_or(0.0), margin.right),
+        };
+        let shim = |alignment: AlignSelf, inset: Line<Option<f32>>, shared_baseline: Option<f32>, baseline: f32| match (
+            alignment,
+            inset,
+            shared_baseline,
+        ) {
+            (AlignSelf::Baseline, Line { start: None, end: None }, Some(shared_baseline)) => shared_baseline - baseline,
+            _ => 0.0,
+        };
+        Point {
+            x: shim(
+                alignment_styles.horizontal,
+                inset_horizontal,
+                shared_baselines.x,
+                inline_start_baseline + inline_start_margin.unwrap_or(0.0),
+            ),
+            y: shim(
+                alignment_styles.vertical,
+                inset_vertical,
+                shared_baselines.y,
+                first_baselines.y.unwrap_or(height) + margin.top.unwrap_or(0.0),
+            ),
+        }
+    } else {
+        baseline_shim
+    };
+
+    let (x, x_margin) = match direction {
+        Direction::Ltr => align_item_within_area(
+            Line { start: grid_area.left, end: grid_area.right },
+            justify_self.unwrap_or(alignment_styles.horizontal),
+            alignment_safety.horizontal,
+            width,
+            position,
+            inset_horizontal,
+            margin.horizontal_components(),
+            baseline_shim.x,
+        ),
+        // The inline axis runs right-to-left, so we align the item in a mirrored coordinate space
+        // (where the right edge of the grid area is the start edge) and then map the result back
+        Direction::Rtl => {
+            let (mirrored_start, mirrored_margin) = align_item_within_area(
+                Line { start: -grid_area.right, end: -grid_area.left },
+                justify_self.unwrap_or(alignment_styles.horizontal),
+                alignment_safety.horizontal,
+                width,
+                position,
+                Line { start: inset_horizontal.end, end: inset_horizontal.start },
+                Line { start: margin.right, end: margin.left },
+                baseline_shim.x,
+            );
+            (-mirrored_start - width, Line { start: mirrored_margin.end, end: mirrored_margin.start })
+        }
+    };
     let (y, y_margin) = align_item_within_area(
         Line { start: grid_area.top, end: grid_area.bottom },
         align_self.unwrap_or(alignment_styles.vertical),
+        alignment_safety.vertical,
         height,
         position,
         inset_vertical,
         margin.vertical_components(),
-        baseline_shim,
+        baseline_shim.y,
     );
 
-    let scrollbar_size = Size {
-        width: if overflow.y == Overflow::Scroll { scrollbar_width } else { 0.0 },
-        height: if overflow.x == Overflow::Scroll { scrollbar_width } else { 0.0 },
-    };
-
     let resolved_margin = Rect { left: x_margin.start, right: x_margin.end, top: y_margin.start, bottom: y_margin.end };
 
+    if position == Position::Static {
+        static_children.push(StaticChild {
+            node_id: node,
+            inputs,
+            location: Point { x, y },
+            size: Size { width, height },
+            inline_containing_block: None,
+        });
+    }
+
     tree.set_unrounded_layout(
         node,
         &Layout {
@@ -245,10 +339,12 @@ pub(super) fn align_and_position_item(
             size: Size { width, height },
             #[cfg(feature = "content_size")]
             content_size: layout_output.content_size,
-            scrollbar_size,
+            scrollbar_size: layout_output.scrollbar_size,
             padding,
             border,
             margin: resolved_margin,
+            visibility,
+            ..Layout::new()
         },
     );
 
@@ -258,13 +354,15 @@ pub(super) fn align_and_position_item(
     #[cfg(not(feature = "content_size"))]
     let contribution = Size::ZERO;
 
-    (contribution, y, height)
+    (contribution, Point { x, y }, Size { width, height })
 }
 
 /// Align and size a grid item along a single axis
+#[allow(clippy::too_many_arguments)]
 pub(super) fn align_item_within_area(
     grid_area: Line<f32>,
     alignment_style: AlignSelf,
+    alignment_safety: AlignmentSafety,
     resolved_size: f32,
     position: Position,
     inset: Line<Option<f32>>,
@@ -274,7 +372,14 @@ pub(super) fn align_item_within_area(
     // Calculate grid area dimension in the axis
     let non_auto_margin = Line { start: margin.start.unwrap_or(0.0) + baseline_shim, end: margin.end.unwrap_or(0.0) };
     let grid_area_size = f32_max(grid_area.end - grid_area.start, 0.0);
-    let free_space = f32_max(grid_area_size - resolved_size - non_auto_margin.sum(), 0.0);
+    let unclamped_free_space = grid_area_size - resolved_size - non_auto_margin.sum();
+    let free_space = f32_max(unclamped_free_space, 0.0);
+
+    // Safe alignment falls back to `start` alignment if the item overflows its grid area
+    let alignment_style = match alignment_safety.is_safe() && unclamped_free_space < 0.0 {
+        true => AlignSelf::Start,
+        false => alignment_style,
+    };
 
     // Expand auto margins to fill available space
     let auto_margin_count = margin.start.is_none() as u8 + margin.end.is_none() as u8;
@@ -289,12 +394,12 @@ pub(super) fn align_item_within_area(
         AlignSelf::Start | AlignSelf::FlexStart => resolved_margin.start,
         AlignSelf::End | AlignSelf::FlexEnd => grid_area_size - resolved_size - resolved_margin.end,
         AlignSelf::Center => (grid_area_size - resolved_size + resolved_margin.start - resolved_margin.end) / 2.0,
-        // TODO: Add support for baseline alignment. For now we treat it as "start".
+        // The baseline shim (which is included in the start margin) aligns the item's baseline with the shared baseline
         AlignSelf::Baseline => resolved_margin.start,
         AlignSelf::Stretch => resolved_margin.start,
     };
 
-    let offset_within_area = if position == Position::Absolute {
+    let offset_within_area = if position.is_absolutely_positioned() {
         if let Some(start) = inset.start {
             start + non_auto_margin.start
         } else if let Some(end) = inset.end {
@@ -1,8 +1,11 @@
 //! Helper functions for initialising GridTrack's from styles
-//! This mainly consists of evaluating GridAutoTracks
+//! This mainly consists of evaluating GridAutoTracks and resolving the names of the explicit grid lines
 use super::types::{GridTrack, TrackCounts};
+use super::OriginZeroLine;
 use crate::geometry::{AbsoluteAxis, Size};
-use crate::style::{GridTrackRepetition, LengthPercentage, NonRepeatedTrackSizingFunction, TrackSizingFunction};
+use crate::style::{
+    CheapCloneStr, GridTrackRepetition, LengthPercentage, NonRepeatedTrackSizingFunction, TrackSizingFunction,
+};
 use crate::style_helpers::TaffyAuto;
 use crate::util::sys::{ceil, floor, Vec};
 use crate::util::MaybeMath;
@@ -22,10 +25,18 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
         return 0;
     }
 
+    // A subgrid's template is replaced by the tracks it adopts from its parent grid before this point. So if the
+    // template is still `subgrid` then the node is not a subgrid (or the template is invalid), and it is treated as
+    // if it contained no tracks. The masonry axis of a masonry grid has no explicit tracks either.
+    if template.iter().any(|track_def| matches!(track_def, TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry))
+    {
+        return 0;
+    }
+
     // If there are any repetitions that contains no tracks, then the whole definition should be considered invalid
     // and we default to no explicit tracks
     let template_has_repetitions_with_zero_tracks = template.iter().any(|track_def| match track_def {
-        TrackSizingFunction::Single(_) => false,
+        TrackSizingFunction::Single(_) | TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => false,
         TrackSizingFunction::Repeat(_, tracks) => tracks.is_empty(),
     });
     if template_has_repetitions_with_zero_tracks {
@@ -40,7 +51,9 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
             match track_def {
                 TrackSizingFunction::Single(_) => 1,
                 TrackSizingFunction::Repeat(Count(count), tracks) => count * tracks.len() as u16,
-                TrackSizingFunction::Repeat(AutoFit | AutoFill, _) => 0,
+                TrackSizingFunction::Repeat(AutoFit | AutoFill, _)
+                | TrackSizingFunction::Subgrid
+                | TrackSizingFunction::Masonry => 0,
             }
         })
         .sum::<u16>();
@@ -51,6 +64,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
         TrackSizingFunction::Repeat(_, tracks) => {
             tracks.iter().all(|sizing_function| sizing_function.has_fixed_component())
         }
+        TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => false,
     });
 
     let template_is_valid =
@@ -73,7 +87,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
         .find_map(|def| {
             use GridTrackRepetition::{AutoFill, AutoFit, Count};
             match def {
-                TrackSizingFunction::Single(_) => None,
+                TrackSizingFunction::Single(_) | TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => None,
                 TrackSizingFunction::Repeat(Count(_), _) => None,
                 TrackSizingFunction::Repeat(AutoFit | AutoFill, tracks) => Some(tracks),
             }
@@ -130,7 +144,9 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
                                 .sum::<f32>();
                             sum * (*count as f32)
                         }
-                        TrackSizingFunction::Repeat(AutoFit | AutoFill, _) => 0.0,
+                        TrackSizingFunction::Repeat(AutoFit | AutoFill, _)
+                        | TrackSizingFunction::Subgrid
+                        | TrackSizingFunction::Masonry => 0.0,
                     }
                 })
                 .sum();
@@ -177,11 +193,121 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
     non_auto_repeating_track_count + (repetition_track_count * num_repetitions)
 }
 
+/// The names of the lines of the explicit grid in a single axis, including the `<name>-start` and `<name>-end`
+/// lines implicitly named by grid areas
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct GridLineNames<S: CheapCloneStr> {
+    /// The number of tracks in the explicit grid. This includes any tracks added so that the explicit grid contains
+    /// every named grid area, and so may be larger than the number of tracks defined by the template.
+    pub explicit_track_count: u16,
+    /// The named lines as (name, suffix, line) triples, sorted by line. The name of each line is its name followed by
+    /// its suffix, which is empty for lines named in the template and `-start` or `-end` for lines named by areas.
+    names: Vec<(S, &'static str, OriginZeroLine)>,
+}
+
+impl<S: CheapCloneStr> GridLineNames<S> {
+    /// Create a `GridLineNames` for an explicit grid whose lines have no names
+    pub fn new(explicit_track_count: u16) -> Self {
+        Self { explicit_track_count, names: Vec::new() }
+    }
+
+    /// The lines named `<name><suffix>`, in increasing order
+    pub fn lines_named<'a>(
+        &'a self,
+        name: &'a str,
+        suffix: &'a str,
+    ) -> impl DoubleEndedIterator<Item = OriginZeroLine> + 'a {
+        self.names
+            .iter()
+            .filter(move |(line_name, line_suffix, _)| {
+                full_name_bytes(line_name.as_ref(), line_suffix).eq(full_name_bytes(name, suffix))
+            })
+            .map(|(_, _, line)| *line)
+    }
+}
+
+/// The bytes of the line name `<name><suffix>`
+fn full_name_bytes<'a>(name: &'a str, suffix: &'a str) -> impl Iterator<Item = u8> + 'a {
+    name.bytes().chain(suffix.bytes())
+}
+
+/// Resolve the names of the lines of the explicit grid in an axis from the container's line names and grid areas.
+/// The explicit grid is extended beyond the `template_track_count` tracks defined by the template if required to
+/// contain every grid area.
+pub(crate) fn compute_grid_line_names_in_axis<S: GridContainerStyle>(
+    style: &S,
+    template: &[TrackSizingFunction],
+    template_track_count: u16,
+    axis: AbsoluteAxis,
+) -> GridLineNames<S::CustomIdent> {
+    let mut line_names = GridLineNames::new(template_track_count);
+    let names = &mut line_names.names;
+
+    // Lines named in the template. As with the template itself, these are ignored if the template is invalid.
+    if template_track_count > 0 {
+        let non_auto_repeating_track_count = template
+            .iter()
+            .map(|track_def| {
+                use GridTrackRepetition::{AutoFill, AutoFit, Count};
+                match track_def {
+                    TrackSizingFunction::Single(_) => 1,
+                    TrackSizingFunction::Repeat(Count(count), tracks) => count * tracks.len() as u16,
+                    TrackSizingFunction::Repeat(AutoFit | AutoFill, _)
+                    | TrackSizingFunction::Subgrid
+                    | TrackSizingFunction::Masonry => 0,
+                }
+            })
+            .sum::<u16>();
+
+        // The nth list of names names the line before the nth track definition (or the last line if there are no more)
+        let mut line = 0;
+        for (index, line_names) in style.grid_template_line_names(axis).iter().take(template.len() + 1).enumerate() {
+            names.extend(line_names.iter().map(|name| (name.clone(), "", OriginZeroLine(line as i16))));
+            line += match template.get(index) {
+                Some(TrackSizingFunction::Single(_)) => 1,
+                Some(TrackSizingFunction::Repeat(GridTrackRepetition::Count(count), tracks)) => {
+                    count * tracks.len() as u16
+                }
+                Some(TrackSizingFunction::Repeat(_, _)) => template_track_count - non_auto_repeating_track_count,
+                Some(TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry) | None => 0,
+            };
+        }
+    }
+
+    // Lines implicitly named by grid areas. Areas whose end line is not after their start line are ignored.
+    for area in style.grid_template_areas() {
+        let lines = area.lines(axis);
+        if lines.start == 0 || lines.end <= lines.start {
+            continue;
+        }
+        line_names.explicit_track_count = line_names.explicit_track_count.max(lines.end - 1);
+
+        names.push((area.name.clone(), "-start", OriginZeroLine(lines.start as i16 - 1)));
+        names.push((area.name.clone(), "-end", OriginZeroLine(lines.end as i16 - 1)));
+    }
+
+    // A line that is given the same name more than once is still only a single line with that name
+    names.sort_by(|(name_a, suffix_a, line_a), (name_b, suffix_b, line_b)| {
+        line_a
+            .cmp(line_b)
+            .then_with(|| full_name_bytes(name_a.as_ref(), suffix_a).cmp(full_name_bytes(name_b.as_ref(), suffix_b)))
+    });
+    names.dedup_by(|(name_a, suffix_a, line_a), (name_b, suffix_b, line_b)| {
+        line_a == line_b && full_name_bytes(name_a.as_ref(), suffix_a).eq(full_name_bytes(name_b.as_ref(), suffix_b))
+    });
+
+    line_names
+}
+
 /// Resolve the track sizing functions of explicit tracks, automatically created tracks, and gutters
 /// given a set of track counts and all of the relevant styles
+///
+/// The first `template_track_count` explicit tracks are created from the template. Any further explicit tracks (which
+/// exist to contain named grid areas) are sized using the auto track sizing functions, as implicit tracks are.
 pub(super) fn initialize_grid_tracks(
     tracks: &mut Vec<GridTrack>,
     counts: TrackCounts,
+    template_track_count: u16,
     track_template: &[TrackSizingFunction],
     auto_tracks: &[NonRepeatedTrackSizingFunction],
     gap: LengthPercentage,
@@ -210,7 +336,7 @@ pub(super) fn initialize_grid_tracks(
     // Create explicit tracks
     // An explicit check against the count (rather than just relying on track_template being empty) is required here
     // because a count of zero can result from the track_template being invalid, in which case it should be ignored.
-    if counts.explicit > 0 {
+    if template_track_count > 0 {
         track_template.iter().for_each(|track_sizing_function| {
             use GridTrackRepetition::{AutoFill, AutoFit, Count};
             match track_sizing_function {
@@ -234,7 +360,7 @@ pub(super) fn initialize_grid_tracks(
                     });
                 }
                 TrackSizingFunction::Repeat(repetition_kind @ (AutoFit | AutoFill), repeated_tracks) => {
-                    let auto_repeated_track_count = (counts.explicit - (track_template.len() as u16 - 1)) as usize;
+                    let auto_repeated_track_count = (template_track_count - (track_template.len() as u16 - 1)) as usize;
                     let iter = repeated_tracks.iter().copied().cycle();
                     for track_def in iter.take(auto_repeated_track_count) {
                         let mut track =
@@ -253,17 +379,20 @@ pub(super) fn initialize_grid_tracks(
                         current_track_index += 1;
                     }
                 }
+                // Templates containing `subgrid` or `masonry` have a track count of zero, so this is never reached
+                TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => {}
             }
         });
     }
 
-    // Create positive implicit tracks
+    // Create positive implicit tracks, preceded by any explicit tracks not defined by the template
+    let auto_sized_track_count = (counts.explicit - template_track_count) + counts.positive_implicit;
     if auto_tracks.is_empty() {
         let iter = core::iter::repeat(NonRepeatedTrackSizingFunction::AUTO);
-        create_implicit_tracks(tracks, counts.positive_implicit, iter, gap)
+        create_implicit_tracks(tracks, auto_sized_track_count, iter, gap)
     } else {
         let iter = auto_tracks.iter().copied().cycle();
-        create_implicit_tracks(tracks, counts.positive_implicit, iter, gap)
+        create_implicit_tracks(tracks, auto_sized_track_count, iter, gap)
     }
 
     // Mark first and last grid lines as collapsed
@@ -600,7 +729,15 @@ mod test {
 
         // Call function
         let mut tracks = Vec::new();
-        initialize_grid_tracks(&mut tracks, track_counts, &track_template, &auto_tracks, gap, |_| false);
+        initialize_grid_tracks(
+            &mut tracks,
+            track_counts,
+            track_counts.explicit,
+            &track_template,
+            &auto_tracks,
+            gap,
+            |_| false,
+        );
 
         // Assertions
         let expected = vec![
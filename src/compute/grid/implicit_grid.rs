@@ -1,10 +1,12 @@
 //! This module is not required for spec compliance, but is used as a performance optimisation
 //! to reduce the number of allocations required when creating a grid.
-use crate::geometry::Line;
-use crate::style::{GenericGridPlacement, GridPlacement};
+use crate::geometry::{InBothAbsAxis, Line};
+use crate::style::{CheapCloneStr, GridPlacement, OriginZeroGridPlacement};
 use crate::GridItemStyle;
 use core::cmp::{max, min};
 
+use super::explicit_grid::GridLineNames;
+use super::placement::resolve_placement_in_axis;
 use super::types::TrackCounts;
 use super::OriginZeroLine;
 
@@ -17,14 +19,16 @@ use super::OriginZeroLine;
 ///
 /// Note that this function internally mixes use of grid track numbers and grid line numbers
 pub(crate) fn compute_grid_size_estimate<'a, S: GridItemStyle + 'a>(
-    explicit_col_count: u16,
-    explicit_row_count: u16,
+    line_names: &InBothAbsAxis<GridLineNames<S::CustomIdent>>,
     child_styles_iter: impl Iterator<Item = S>,
 ) -> (TrackCounts, TrackCounts) {
+    let explicit_col_count = line_names.horizontal.explicit_track_count;
+    let explicit_row_count = line_names.vertical.explicit_track_count;
+
     // Iterate over children, producing an estimate of the min and max grid lines (in origin-zero coordinates where)
     // along with the span of each item
     let (col_min, col_max, col_max_span, row_min, row_max, row_max_span) =
-        get_known_child_positions(child_styles_iter, explicit_col_count, explicit_row_count);
+        get_known_child_positions(child_styles_iter, line_names);
 
     // Compute *track* count estimates for each axis from:
     //   - The explicit track counts
@@ -63,8 +67,7 @@ pub(crate) fn compute_grid_size_estimate<'a, S: GridItemStyle + 'a>(
 /// The span is measured in tracks spanned
 fn get_known_child_positions<'a, S: GridItemStyle + 'a>(
     children_iter: impl Iterator<Item = S>,
-    explicit_col_count: u16,
-    explicit_row_count: u16,
+    line_names: &InBothAbsAxis<GridLineNames<S::CustomIdent>>,
 ) -> (OriginZeroLine, OriginZeroLine, u16, OriginZeroLine, OriginZeroLine, u16) {
     let (mut col_min, mut col_max, mut col_max_span) = (OriginZeroLine(0), OriginZeroLine(0), 0);
     let (mut row_min, mut row_max, mut row_max_span) = (OriginZeroLine(0), OriginZeroLine(0), 0);
@@ -72,9 +75,9 @@ fn get_known_child_positions<'a, S: GridItemStyle + 'a>(
         // Note: that the children reference the lines in between (and around) the tracks not tracks themselves,
         // and thus we must subtract 1 to get an accurate estimate of the number of tracks
         let (child_col_min, child_col_max, child_col_span) =
-            child_min_line_max_line_span(child_style.grid_column(), explicit_col_count);
+            child_min_line_max_line_span(&child_style.grid_column(), &line_names.horizontal);
         let (child_row_min, child_row_max, child_row_span) =
-            child_min_line_max_line_span(child_style.grid_row(), explicit_row_count);
+            child_min_line_max_line_span(&child_style.grid_row(), &line_names.vertical);
         col_min = min(col_min, child_col_min);
         col_max = max(col_max, child_col_max);
         col_max_span = max(col_max_span, child_col_span);
@@ -91,11 +94,11 @@ fn get_known_child_positions<'a, S: GridItemStyle + 'a>(
 ///
 /// Values are returned in origin-zero coordinates
 #[inline]
-fn child_min_line_max_line_span(
-    line: Line<GridPlacement>,
-    explicit_track_count: u16,
+fn child_min_line_max_line_span<S: CheapCloneStr>(
+    line: &Line<GridPlacement<S>>,
+    line_names: &GridLineNames<S>,
 ) -> (OriginZeroLine, OriginZeroLine, u16) {
-    use GenericGridPlacement::*;
+    use OriginZeroGridPlacement::*;
 
     // 8.3.1. Grid Placement Conflict Handling
     // A. If the placement for a grid item contains two lines, and the start line is further end-ward than the end line, swap the two lines.
@@ -103,8 +106,8 @@ fn child_min_line_max_line_span(
     // C. If the placement contains two spans, remove the one contributed by the end grid-placement property.
     // D. If the placement contains only a span for a named line, replace it with a span of 1.
 
-    // Convert line into origin-zero coordinates before attempting to analyze
-    let oz_line = line.into_origin_zero(explicit_track_count);
+    // Convert line into origin-zero coordinates (resolving any named lines) before attempting to analyze
+    let oz_line = resolve_placement_in_axis(line, line_names);
 
     let min = match (oz_line.start, oz_line.end) {
         // Both tracks specified
@@ -156,8 +159,8 @@ fn child_min_line_max_line_span(
 
     // Calculate span only for indefinitely placed items as we don't need for other items (whose required space will
     // be taken into account by min and max)
-    let span = match (line.start, line.end) {
-        (Auto | Span(_), Auto | Span(_)) => line.indefinite_span(),
+    let span = match (oz_line.start, oz_line.end) {
+        (Auto | Span(_), Auto | Span(_)) => oz_line.indefinite_span(),
         _ => 1,
     };
 
@@ -169,13 +172,15 @@ fn child_min_line_max_line_span(
 mod tests {
     mod test_child_min_max_line {
         use super::super::child_min_line_max_line_span;
+        use super::super::GridLineNames;
         use super::super::OriginZeroLine;
         use crate::geometry::Line;
         use crate::style_helpers::*;
 
         #[test]
         fn child_min_max_line_auto() {
-            let (min_col, max_col, span) = child_min_line_max_line_span(Line { start: line(5), end: span(6) }, 6);
+            let (min_col, max_col, span) =
+                child_min_line_max_line_span(&Line { start: line(5), end: span(6) }, &GridLineNames::<&str>::new(6));
             assert_eq!(min_col, OriginZeroLine(4));
             assert_eq!(max_col, OriginZeroLine(10));
             assert_eq!(span, 1);
@@ -183,7 +188,8 @@ mod tests {
 
         #[test]
         fn child_min_max_line_negative_track() {
-            let (min_col, max_col, span) = child_min_line_max_line_span(Line { start: line(-5), end: span(3) }, 6);
+            let (min_col, max_col, span) =
+                child_min_line_max_line_span(&Line { start: line(-5), end: span(3) }, &GridLineNames::<&str>::new(6));
             assert_eq!(min_col, OriginZeroLine(2));
             assert_eq!(max_col, OriginZeroLine(5));
             assert_eq!(span, 1);
@@ -192,7 +198,9 @@ mod tests {
 
     mod test_initial_grid_sizing {
         use super::super::compute_grid_size_estimate;
+        use super::super::GridLineNames;
         use crate::compute::grid::util::test_helpers::*;
+        use crate::geometry::InBothAbsAxis;
         use crate::style_helpers::*;
 
         #[test]
@@ -203,8 +211,11 @@ mod tests {
                 (line(1), span(2), line(2), auto()).into_grid_child(),
                 (line(-4), auto(), line(-2), auto()).into_grid_child(),
             ];
-            let (inline, block) =
-                compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles.iter());
+            let line_names = InBothAbsAxis {
+                horizontal: GridLineNames::new(explicit_col_count),
+                vertical: GridLineNames::new(explicit_row_count),
+            };
+            let (inline, block) = compute_grid_size_estimate(&line_names, child_styles.iter());
             assert_eq!(inline.negative_implicit, 0);
             assert_eq!(inline.explicit, explicit_col_count);
             assert_eq!(inline.positive_implicit, 0);
@@ -221,8 +232,11 @@ mod tests {
                 (line(-6), span(2), line(-8), auto()).into_grid_child(),
                 (line(4), auto(), line(3), auto()).into_grid_child(),
             ];
-            let (inline, block) =
-                compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles.iter());
+            let line_names = InBothAbsAxis {
+                horizontal: GridLineNames::new(explicit_col_count),
+                vertical: GridLineNames::new(explicit_row_count),
+            };
+            let (inline, block) = compute_grid_size_estimate(&line_names, child_styles.iter());
             assert_eq!(inline.negative_implicit, 1);
             assert_eq!(inline.explicit, explicit_col_count);
             assert_eq!(inline.positive_implicit, 0);
@@ -0,0 +1,297 @@
+//! Masonry layout: grids in which one axis (the masonry axis) has no tracks, and the items are instead packed into
+//! whichever of the tracks of the other axis (the grid axis) are currently the shortest
+//! <https://drafts.csswg.org/css-grid-3/#masonry-layout>
+use super::explicit_grid::GridLineNames;
+use super::placement::{order_modified_document_order, resolve_placement_in_axis};
+use super::track_sizing::track_sizing_algorithm;
+use super::types::{GridItem, GridTrack, TrackCounts};
+use super::OriginZeroLine;
+use crate::compute::common::alignment::{apply_alignment_fallback, compute_alignment_offset};
+use crate::geometry::{AbstractAxis, Line, Size};
+use crate::style::{AlignContent, AlignItems, AvailableSpace, Direction, LengthPercentage, TrackSizingFunction};
+use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId};
+use crate::util::sys::{f32_max, Vec};
+use crate::util::ResolveOrZero;
+use crate::GridItemStyle;
+use core::cmp::max;
+
+/// The masonry axis of a grid with the given (resolved) templates, or `None` if it is not a masonry grid. If both
+/// templates are `masonry` then the rows are the masonry axis.
+pub(super) fn resolve_masonry_axis(
+    column_template: &[TrackSizingFunction],
+    row_template: &[TrackSizingFunction],
+) -> Option<AbstractAxis> {
+    if TrackSizingFunction::is_masonry_template(row_template) {
+        Some(AbstractAxis::Block)
+    } else if TrackSizingFunction::is_masonry_template(column_template) {
+        Some(AbstractAxis::Inline)
+    } else {
+        None
+    }
+}
+
+/// Create the items of a masonry grid in order-modified document order, and return the track counts of the grid axis.
+///
+/// Every item is placed into the single track of the masonry axis. In the grid axis, items with a definite placement
+/// are placed there, and auto-placed items are provisionally placed at the start of the grid. They are moved into the
+/// shortest tracks when the items are packed. `sizing_items` receives the items used to size the tracks of the grid
+/// axis, in which each auto-placed item is placed at every position that it could be packed into.
+pub(super) fn place_masonry_items<S, ChildIter>(
+    items: &mut Vec<GridItem>,
+    sizing_items: &mut Vec<GridItem>,
+    children_iter: ChildIter,
+    grid_axis_line_names: &GridLineNames<S::CustomIdent>,
+    masonry_axis: AbstractAxis,
+    align_items: AlignItems,
+    justify_items: AlignItems,
+) -> TrackCounts
+where
+    S: GridItemStyle,
+    ChildIter: Iterator<Item = (usize, NodeId, S)>,
+{
+    let grid_axis = masonry_axis.other();
+
+    // Resolve each item's placement in the grid axis into a definite placement or the span of an auto-placed item
+    let children: Vec<_> = order_modified_document_order(children_iter)
+        .into_iter()
+        .map(|(index, node, style)| {
+            let placement =
+                resolve_placement_in_axis(&style.grid_placement(grid_axis.as_abs_naive()), grid_axis_line_names);
+            let placement = match placement.is_definite() {
+                true => Ok(placement.resolve_definite_grid_lines()),
+                false => Err(placement.indefinite_span()),
+            };
+            (index, node, style, placement)
+        })
+        .collect();
+
+    // The grid axis contains every definitely placed item, and is large enough for the span of every auto-placed item
+    let explicit_track_count = grid_axis_line_names.explicit_track_count;
+    let (mut negative_implicit, mut positive_implicit, mut max_auto_span) = (0, 0, 0);
+    for (_, _, _, placement) in children.iter() {
+        match placement {
+            Ok(lines) => {
+                negative_implicit = max(negative_implicit, lines.start.implied_negative_implicit_tracks());
+                positive_implicit =
+                    max(positive_implicit, lines.end.implied_positive_implicit_tracks(explicit_track_count));
+            }
+            Err(span) => max_auto_span = max(max_auto_span, *span),
+        }
+    }
+    positive_implicit = max(positive_implicit, max_auto_span.saturating_sub(negative_implicit + explicit_track_count));
+    let track_counts = TrackCounts::from_raw(negative_implicit, explicit_track_count, positive_implicit);
+
+    for (index, node, style, placement) in children {
+        match placement {
+            Ok(lines) => {
+                sizing_items.push(masonry_item(node, index, lines, masonry_axis, &style, align_items, justify_items));
+                items.push(masonry_item(node, index, lines, masonry_axis, style, align_items, justify_items));
+            }
+            Err(span) => {
+                let grid_start = track_counts.implicit_start_line();
+                let last_start = track_counts.implicit_end_line() - span;
+                for start in grid_start.0..=last_start.0 {
+                    let lines = Line { start: OriginZeroLine(start), end: OriginZeroLine(start) + span };
+                    sizing_items.push(masonry_item(
+                        node,
+                        index,
+                        lines,
+                        masonry_axis,
+                        &style,
+                        align_items,
+                        justify_items,
+                    ));
+                }
+                let lines = Line { start: grid_start, end: grid_start + span };
+                let mut item = masonry_item(node, index, lines, masonry_axis, style, align_items, justify_items);
+                item.is_masonry_auto_placed = true;
+                items.push(item);
+            }
+        }
+    }
+
+    track_counts
+}
+
+/// Create an item of a masonry grid with the given placement in the grid axis
+fn masonry_item<S: GridItemStyle>(
+    node: NodeId,
+    index: usize,
+    grid_axis_placement: Line<OriginZeroLine>,
+    masonry_axis: AbstractAxis,
+    style: S,
+    align_items: AlignItems,
+    justify_items: AlignItems,
+) -> GridItem {
+    let masonry_track = Line { start: OriginZeroLine(0), end: OriginZeroLine(1) };
+    let (column, row) = match masonry_axis {
+        AbstractAxis::Block => (grid_axis_placement, masonry_track),
+        AbstractAxis::Inline => (masonry_track, grid_axis_placement),
+    };
+    GridItem::new_with_placement_style_and_order(node, column, row, style, align_items, justify_items, index as u16)
+}
+
+/// Size the tracks of the grid axis of a masonry grid, and then pack the grid's items into them. Sets the size of the
+/// single track of the masonry axis, and returns the running position of each track of the grid axis once all of the
+/// items have been packed.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn masonry_track_sizing_algorithm<Tree: LayoutPartialTree>(
+    tree: &mut Tree,
+    node: NodeId,
+    masonry_axis: AbstractAxis,
+    min_size: Size<Option<f32>>,
+    max_size: Size<Option<f32>>,
+    content_alignment: Size<AlignContent>,
+    available_grid_space: Size<AvailableSpace>,
+    inner_node_size: &mut Size<Option<f32>>,
+    columns: &mut [GridTrack],
+    rows: &mut [GridTrack],
+    items: &mut [GridItem],
+    sizing_items: &mut [GridItem],
+    gap: LengthPercentage,
+) -> Vec<f32> {
+    let grid_axis = masonry_axis.other();
+    let (grid_axis_tracks, masonry_axis_tracks) = match masonry_axis {
+        AbstractAxis::Block => (columns, rows),
+        AbstractAxis::Inline => (rows, columns),
+    };
+
+    // The items are sized with an indefinite size in the masonry axis, as the masonry track is sized by the items
+    track_sizing_algorithm(
+        tree,
+        node,
+        grid_axis,
+        min_size.get(grid_axis),
+        max_size.get(grid_axis),
+        content_alignment.get(grid_axis),
+        content_alignment.get(masonry_axis),
+        available_grid_space,
+        *inner_node_size,
+        grid_axis_tracks,
+        masonry_axis_tracks,
+        sizing_items,
+        |track: &GridTrack, parent_size: Option<f32>, tree: &Tree, node: NodeId| {
+            track.max_track_sizing_function.definite_value(parent_size, |val, basis| tree.calc(node, val, basis))
+        },
+        // Baseline alignment is not supported in masonry grids
+        false,
+        Direction::Ltr,
+    );
+    let grid_axis_size = grid_axis_tracks.iter().map(|track| track.base_size).sum::<f32>();
+    inner_node_size.set(grid_axis, inner_node_size.get(grid_axis).or(Some(grid_axis_size)));
+
+    let gap = gap.resolve_or_zero(inner_node_size.get(masonry_axis), |val, basis| tree.calc(node, val, basis));
+    let running_positions = pack_masonry_items(tree, items, masonry_axis, grid_axis_tracks, gap, *inner_node_size);
+
+    let masonry_axis_size = running_positions.iter().copied().fold(0.0, f32_max);
+    masonry_axis_tracks[1].base_size = masonry_axis_size;
+    inner_node_size.set(masonry_axis, inner_node_size.get(masonry_axis).or(Some(masonry_axis_size)));
+
+    running_positions
+}
+
+/// Pack the items of a masonry grid into the tracks of the grid axis in order-modified document order, and return the
+/// running position of each track: the end of the last item in that track.
+///
+/// Each item is placed after the items already in the tracks that it spans. Auto-placed items are placed into the
+/// tracks in which they can be placed soonest, preferring the earliest such tracks.
+fn pack_masonry_items(
+    tree: &mut impl LayoutPartialTree,
+    items: &mut [GridItem],
+    masonry_axis: AbstractAxis,
+    grid_axis_tracks: &[GridTrack],
+    gap: f32,
+    inner_node_size: Size<Option<f32>>,
+) -> Vec<f32> {
+    let grid_axis = masonry_axis.other();
+    let track_count = grid_axis_tracks.len() / 2;
+    let mut running_positions: Vec<f32> = (0..track_count).map(|_| 0.0).collect();
+    // The position at which the next item in each track can start, which is after the gap following its last item
+    let mut next_positions: Vec<f32> = (0..track_count).map(|_| 0.0).collect();
+
+    for item in items.iter_mut() {
+        let span = item.span(grid_axis) as usize;
+        let start_position = |next_positions: &[f32], first_track: usize| {
+            next_positions[first_track..first_track + span].iter().copied().fold(0.0, f32_max)
+        };
+
+        let first_track = if item.is_masonry_auto_placed {
+            let first_track = (0..=track_count - span)
+                .min_by(|a, b| start_position(&next_positions, *a).total_cmp(&start_position(&next_positions, *b)))
+                .unwrap();
+
+            // Auto-placed items are provisionally placed at the start of the grid axis
+            let placement = item.placement(grid_axis);
+            let placement =
+                Line { start: placement.start + first_track as u16, end: placement.end + first_track as u16 };
+            match grid_axis {
+                AbstractAxis::Inline => item.column = placement,
+                AbstractAxis::Block => item.row = placement,
+            }
+            first_track
+        } else {
+            item.placement_indexes(grid_axis).start as usize / 2
+        };
+
+        // Measure the item given the size of the tracks (and gutters) that it spans
+        let spanned_tracks = &grid_axis_tracks[(2 * first_track + 1)..(2 * (first_track + span))];
+        let mut available_space = Size::NONE;
+        available_space.set(grid_axis, Some(spanned_tracks.iter().map(|track| track.base_size).sum::<f32>()));
+        let margins = item.margins_axis_sums_with_baseline_shims(inner_node_size.width, tree);
+        let size = item.max_content_contribution(masonry_axis, tree, available_space, inner_node_size)
+            + margins.get(masonry_axis);
+
+        let start = start_position(&next_positions, first_track);
+        item.masonry_position = Some(Line { start, end: start + size });
+        for track in first_track..first_track + span {
+            running_positions[track] = start + size;
+            next_positions[track] = start + size + gap;
+        }
+    }
+
+    running_positions
+}
+
+/// Align the items within each track of a masonry grid in the masonry axis according to the align-tracks (rows) or
+/// justify-tracks (columns) property, given the size of the masonry axis' track. Tracks which contain an item that
+/// spans multiple tracks are not aligned, as this could cause that item to overlap the items in its other tracks.
+pub(super) fn align_masonry_tracks(
+    items: &mut [GridItem],
+    masonry_axis: AbstractAxis,
+    running_positions: &[f32],
+    masonry_track_size: f32,
+    track_alignment_style: AlignContent,
+) {
+    let grid_axis = masonry_axis.other();
+    let first_track = |item: &GridItem| item.placement_indexes(grid_axis).start as usize / 2;
+
+    let mut track_is_aligned: Vec<bool> = running_positions.iter().map(|_| true).collect();
+    for item in items.iter().filter(|item| item.span(grid_axis) > 1) {
+        let first_track = first_track(item);
+        track_is_aligned[first_track..first_track + item.span(grid_axis) as usize].iter_mut().for_each(|a| *a = false);
+    }
+
+    for (track, running_position) in running_positions.iter().enumerate() {
+        if !track_is_aligned[track] {
+            continue;
+        }
+        let free_space = masonry_track_size - running_position;
+        let num_items = items.iter().filter(|item| first_track(item) == track).count();
+
+        // Stretch alignment distributes the free space equally amongst the track's items
+        let stretch = match track_alignment_style {
+            AlignContent::Stretch if free_space > 0.0 => free_space / num_items as f32,
+            _ => 0.0,
+        };
+        let track_alignment = apply_alignment_fallback(free_space, num_items, track_alignment_style, false);
+
+        let mut offset = 0.0;
+        for (index, item) in items.iter_mut().filter(|item| first_track(item) == track).enumerate() {
+            offset += compute_alignment_offset(free_space, num_items, 0.0, track_alignment, false, index == 0);
+            let position = item.masonry_position.as_mut().unwrap();
+            position.start += offset;
+            position.end += offset + stretch;
+            offset += stretch;
+        }
+    }
+}
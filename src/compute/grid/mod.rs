@@ -2,9 +2,13 @@
 //! <https://www.w3.org/TR/css-grid-1>
 use core::borrow::Borrow;
 
+use super::common::containing_block::{perform_static_child_layouts, AbsoluteContainingBlock};
+use super::common::scrollbars::{compute_with_scrollbars, Scrollbars};
+use super::common::sizing_keywords::{compute_with_sizing_keywords, SizingKeywords};
+use super::common::writing_mode::compute_with_transposed_axes;
 use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis};
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AlignItems, AlignSelf, AvailableSpace, Overflow, Position};
+use crate::style::{AlignItems, AlignSelf, AvailableSpace, Direction};
 use crate::tree::{Layout, LayoutInput, LayoutOutput, LayoutPartialTreeExt, NodeId, RunMode, SizingMode};
 use crate::util::debug::debug_log;
 use crate::util::sys::{f32_max, GridTrackVec, Vec};
@@ -15,23 +19,28 @@ use crate::{
     JustifyContent, LayoutGridContainer,
 };
 use alignment::{align_and_position_item, align_tracks};
-use explicit_grid::{compute_explicit_grid_size_in_axis, initialize_grid_tracks};
+use explicit_grid::{compute_explicit_grid_size_in_axis, compute_grid_line_names_in_axis, initialize_grid_tracks};
 use implicit_grid::compute_grid_size_estimate;
-use placement::place_grid_items;
+use masonry::{align_masonry_tracks, masonry_track_sizing_algorithm, place_masonry_items, resolve_masonry_axis};
+use placement::{place_grid_items, resolve_placement_in_axis};
+use subgrid::{add_subgrid_items, adopted_tracks, clamp_to_subgrid, set_subgrid_tracks, subgrid_template};
 use track_sizing::{
     determine_if_item_crosses_flexible_or_intrinsic_tracks, resolve_item_track_indexes, track_sizing_algorithm,
 };
-use types::{CellOccupancyMatrix, GridTrack};
+use types::{CellOccupancyMatrix, GridTrack, TrackCounts};
 
 #[cfg(feature = "detailed_layout_info")]
-use types::{GridItem, GridTrackKind, TrackCounts};
+use types::{GridItem, GridTrackKind};
 
+pub use subgrid::SubgridTracks;
 pub(crate) use types::{GridCoordinate, GridLine, OriginZeroLine};
 
 mod alignment;
 mod explicit_grid;
 mod implicit_grid;
+mod masonry;
 mod placement;
+mod subgrid;
 mod track_sizing;
 mod types;
 mod util;
@@ -47,15 +56,46 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     node: NodeId,
     inputs: LayoutInput,
 ) -> LayoutOutput {
-    let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
+    let style = tree.get_grid_container_style(node);
+    let writing_mode = style.writing_mode();
+    let sizing_keywords = SizingKeywords::new(&style, inputs.parent_size, |val, basis| tree.calc(node, val, basis));
+    drop(style);
+
+    compute_with_sizing_keywords(sizing_keywords, inputs, |inputs| match writing_mode.is_vertical() {
+        true => compute_with_transposed_axes(tree, node, inputs, writing_mode, compute_horizontal_tb),
+        false => compute_horizontal_tb(tree, node, inputs),
+    })
+}
+
+/// Grid layout algorithm, treating the x axis as the inline axis
+fn compute_horizontal_tb<Tree: LayoutGridContainer>(
+    tree: &mut Tree,
+    node: NodeId,
+    inputs: LayoutInput,
+) -> LayoutOutput {
+    let style = tree.get_grid_container_style(node);
+    let scrollbars = Scrollbars::new(&style, inputs.parent_size.width, |val, basis| tree.calc(node, val, basis));
+    drop(style);
+
+    compute_with_scrollbars(scrollbars, |scrollbar_gutter| compute_inner(tree, node, inputs, scrollbar_gutter))
+}
+
+/// Grid layout algorithm, reserving `scrollbar_gutter` for the container's scrollbars
+fn compute_inner<Tree: LayoutGridContainer>(
+    tree: &mut Tree,
+    node: NodeId,
+    inputs: LayoutInput,
+    scrollbar_gutter: Rect<f32>,
+) -> LayoutOutput {
+    let LayoutInput { known_dimensions, parent_size, available_space, run_mode, containing_block, .. } = inputs;
 
     let style = tree.get_grid_container_style(node);
 
     // 1. Compute "available grid space"
     // https://www.w3.org/TR/css-grid-1/#available-grid-space
     let aspect_ratio = style.aspect_ratio();
-    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
-    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node, val, basis));
+    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node, val, basis));
     let padding_border = padding + border;
     let padding_border_size = padding_border.sum_axes();
     let box_sizing_adjustment =
@@ -63,40 +103,36 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     let min_size = style
         .min_size()
-        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let max_size = style
         .max_size()
-        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node, val, basis))
         .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let preferred_size = if inputs.sizing_mode == SizingMode::InherentSize {
         style
             .size()
-            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(parent_size, |val, basis| tree.calc(node, val, basis))
             .maybe_apply_aspect_ratio(style.aspect_ratio())
             .maybe_add(box_sizing_adjustment)
     } else {
         Size::NONE
     };
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow().transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width(),
-        _ => 0.0,
-    });
-    // TODO: make side configurable based on the `direction` property
-    let mut content_box_inset = padding_border;
-    content_box_inset.right += scrollbar_gutter.x;
-    content_box_inset.bottom += scrollbar_gutter.y;
+    let direction = style.direction();
+    let position = style.position();
+    let content_box_inset = padding_border + scrollbar_gutter;
 
     let align_content = style.align_content().unwrap_or(AlignContent::Stretch);
     let justify_content = style.justify_content().unwrap_or(JustifyContent::Stretch);
     let align_items = style.align_items();
     let justify_items = style.justify_items();
+    let content_alignment_safety =
+        InBothAbsAxis { horizontal: style.justify_content_safety(), vertical: style.align_content_safety() };
+    let container_alignment_safety =
+        InBothAbsAxis { horizontal: style.justify_items_safety(), vertical: style.align_items_safety() };
 
     // Note: we avoid accessing the grid rows/columns methods more than once as this can
     // cause an expensive-ish computation
@@ -105,6 +141,28 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     let grid_auto_columms = style.grid_auto_columns();
     let grid_auto_rows = style.grid_auto_rows();
 
+    // A subgrid adopts the tracks and gaps that it spans from its parent grid in place of its own template
+    let subgrid_columns = adopted_tracks(tree, node, grid_template_columms.borrow(), AbstractAxis::Inline);
+    let subgrid_rows = adopted_tracks(tree, node, grid_template_rows.borrow(), AbstractAxis::Block);
+    let subgrid_column_template = subgrid_columns.as_ref().map(|tracks| subgrid_template(tracks.sizes.iter().copied()));
+    let subgrid_row_template = subgrid_rows.as_ref().map(|tracks| subgrid_template(tracks.sizes.iter().copied()));
+    let column_template = subgrid_column_template.as_deref().unwrap_or(grid_template_columms.borrow());
+    let row_template = subgrid_row_template.as_deref().unwrap_or(grid_template_rows.borrow());
+    let gap = Size {
+        width: subgrid_columns.as_ref().map(|tracks| length(tracks.gap)).unwrap_or(style.gap().width),
+        height: subgrid_rows.as_ref().map(|tracks| length(tracks.gap)).unwrap_or(style.gap().height),
+    };
+
+    // In a masonry grid, the items are packed into the tracks of one axis (the grid axis) rather than being placed
+    // into a grid. The other axis (the masonry axis) has a single track which contains every item.
+    let masonry_axis = resolve_masonry_axis(column_template, row_template);
+    let masonry_track_alignment = match masonry_axis {
+        Some(AbstractAxis::Block) => style.align_tracks(),
+        Some(AbstractAxis::Inline) => style.justify_tracks(),
+        None => None,
+    }
+    .unwrap_or(AlignContent::Start);
+
     let constrained_available_space = known_dimensions
         .or(preferred_size)
         .map(|size| size.map(AvailableSpace::Definite))
@@ -152,27 +210,38 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         .maybe_max(padding_border_size)
         .maybe_sub(content_box_inset.sum_axes());
 
-    // Exactly compute the number of rows and columns in the explicit grid.
-    let explicit_col_count = compute_explicit_grid_size_in_axis(
+    // Exactly compute the number of rows and columns defined by the grid template.
+    let template_col_count = compute_explicit_grid_size_in_axis(
         &style,
-        grid_template_columms.borrow(),
+        column_template,
         auto_fit_container_size,
-        |val, basis| tree.calc(val, basis),
+        |val, basis| tree.calc(node, val, basis),
         AbsoluteAxis::Horizontal,
     );
-    let explicit_row_count = compute_explicit_grid_size_in_axis(
+    let template_row_count = compute_explicit_grid_size_in_axis(
         &style,
-        grid_template_rows.borrow(),
+        row_template,
         auto_fit_container_size,
-        |val, basis| tree.calc(val, basis),
+        |val, basis| tree.calc(node, val, basis),
         AbsoluteAxis::Vertical,
     );
 
+    // Resolve the names of the explicit grid lines. The explicit grid is extended beyond the tracks defined by
+    // the template if this is required to contain the named grid areas.
+    let line_names = InBothAbsAxis {
+        horizontal: compute_grid_line_names_in_axis(
+            &style,
+            column_template,
+            template_col_count,
+            AbsoluteAxis::Horizontal,
+        ),
+        vertical: compute_grid_line_names_in_axis(&style, row_template, template_row_count, AbsoluteAxis::Vertical),
+    };
+
     // 3. Implicit Grid: Estimate Track Counts
     // Estimate the number of rows and columns in the implicit grid (= the entire grid)
     // This is necessary as part of placement. Doing it early here is a perf optimisation to reduce allocations.
-    let (est_col_counts, est_row_counts) =
-        compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
+    let (est_col_counts, est_row_counts) = compute_grid_size_estimate(&line_names, child_styles_iter);
 
     // 4. Grid Item Placement
     // Match items (children) to a definite grid position (row start/end and column start/end position)
@@ -183,42 +252,89 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
             .enumerate()
             .map(|(index, child_node)| (index, child_node, tree.get_grid_child_style(child_node)))
             .filter(|(_, _, style)| {
-                style.box_generation_mode() != BoxGenerationMode::None && style.position() != Position::Absolute
+                style.box_generation_mode() != BoxGenerationMode::None && !style.position().is_absolutely_positioned()
             })
     };
-    place_grid_items(
-        &mut cell_occupancy_matrix,
-        &mut items,
-        in_flow_children_iter,
-        style.grid_auto_flow(),
-        align_items.unwrap_or(AlignItems::Stretch),
-        justify_items.unwrap_or(AlignItems::Stretch),
-    );
+    let mut masonry_sizing_items = Vec::new();
+    let (final_col_counts, final_row_counts) = match masonry_axis {
+        Some(masonry_axis) => {
+            let grid_axis_counts = place_masonry_items(
+                &mut items,
+                &mut masonry_sizing_items,
+                in_flow_children_iter(),
+                match masonry_axis {
+                    AbstractAxis::Block => &line_names.horizontal,
+                    AbstractAxis::Inline => &line_names.vertical,
+                },
+                masonry_axis,
+                align_items.unwrap_or(AlignItems::Stretch),
+                justify_items.unwrap_or(AlignItems::Stretch),
+            );
+            let masonry_axis_counts = TrackCounts::from_raw(0, 0, 1);
+            match masonry_axis {
+                AbstractAxis::Block => (grid_axis_counts, masonry_axis_counts),
+                AbstractAxis::Inline => (masonry_axis_counts, grid_axis_counts),
+            }
+        }
+        None => {
+            place_grid_items(
+                &mut cell_occupancy_matrix,
+                &mut items,
+                in_flow_children_iter,
+                &line_names,
+                style.grid_auto_flow(),
+                align_items.unwrap_or(AlignItems::Stretch),
+                justify_items.unwrap_or(AlignItems::Stretch),
+            );
+
+            // Extract track counts from previous step (auto-placement can expand the number of tracks)
+            let mut final_col_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal);
+            let mut final_row_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical);
+
+            // A subgrid has no implicit tracks in the axes in which it adopts its parent's tracks, so items placed
+            // outside of the adopted tracks are clamped into them
+            if let Some(tracks) = &subgrid_columns {
+                let track_count = tracks.sizes.len() as u16;
+                items.iter_mut().for_each(|item| item.column = clamp_to_subgrid(item.column, track_count));
+                final_col_counts = TrackCounts::from_raw(0, track_count, 0);
+            }
+            if let Some(tracks) = &subgrid_rows {
+                let track_count = tracks.sizes.len() as u16;
+                items.iter_mut().for_each(|item| item.row = clamp_to_subgrid(item.row, track_count));
+                final_row_counts = TrackCounts::from_raw(0, track_count, 0);
+            }
 
-    // Extract track counts from previous step (auto-placement can expand the number of tracks)
-    let final_col_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal);
-    let final_row_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical);
+            // The items of any subgrids contribute to the sizing of the tracks that the subgrid adopts
+            add_subgrid_items(tree, &mut items, style.writing_mode().is_vertical(), direction, inner_node_size.width);
+
+            (final_col_counts, final_row_counts)
+        }
+    };
 
     // 5. Initialize Tracks
     // Initialize (explicit and implicit) grid tracks (and gutters)
     // This resolves the min and max track sizing functions for all tracks and gutters
+    // The single track of the masonry axis is sized by the items packed into it, regardless of the auto track sizes.
+    // The cell occupancy matrix is not used for masonry grids, so no auto-fit tracks are collapsed.
     let mut columns = GridTrackVec::new();
     let mut rows = GridTrackVec::new();
     initialize_grid_tracks(
         &mut columns,
         final_col_counts,
-        grid_template_columms.borrow(),
-        grid_auto_columms.borrow(),
-        style.gap().width,
-        |column_index| cell_occupancy_matrix.column_is_occupied(column_index),
+        template_col_count,
+        column_template,
+        if masonry_axis == Some(AbstractAxis::Inline) { &[] } else { grid_auto_columms.borrow() },
+        gap.width,
+        |column_index| masonry_axis.is_some() || cell_occupancy_matrix.column_is_occupied(column_index),
     );
     initialize_grid_tracks(
         &mut rows,
         final_row_counts,
-        grid_template_rows.borrow(),
-        grid_auto_rows.borrow(),
-        style.gap().height,
-        |row_index| cell_occupancy_matrix.row_is_occupied(row_index),
+        template_row_count,
+        row_template,
+        if masonry_axis == Some(AbstractAxis::Block) { &[] } else { grid_auto_rows.borrow() },
+        gap.height,
+        |row_index| masonry_axis.is_some() || cell_occupancy_matrix.row_is_occupied(row_index),
     );
 
     drop(grid_template_rows);
@@ -233,55 +349,97 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     // This computation is relatively trivial, but it requires the final number of negative (implicit) tracks in
     // each axis, and doing it up-front here means we don't have to keep repeating that calculation
     resolve_item_track_indexes(&mut items, final_col_counts, final_row_counts);
+    resolve_item_track_indexes(&mut masonry_sizing_items, final_col_counts, final_row_counts);
 
     // For each item, and in each axis, determine whether the item crosses any flexible (fr) tracks
     // Record this as a boolean (per-axis) on each item for later use in the track-sizing algorithm
     determine_if_item_crosses_flexible_or_intrinsic_tracks(&mut items, &columns, &rows);
+    determine_if_item_crosses_flexible_or_intrinsic_tracks(&mut masonry_sizing_items, &columns, &rows);
 
-    // Determine if the grid has any baseline aligned items
-    let has_baseline_aligned_item = items.iter().any(|item| item.align_self == AlignSelf::Baseline);
+    // Determine if the grid has any baseline aligned items in each axis
+    let has_baseline_aligned_item = Size {
+        width: items.iter().any(|item| item.justify_self == AlignSelf::Baseline && !item.is_subgrid_item),
+        height: items.iter().any(|item| item.align_self == AlignSelf::Baseline && !item.is_subgrid_item),
+    };
 
-    // Run track sizing algorithm for Inline axis
-    track_sizing_algorithm(
-        tree,
-        AbstractAxis::Inline,
-        min_size.get(AbstractAxis::Inline),
-        max_size.get(AbstractAxis::Inline),
-        justify_content,
-        align_content,
-        available_grid_space,
-        inner_node_size,
-        &mut columns,
-        &mut rows,
-        &mut items,
-        |track: &GridTrack, parent_size: Option<f32>, tree: &Tree| {
-            track.max_track_sizing_function.definite_value(parent_size, |val, basis| tree.calc(val, basis))
-        },
-        has_baseline_aligned_item,
-    );
-    let initial_column_sum = columns.iter().map(|track| track.base_size).sum::<f32>();
-    inner_node_size.width = inner_node_size.width.or_else(|| initial_column_sum.into());
+    let masonry_running_positions = match masonry_axis {
+        Some(masonry_axis) => {
+            let running_positions = masonry_track_sizing_algorithm(
+                tree,
+                node,
+                masonry_axis,
+                min_size,
+                max_size,
+                Size { width: justify_content, height: align_content },
+                available_grid_space,
+                &mut inner_node_size,
+                &mut columns,
+                &mut rows,
+                &mut items,
+                &mut masonry_sizing_items,
+                gap.get(masonry_axis),
+            );
+            // Packing moves the auto-placed items into the tracks that they were packed into
+            resolve_item_track_indexes(&mut items, final_col_counts, final_row_counts);
+            Some(running_positions)
+        }
+        None => {
+            // Run track sizing algorithm for Inline axis
+            track_sizing_algorithm(
+                tree,
+                node,
+                AbstractAxis::Inline,
+                min_size.get(AbstractAxis::Inline),
+                max_size.get(AbstractAxis::Inline),
+                justify_content,
+                align_content,
+                available_grid_space,
+                inner_node_size,
+                &mut columns,
+                &mut rows,
+                &mut items,
+                |track: &GridTrack, parent_size: Option<f32>, tree: &Tree, node: NodeId| {
+                    track
+                        .max_track_sizing_function
+                        .definite_value(parent_size, |val, basis| tree.calc(node, val, basis))
+                },
+                has_baseline_aligned_item.width,
+                direction,
+            );
+            let initial_column_sum = columns.iter().map(|track| track.base_size).sum::<f32>();
+            inner_node_size.width = inner_node_size.width.or_else(|| initial_column_sum.into());
 
-    items.iter_mut().for_each(|item| item.available_space_cache = None);
+            // Subgrids need their columns in order to be measured during row sizing
+            set_subgrid_tracks(tree, &items, AbstractAxis::Inline, &columns, direction, inner_node_size.width);
 
-    // Run track sizing algorithm for Block axis
-    track_sizing_algorithm(
-        tree,
-        AbstractAxis::Block,
-        min_size.get(AbstractAxis::Block),
-        max_size.get(AbstractAxis::Block),
-        align_content,
-        justify_content,
-        available_grid_space,
-        inner_node_size,
-        &mut rows,
-        &mut columns,
-        &mut items,
-        |track: &GridTrack, _, _| Some(track.base_size),
-        false, // TODO: Support baseline alignment in the vertical axis
-    );
+            items.iter_mut().for_each(|item| item.available_space_cache = None);
+
+            // Run track sizing algorithm for Block axis
+            track_sizing_algorithm(
+                tree,
+                node,
+                AbstractAxis::Block,
+                min_size.get(AbstractAxis::Block),
+                max_size.get(AbstractAxis::Block),
+                align_content,
+                justify_content,
+                available_grid_space,
+                inner_node_size,
+                &mut rows,
+                &mut columns,
+                &mut items,
+                |track: &GridTrack, _, _, _| Some(track.base_size),
+                has_baseline_aligned_item.height,
+                direction,
+            );
+            let initial_row_sum = rows.iter().map(|track| track.base_size).sum::<f32>();
+            inner_node_size.height = inner_node_size.height.or_else(|| initial_row_sum.into());
+
+            None
+        }
+    };
+    let initial_column_sum = columns.iter().map(|track| track.base_size).sum::<f32>();
     let initial_row_sum = rows.iter().map(|track| track.base_size).sum::<f32>();
-    inner_node_size.height = inner_node_size.height.or_else(|| initial_row_sum.into());
 
     debug_log!("initial_column_sum", dbg:initial_column_sum);
     debug_log!(dbg: columns.iter().map(|track| track.base_size).collect::<Vec<_>>());
@@ -319,10 +477,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         for column in &mut columns {
             let min: Option<f32> = column
                 .min_track_sizing_function
-                .resolved_percentage_size(container_content_box.width, |val, basis| tree.calc(val, basis));
+                .resolved_percentage_size(container_content_box.width, |val, basis| tree.calc(node, val, basis));
             let max: Option<f32> = column
                 .max_track_sizing_function
-                .resolved_percentage_size(container_content_box.width, |val, basis| tree.calc(val, basis));
+                .resolved_percentage_size(container_content_box.width, |val, basis| tree.calc(node, val, basis));
             column.base_size = column.base_size.maybe_clamp(min, max);
         }
     }
@@ -330,10 +488,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         for row in &mut rows {
             let min: Option<f32> = row
                 .min_track_sizing_function
-                .resolved_percentage_size(container_content_box.height, |val, basis| tree.calc(val, basis));
+                .resolved_percentage_size(container_content_box.height, |val, basis| tree.calc(node, val, basis));
             let max: Option<f32> = row
                 .max_track_sizing_function
-                .resolved_percentage_size(container_content_box.height, |val, basis| tree.calc(val, basis));
+                .resolved_percentage_size(container_content_box.height, |val, basis| tree.calc(node, val, basis));
             row.base_size = row.base_size.maybe_clamp(min, max);
         }
     }
@@ -342,133 +500,171 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     //   - The grid container's width was initially indefinite and there are any columns with percentage track sizing functions
     //   - Any grid item crossing an intrinsically sized track's min content contribution width has changed
     // TODO: Only rerun sizing for tracks that actually require it rather than for all tracks if any need it.
-    let mut rerun_column_sizing;
-
-    let has_percentage_column = columns.iter().any(|track| track.uses_percentage());
-    let parent_width_indefinite = !available_space.width.is_definite();
-    rerun_column_sizing = parent_width_indefinite && has_percentage_column;
-
-    if !rerun_column_sizing {
-        let min_content_contribution_changed =
-            items.iter_mut().filter(|item| item.crosses_intrinsic_column).any(|item| {
-                let available_space = item.available_space(
-                    AbstractAxis::Inline,
-                    &rows,
-                    inner_node_size.height,
-                    |track: &GridTrack, _| Some(track.base_size),
-                );
-                let new_min_content_contribution =
-                    item.min_content_contribution(AbstractAxis::Inline, tree, available_space, inner_node_size);
+    // Masonry grids are sized in a single pass, as re-running the sizing of the grid axis would require the items to be
+    // packed again.
+    if masonry_axis.is_none() {
+        let mut rerun_column_sizing;
 
-                let has_changed = Some(new_min_content_contribution) != item.min_content_contribution_cache.width;
+        let has_percentage_column = columns.iter().any(|track| track.uses_percentage());
+        let parent_width_indefinite = !available_space.width.is_definite();
+        rerun_column_sizing = parent_width_indefinite && has_percentage_column;
 
-                item.available_space_cache = Some(available_space);
-                item.min_content_contribution_cache.width = Some(new_min_content_contribution);
-                item.max_content_contribution_cache.width = None;
-                item.minimum_contribution_cache.width = None;
-
-                has_changed
-            });
-        rerun_column_sizing = min_content_contribution_changed;
-    } else {
-        // Clear intrisic width caches
-        items.iter_mut().for_each(|item| {
-            item.available_space_cache = None;
-            item.min_content_contribution_cache.width = None;
-            item.max_content_contribution_cache.width = None;
-            item.minimum_contribution_cache.width = None;
-        });
-    }
-
-    if rerun_column_sizing {
-        // Re-run track sizing algorithm for Inline axis
-        track_sizing_algorithm(
-            tree,
-            AbstractAxis::Inline,
-            min_size.get(AbstractAxis::Inline),
-            max_size.get(AbstractAxis::Inline),
-            justify_content,
-            align_content,
-            available_grid_space,
-            inner_node_size,
-            &mut columns,
-            &mut rows,
-            &mut items,
-            |track: &GridTrack, _, _| Some(track.base_size),
-            has_baseline_aligned_item,
-        );
-
-        // Row sizing must be re-run (once) if:
-        //   - The grid container's height was initially indefinite and there are any rows with percentage track sizing functions
-        //   - Any grid item crossing an intrinsically sized track's min content contribution height has changed
-        // TODO: Only rerun sizing for tracks that actually require it rather than for all tracks if any need it.
-        let mut rerun_row_sizing;
-
-        let has_percentage_row = rows.iter().any(|track| track.uses_percentage());
-        let parent_height_indefinite = !available_space.height.is_definite();
-        rerun_row_sizing = parent_height_indefinite && has_percentage_row;
-
-        if !rerun_row_sizing {
+        if !rerun_column_sizing {
             let min_content_contribution_changed =
                 items.iter_mut().filter(|item| item.crosses_intrinsic_column).any(|item| {
                     let available_space = item.available_space(
-                        AbstractAxis::Block,
-                        &columns,
-                        inner_node_size.width,
+                        AbstractAxis::Inline,
+                        &rows,
+                        inner_node_size.height,
                         |track: &GridTrack, _| Some(track.base_size),
                     );
                     let new_min_content_contribution =
-                        item.min_content_contribution(AbstractAxis::Block, tree, available_space, inner_node_size);
+                        item.min_content_contribution(AbstractAxis::Inline, tree, available_space, inner_node_size);
 
-                    let has_changed = Some(new_min_content_contribution) != item.min_content_contribution_cache.height;
+                    let has_changed = Some(new_min_content_contribution) != item.min_content_contribution_cache.width;
 
                     item.available_space_cache = Some(available_space);
-                    item.min_content_contribution_cache.height = Some(new_min_content_contribution);
-                    item.max_content_contribution_cache.height = None;
-                    item.minimum_contribution_cache.height = None;
+                    item.min_content_contribution_cache.width = Some(new_min_content_contribution);
+                    item.max_content_contribution_cache.width = None;
+                    item.minimum_contribution_cache.width = None;
 
                     has_changed
                 });
-            rerun_row_sizing = min_content_contribution_changed;
+            rerun_column_sizing = min_content_contribution_changed;
         } else {
+            // Clear intrisic width caches
             items.iter_mut().for_each(|item| {
-                // Clear intrisic height caches
                 item.available_space_cache = None;
-                item.min_content_contribution_cache.height = None;
-                item.max_content_contribution_cache.height = None;
-                item.minimum_contribution_cache.height = None;
+                item.min_content_contribution_cache.width = None;
+                item.max_content_contribution_cache.width = None;
+                item.minimum_contribution_cache.width = None;
             });
         }
 
-        if rerun_row_sizing {
-            // Re-run track sizing algorithm for Block axis
+        if rerun_column_sizing {
+            // Re-run track sizing algorithm for Inline axis
             track_sizing_algorithm(
                 tree,
-                AbstractAxis::Block,
-                min_size.get(AbstractAxis::Block),
-                max_size.get(AbstractAxis::Block),
-                align_content,
+                node,
+                AbstractAxis::Inline,
+                min_size.get(AbstractAxis::Inline),
+                max_size.get(AbstractAxis::Inline),
                 justify_content,
+                align_content,
                 available_grid_space,
                 inner_node_size,
-                &mut rows,
                 &mut columns,
+                &mut rows,
                 &mut items,
-                |track: &GridTrack, _, _| Some(track.base_size),
-                false, // TODO: Support baseline alignment in the vertical axis
+                |track: &GridTrack, _, _, _| Some(track.base_size),
+                has_baseline_aligned_item.width,
+                direction,
             );
+
+            // Row sizing must be re-run (once) if:
+            //   - The grid container's height was initially indefinite and there are any rows with percentage track sizing functions
+            //   - Any grid item crossing an intrinsically sized track's min content contribution height has changed
+            // TODO: Only rerun sizing for tracks that actually require it rather than for all tracks if any need it.
+            let mut rerun_row_sizing;
+
+            let has_percentage_row = rows.iter().any(|track| track.uses_percentage());
+            let parent_height_indefinite = !available_space.height.is_definite();
+            rerun_row_sizing = parent_height_indefinite && has_percentage_row;
+
+            if !rerun_row_sizing {
+                let min_content_contribution_changed =
+                    items.iter_mut().filter(|item| item.crosses_intrinsic_column).any(|item| {
+                        let available_space = item.available_space(
+                            AbstractAxis::Block,
+                            &columns,
+                            inner_node_size.width,
+                            |track: &GridTrack, _| Some(track.base_size),
+                        );
+                        let new_min_content_contribution =
+                            item.min_content_contribution(AbstractAxis::Block, tree, available_space, inner_node_size);
+
+                        let has_changed =
+                            Some(new_min_content_contribution) != item.min_content_contribution_cache.height;
+
+                        item.available_space_cache = Some(available_space);
+                        item.min_content_contribution_cache.height = Some(new_min_content_contribution);
+                        item.max_content_contribution_cache.height = None;
+                        item.minimum_contribution_cache.height = None;
+
+                        has_changed
+                    });
+                rerun_row_sizing = min_content_contribution_changed;
+            } else {
+                items.iter_mut().for_each(|item| {
+                    // Clear intrisic height caches
+                    item.available_space_cache = None;
+                    item.min_content_contribution_cache.height = None;
+                    item.max_content_contribution_cache.height = None;
+                    item.minimum_contribution_cache.height = None;
+                });
+            }
+
+            if rerun_row_sizing {
+                // Re-run track sizing algorithm for Block axis
+                track_sizing_algorithm(
+                    tree,
+                    node,
+                    AbstractAxis::Block,
+                    min_size.get(AbstractAxis::Block),
+                    max_size.get(AbstractAxis::Block),
+                    align_content,
+                    justify_content,
+                    available_grid_space,
+                    inner_node_size,
+                    &mut rows,
+                    &mut columns,
+                    &mut items,
+                    |track: &GridTrack, _, _, _| Some(track.base_size),
+                    has_baseline_aligned_item.height,
+                    direction,
+                );
+            }
         }
     }
 
+    // Pass the final track sizes to any subgrids before they are laid out
+    set_subgrid_tracks(tree, &items, AbstractAxis::Inline, &columns, direction, inner_node_size.width);
+    set_subgrid_tracks(tree, &items, AbstractAxis::Block, &rows, direction, inner_node_size.width);
+
     // 8. Track Alignment
 
+    // The masonry axis' track is stretched to fill the grid container unless its content alignment is otherwise
+    if let Some(masonry_axis) = masonry_axis {
+        let (masonry_axis_tracks, alignment) = match masonry_axis {
+            AbstractAxis::Block => (&mut rows, align_content),
+            AbstractAxis::Inline => (&mut columns, justify_content),
+        };
+        if alignment == AlignContent::Stretch {
+            let container_size = container_content_box.get(masonry_axis);
+            masonry_axis_tracks[1].base_size = f32_max(masonry_axis_tracks[1].base_size, container_size);
+        }
+    }
+
     // Align columns
+    // Column offsets are computed from the inline-start edge of the container, which is the right edge
+    // for right-to-left containers. They are converted into physical offsets when items are positioned.
+    // The scrollbar gutters lie between the border and the padding, so they are included in the border.
+    let border = border + scrollbar_gutter;
+    let (inline_padding, inline_border) = match direction {
+        Direction::Ltr => {
+            (Line { start: padding.left, end: padding.right }, Line { start: border.left, end: border.right })
+        }
+        Direction::Rtl => {
+            (Line { start: padding.right, end: padding.left }, Line { start: border.right, end: border.left })
+        }
+    };
     align_tracks(
         container_content_box.get(AbstractAxis::Inline),
-        Line { start: padding.left, end: padding.right },
-        Line { start: border.left, end: border.right },
+        inline_padding,
+        inline_border,
         &mut columns,
         justify_content,
+        content_alignment_safety.horizontal,
     );
     // Align rows
     align_tracks(
@@ -477,43 +673,89 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         Line { start: border.top, end: border.bottom },
         &mut rows,
         align_content,
+        content_alignment_safety.vertical,
     );
+    // Align the items within each track of a masonry grid
+    if let (Some(masonry_axis), Some(running_positions)) = (masonry_axis, &masonry_running_positions) {
+        let masonry_track_size = match masonry_axis {
+            AbstractAxis::Block => rows[1].base_size,
+            AbstractAxis::Inline => columns[1].base_size,
+        };
+        align_masonry_tracks(&mut items, masonry_axis, running_positions, masonry_track_size, masonry_track_alignment);
+    }
 
     // 9. Size, Align, and Position Grid Items
 
     #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
     let mut item_content_size_contribution = Size::ZERO;
 
-    // Sort items back into original order to allow them to be matched up with styles
+    // Items of subgrids are positioned by their subgrid. Sort the remaining items back into original order to
+    // allow them to be matched up with styles.
+    items.retain(|item| !item.is_subgrid_item);
     items.sort_by_key(|item| item.source_order);
 
     let container_alignment_styles = InBothAbsAxis { horizontal: justify_items, vertical: align_items };
 
+    // Converts a range of column offsets (measured from the inline-start edge) into physical left and right offsets
+    let columns_to_physical = |inline_start: f32, inline_end: f32| match direction {
+        Direction::Ltr => Line { start: inline_start, end: inline_end },
+        Direction::Rtl => {
+            Line { start: container_border_box.width - inline_end, end: container_border_box.width - inline_start }
+        }
+    };
+
+    // Absolutely positioned children (and the absolutely positioned descendants of static children) are positioned
+    // once their containing block is known
+    let containing_block = AbsoluteContainingBlock::resolve(containing_block, position, border);
+    let mut static_children = Vec::new();
+
     // Position in-flow children (stored in items vector)
     for (index, item) in items.iter_mut().enumerate() {
+        let mut inline_area = Line {
+            start: columns[item.column_indexes.start as usize + 1].offset,
+            end: columns[item.column_indexes.end as usize].offset,
+        };
+        let mut block_area = Line {
+            start: rows[item.row_indexes.start as usize + 1].offset,
+            end: rows[item.row_indexes.end as usize].offset,
+        };
+        // The items of a masonry grid are positioned within the masonry axis' track where they were packed
+        if let (Some(masonry_axis), Some(position)) = (masonry_axis, item.masonry_position) {
+            let area = match masonry_axis {
+                AbstractAxis::Inline => &mut inline_area,
+                AbstractAxis::Block => &mut block_area,
+            };
+            *area = Line { start: area.start + position.start, end: area.start + position.end };
+        }
+        let horizontal_area = columns_to_physical(inline_area.start, inline_area.end);
         let grid_area = Rect {
-            top: rows[item.row_indexes.start as usize + 1].offset,
-            bottom: rows[item.row_indexes.end as usize].offset,
-            left: columns[item.column_indexes.start as usize + 1].offset,
-            right: columns[item.column_indexes.end as usize].offset,
+            top: block_area.start,
+            bottom: block_area.end,
+            left: horizontal_area.start,
+            right: horizontal_area.end,
         };
         #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
-        let (content_size_contribution, y_position, height) = align_and_position_item(
+        let (content_size_contribution, position, final_size) = align_and_position_item(
             tree,
             item.node,
             index as u32,
             grid_area,
             container_alignment_styles,
+            container_alignment_safety,
+            direction,
             item.baseline_shim,
+            Point::NONE,
+            &mut static_children,
         );
-        item.y_position = y_position;
-        item.height = height;
+        item.position = position;
+        item.final_size = final_size;
 
         #[cfg(feature = "content_size")]
         {
             item_content_size_contribution = item_content_size_contribution.f32_max(content_size_contribution);
         }
     }
+    perform_static_child_layouts(tree, &static_children, containing_block, container_border_box);
 
     // Position hidden and absolutely positioned children
     let mut order = items.len() as u32;
@@ -538,46 +780,104 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         }
 
         // Position absolutely positioned child
-        if child_style.position() == Position::Absolute {
+        if child_style.position().is_absolutely_positioned() {
+            let Some(containing_block) = containing_block else {
+                order += 1;
+                return;
+            };
+
             // Convert grid-col-{start/end} into Option's of indexes into the columns vector
-            // The Option is None if the style property is Auto and an unresolvable Span
-            let maybe_col_indexes = child_style
-                .grid_column()
-                .into_origin_zero(final_col_counts.explicit)
+            // The Option is None if the style property is Auto and an unresolvable Span, or if the line is outside of
+            // the grid (which can only happen in an axis in which a subgrid adopts its parent's tracks)
+            let maybe_col_indexes = resolve_placement_in_axis(&child_style.grid_column(), &line_names.horizontal)
                 .resolve_absolutely_positioned_grid_tracks()
                 .map(|maybe_grid_line| {
-                    maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_col_counts))
+                    maybe_grid_line
+                        .filter(|line| {
+                            *line >= final_col_counts.implicit_start_line()
+                                && *line <= final_col_counts.implicit_end_line()
+                        })
+                        .map(|line: OriginZeroLine| line.into_track_vec_index(final_col_counts))
                 });
             // Convert grid-row-{start/end} into Option's of indexes into the row vector
             // The Option is None if the style property is Auto and an unresolvable Span
-            let maybe_row_indexes = child_style
-                .grid_row()
-                .into_origin_zero(final_row_counts.explicit)
+            let maybe_row_indexes = resolve_placement_in_axis(&child_style.grid_row(), &line_names.vertical)
                 .resolve_absolutely_positioned_grid_tracks()
                 .map(|maybe_grid_line| {
-                    maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_row_counts))
+                    maybe_grid_line
+                        .filter(|line| {
+                            *line >= final_row_counts.implicit_start_line()
+                                && *line <= final_row_counts.implicit_end_line()
+                        })
+                        .map(|line: OriginZeroLine| line.into_track_vec_index(final_row_counts))
                 });
 
-            let grid_area = Rect {
+            let horizontal_area = columns_to_physical(
+                maybe_col_indexes.start.map(|index| columns[index].offset).unwrap_or(inline_border.start),
+                maybe_col_indexes
+                    .end
+                    .map(|index| columns[index].offset)
+                    .unwrap_or(container_border_box.width - inline_border.end),
+            );
+            let mut grid_area = Rect {
                 top: maybe_row_indexes.start.map(|index| rows[index].offset).unwrap_or(border.top),
                 bottom: maybe_row_indexes
                     .end
                     .map(|index| rows[index].offset)
-                    .unwrap_or(container_border_box.height - border.bottom - scrollbar_gutter.y),
-                left: maybe_col_indexes.start.map(|index| columns[index].offset).unwrap_or(border.left),
-                right: maybe_col_indexes
-                    .end
-                    .map(|index| columns[index].offset)
-                    .unwrap_or(container_border_box.width - border.right - scrollbar_gutter.x),
+                    .unwrap_or(container_border_box.height - border.bottom),
+                left: horizontal_area.start,
+                right: horizontal_area.end,
             };
+
+            // Grid placement only applies if the container is the containing block. Otherwise the child is
+            // positioned against its containing block in axes in which it has an inset, and is placed at its static
+            // position (at the start of the container's padding box) in the others.
+            if !containing_block.is_own {
+                let inset = child_style.inset();
+                let edges = Rect {
+                    left: containing_block.insets.left,
+                    right: container_border_box.width - containing_block.insets.right,
+                    top: containing_block.insets.top,
+                    bottom: container_border_box.height - containing_block.insets.bottom,
+                };
+                let (left, right) = match inset.left.is_auto() && inset.right.is_auto() {
+                    true => (border.left, container_border_box.width - border.right),
+                    false => (edges.left, edges.right),
+                };
+                let (top, bottom) = match inset.top.is_auto() && inset.bottom.is_auto() {
+                    true => (border.top, container_border_box.height - border.bottom),
+                    false => (edges.top, edges.bottom),
+                };
+                grid_area = Rect { left, right, top, bottom };
+            }
             drop(child_style);
 
-            // TODO: Baseline alignment support for absolutely positioned items (should check if is actuallty specified)
+            // The shared baselines of the tracks in which the grid area starts, measured from the start of the area
+            let shared_baseline = |tracks: &[GridTrack], start_line_index: Option<usize>| {
+                let start_line_index = start_line_index?;
+                Some(tracks[start_line_index].base_size + tracks.get(start_line_index + 1)?.baseline?)
+            };
+            let shared_baselines = Point {
+                x: shared_baseline(&columns, maybe_col_indexes.start),
+                y: shared_baseline(&rows, maybe_row_indexes.start),
+            };
+
             #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
-            let (content_size_contribution, _, _) =
-                align_and_position_item(tree, child, order, grid_area, container_alignment_styles, 0.0);
+            let (content_size_contribution, _, _) = align_and_position_item(
+                tree,
+                child,
+                order,
+                grid_area,
+                container_alignment_styles,
+                container_alignment_safety,
+                direction,
+                Point::ZERO,
+                shared_baselines,
+                &mut static_children,
+            );
+            // Only the containing block's content size includes the child
             #[cfg(feature = "content_size")]
-            {
+            if containing_block.is_own {
                 item_content_size_contribution = item_content_size_contribution.f32_max(content_size_contribution);
             }
 
@@ -593,6 +893,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
             rows: DetailedGridTracksInfo::from_grid_tracks_and_track_count(final_row_counts, rows),
             columns: DetailedGridTracksInfo::from_grid_tracks_and_track_count(final_col_counts, columns),
             items: items.iter().map(DetailedGridItemsInfo::from_grid_item).collect(),
+            masonry_running_positions,
         },
     );
 
@@ -601,34 +902,41 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         return LayoutOutput::from_outer_size(container_border_box);
     }
 
-    // Determine the grid container baseline(s) (currently we only compute the first baseline)
-    let grid_container_baseline: f32 = {
-        // Sort items by row start position so that we can iterate items in groups which are in the same row
-        items.sort_by_key(|item| item.row_indexes.start);
-
-        // Get the row index of the first row containing items
-        let first_row = items[0].row_indexes.start;
-
-        // Create a slice of all of the items start in this row (taking advantage of the fact that we have just sorted the array)
-        let first_row_items = &items[0..].split(|item| item.row_indexes.start != first_row).next().unwrap();
+    // Determine the grid container baseline(s) (currently we only compute the first baselines)
+    let mut grid_container_baseline = |axis: AbstractAxis| {
+        // Sort items by start position so that we can iterate items in groups which are in the same track
+        items.sort_by_key(|item| item.placement_indexes(axis).start);
 
-        // Check if any items in *this row* are baseline aligned
-        let row_has_baseline_item = first_row_items.iter().any(|item| item.align_self == AlignSelf::Baseline);
+        // Get the index of the first track containing items
+        let first_track = items[0].placement_indexes(axis).start;
 
-        let item = if row_has_baseline_item {
-            first_row_items.iter().find(|item| item.align_self == AlignSelf::Baseline).unwrap()
-        } else {
-            &first_row_items[0]
-        };
+        // Create a slice of all of the items start in this track (taking advantage of the fact that we have just sorted the array)
+        let first_track_items =
+            &items[0..].split(|item| item.placement_indexes(axis).start != first_track).next().unwrap();
 
-        item.y_position + item.baseline.unwrap_or(item.height)
+        // Use the first baseline-aligned item in *this track* if there is one
+        let item = first_track_items
+            .iter()
+            .find(|item| item.self_alignment(axis) == AlignSelf::Baseline)
+            .unwrap_or(&first_track_items[0]);
+
+        // Item baselines in the inline axis are measured from the inline-start edge
+        match (axis, direction) {
+            (AbstractAxis::Block, _) => item.position.y + item.baseline.y.unwrap_or(item.final_size.height),
+            (AbstractAxis::Inline, Direction::Ltr) => {
+                item.position.x + item.baseline.x.unwrap_or(item.final_size.width)
+            }
+            (AbstractAxis::Inline, Direction::Rtl) => {
+                item.position.x + item.final_size.width - item.baseline.x.unwrap_or(item.final_size.width)
+            }
+        }
+    };
+    let first_baselines = Point {
+        x: Some(grid_container_baseline(AbstractAxis::Inline)),
+        y: Some(grid_container_baseline(AbstractAxis::Block)),
     };
 
-    LayoutOutput::from_sizes_and_baselines(
-        container_border_box,
-        item_content_size_contribution,
-        Point { x: None, y: Some(grid_container_baseline) },
-    )
+    LayoutOutput::from_sizes_and_baselines(container_border_box, item_content_size_contribution, first_baselines)
 }
 
 /// Information from the computation of grid
@@ -641,6 +949,11 @@ pub struct DetailedGridInfo {
     pub columns: DetailedGridTracksInfo,
     /// <https://drafts.csswg.org/css-grid-1/#grid-items>
     pub items: Vec<DetailedGridItemsInfo>,
+    /// For a masonry grid, the running position of each track of the grid axis once all of the items have been packed:
+    /// the distance from the start of the masonry axis' track to the end of the last item in the track (before
+    /// align-tracks/justify-tracks alignment). `None` for other grids.
+    /// <https://drafts.csswg.org/css-grid-3/#masonry-layout-algorithm>
+    pub masonry_running_positions: Option<Vec<f32>>,
 }
 
 /// Information from the computation of grids tracks
@@ -1,13 +1,15 @@
 //! Implements placing items in the grid and resolving the implicit grid.
 //! <https://www.w3.org/TR/css-grid-1/#placement>
+use super::explicit_grid::GridLineNames;
 use super::types::{CellOccupancyMatrix, CellOccupancyState, GridItem};
 use super::OriginZeroLine;
 use crate::geometry::Line;
 use crate::geometry::{AbsoluteAxis, InBothAbsAxis};
-use crate::style::{AlignItems, GridAutoFlow, OriginZeroGridPlacement};
+use crate::style::{AlignItems, CheapCloneStr, GridAutoFlow, GridPlacement, OriginZeroGridPlacement};
 use crate::tree::NodeId;
 use crate::util::sys::Vec;
 use crate::GridItemStyle;
+use core::cmp::{max, min};
 
 /// 8.5. Grid Item Placement Algorithm
 /// Place items into the grid, generating new rows/column into the implicit grid as required
@@ -17,6 +19,7 @@ pub(super) fn place_grid_items<'a, S, ChildIter>(
     cell_occupancy_matrix: &mut CellOccupancyMatrix,
     items: &mut Vec<GridItem>,
     children_iter: impl Fn() -> ChildIter,
+    line_names: &InBothAbsAxis<GridLineNames<S::CustomIdent>>,
     grid_auto_flow: GridAutoFlow,
     align_items: AlignItems,
     justify_items: AlignItems,
@@ -27,18 +30,12 @@ pub(super) fn place_grid_items<'a, S, ChildIter>(
     let primary_axis = grid_auto_flow.primary_axis();
     let secondary_axis = primary_axis.other_axis();
 
-    let map_child_style_to_origin_zero_placement = {
-        let explicit_col_count = cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal).explicit;
-        let explicit_row_count = cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical).explicit;
-        move |(index, node, style): (usize, NodeId, S)| -> (_, _, _, S) {
-            let origin_zero_placement = InBothAbsAxis {
-                horizontal: style
-                    .grid_column()
-                    .map(|placement| placement.into_origin_zero_placement(explicit_col_count)),
-                vertical: style.grid_row().map(|placement| placement.into_origin_zero_placement(explicit_row_count)),
-            };
-            (index, node, origin_zero_placement, style)
-        }
+    let map_child_style_to_origin_zero_placement = move |(index, node, style): (usize, NodeId, S)| -> (_, _, _, S) {
+        let origin_zero_placement = InBothAbsAxis {
+            horizontal: resolve_placement_in_axis(&style.grid_column(), &line_names.horizontal),
+            vertical: resolve_placement_in_axis(&style.grid_row(), &line_names.vertical),
+        };
+        (index, node, origin_zero_placement, style)
     };
 
     // 1. Place children with definite positions
@@ -69,13 +66,13 @@ pub(super) fn place_grid_items<'a, S, ChildIter>(
 
     // 2. Place remaining children with definite secondary axis positions
     let mut idx = 0;
-    children_iter()
-        .filter(|(_, _, child_style)| {
+    let definite_secondary_axis_children =
+        order_modified_document_order(children_iter().filter(|(_, _, child_style)| {
             child_style.grid_placement(secondary_axis).is_definite()
                 && !child_style.grid_placement(primary_axis).is_definite()
-        })
-        .map(map_child_style_to_origin_zero_placement)
-        .for_each(|(index, child_node, child_placement, style)| {
+        }));
+    definite_secondary_axis_children.into_iter().map(map_child_style_to_origin_zero_placement).for_each(
+        |(index, child_node, child_placement, style)| {
             idx += 1;
             #[cfg(test)]
             println!("Definite Secondary Item {idx}\n==============");
@@ -96,7 +93,8 @@ pub(super) fn place_grid_items<'a, S, ChildIter>(
                 secondary_span,
                 CellOccupancyState::AutoPlaced,
             );
-        });
+        },
+    );
 
     // 3. Determine the number of columns in the implicit grid
     // By the time we get to this point in the execution, this is actually already accounted for:
@@ -124,10 +122,11 @@ pub(super) fn place_grid_items<'a, S, ChildIter>(
     let grid_start_position = (OriginZeroLine(-primary_neg_tracks), OriginZeroLine(-secondary_neg_tracks));
     let mut grid_position = grid_start_position;
     let mut idx = 0;
-    children_iter()
-        .filter(|(_, _, child_style)| !child_style.grid_placement(secondary_axis).is_definite())
-        .map(map_child_style_to_origin_zero_placement)
-        .for_each(|(index, child_node, child_placement, style)| {
+    let remaining_children = order_modified_document_order(
+        children_iter().filter(|(_, _, child_style)| !child_style.grid_placement(secondary_axis).is_definite()),
+    );
+    remaining_children.into_iter().map(map_child_style_to_origin_zero_placement).for_each(
+        |(index, child_node, child_placement, style)| {
             idx += 1;
             #[cfg(test)]
             println!("\nAuto Item {idx}\n==============");
@@ -161,7 +160,116 @@ pub(super) fn place_grid_items<'a, S, ChildIter>(
                 true => grid_start_position,
                 false => (primary_span.end, secondary_span.start),
             }
-        });
+        },
+    );
+}
+
+/// 8.3. Line-based Placement
+/// Resolve an item's placement in a single axis into origin-zero coordinates, resolving named lines, named spans
+/// and named areas against the names of the grid's lines.
+///
+/// [Specification](https://www.w3.org/TR/css-grid-2/#line-placement)
+pub(super) fn resolve_placement_in_axis<S: CheapCloneStr>(
+    placement: &Line<GridPlacement<S>>,
+    line_names: &GridLineNames<S>,
+) -> Line<OriginZeroGridPlacement> {
+    let mut start = resolve_grid_placement(&placement.start, "-start", line_names);
+    let mut end = resolve_grid_placement(&placement.end, "-end", line_names);
+
+    // Named spans are counted from the line on the item's other edge. If the other edge is not a definite line then
+    // the named span is left as a span of 1 (8.3.1 rule D), and if both edges are spans then the end span will be
+    // ignored (8.3.1 rule C).
+    if let (GridPlacement::NamedSpan(name, span), OriginZeroGridPlacement::Line(end_line)) = (&placement.start, end) {
+        start = OriginZeroGridPlacement::Line(find_named_span_line(line_names, name.as_ref(), *span, end_line, false));
+    }
+    if let (OriginZeroGridPlacement::Line(start_line), GridPlacement::NamedSpan(name, span)) = (start, &placement.end) {
+        end = OriginZeroGridPlacement::Line(find_named_span_line(line_names, name.as_ref(), *span, start_line, true));
+    }
+
+    Line { start, end }
+}
+
+/// Resolve a single grid placement into origin-zero coordinates. `area_suffix` is the suffix of the implicit line
+/// name that named areas resolve to on this edge of the item. Named spans are resolved to a span of 1.
+fn resolve_grid_placement<S: CheapCloneStr>(
+    placement: &GridPlacement<S>,
+    area_suffix: &str,
+    line_names: &GridLineNames<S>,
+) -> OriginZeroGridPlacement {
+    match placement {
+        GridPlacement::Auto => OriginZeroGridPlacement::Auto,
+        GridPlacement::Span(span) => OriginZeroGridPlacement::Span(*span),
+        // Grid line zero is an invalid index, so it gets treated as Auto
+        // See: https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row-start#values
+        GridPlacement::Line(line) => match line.as_i16() {
+            0 => OriginZeroGridPlacement::Auto,
+            _ => OriginZeroGridPlacement::Line(line.into_origin_zero_line(line_names.explicit_track_count)),
+        },
+        GridPlacement::NamedLine(_, 0) => OriginZeroGridPlacement::Auto,
+        GridPlacement::NamedLine(name, index) => {
+            OriginZeroGridPlacement::Line(find_nth_named_line(line_names, name.as_ref(), *index))
+        }
+        // A named area resolves to the first line named `<name>-start` or `<name>-end`, or if there is no such line
+        // then it is treated as if it were the first line named `<name>`
+        GridPlacement::NamedArea(name) => OriginZeroGridPlacement::Line(
+            line_names
+                .lines_named(name.as_ref(), area_suffix)
+                .next()
+                .unwrap_or_else(|| find_nth_named_line(line_names, name.as_ref(), 1)),
+        ),
+        GridPlacement::NamedSpan(_, _) => OriginZeroGridPlacement::Span(1),
+    }
+}
+
+/// Find the nth line with the specified name, counting backwards from the end of the explicit grid if n is negative.
+/// If there are fewer than n lines with the name then all implicit lines are assumed to have the name.
+fn find_nth_named_line(line_names: &GridLineNames<impl CheapCloneStr>, name: &str, n: i16) -> OriginZeroLine {
+    let named_line_count = line_names.lines_named(name, "").count() as i16;
+    let explicit_end_line = line_names.explicit_track_count as i16;
+    match n {
+        n if n > 0 && n <= named_line_count => line_names.lines_named(name, "").nth(n as usize - 1).unwrap(),
+        n if n > 0 => OriginZeroLine(explicit_end_line + (n - named_line_count)),
+        n if -n <= named_line_count => line_names.lines_named(name, "").rev().nth((-n) as usize - 1).unwrap(),
+        n => OriginZeroLine(n + named_line_count),
+    }
+}
+
+/// Find the line that a span to the nth line with the specified name reaches from the line `from`, searching endward
+/// if `endward` is true and startward otherwise. If there are fewer than n lines with the name in the search direction
+/// then all implicit lines on that side of the explicit grid are assumed to have the name.
+fn find_named_span_line(
+    line_names: &GridLineNames<impl CheapCloneStr>,
+    name: &str,
+    span: u16,
+    from: OriginZeroLine,
+    endward: bool,
+) -> OriginZeroLine {
+    let span = max(span, 1) as usize;
+    if endward {
+        let named_lines = || line_names.lines_named(name, "").filter(move |line| *line > from);
+        named_lines().nth(span - 1).unwrap_or_else(|| {
+            let implicit_span = (span - named_lines().count()) as i16;
+            OriginZeroLine(max(from.0, line_names.explicit_track_count as i16) + implicit_span)
+        })
+    } else {
+        let named_lines = || line_names.lines_named(name, "").rev().filter(move |line| *line < from);
+        named_lines().nth(span - 1).unwrap_or_else(|| {
+            let implicit_span = (span - named_lines().count()) as i16;
+            OriginZeroLine(min(from.0, 0) - implicit_span)
+        })
+    }
+}
+
+/// Collect grid items into "order-modified document order" which is the order in which they are auto-placed.
+/// The sort is stable so that items with an equal `order` retain their document order.
+///
+/// [Specification](https://www.w3.org/TR/css-display-3/#order-modified-document-order)
+pub(super) fn order_modified_document_order<S: GridItemStyle>(
+    children: impl Iterator<Item = (usize, NodeId, S)>,
+) -> Vec<(usize, NodeId, S)> {
+    let mut children: Vec<_> = children.collect();
+    children.sort_by_key(|(_, _, style)| style.order());
+    children
 }
 
 /// 8.5. Grid Item Placement Algorithm
@@ -349,10 +457,12 @@ fn record_grid_placement<S: GridItemStyle>(
 mod tests {
 
     mod test_placement_algorithm {
+        use crate::compute::grid::explicit_grid::GridLineNames;
         use crate::compute::grid::implicit_grid::compute_grid_size_estimate;
         use crate::compute::grid::types::TrackCounts;
         use crate::compute::grid::util::*;
         use crate::compute::grid::CellOccupancyMatrix;
+        use crate::compute::grid::InBothAbsAxis;
         use crate::prelude::*;
         use crate::style::GridAutoFlow;
 
@@ -371,7 +481,11 @@ mod tests {
             // Setup test
             let children_iter = || children.iter().map(|(index, style, _)| (*index, NodeId::from(*index), style));
             let child_styles_iter = children.iter().map(|(_, style, _)| style);
-            let estimated_sizes = compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
+            let line_names = InBothAbsAxis {
+                horizontal: GridLineNames::new(explicit_col_count),
+                vertical: GridLineNames::new(explicit_row_count),
+            };
+            let estimated_sizes = compute_grid_size_estimate(&line_names, child_styles_iter);
             let mut items = Vec::new();
             let mut cell_occupancy_matrix =
                 CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1);
@@ -381,6 +495,7 @@ mod tests {
                 &mut cell_occupancy_matrix,
                 &mut items,
                 children_iter,
+                &line_names,
                 flow,
                 AlignSelf::Start,
                 AlignSelf::Start,
@@ -389,6 +504,7 @@ mod tests {
             // Assert that each item has been placed in the right location
             let mut sorted_children = children.clone();
             sorted_children.sort_by_key(|child| child.0);
+            items.sort_by_key(|item| item.source_order);
             for (idx, ((id, _style, expected_placement), item)) in sorted_children.iter().zip(items.iter()).enumerate()
             {
                 assert_eq!(item.node, NodeId::from(*id));
@@ -578,6 +694,26 @@ mod tests {
             placement_test_runner(explicit_col_count, explicit_row_count, children, expected_cols, expected_rows, flow);
         }
 
+        #[test]
+        fn test_order_affects_auto_placement() {
+            let flow = GridAutoFlow::Row;
+            let explicit_col_count = 2;
+            let explicit_row_count = 2;
+            let children = {
+                let with_order = |order: i32| Style { order, ..(auto(), auto(), auto(), auto()).into_grid_child() };
+                vec![
+                    // output order, node, style (grid coords), expected_placement (oz coords)
+                    (1, with_order(1), (1, 2, 1, 2)), // Placed last as it has the highest order
+                    (2, with_order(0), (1, 2, 0, 1)), // Placed before item 4 as it has the same order but comes earlier in the document
+                    (3, with_order(-1), (0, 1, 0, 1)), // Placed first as it has the lowest order
+                    (4, with_order(0), (0, 1, 1, 2)), // Placed third
+                ]
+            };
+            let expected_cols = TrackCounts { negative_implicit: 0, explicit: 2, positive_implicit: 0 };
+            let expected_rows = TrackCounts { negative_implicit: 0, explicit: 2, positive_implicit: 0 };
+            placement_test_runner(explicit_col_count, explicit_row_count, children, expected_cols, expected_rows, flow);
+        }
+
         #[test]
         fn test_auto_placement_in_negative_tracks() {
             let flow = GridAutoFlow::RowDense;
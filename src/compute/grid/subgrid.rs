@@ -0,0 +1,263 @@
+//! Helper functions for subgrids: grids which adopt the tracks of their parent grid in one or both axes
+//! <https://www.w3.org/TR/css-grid-2/#subgrids>
+use core::borrow::Borrow;
+
+use super::explicit_grid::{compute_explicit_grid_size_in_axis, compute_grid_line_names_in_axis};
+use super::implicit_grid::compute_grid_size_estimate;
+use super::placement::place_grid_items;
+use super::types::{CellOccupancyMatrix, GridItem, GridTrack};
+use super::OriginZeroLine;
+use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis, Line, Rect, Size};
+use crate::style::{AlignItems, BoxGenerationMode, Direction, TrackSizingFunction};
+use crate::style_helpers::length;
+use crate::tree::{LayoutPartialTreeExt, NodeId};
+use crate::util::sys::{f32_max, GridTrackVec, Vec};
+use crate::util::ResolveOrZero;
+use crate::{CoreStyle, GridContainerStyle, GridItemStyle, LayoutGridContainer};
+
+/// The tracks that a subgrid adopts from its parent grid in a single axis
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubgridTracks {
+    /// The sizes of the parent grid's tracks that are spanned by the subgrid. The first and last tracks are reduced by
+    /// the subgrid's margin, border and padding at its edges.
+    pub sizes: Vec<f32>,
+    /// The size of the parent grid's gutters between those tracks
+    pub gap: f32,
+}
+
+/// Get the tracks that a grid adopts from its parent grid in an axis, if the grid is a subgrid in that axis
+pub(super) fn adopted_tracks(
+    tree: &impl LayoutGridContainer,
+    node: NodeId,
+    template: &[TrackSizingFunction],
+    axis: AbstractAxis,
+) -> Option<SubgridTracks> {
+    if !TrackSizingFunction::is_subgrid_template(template) {
+        return None;
+    }
+    tree.get_subgrid_tracks(node, axis).filter(|tracks| !tracks.sizes.is_empty()).cloned()
+}
+
+/// The track template of a subgrid in an axis in which it adopts its parent's tracks: a fixed-size track for each
+/// adopted track
+pub(super) fn subgrid_template(track_sizes: impl Iterator<Item = f32>) -> GridTrackVec<TrackSizingFunction> {
+    track_sizes.map(length).collect()
+}
+
+/// Clamp an item's placement into the explicit grid of a subgrid. A subgrid has no implicit tracks in the axes in
+/// which it adopts its parent's tracks.
+pub(super) fn clamp_to_subgrid(placement: Line<OriginZeroLine>, track_count: u16) -> Line<OriginZeroLine> {
+    let last_line = track_count as i16;
+    let start = placement.start.0.clamp(0, last_line - 1);
+    let end = placement.end.0.clamp(start + 1, last_line);
+    Line { start: OriginZeroLine(start), end: OriginZeroLine(end) }
+}
+
+/// Whether a grid item is a subgrid which adopts the tracks of a grid with the given orientation in an axis
+///
+/// Subgrids whose writing mode is orthogonal to their parent's are not supported, and are laid out as if their
+/// template in the subgridded axis were empty.
+fn is_subgrid_of(style: &impl GridItemStyle, axis: AbsoluteAxis, parent_is_vertical: bool) -> bool {
+    style.is_subgrid(axis) && style.writing_mode().is_vertical() == parent_is_vertical
+}
+
+/// The margin, border and padding of a subgrid, which are applied as extra margin to the subgrid's items at the
+/// corresponding edges of the subgrid <https://www.w3.org/TR/css-grid-2/#subgrid-item-contribution>
+fn subgrid_edges(tree: &impl LayoutGridContainer, subgrid: NodeId, percentage_basis: Option<f32>) -> Rect<f32> {
+    let style = tree.get_grid_child_style(subgrid);
+    let margin = style.margin().resolve_or_zero(percentage_basis, |val, basis| tree.calc(subgrid, val, basis));
+    let border = style.border().resolve_or_zero(percentage_basis, |val, basis| tree.calc(subgrid, val, basis));
+    let padding = style.padding().resolve_or_zero(percentage_basis, |val, basis| tree.calc(subgrid, val, basis));
+    margin + border + padding
+}
+
+/// Find the subgrids amongst a grid's `items` and add the items of each subgrid (including those of any subgrids
+/// nested within it) to `items`, so that they contribute to the sizing of the tracks that their subgrid adopts.
+/// The subgrids themselves no longer contribute to the sizing of the tracks that they adopt.
+///
+/// The percentage margins, borders and padding of the subgrids are resolved against `percentage_basis`.
+pub(super) fn add_subgrid_items(
+    tree: &impl LayoutGridContainer,
+    items: &mut Vec<GridItem>,
+    is_vertical: bool,
+    direction: Direction,
+    percentage_basis: Option<f32>,
+) {
+    for index in 0..items.len() {
+        let item = &mut items[index];
+        let style = tree.get_grid_child_style(item.node);
+        let adopted = InBothAbsAxis {
+            horizontal: is_subgrid_of(&style, AbsoluteAxis::Horizontal, is_vertical),
+            vertical: is_subgrid_of(&style, AbsoluteAxis::Vertical, is_vertical),
+        };
+        drop(style);
+
+        if adopted.horizontal || adopted.vertical {
+            item.contributes_to_column_sizing = !adopted.horizontal;
+            item.contributes_to_row_sizing = !adopted.vertical;
+            let (node, area) = (item.node, InBothAbsAxis { horizontal: item.column, vertical: item.row });
+            let edges = subgrid_edges(tree, node, percentage_basis);
+            add_items_of_subgrid(tree, items, node, area, edges, adopted, is_vertical, direction, percentage_basis);
+        }
+    }
+}
+
+/// Place the in-flow children of `subgrid` (which occupies `area` of the grid) and add them to `items`
+///
+/// In the axes in which the subgrid adopts the grid's tracks the children are placed into the tracks that the subgrid
+/// spans. In any other axis they are considered to span the whole of the subgrid's area, and do not contribute to
+/// the sizing of the grid's tracks. The children at each edge of the subgrid are given the extra margin in `edges`.
+#[allow(clippy::too_many_arguments)]
+fn add_items_of_subgrid(
+    tree: &impl LayoutGridContainer,
+    items: &mut Vec<GridItem>,
+    subgrid: NodeId,
+    area: InBothAbsAxis<Line<OriginZeroLine>>,
+    edges: Rect<f32>,
+    adopted: InBothAbsAxis<bool>,
+    is_vertical: bool,
+    direction: Direction,
+    percentage_basis: Option<f32>,
+) {
+    let style = tree.get_grid_container_style(subgrid);
+
+    // Resolve the subgrid's explicit grid. In each adopted axis this has a track for each of the tracks it spans.
+    let line_names_in_axis = |template: &[TrackSizingFunction], axis: AbsoluteAxis| {
+        let template_track_count = compute_explicit_grid_size_in_axis(
+            &style,
+            template,
+            Size::NONE,
+            |val, basis| tree.calc(subgrid, val, basis),
+            axis,
+        );
+        compute_grid_line_names_in_axis(&style, template, template_track_count, axis)
+    };
+    let adopted_template = |axis: AbsoluteAxis| subgrid_template((0..area.get(axis).span()).map(|_| 0.0));
+    let line_names = InBothAbsAxis {
+        horizontal: match adopted.horizontal {
+            true => line_names_in_axis(&adopted_template(AbsoluteAxis::Horizontal), AbsoluteAxis::Horizontal),
+            false => line_names_in_axis(style.grid_template_columns().borrow(), AbsoluteAxis::Horizontal),
+        },
+        vertical: match adopted.vertical {
+            true => line_names_in_axis(&adopted_template(AbsoluteAxis::Vertical), AbsoluteAxis::Vertical),
+            false => line_names_in_axis(style.grid_template_rows().borrow(), AbsoluteAxis::Vertical),
+        },
+    };
+
+    // Place the subgrid's children
+    let in_flow_children_iter = || {
+        tree.child_ids(subgrid)
+            .enumerate()
+            .map(|(index, child_node)| (index, child_node, tree.get_grid_child_style(child_node)))
+            .filter(|(_, _, style)| {
+                style.box_generation_mode() != BoxGenerationMode::None && !style.position().is_absolutely_positioned()
+            })
+    };
+    let (est_col_counts, est_row_counts) =
+        compute_grid_size_estimate(&line_names, in_flow_children_iter().map(|(_, _, style)| style));
+    let mut cell_occupancy_matrix = CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts);
+    let first_subgrid_item = items.len();
+    place_grid_items(
+        &mut cell_occupancy_matrix,
+        items,
+        in_flow_children_iter,
+        &line_names,
+        style.grid_auto_flow(),
+        style.align_items().unwrap_or(AlignItems::Stretch),
+        style.justify_items().unwrap_or(AlignItems::Stretch),
+    );
+    drop(style);
+
+    // Convert the placements of the subgrid's children into the grid's coordinates, and recurse into nested subgrids
+    let into_grid_placement = |placement: Line<OriginZeroLine>, axis: AbsoluteAxis| {
+        let subgrid_area = area.get(axis);
+        match adopted.get(axis) {
+            true => {
+                let placement = clamp_to_subgrid(placement, subgrid_area.span());
+                Line { start: subgrid_area.start + placement.start, end: subgrid_area.start + placement.end }
+            }
+            false => subgrid_area,
+        }
+    };
+    for index in first_subgrid_item..items.len() {
+        let item = &mut items[index];
+        item.is_subgrid_item = true;
+        item.column = into_grid_placement(item.column, AbsoluteAxis::Horizontal);
+        item.row = into_grid_placement(item.row, AbsoluteAxis::Vertical);
+
+        // The column-start edge is the right edge in a right-to-left grid
+        let at_column_start = item.column.start == area.horizontal.start;
+        let at_column_end = item.column.end == area.horizontal.end;
+        let (at_left, at_right) = match direction {
+            Direction::Ltr => (at_column_start, at_column_end),
+            Direction::Rtl => (at_column_end, at_column_start),
+        };
+        let edge_margin = |is_at_edge: bool, edge: f32| if is_at_edge { edge } else { 0.0 };
+        item.subgrid_edge_margin = Rect {
+            left: edge_margin(at_left, edges.left),
+            right: edge_margin(at_right, edges.right),
+            top: edge_margin(item.row.start == area.vertical.start, edges.top),
+            bottom: edge_margin(item.row.end == area.vertical.end, edges.bottom),
+        };
+
+        let style = tree.get_grid_child_style(item.node);
+        let nested_adopted = InBothAbsAxis {
+            horizontal: adopted.horizontal && is_subgrid_of(&style, AbsoluteAxis::Horizontal, is_vertical),
+            vertical: adopted.vertical && is_subgrid_of(&style, AbsoluteAxis::Vertical, is_vertical),
+        };
+        drop(style);
+
+        item.contributes_to_column_sizing = adopted.horizontal && !nested_adopted.horizontal;
+        item.contributes_to_row_sizing = adopted.vertical && !nested_adopted.vertical;
+        if nested_adopted.horizontal || nested_adopted.vertical {
+            let (node, nested_area) = (item.node, InBothAbsAxis { horizontal: item.column, vertical: item.row });
+            let nested_edges = item.subgrid_edge_margin + subgrid_edges(tree, node, percentage_basis);
+            add_items_of_subgrid(
+                tree,
+                items,
+                node,
+                nested_area,
+                nested_edges,
+                nested_adopted,
+                is_vertical,
+                direction,
+                percentage_basis,
+            );
+        }
+    }
+}
+
+/// Pass the sizes of the tracks in `axis` that are spanned by each of the grid's subgrids to that subgrid, so that it
+/// can adopt them
+///
+/// The tracks at the edges of a subgrid are reduced by its margin, border and padding at those edges (resolved against
+/// `percentage_basis`), so that the subgrid's content box is spanned by its tracks and they line up with the grid's.
+pub(super) fn set_subgrid_tracks(
+    tree: &mut impl LayoutGridContainer,
+    items: &[GridItem],
+    axis: AbstractAxis,
+    tracks: &[GridTrack],
+    direction: Direction,
+    percentage_basis: Option<f32>,
+) {
+    for item in items.iter().filter(|item| !item.is_subgrid_item && !item.contributes_to_track_sizing(axis)) {
+        // The spanned range alternates between tracks and the gutters between them
+        let spanned_tracks = &tracks[item.track_range_excluding_lines(axis)];
+        let mut sizes: Vec<f32> = spanned_tracks.iter().step_by(2).map(|track| track.base_size).collect();
+        let gap = spanned_tracks.get(1).map(|gutter| gutter.base_size).unwrap_or(0.0);
+
+        let edges = subgrid_edges(tree, item.node, percentage_basis);
+        let (start_edge, end_edge) = match (axis, direction) {
+            (AbstractAxis::Block, _) => (edges.top, edges.bottom),
+            (AbstractAxis::Inline, Direction::Ltr) => (edges.left, edges.right),
+            (AbstractAxis::Inline, Direction::Rtl) => (edges.right, edges.left),
+        };
+        if let Some(first) = sizes.first_mut() {
+            *first = f32_max(*first - start_edge, 0.0);
+        }
+        if let Some(last) = sizes.last_mut() {
+            *last = f32_max(*last - end_edge, 0.0);
+        }
+        tree.set_subgrid_tracks(item.node, axis, SubgridTracks { sizes, gap });
+    }
+}
@@ -2,9 +2,9 @@
 //! <https://www.w3.org/TR/css-grid-1/#layout-algorithm>
 use super::types::{GridItem, GridTrack, TrackCounts};
 use crate::geometry::{AbstractAxis, Line, Size};
-use crate::style::{AlignContent, AlignSelf, AvailableSpace};
+use crate::style::{AlignContent, AlignSelf, AvailableSpace, Direction};
 use crate::style_helpers::TaffyMinContent;
-use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, SizingMode};
+use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId, SizingMode};
 use crate::util::sys::{f32_max, f32_min, Vec};
 use crate::util::{MaybeMath, ResolveOrZero};
 use crate::CompactLength;
@@ -67,10 +67,12 @@ impl ItemBatcher {
 struct IntrisicSizeMeasurer<'tree, 'oat, Tree, EstimateFunction>
 where
     Tree: LayoutPartialTree,
-    EstimateFunction: Fn(&GridTrack, Option<f32>, &Tree) -> Option<f32>,
+    EstimateFunction: Fn(&GridTrack, Option<f32>, &Tree, NodeId) -> Option<f32>,
 {
     /// The layout tree
     tree: &'tree mut Tree,
+    /// The grid container node, whose styles define the track sizing functions
+    node: NodeId,
     /// The tracks in the opposite axis to the one we are currently sizing
     other_axis_tracks: &'oat [GridTrack],
     /// A function that computes an estimate of an other-axis track's size which is passed to
@@ -85,7 +87,7 @@ where
 impl<Tree, EstimateFunction> IntrisicSizeMeasurer<'_, '_, Tree, EstimateFunction>
 where
     Tree: LayoutPartialTree,
-    EstimateFunction: Fn(&GridTrack, Option<f32>, &Tree) -> Option<f32>,
+    EstimateFunction: Fn(&GridTrack, Option<f32>, &Tree, NodeId) -> Option<f32>,
 {
     /// Compute the available_space to be passed to the child sizing functions
     /// These are estimates based on either the max track sizing function or the provisional base size in the opposite
@@ -97,7 +99,7 @@ where
             self.axis,
             self.other_axis_tracks,
             self.inner_node_size.get(self.axis.other()),
-            |track, basis| (self.get_track_size_estimate)(track, basis, self.tree),
+            |track, basis| (self.get_track_size_estimate)(track, basis, self.tree, self.node),
         )
     }
 
@@ -111,7 +113,7 @@ where
     /// Simple pass-through function to `LayoutPartialTreeExt::calc`
     #[inline(always)]
     fn calc(&self, val: u64, basis: f32) -> f32 {
-        self.tree.calc(val, basis)
+        self.tree.calc(self.node, val, basis)
     }
 
     /// Retrieve the item's min content contribution from the cache or compute it using the provided parameters
@@ -145,8 +147,14 @@ where
     fn minimum_contribution(&mut self, item: &mut GridItem, axis_tracks: &[GridTrack]) -> f32 {
         let available_space = self.available_space(item);
         let margin_axis_sums = self.margins_axis_sums_with_baseline_shims(item);
-        let contribution =
-            item.minimum_contribution_cached(self.tree, self.axis, axis_tracks, available_space, self.inner_node_size);
+        let contribution = item.minimum_contribution_cached(
+            self.tree,
+            self.node,
+            self.axis,
+            axis_tracks,
+            available_space,
+            self.inner_node_size,
+        );
         contribution + margin_axis_sums.get(self.axis)
     }
 }
@@ -252,14 +260,17 @@ pub(super) fn determine_if_item_crosses_flexible_or_intrinsic_tracks(
     rows: &[GridTrack],
 ) {
     for item in items {
-        item.crosses_flexible_column =
-            item.track_range_excluding_lines(AbstractAxis::Inline).any(|i| columns[i].is_flexible());
-        item.crosses_intrinsic_column =
-            item.track_range_excluding_lines(AbstractAxis::Inline).any(|i| columns[i].has_intrinsic_sizing_function());
-        item.crosses_flexible_row =
-            item.track_range_excluding_lines(AbstractAxis::Block).any(|i| rows[i].is_flexible());
-        item.crosses_intrinsic_row =
-            item.track_range_excluding_lines(AbstractAxis::Block).any(|i| rows[i].has_intrinsic_sizing_function());
+        // Items only need to be measured in an axis if they contribute to the sizing of its tracks
+        item.crosses_flexible_column = item.contributes_to_column_sizing
+            && item.track_range_excluding_lines(AbstractAxis::Inline).any(|i| columns[i].is_flexible());
+        item.crosses_intrinsic_column = item.contributes_to_column_sizing
+            && item
+                .track_range_excluding_lines(AbstractAxis::Inline)
+                .any(|i| columns[i].has_intrinsic_sizing_function());
+        item.crosses_flexible_row = item.contributes_to_row_sizing
+            && item.track_range_excluding_lines(AbstractAxis::Block).any(|i| rows[i].is_flexible());
+        item.crosses_intrinsic_row = item.contributes_to_row_sizing
+            && item.track_range_excluding_lines(AbstractAxis::Block).any(|i| rows[i].has_intrinsic_sizing_function());
     }
 }
 
@@ -268,6 +279,7 @@ pub(super) fn determine_if_item_crosses_flexible_or_intrinsic_tracks(
 #[allow(clippy::too_many_arguments)]
 pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
     tree: &mut Tree,
+    node: NodeId,
     axis: AbstractAxis,
     axis_min_size: Option<f32>,
     axis_max_size: Option<f32>,
@@ -278,16 +290,23 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
     axis_tracks: &mut [GridTrack],
     other_axis_tracks: &mut [GridTrack],
     items: &mut [GridItem],
-    get_track_size_estimate: fn(&GridTrack, Option<f32>, &Tree) -> Option<f32>,
+    get_track_size_estimate: fn(&GridTrack, Option<f32>, &Tree, NodeId) -> Option<f32>,
     has_baseline_aligned_item: bool,
+    direction: Direction,
 ) {
     // 11.4 Initialise Track sizes
     // Initialize each track’s base size and growth limit.
-    initialize_track_sizes(tree, axis_tracks, inner_node_size.get(axis));
+    initialize_track_sizes(tree, node, axis_tracks, inner_node_size.get(axis));
+
+    // Exclude items that do not contribute to the sizing of the tracks in this axis. These are subgrids which adopt
+    // these tracks, and the items of subgrids which only adopt tracks in the other axis.
+    items.sort_by_key(|item| !item.contributes_to_track_sizing(axis));
+    let contributing_item_count = items.iter().take_while(|item| item.contributes_to_track_sizing(axis)).count();
+    let items = &mut items[..contributing_item_count];
 
     // 11.5.1 Shim item baselines
     if has_baseline_aligned_item {
-        resolve_item_baselines(tree, axis, items, inner_node_size);
+        resolve_item_baselines(tree, axis, axis_tracks, items, inner_node_size, direction);
     }
 
     // If all tracks have base_size = growth_limit, then skip the rest of this function.
@@ -303,7 +322,7 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
     let gutter_alignment_adjustment = compute_alignment_gutter_adjustment(
         other_axis_alignment,
         inner_node_size.get(axis.other()),
-        |track, basis| get_track_size_estimate(track, basis, tree),
+        |track, basis| get_track_size_estimate(track, basis, tree, node),
         other_axis_tracks,
     );
     if other_axis_tracks.len() > 3 {
@@ -317,6 +336,7 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
     // 11.5 Resolve Intrinsic Track Sizes
     resolve_intrinsic_track_sizes(
         tree,
+        node,
         axis,
         axis_tracks,
         other_axis_tracks,
@@ -408,6 +428,7 @@ fn flush_planned_growth_limit_increases(tracks: &mut [GridTrack], set_infinitely
 #[inline(always)]
 fn initialize_track_sizes(
     tree: &impl LayoutPartialTree,
+    node: NodeId,
     axis_tracks: &mut [GridTrack],
     axis_inner_node_size: Option<f32>,
 ) {
@@ -420,7 +441,7 @@ fn initialize_track_sizes(
         //     Use an initial base size of zero.
         track.base_size = track
             .min_track_sizing_function
-            .definite_value(axis_inner_node_size, |val, basis| tree.calc(val, basis))
+            .definite_value(axis_inner_node_size, |val, basis| tree.calc(node, val, basis))
             .unwrap_or(0.0);
 
         // For each track, if the track’s max track sizing function is:
@@ -432,7 +453,7 @@ fn initialize_track_sizes(
         //     Use an initial growth limit of infinity.
         track.growth_limit = track
             .max_track_sizing_function
-            .definite_value(axis_inner_node_size, |val, basis| tree.calc(val, basis))
+            .definite_value(axis_inner_node_size, |val, basis| tree.calc(node, val, basis))
             .unwrap_or(f32::INFINITY);
 
         // In all cases, if the growth limit is less than the base size, increase the growth limit to match the base size.
@@ -443,51 +464,70 @@ fn initialize_track_sizes(
 }
 
 /// 11.5.1 Shim baseline-aligned items so their intrinsic size contributions reflect their baseline alignment.
-fn resolve_item_baselines(
-    tree: &mut impl LayoutPartialTree,
+///
+/// The items which start in the same track and are baseline-aligned in `axis` (by `justify_self` in the inline axis
+/// and by `align_self` in the block axis) form a baseline-sharing group. Each item in a group is shimmed so that its
+/// baseline lines up with the group's shared baseline, which is recorded on the track.
+fn resolve_item_baselines<Tree: LayoutPartialTree>(
+    tree: &mut Tree,
     axis: AbstractAxis,
+    axis_tracks: &mut [GridTrack],
     items: &mut [GridItem],
     inner_node_size: Size<Option<f32>>,
+    direction: Direction,
 ) {
-    // Sort items by track in the other axis (row) start position so that we can iterate items in groups which
-    // are in the same track in the other axis (row)
-    let other_axis = axis.other();
-    items.sort_by_key(|item| item.placement(other_axis).start);
+    axis_tracks.iter_mut().for_each(|track| track.baseline = None);
+
+    // Sort items by their start track so that we can iterate items in groups which start in the same track
+    items.sort_by_key(|item| item.placement(axis).start);
+
+    // Items of subgrids are excluded as they participate in their subgrid's baseline alignment instead
+    let is_baseline_aligned =
+        |item: &GridItem| item.self_alignment(axis) == AlignSelf::Baseline && !item.is_subgrid_item;
+
+    // The margin on the start edge of an item in the axis. Baselines are measured from the inline-start edge, which
+    // is the right edge in a right-to-left grid.
+    let margin_start = |item: &GridItem, tree: &Tree| {
+        let margin = match (axis, direction) {
+            (AbstractAxis::Block, _) => item.margin.top,
+            (AbstractAxis::Inline, Direction::Ltr) => item.margin.left,
+            (AbstractAxis::Inline, Direction::Rtl) => item.margin.right,
+        };
+        margin.resolve_or_zero(inner_node_size.width, |val, basis| tree.calc(item.node, val, basis))
+    };
 
-    // Iterate over grid rows
+    // Iterate over the tracks
     let mut remaining_items = &mut items[0..];
     while !remaining_items.is_empty() {
-        // Get the row index of the current row
-        let current_row = remaining_items[0].placement(other_axis).start;
+        // Get the line index of the start of the current track
+        let current_track = remaining_items[0].placement(axis).start;
 
-        // Find the item index of the first item that is in a different row (or None if we've reached the end of the list)
-        let next_row_first_item =
-            remaining_items.iter().position(|item| item.placement(other_axis).start != current_row);
+        // Find the item index of the first item that is in a different track (or None if we've reached the end of the list)
+        let next_track_first_item = remaining_items.iter().position(|item| item.placement(axis).start != current_track);
 
         // Use this index to split the `remaining_items` slice in two slices:
-        //    - A `row_items` slice containing the items (that start) in the current row
+        //    - A `track_items` slice containing the items (that start) in the current track
         //    - A new `remaining_items` consisting of the remainder of the `remaining_items` slice
-        //      that hasn't been split off into `row_items
-        let row_items = if let Some(index) = next_row_first_item {
-            let (row_items, tail) = remaining_items.split_at_mut(index);
+        //      that hasn't been split off into `track_items`
+        let track_items = if let Some(index) = next_track_first_item {
+            let (track_items, tail) = remaining_items.split_at_mut(index);
             remaining_items = tail;
-            row_items
+            track_items
         } else {
-            let row_items = remaining_items;
+            let track_items = remaining_items;
             remaining_items = &mut [];
-            row_items
+            track_items
         };
 
-        // Count how many items in *this row* are baseline aligned
-        // If a row has one or zero items participating in baseline alignment then baseline alignment is a no-op
-        // for those items and we skip further computations for that row
-        let row_baseline_item_count = row_items.iter().filter(|item| item.align_self == AlignSelf::Baseline).count();
-        if row_baseline_item_count <= 1 {
+        // Skip tracks without a baseline-sharing group. The baseline of a group consisting of a single item is still
+        // computed, as absolutely positioned items and the grid container's own baseline are aligned to it.
+        if !track_items.iter().any(is_baseline_aligned) {
             continue;
         }
 
-        // Compute the baselines of all items in the row
-        for item in row_items.iter_mut() {
+        // Compute the baselines of the items in the group
+        let mut shared_baseline: f32 = 0.0;
+        for item in track_items.iter_mut().filter(|item| is_baseline_aligned(item)) {
             let measured_size_and_baselines = tree.perform_child_layout(
                 item.node,
                 Size::NONE,
@@ -497,23 +537,25 @@ fn resolve_item_baselines(
                 Line::FALSE,
             );
 
-            let baseline = measured_size_and_baselines.first_baselines.y;
-            let height = measured_size_and_baselines.size.height;
-
-            item.baseline = Some(
-                baseline.unwrap_or(height)
-                    + item.margin.top.resolve_or_zero(inner_node_size.width, |val, basis| tree.calc(val, basis)),
-            );
+            let size = measured_size_and_baselines.size;
+            let first_baselines = measured_size_and_baselines.first_baselines;
+            let baseline = match (axis, direction) {
+                (AbstractAxis::Block, _) => first_baselines.y.unwrap_or(size.height),
+                (AbstractAxis::Inline, Direction::Ltr) => first_baselines.x.unwrap_or(size.width),
+                (AbstractAxis::Inline, Direction::Rtl) => size.width - first_baselines.x.unwrap_or(0.0),
+            };
+            item.baseline.set(axis, Some(baseline));
+            shared_baseline = f32_max(shared_baseline, baseline + margin_start(item, tree));
         }
 
-        // Compute the max baseline of all items in the row
-        let row_max_baseline =
-            row_items.iter().map(|item| item.baseline.unwrap_or(0.0)).max_by(|a, b| a.total_cmp(b)).unwrap();
-
-        // Compute the baseline shim for each item in the row
-        for item in row_items.iter_mut() {
-            item.baseline_shim = row_max_baseline - item.baseline.unwrap_or(0.0);
+        // Compute the baseline shim for each item in the group
+        for item in track_items.iter_mut().filter(|item| is_baseline_aligned(item)) {
+            let baseline = item.baseline.get(axis).unwrap_or(0.0) + margin_start(item, tree);
+            item.baseline_shim.set(axis, shared_baseline - baseline);
         }
+
+        let track_index = track_items[0].placement_indexes(axis).start as usize + 1;
+        axis_tracks[track_index].baseline = Some(shared_baseline);
     }
 }
 
@@ -521,13 +563,14 @@ fn resolve_item_baselines(
 #[allow(clippy::too_many_arguments)]
 fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
     tree: &mut Tree,
+    node: NodeId,
     axis: AbstractAxis,
     axis_tracks: &mut [GridTrack],
     other_axis_tracks: &[GridTrack],
     items: &mut [GridItem],
     axis_available_grid_space: AvailableSpace,
     inner_node_size: Size<Option<f32>>,
-    get_track_size_estimate: impl Fn(&GridTrack, Option<f32>, &Tree) -> Option<f32>,
+    get_track_size_estimate: impl Fn(&GridTrack, Option<f32>, &Tree, NodeId) -> Option<f32>,
 ) {
     // Step 1. Shim baseline-aligned items so their intrinsic size contributions reflect their baseline alignment.
 
@@ -554,7 +597,7 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
     let axis_inner_node_size = inner_node_size.get(axis);
     let flex_factor_sum = axis_tracks.iter().map(|track| track.flex_factor()).sum::<f32>();
     let mut item_sizer =
-        IntrisicSizeMeasurer { tree, other_axis_tracks, axis, inner_node_size, get_track_size_estimate };
+        IntrisicSizeMeasurer { tree, node, other_axis_tracks, axis, inner_node_size, get_track_size_estimate };
 
     let mut batched_item_iterator = ItemBatcher::new(axis);
     while let Some((batch, is_flex)) = batched_item_iterator.next(items) {
@@ -611,6 +654,7 @@ fn resolve_intrinsic_track_sizes<Tree: LayoutPartialTree>(
                         // Do nothing as it's not an intrinsic track sizing function
                         track.base_size
                     }
+                    _ if track.min_track_sizing_function.0.is_relative_length() => track.base_size,
                     // Handle calc() like percentage
                     _ if track.min_track_sizing_function.0.is_calc() => {
                         if axis_inner_node_size.is_none() {
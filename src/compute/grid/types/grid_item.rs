@@ -50,11 +50,11 @@ pub(in super::super) struct GridItem {
     pub align_self: AlignSelf,
     /// The item's justify_self property, or the parent's justify_items property is not set
     pub justify_self: AlignSelf,
-    /// The items first baseline (horizontal)
-    pub baseline: Option<f32>,
-    /// Shim for baseline alignment that acts like an extra top margin
-    /// TODO: Support last baseline and vertical text baselines
-    pub baseline_shim: f32,
+    /// The item's first baselines, measured from the inline-start (x) and top (y) edges of its border box
+    pub baseline: Point<Option<f32>>,
+    /// Shim for baseline alignment that acts like an extra inline-start (x) or top (y) margin
+    /// TODO: Support last baseline alignment
+    pub baseline_shim: Point<f32>,
 
     /// The item's definite row-start and row-end (same as `row` field, except in a different coordinate system)
     /// (as indexes into the Vec<GridTrack> stored in a grid's AbstractAxisTracks)
@@ -72,6 +72,26 @@ pub(in super::super) struct GridItem {
     /// Whether the item crosses a intrinsic column
     pub crosses_intrinsic_column: bool,
 
+    /// Whether the item contributes to the sizing of the rows it spans. This is false for subgrids which adopt
+    /// their parent's rows (as the subgrid's items contribute in their place), and for the items of subgrids
+    /// which do not.
+    pub contributes_to_row_sizing: bool,
+    /// Whether the item contributes to the sizing of the columns it spans. This is false for subgrids which adopt
+    /// their parent's columns (as the subgrid's items contribute in their place), and for the items of subgrids
+    /// which do not.
+    pub contributes_to_column_sizing: bool,
+    /// Whether the item is an item of a (possibly nested) subgrid rather than of the grid itself. Such items
+    /// contribute to the sizing of the grid's tracks, but are positioned by their subgrid.
+    pub is_subgrid_item: bool,
+    /// The extra margin of an item of a subgrid on each edge which is at an edge of its subgrid: the margin, border and
+    /// padding of the subgrid (and of any subgrids which contain it at that edge)
+    pub subgrid_edge_margin: Rect<f32>,
+    /// Whether the item of a masonry grid is auto-placed in the grid axis. Such items are placed into the tracks with
+    /// the smallest running position when the items are packed, rather than at their provisional placement.
+    pub is_masonry_auto_placed: bool,
+    /// The start and end offsets of the margin box of an item of a masonry grid within the grid's masonry track
+    pub masonry_position: Option<Line<f32>>,
+
     // Caches for intrinsic size computation. These caches are only valid for a single run of the track-sizing algorithm.
     /// Cache for the known_dimensions input to intrinsic sizing computation
     pub available_space_cache: Option<Size<Option<f32>>>,
@@ -82,10 +102,10 @@ pub(in super::super) struct GridItem {
     /// Cache for the max-content size
     pub max_content_contribution_cache: Size<Option<f32>>,
 
-    /// Final y position. Used to compute baseline alignment for the container.
-    pub y_position: f32,
-    /// Final height. Used to compute baseline alignment for the container.
-    pub height: f32,
+    /// Final position. Used to compute baseline alignment for the container.
+    pub position: Point<f32>,
+    /// Final size. Used to compute baseline alignment for the container.
+    pub final_size: Size<f32>,
 }
 
 impl GridItem {
@@ -115,20 +135,26 @@ impl GridItem {
             margin: style.margin(),
             align_self: style.align_self().unwrap_or(parent_align_items),
             justify_self: style.justify_self().unwrap_or(parent_justify_items),
-            baseline: None,
-            baseline_shim: 0.0,
+            baseline: Point::NONE,
+            baseline_shim: Point::ZERO,
             row_indexes: Line { start: 0, end: 0 }, // Properly initialised later
             column_indexes: Line { start: 0, end: 0 }, // Properly initialised later
             crosses_flexible_row: false,            // Properly initialised later
             crosses_flexible_column: false,         // Properly initialised later
             crosses_intrinsic_row: false,           // Properly initialised later
             crosses_intrinsic_column: false,        // Properly initialised later
+            contributes_to_row_sizing: true,
+            contributes_to_column_sizing: true,
+            is_subgrid_item: false,
+            subgrid_edge_margin: Rect::ZERO,
+            is_masonry_auto_placed: false,
+            masonry_position: None,
             available_space_cache: None,
             min_content_contribution_cache: Size::NONE,
             max_content_contribution_cache: Size::NONE,
             minimum_contribution_cache: Size::NONE,
-            y_position: 0.0,
-            height: 0.0,
+            position: Point::ZERO,
+            final_size: Size::ZERO,
         }
     }
 
@@ -148,6 +174,15 @@ impl GridItem {
         }
     }
 
+    /// This item's self-alignment in the specified axis (its justify_self in the inline axis and align_self in the
+    /// block axis)
+    pub fn self_alignment(&self, axis: AbstractAxis) -> AlignSelf {
+        match axis {
+            AbstractAxis::Inline => self.justify_self,
+            AbstractAxis::Block => self.align_self,
+        }
+    }
+
     /// Returns a range which can be used as an index into the GridTrackVec in the specified axis
     /// which will produce a sub-slice of covering all the tracks and lines that this item spans
     /// excluding the lines that bound it.
@@ -182,6 +217,14 @@ impl GridItem {
         }
     }
 
+    /// Returns whether the item contributes to the sizing of the tracks it spans in the specified axis
+    pub fn contributes_to_track_sizing(&self, axis: AbstractAxis) -> bool {
+        match axis {
+            AbstractAxis::Inline => self.contributes_to_column_sizing,
+            AbstractAxis::Block => self.contributes_to_row_sizing,
+        }
+    }
+
     /// For an item spanning multiple tracks, the upper limit used to calculate its limited min-/max-content contribution is the
     /// sum of the fixed max track sizing functions of any tracks it spans, and is applied if it only spans such tracks.
     pub fn spanned_track_limit(
@@ -246,24 +289,24 @@ impl GridItem {
         let margins = self.margins_axis_sums_with_baseline_shims(inner_node_size.width, tree);
 
         let aspect_ratio = self.aspect_ratio;
-        let padding = self.padding.resolve_or_zero(grid_area_size, |val, basis| tree.calc(val, basis));
-        let border = self.border.resolve_or_zero(grid_area_size, |val, basis| tree.calc(val, basis));
+        let padding = self.padding.resolve_or_zero(grid_area_size, |val, basis| tree.calc(self.node, val, basis));
+        let border = self.border.resolve_or_zero(grid_area_size, |val, basis| tree.calc(self.node, val, basis));
         let padding_border_size = (padding + border).sum_axes();
         let box_sizing_adjustment =
             if self.box_sizing == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
         let inherent_size = self
             .size
-            .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(grid_area_size, |val, basis| tree.calc(self.node, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
         let min_size = self
             .min_size
-            .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(grid_area_size, |val, basis| tree.calc(self.node, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
         let max_size = self
             .max_size
-            .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(grid_area_size, |val, basis| tree.calc(self.node, val, basis))
             .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
 
@@ -350,20 +393,22 @@ impl GridItem {
 
     /// Compute the item's resolved margins for size contributions. Horizontal percentage margins always resolve
     /// to zero if the container size is indefinite as otherwise this would introduce a cyclic dependency.
+    /// The extra margin of the items of subgrids at the edges of their subgrid is included.
     #[inline(always)]
     pub fn margins_axis_sums_with_baseline_shims(
         &self,
         inner_node_width: Option<f32>,
         tree: &impl LayoutPartialTree,
     ) -> Size<f32> {
-        Rect {
-            left: self.margin.left.resolve_or_zero(Some(0.0), |val, basis| tree.calc(val, basis)),
-            right: self.margin.right.resolve_or_zero(Some(0.0), |val, basis| tree.calc(val, basis)),
-            top: self.margin.top.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis))
-                + self.baseline_shim,
-            bottom: self.margin.bottom.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis)),
-        }
-        .sum_axes()
+        let margin = Rect {
+            left: self.margin.left.resolve_or_zero(Some(0.0), |val, basis| tree.calc(self.node, val, basis))
+                + self.baseline_shim.x,
+            right: self.margin.right.resolve_or_zero(Some(0.0), |val, basis| tree.calc(self.node, val, basis)),
+            top: self.margin.top.resolve_or_zero(inner_node_width, |val, basis| tree.calc(self.node, val, basis))
+                + self.baseline_shim.y,
+            bottom: self.margin.bottom.resolve_or_zero(inner_node_width, |val, basis| tree.calc(self.node, val, basis)),
+        };
+        (margin + self.subgrid_edge_margin).sum_axes()
     }
 
     /// Compute the item's min content contribution from the provided parameters
@@ -455,25 +500,26 @@ impl GridItem {
     pub fn minimum_contribution(
         &mut self,
         tree: &mut impl LayoutPartialTree,
+        container: NodeId,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
         known_dimensions: Size<Option<f32>>,
         inner_node_size: Size<Option<f32>>,
     ) -> f32 {
-        let padding = self.padding.resolve_or_zero(inner_node_size, |val, basis| tree.calc(val, basis));
-        let border = self.border.resolve_or_zero(inner_node_size, |val, basis| tree.calc(val, basis));
+        let padding = self.padding.resolve_or_zero(inner_node_size, |val, basis| tree.calc(self.node, val, basis));
+        let border = self.border.resolve_or_zero(inner_node_size, |val, basis| tree.calc(self.node, val, basis));
         let padding_border_size = (padding + border).sum_axes();
         let box_sizing_adjustment =
             if self.box_sizing == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
         let size = self
             .size
-            .maybe_resolve(inner_node_size, |val, basis| tree.calc(val, basis))
+            .maybe_resolve(inner_node_size, |val, basis| tree.calc(self.node, val, basis))
             .maybe_apply_aspect_ratio(self.aspect_ratio)
             .maybe_add(box_sizing_adjustment)
             .get(axis)
             .or_else(|| {
                 self.min_size
-                    .maybe_resolve(inner_node_size, |val, basis| tree.calc(val, basis))
+                    .maybe_resolve(inner_node_size, |val, basis| tree.calc(self.node, val, basis))
                     .maybe_apply_aspect_ratio(self.aspect_ratio)
                     .maybe_add(box_sizing_adjustment)
                     .get(axis)
@@ -514,7 +560,7 @@ impl GridItem {
         // Note: The argument to fit-content() does not clamp the content-based minimum size in the same way as a fixed max track
         // sizing function.
         let limit = self.spanned_fixed_track_limit(axis, axis_tracks, inner_node_size.get(axis), &|val, basis| {
-            tree.resolve_calc_value(val, basis)
+            tree.calc(container, val, basis)
         });
         size.maybe_min(limit)
     }
@@ -524,13 +570,14 @@ impl GridItem {
     pub fn minimum_contribution_cached(
         &mut self,
         tree: &mut impl LayoutPartialTree,
+        container: NodeId,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
         known_dimensions: Size<Option<f32>>,
         inner_node_size: Size<Option<f32>>,
     ) -> f32 {
         self.minimum_contribution_cache.get(axis).unwrap_or_else(|| {
-            let size = self.minimum_contribution(tree, axis, axis_tracks, known_dimensions, inner_node_size);
+            let size = self.minimum_contribution(tree, container, axis, axis_tracks, known_dimensions, inner_node_size);
             self.minimum_contribution_cache.set(axis, Some(size));
             size
         })
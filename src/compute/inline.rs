@@ -0,0 +1,1245 @@
+//! Computes the layout of the inline-level content of a block container: breaks it into line boxes, aligns the items
+//! on each line (`vertical_align`) and aligns each line within the container (`text_align`).
+//!
+//! The inline-level content of a block container is a run of consecutive inline-level children, which are either:
+//!   - Inline boxes (`DisplayOutside::Inline` nodes with children), whose children are laid out as part of the same lines
+//!   - Text runs (`DisplayOutside::Inline` nodes without children), which are split across lines using
+//!     [`LayoutBlockContainer::break_text_run`]
+//!   - Atomic inline-level boxes (`DisplayOutside::InlineBlock` nodes, and any other children of inline boxes), which
+//!     are laid out as a single unbreakable box using the node's own layout algorithm
+//!
+//! Floats which occur between the inline-level children are placed when the line on which they occur is reached: beside
+//! that line if they fit beside its content, and otherwise below it.
+//!
+//! Limitations:
+//!   - `vertical_align` is ignored on inline boxes (their content is aligned to the baseline of the line box), and the
+//!     `Middle` value centers an item within its line box rather than aligning it to the middle of the parent's x-height
+//!   - Block-level children of inline boxes are laid out as atomic inline-level boxes rather than splitting the inline box
+//!   - Absolutely positioned children of inline boxes are placed at their static position, and their insets are ignored
+//!   - Bidirectional reordering is not performed: in right-to-left containers, items are placed from right to left in
+//!     document order
+//!   - Lines are shortened to avoid the floats of the container which are beside the top of the line, rather than all
+//!     floats which the line overlaps
+//!   - Floats which occur between the inline-level children are not offset by their insets
+use core::ops::Range;
+
+use super::common::containing_block::StaticChild;
+use super::float::FloatContext;
+use crate::geometry::{Line, Point, Rect, Size};
+use crate::style::{AvailableSpace, BlockItemStyle, CoreStyle, Direction, DisplayOutside, TextAlign, VerticalAlign};
+use crate::style::{BoxGenerationMode, Clear, Float, Overflow, Position, Visibility};
+use crate::style_helpers::TaffyMaxContent;
+use crate::tree::{ContainingBlock, InlineFragment, Layout, LayoutBlockContainer, LayoutInput, LayoutOutput, NodeId};
+use crate::tree::{LayoutPartialTreeExt, RequestedAxis, RunMode, SizingMode, TextRunBreak};
+use crate::util::sys::{f32_max, f32_min, Vec};
+use crate::util::{MaybeMath, ResolveOrZero};
+
+/// An inline box whose children are laid out as part of the lines of the inline formatting context
+struct InlineBox {
+    /// The node of the inline box
+    node_id: NodeId,
+    /// The index of the node within its parent's children
+    order: u32,
+    /// The index of the inline box which contains this inline box, if any
+    parent: Option<usize>,
+    /// The position style of the inline box
+    position: Position,
+    /// The visibility style of the inline box
+    visibility: Visibility,
+    /// The resolved margin of the inline box
+    margin: Rect<f32>,
+    /// The resolved padding of the inline box
+    padding: Rect<f32>,
+    /// The resolved border of the inline box
+    border: Rect<f32>,
+}
+
+/// How a leaf item is laid out
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum LeafKind {
+    /// A childless inline box, which is a text run unless the tree says otherwise
+    Inline,
+    /// An atomic inline-level box
+    Atomic,
+    /// An absolutely positioned child of an inline box
+    Absolute,
+    /// A float which occurs between the inline-level children of the container
+    Float,
+}
+
+/// An item which is not an inline box: a text run, an atomic inline-level box or an absolutely positioned box
+struct Leaf {
+    /// The node of the item
+    node_id: NodeId,
+    /// The index of the node within its parent's children
+    order: u32,
+    /// The index of the inline box which contains this item, if any
+    parent: Option<usize>,
+    /// How the item is laid out
+    kind: LeafKind,
+    /// The position style of the item
+    position: Position,
+    /// The visibility style of the item
+    visibility: Visibility,
+    /// The vertical alignment of the item within its line box
+    vertical_align: VerticalAlign,
+    /// The resolved margin of the item (only used for atomic inline-level and absolutely positioned items)
+    margin: Rect<f32>,
+    /// The resolved padding of the item (only used for atomic inline-level and absolutely positioned items)
+    padding: Rect<f32>,
+    /// The resolved border of the item (only used for atomic inline-level and absolutely positioned items)
+    border: Rect<f32>,
+}
+
+/// A float which occurs between the inline-level children of the container
+#[derive(Copy, Clone)]
+struct InlineFloat {
+    /// The index of the leaf of the float
+    leaf: usize,
+    /// Which side the float is floated to
+    float: Float,
+    /// The clear style of the float
+    clear: Clear,
+    /// The overflow style of the float
+    #[cfg_attr(not(feature = "content_size"), allow(dead_code))]
+    overflow: Point<Overflow>,
+    /// The inputs of the float's layout
+    inputs: LayoutInput,
+    /// The output of the float's layout
+    output: LayoutOutput,
+    /// The size of the float's margin box
+    size: Size<f32>,
+    /// The position of the float's margin box relative to the container
+    location: Point<f32>,
+    /// The index of the line at which the float is placed
+    line: usize,
+}
+
+/// An item of the flattened inline-level content, in document order
+#[derive(Copy, Clone)]
+enum InlineItem {
+    /// The start of the inline box with the specified index
+    BoxStart(usize),
+    /// The end of the inline box with the specified index
+    BoxEnd(usize),
+    /// The leaf item with the specified index
+    Leaf(usize),
+}
+
+/// What a piece of a line represents
+#[derive(Copy, Clone)]
+enum PieceKind {
+    /// The inline-start margin, border and padding of the inline box with the specified index
+    BoxStart(usize),
+    /// The inline-end margin, border and padding of the inline box with the specified index
+    BoxEnd(usize),
+    /// A fragment of the text run of a leaf
+    Text {
+        /// The index of the leaf
+        leaf: usize,
+        /// The offset into the text run at which the fragment starts
+        start: usize,
+        /// The offset into the text run at which the fragment ends
+        end: usize,
+    },
+    /// An atomic inline-level leaf
+    Atomic {
+        /// The index of the leaf
+        leaf: usize,
+        /// The inputs of the leaf's layout
+        inputs: LayoutInput,
+        /// The output of the leaf's layout
+        output: LayoutOutput,
+    },
+    /// The static position of an absolutely positioned leaf
+    Absolute {
+        /// The index of the leaf
+        leaf: usize,
+    },
+}
+
+/// Something which has been placed on a line
+#[derive(Copy, Clone)]
+struct Piece {
+    /// What the piece represents
+    kind: PieceKind,
+    /// The inline box which contains the piece. For the start and end of an inline box, this is the inline box itself.
+    parent: Option<usize>,
+    /// The offset of the start of the piece from the inline-start edge of the line
+    x: f32,
+    /// The width of the piece
+    width: f32,
+    /// The distance from the top of the piece to its baseline
+    ascent: f32,
+    /// The distance from the baseline of the piece to its bottom
+    descent: f32,
+    /// The vertical alignment of the piece within its line box
+    vertical_align: VerticalAlign,
+    /// Whether the piece is content which contributes to the height of the line
+    is_content: bool,
+}
+
+/// A line box
+#[derive(Copy, Clone)]
+struct LineBox {
+    /// The index of the first piece of the line
+    start: usize,
+    /// The index after the last piece of the line
+    end: usize,
+    /// The width of the content of the line
+    width: f32,
+    /// The offset of the top of the line from the top of the first line
+    top: f32,
+    /// The height of the line
+    height: f32,
+    /// The distance from the top of the line to its baseline
+    baseline: f32,
+    /// The left and right edges of the space which is not excluded by floats beside the line, relative to the left edge
+    /// of the container's content box. `None` if the line spans the whole content box.
+    band: Option<Line<f32>>,
+}
+
+/// The extent of a fragment of an inline box on one line, in line-relative coordinates
+#[derive(Copy, Clone)]
+struct FragmentExtent {
+    /// The inline-start edge of the fragment's border box, if the inline box starts on the line
+    box_start: Option<f32>,
+    /// The inline-end edge of the fragment's border box, if the inline box ends on the line
+    box_end: Option<f32>,
+    /// The inline-start and inline-end edges of the box's content on the line
+    content_x: Line<f32>,
+    /// The top and bottom edges of the box's content on the line
+    content_y: Option<Line<f32>>,
+}
+
+/// Inline-level content which has been broken into lines
+pub(crate) struct InlineLayout {
+    /// The inline boxes of the content
+    boxes: Vec<InlineBox>,
+    /// The leaf items of the content
+    leaves: Vec<Leaf>,
+    /// Hidden (`display: none`) descendants of inline boxes, and their index within their parent's children
+    hidden: Vec<(NodeId, u32)>,
+    /// The pieces placed on each line
+    pieces: Vec<Piece>,
+    /// The line boxes
+    lines: Vec<LineBox>,
+    /// The floats which occur within the content, in document order
+    floats: Vec<InlineFloat>,
+    /// The direction of the block container
+    direction: Direction,
+    /// The text-align style of the block container
+    text_align: TextAlign,
+}
+
+impl InlineLayout {
+    /// The width of the widest line
+    pub(crate) fn width(&self) -> f32 {
+        self.lines.iter().map(|line| line.width).fold(0.0, f32_max)
+    }
+
+    /// The total height of the lines
+    pub(crate) fn height(&self) -> f32 {
+        self.lines.last().map(|line| line.top + line.height).unwrap_or(0.0)
+    }
+
+    /// The baseline of the first line which contains content, relative to the top of the first line
+    pub(crate) fn first_baseline(&self) -> Option<f32> {
+        self.lines
+            .iter()
+            .find(|line| self.pieces[line.start..line.end].iter().any(|piece| piece.is_content))
+            .map(|line| line.top + line.baseline)
+    }
+
+    /// Whether there is any content which gives the lines a height
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pieces.iter().all(|piece| !piece.is_content)
+    }
+
+    /// The top and bottom edges of each line, relative to the top of the first line
+    pub(crate) fn line_extents(&self) -> impl Iterator<Item = Line<f32>> + '_ {
+        self.lines.iter().map(|line| Line { start: line.top, end: line.top + line.height })
+    }
+
+    /// The widths of the margin boxes of the floats which occur within the content
+    pub(crate) fn float_widths(&self) -> impl Iterator<Item = f32> + '_ {
+        self.floats.iter().map(|float| float.size.width)
+    }
+
+    /// The contribution of the floats which occur within the content to the content size of the container. Each float
+    /// is moved by the offset in `line_offsets` of the line at which it is placed.
+    #[cfg(feature = "content_size")]
+    pub(crate) fn float_content_size(&self, line_offsets: &[Point<f32>]) -> Size<f32> {
+        self.floats.iter().fold(Size::ZERO, |content_size, float| {
+            let contribution = super::common::content_size::compute_content_size_contribution(
+                self.float_location(float, line_offsets),
+                float.output.size,
+                float.output.content_size,
+                float.overflow,
+            );
+            content_size.f32_max(contribution)
+        })
+    }
+
+    /// The location of the border box of a float relative to the container
+    fn float_location(&self, float: &InlineFloat, line_offsets: &[Point<f32>]) -> Point<f32> {
+        let margin = self.leaves[float.leaf].margin;
+        let line_offset = line_offsets.get(float.line).copied().unwrap_or(Point::ZERO);
+        Point { x: float.location.x + margin.left, y: float.location.y + margin.top } + line_offset
+    }
+}
+
+/// Breaks the inline-level `children` of a block container into lines.
+///
+/// `children` are the inline-level children along with their index within their parent's children. If
+/// `available_width` is `MinContent` then every line break opportunity is taken, and if it is `MaxContent` then lines
+/// are only broken at forced line breaks. Percentages are resolved against `percentage_basis`.
+///
+/// If `floats` is specified, then lines are shortened to avoid the container's floats, and moved below them if their
+/// content does not fit beside them, and the floats which occur within the content are placed in it. The `f32` is the
+/// position of the top of the first line within the container.
+pub(crate) fn compute_inline_layout(
+    tree: &mut impl LayoutBlockContainer,
+    children: &[(NodeId, u32)],
+    available_width: AvailableSpace,
+    percentage_basis: Option<f32>,
+    direction: Direction,
+    text_align: TextAlign,
+    floats: Option<(&mut FloatContext, f32)>,
+) -> InlineLayout {
+    let mut layout = InlineLayout {
+        boxes: Vec::new(),
+        leaves: Vec::new(),
+        hidden: Vec::new(),
+        pieces: Vec::new(),
+        lines: Vec::new(),
+        floats: Vec::new(),
+        direction,
+        text_align,
+    };
+    let mut items = Vec::new();
+    for &(child, order) in children {
+        collect_item(tree, child, order, None, percentage_basis, &mut layout, &mut items);
+    }
+
+    let mut builder = LineBuilder::new(available_width, floats);
+    for item in items {
+        match item {
+            InlineItem::BoxStart(index) => {
+                let inline_box = &layout.boxes[index];
+                let edges = (inline_box.margin + inline_box.padding + inline_box.border).horizontal_components();
+                let width = if direction.is_rtl() { edges.end } else { edges.start };
+                builder.push_edge(&mut layout.pieces, PieceKind::BoxStart(index), Some(index), width, true);
+            }
+            InlineItem::BoxEnd(index) => {
+                let inline_box = &layout.boxes[index];
+                let edges = (inline_box.margin + inline_box.padding + inline_box.border).horizontal_components();
+                let width = if direction.is_rtl() { edges.start } else { edges.end };
+                builder.push_edge(&mut layout.pieces, PieceKind::BoxEnd(index), Some(index), width, false);
+            }
+            InlineItem::Leaf(index) => {
+                if layout.leaves[index].kind == LeafKind::Inline {
+                    let is_text_run = break_text_run(tree, &mut layout, &mut builder, index);
+                    if !is_text_run {
+                        layout.leaves[index].kind = LeafKind::Atomic;
+                    }
+                }
+                match layout.leaves[index].kind {
+                    LeafKind::Inline => {}
+                    LeafKind::Atomic => place_atomic_box(tree, &mut layout, &mut builder, index, percentage_basis),
+                    LeafKind::Float => place_float(tree, &mut layout, &mut builder, index, percentage_basis),
+                    LeafKind::Absolute => {
+                        let leaf = &layout.leaves[index];
+                        let piece = Piece {
+                            kind: PieceKind::Absolute { leaf: index },
+                            parent: leaf.parent,
+                            x: 0.0,
+                            width: 0.0,
+                            ascent: 0.0,
+                            descent: 0.0,
+                            vertical_align: VerticalAlign::Baseline,
+                            is_content: false,
+                        };
+                        builder.push_piece(&mut layout.pieces, piece);
+                    }
+                }
+            }
+        }
+    }
+    builder.finish_line(&layout.pieces);
+    builder.place_pending_floats();
+    layout.lines = builder.lines;
+    layout.floats = builder.placed_floats;
+
+    layout
+}
+
+/// Appends the items of the inline-level node `node` (and of its descendants, if it is an inline box) to `items`
+fn collect_item(
+    tree: &impl LayoutBlockContainer,
+    node: NodeId,
+    order: u32,
+    parent: Option<usize>,
+    percentage_basis: Option<f32>,
+    layout: &mut InlineLayout,
+    items: &mut Vec<InlineItem>,
+) {
+    let style = tree.get_block_child_style(node);
+    if style.box_generation_mode() == BoxGenerationMode::None {
+        layout.hidden.push((node, order));
+        return;
+    }
+    let margin = style.margin().resolve_or_zero(percentage_basis, |val, basis| tree.calc(node, val, basis));
+    let padding = style.padding().resolve_or_zero(percentage_basis, |val, basis| tree.calc(node, val, basis));
+    let border = style.border().resolve_or_zero(percentage_basis, |val, basis| tree.calc(node, val, basis));
+    let position = style.position();
+    let visibility = style.visibility();
+    let vertical_align = style.vertical_align();
+    let kind = match (style.display_outside(), style.position().is_absolutely_positioned()) {
+        (_, true) => LeafKind::Absolute,
+        _ if parent.is_none() && style.float() != Float::None => LeafKind::Float,
+        (DisplayOutside::Inline, false) => LeafKind::Inline,
+        _ => LeafKind::Atomic,
+    };
+    drop(style);
+
+    let child_count = tree.child_count(node);
+    if kind == LeafKind::Inline && child_count > 0 {
+        let index = layout.boxes.len();
+        layout.boxes.push(InlineBox { node_id: node, order, parent, position, visibility, margin, padding, border });
+        items.push(InlineItem::BoxStart(index));
+        for child_order in 0..child_count {
+            let child = tree.get_child_id(node, child_order);
+            collect_item(tree, child, child_order as u32, Some(index), percentage_basis, layout, items);
+        }
+        items.push(InlineItem::BoxEnd(index));
+    } else {
+        items.push(InlineItem::Leaf(layout.leaves.len()));
+        layout.leaves.push(Leaf {
+            node_id: node,
+            order,
+            parent,
+            kind,
+            position,
+            visibility,
+            vertical_align,
+            margin,
+            padding,
+            border,
+        });
+    }
+}
+
+/// Places the fragments of the text run of a leaf on the lines. Returns false if the leaf is not a text run.
+fn break_text_run(
+    tree: &mut impl LayoutBlockContainer,
+    layout: &mut InlineLayout,
+    builder: &mut LineBuilder,
+    leaf_index: usize,
+) -> bool {
+    let leaf = &layout.leaves[leaf_index];
+    let (node, parent, vertical_align) = (leaf.node_id, leaf.parent, leaf.vertical_align);
+
+    let mut start = 0;
+    loop {
+        let remaining_width = builder.line_width.map(|width| f32_max(width - builder.pen, 0.0));
+        let line_is_empty = !builder.line_has_content;
+        // An empty line which is shortened by floats only has to fit something if it cannot be moved below them
+        let can_move_down = line_is_empty && builder.next_float_bottom().is_some();
+        let Some(TextRunBreak { end, is_end_of_run, width, ascent, descent }) =
+            tree.break_text_run(node, start, remaining_width, line_is_empty && !can_move_down)
+        else {
+            return start != 0;
+        };
+
+        if end == start && !is_end_of_run {
+            // Nothing fits on the current line. An empty line is guaranteed to fit something, so this can only
+            // fail to make progress if the tree has broken its contract.
+            if can_move_down {
+                builder.move_down();
+                continue;
+            }
+            if line_is_empty {
+                return true;
+            }
+            builder.break_line(&mut layout.pieces);
+            continue;
+        }
+
+        if end > start {
+            let piece = Piece {
+                kind: PieceKind::Text { leaf: leaf_index, start, end },
+                parent,
+                x: 0.0,
+                width,
+                ascent,
+                descent,
+                vertical_align,
+                is_content: true,
+            };
+            builder.push_piece(&mut layout.pieces, piece);
+        }
+        start = end;
+
+        if is_end_of_run {
+            return true;
+        }
+        builder.break_line(&mut layout.pieces);
+    }
+}
+
+/// Lays out an atomic inline-level leaf and places it on the lines
+fn place_atomic_box(
+    tree: &mut impl LayoutBlockContainer,
+    layout: &mut InlineLayout,
+    builder: &mut LineBuilder,
+    leaf_index: usize,
+    percentage_basis: Option<f32>,
+) {
+    let leaf = &layout.leaves[leaf_index];
+    let (node, parent, vertical_align, margin) = (leaf.node_id, leaf.parent, leaf.vertical_align, leaf.margin);
+    let inputs = LayoutInput {
+        known_dimensions: Size::NONE,
+        parent_size: Size { width: percentage_basis, height: None },
+        available_space: Size {
+            width: builder.available_width().maybe_sub(margin.horizontal_axis_sum()),
+            height: AvailableSpace::MinContent,
+        },
+        sizing_mode: SizingMode::InherentSize,
+        axis: RequestedAxis::Both,
+        run_mode: RunMode::PerformLayout,
+        vertical_margins_are_collapsible: Line::FALSE,
+        containing_block: ContainingBlock::Deferred,
+    };
+    let output = tree.compute_child_layout(node, inputs);
+
+    // Atomic inline-level boxes without a baseline are aligned by their bottom margin edge
+    let outer_height = output.size.height + margin.vertical_axis_sum();
+    let ascent = match output.first_baselines.y {
+        Some(baseline) => margin.top + baseline,
+        None => outer_height,
+    };
+    let piece = Piece {
+        kind: PieceKind::Atomic { leaf: leaf_index, inputs, output },
+        parent,
+        x: 0.0,
+        width: output.size.width + margin.horizontal_axis_sum(),
+        ascent,
+        descent: outer_height - ascent,
+        vertical_align,
+        is_content: true,
+    };
+    builder.push_content(&mut layout.pieces, piece);
+}
+
+/// Lays out a float which occurs within the inline-level content, and places it at the current line
+fn place_float(
+    tree: &mut impl LayoutBlockContainer,
+    layout: &mut InlineLayout,
+    builder: &mut LineBuilder,
+    leaf_index: usize,
+    percentage_basis: Option<f32>,
+) {
+    let leaf = &layout.leaves[leaf_index];
+    let (node, margin) = (leaf.node_id, leaf.margin);
+    let style = tree.get_block_child_style(node);
+    let (float, clear, overflow) = (style.float(), style.clear(), style.overflow());
+    drop(style);
+
+    let inputs = LayoutInput {
+        known_dimensions: Size::NONE,
+        parent_size: Size { width: percentage_basis, height: None },
+        available_space: Size {
+            width: builder.available_width.maybe_sub(margin.horizontal_axis_sum()),
+            height: AvailableSpace::MinContent,
+        },
+        sizing_mode: SizingMode::InherentSize,
+        axis: RequestedAxis::Both,
+        run_mode: RunMode::PerformLayout,
+        vertical_margins_are_collapsible: Line::FALSE,
+        containing_block: ContainingBlock::Deferred,
+    };
+    let output = tree.compute_child_layout(node, inputs);
+    let size = output.size + margin.sum_axes();
+    builder.push_float(InlineFloat {
+        leaf: leaf_index,
+        float,
+        clear,
+        overflow,
+        inputs,
+        output,
+        size,
+        location: Point::ZERO,
+        line: 0,
+    });
+}
+
+/// Breaks a sequence of pieces into lines
+struct LineBuilder<'a> {
+    /// The inline space available to the lines, ignoring floats
+    available_width: AvailableSpace,
+    /// The floats of the container, and the position of the top of the first line within the container
+    floats: Option<(&'a mut FloatContext, f32)>,
+    /// The width available for the current line, or `None` if lines are not broken
+    line_width: Option<f32>,
+    /// The space which is not excluded by floats beside the current line
+    band: Option<Line<f32>>,
+    /// The offset of the top of the current line from the top of the first line
+    top: f32,
+    /// The lines which have been finished
+    lines: Vec<LineBox>,
+    /// The index of the first piece of the current line
+    line_start: usize,
+    /// The offset from the start of the current line at which the next piece will be placed
+    pen: f32,
+    /// Whether any content has been placed on the current line
+    line_has_content: bool,
+    /// The index of the first of the pieces at the end of the current line which are the edges of inline boxes that
+    /// start after the last content of the line. These move to the next line along with the content which follows them.
+    pending_edges_start: Option<usize>,
+    /// The total width of the pending edges
+    pending_edges_width: f32,
+    /// The floats which did not fit beside the content of the current line, and are placed below it
+    pending_floats: Vec<InlineFloat>,
+    /// The floats which have been placed
+    placed_floats: Vec<InlineFloat>,
+}
+
+impl<'a> LineBuilder<'a> {
+    /// Create a new line builder
+    fn new(available_width: AvailableSpace, floats: Option<(&'a mut FloatContext, f32)>) -> Self {
+        let mut builder = Self {
+            available_width,
+            floats,
+            line_width: None,
+            band: None,
+            top: 0.0,
+            lines: Vec::new(),
+            line_start: 0,
+            pen: 0.0,
+            line_has_content: false,
+            pending_edges_start: None,
+            pending_edges_width: 0.0,
+            pending_floats: Vec::new(),
+            placed_floats: Vec::new(),
+        };
+        builder.start_line();
+        builder
+    }
+
+    /// Determine the space available to the current line
+    fn start_line(&mut self) {
+        self.band = self.floats.as_ref().filter(|(floats, _)| !floats.is_empty()).map(|(floats, origin_y)| {
+            let y = origin_y + self.top;
+            let space = floats.available_space(y, y);
+            let content_box_start = floats.content_box().start;
+            Line { start: space.start - content_box_start, end: f32_max(space.end, space.start) - content_box_start }
+        });
+        self.line_width = match (self.band, self.available_width) {
+            (Some(band), _) => Some(band.end - band.start),
+            (None, AvailableSpace::Definite(width)) => Some(width),
+            (None, AvailableSpace::MinContent) => Some(0.0),
+            (None, AvailableSpace::MaxContent) => None,
+        };
+    }
+
+    /// The inline space available to an atomic inline-level box placed on the current line
+    fn available_width(&self) -> AvailableSpace {
+        match self.band {
+            Some(band) => AvailableSpace::Definite(band.end - band.start),
+            None => self.available_width,
+        }
+    }
+
+    /// The position below the top of the current line at which the floats beside it end, if any floats shorten it
+    fn next_float_bottom(&self) -> Option<f32> {
+        let (floats, origin_y) = self.floats.as_ref()?;
+        let y = origin_y + self.top;
+        floats.next_float_bottom(y, y).map(|bottom| bottom - origin_y)
+    }
+
+    /// Move the current line below the floats beside it. Returns false if there are no such floats. Must only be called
+    /// when no content has been placed on the line.
+    fn move_down(&mut self) -> bool {
+        let Some(top) = self.next_float_bottom() else { return false };
+        self.top = top;
+        self.start_line();
+        true
+    }
+
+    /// Place a float which occurs on the current line. The float is placed beside the line if the line is empty or the
+    /// float fits beside its content, and otherwise below the line once it is finished.
+    fn push_float(&mut self, float: InlineFloat) {
+        let fits = match self.line_width {
+            Some(line_width) => !self.line_has_content || self.pen + float.size.width <= line_width,
+            None => true,
+        };
+        if fits && self.pending_floats.is_empty() {
+            self.place_float(float);
+            self.start_line();
+        } else {
+            self.pending_floats.push(float);
+        }
+    }
+
+    /// Place a float at the top of the current line
+    fn place_float(&mut self, mut float: InlineFloat) {
+        if let Some((floats, origin_y)) = self.floats.as_mut() {
+            float.location = floats.place(float.float, float.clear, float.size, *origin_y + self.top);
+        }
+        float.line = self.lines.len();
+        self.placed_floats.push(float);
+    }
+
+    /// Place the floats which did not fit beside the content of the previous line
+    fn place_pending_floats(&mut self) {
+        for float in core::mem::take(&mut self.pending_floats) {
+            self.place_float(float);
+        }
+    }
+
+    /// Place the start or end edge of an inline box on the current line. The start edges of inline boxes are kept on the
+    /// same line as the content which follows them, and the end edges of inline boxes on the same line as the content
+    /// which precedes them.
+    fn push_edge(
+        &mut self,
+        pieces: &mut Vec<Piece>,
+        kind: PieceKind,
+        parent: Option<usize>,
+        width: f32,
+        is_start: bool,
+    ) {
+        if is_start && self.pending_edges_start.is_none() {
+            self.pending_edges_start = Some(pieces.len());
+        }
+        if self.pending_edges_start.is_some() {
+            self.pending_edges_width += width;
+        }
+        let piece = Piece {
+            kind,
+            parent,
+            x: self.pen,
+            width,
+            ascent: 0.0,
+            descent: 0.0,
+            vertical_align: VerticalAlign::Baseline,
+            is_content: false,
+        };
+        self.pen += width;
+        pieces.push(piece);
+    }
+
+    /// Place content on the current line, first breaking the line (or moving it below floats) if the content does not fit
+    fn push_content(&mut self, pieces: &mut Vec<Piece>, piece: Piece) {
+        loop {
+            let fits = match self.line_width {
+                Some(line_width) => self.pen + piece.width <= line_width,
+                None => true,
+            };
+            if fits {
+                break;
+            }
+            if self.line_has_content {
+                self.break_line(pieces);
+            } else if !self.move_down() {
+                break;
+            }
+        }
+        self.push_piece(pieces, piece);
+    }
+
+    /// Place a piece on the current line without checking whether it fits
+    fn push_piece(&mut self, pieces: &mut Vec<Piece>, piece: Piece) {
+        pieces.push(Piece { x: self.pen, ..piece });
+        self.pen += piece.width;
+        if piece.is_content {
+            self.line_has_content = true;
+            self.pending_edges_start = None;
+            self.pending_edges_width = 0.0;
+        }
+    }
+
+    /// Finish the current line, aligning the items on it vertically
+    fn finish_line(&mut self, pieces: &[Piece]) {
+        let end = self.pending_edges_start.unwrap_or(pieces.len());
+        let width = self.pen - self.pending_edges_width;
+
+        let line_pieces = &pieces[self.line_start..end];
+        let baseline_aligned =
+            line_pieces.iter().filter(|p| p.is_content && p.vertical_align == VerticalAlign::Baseline);
+        let (mut ascent, descent) =
+            baseline_aligned.fold((0.0, 0.0), |(a, d), piece| (f32_max(a, piece.ascent), f32_max(d, piece.descent)));
+        let mut height = ascent + descent;
+
+        // Items which are aligned to the edges of the line box grow the line box away from that edge
+        for vertical_align in [VerticalAlign::Top, VerticalAlign::Bottom, VerticalAlign::Middle] {
+            for piece in line_pieces.iter().filter(|p| p.is_content && p.vertical_align == vertical_align) {
+                let piece_height = piece.ascent + piece.descent;
+                if piece_height > height {
+                    match vertical_align {
+                        VerticalAlign::Bottom => ascent += piece_height - height,
+                        VerticalAlign::Middle => ascent += (piece_height - height) / 2.0,
+                        _ => {}
+                    }
+                    height = piece_height;
+                }
+            }
+        }
+
+        self.lines.push(LineBox {
+            start: self.line_start,
+            end,
+            width,
+            top: self.top,
+            height,
+            baseline: ascent,
+            band: self.band,
+        });
+        self.line_start = end;
+        self.top += height;
+    }
+
+    /// Finish the current line and start a new one. Any pending edges are moved to the new line.
+    fn break_line(&mut self, pieces: &mut [Piece]) {
+        self.finish_line(pieces);
+        self.place_pending_floats();
+        self.start_line();
+        self.pen = 0.0;
+        for piece in pieces[self.line_start..].iter_mut() {
+            piece.x = self.pen;
+            self.pen += piece.width;
+        }
+        self.line_has_content = false;
+        self.pending_edges_start = self.pending_edges_start.map(|_| self.line_start);
+    }
+}
+
+impl InlineLayout {
+    /// Position the inline-level content within a block container, setting the layouts of the inline-level children and
+    /// their descendants. `origin` is the position of the top of the first line at the inline-start edge of the
+    /// container's content box (relative to the container), and `line_width` is the width of the content box.
+    ///
+    /// In multi-column containers each line is moved into its column by the corresponding offset in `line_offsets`.
+    /// Lines without an offset are not moved.
+    ///
+    /// The final layouts of `Position::Static` atomic inline-level leaves are appended to `static_children`, so that
+    /// they can be laid out again once the containing block of their absolutely positioned descendants is known.
+    pub(crate) fn perform_layout(
+        &self,
+        tree: &mut impl LayoutBlockContainer,
+        origin: Point<f32>,
+        line_width: f32,
+        line_offsets: &[Point<f32>],
+        static_children: &mut Vec<StaticChild>,
+    ) {
+        let is_rtl = self.direction.is_rtl();
+
+        let mut box_fragments: Vec<PlacedFragment> = Vec::new();
+        let mut leaf_fragments: Vec<PlacedFragment> = Vec::new();
+
+        let mut extents: Vec<Option<FragmentExtent>> = self.boxes.iter().map(|_| None).collect();
+        for (line_index, line) in self.lines.iter().enumerate() {
+            let line_offset = line_offsets.get(line_index).copied().unwrap_or(Point::ZERO);
+            let band = line.band.unwrap_or(Line { start: 0.0, end: line_width });
+            let free_space = f32_max(band.end - band.start - line.width, 0.0);
+            let align_offset = match (self.text_align, is_rtl) {
+                (TextAlign::Auto, _) => 0.0,
+                (TextAlign::End, _) => free_space,
+                (TextAlign::Center | TextAlign::LegacyCenter, _) => free_space / 2.0,
+                (TextAlign::Left | TextAlign::LegacyLeft, false)
+                | (TextAlign::Right | TextAlign::LegacyRight, true) => 0.0,
+                (TextAlign::Left | TextAlign::LegacyLeft, true)
+                | (TextAlign::Right | TextAlign::LegacyRight, false) => free_space,
+            };
+            // Maps a span of the line (as an inline-start offset and a width) to a physical x coordinate
+            let to_physical_x = |x: f32, width: f32| match is_rtl {
+                true => band.end - (align_offset + x) - width,
+                false => band.start + align_offset + x,
+            };
+
+            extents.iter_mut().for_each(|extent| *extent = None);
+            for piece in &self.pieces[line.start..line.end] {
+                let top = match piece.vertical_align {
+                    VerticalAlign::Baseline => line.baseline - piece.ascent,
+                    VerticalAlign::Top => 0.0,
+                    VerticalAlign::Middle => (line.height - piece.ascent - piece.descent) / 2.0,
+                    VerticalAlign::Bottom => line.height - piece.ascent - piece.descent,
+                } + line.top;
+                let bottom = top + piece.ascent + piece.descent;
+
+                // The content of the piece (which contributes to the size of the fragments of its ancestor boxes)
+                let content = match piece.kind {
+                    PieceKind::BoxStart(index) | PieceKind::BoxEnd(index) => {
+                        let extent = extents[index].get_or_insert(FragmentExtent::EMPTY);
+                        let margin = self.boxes[index].margin;
+                        match (piece.kind, is_rtl) {
+                            (PieceKind::BoxStart(_), false) => extent.box_start = Some(piece.x + margin.left),
+                            (PieceKind::BoxStart(_), true) => extent.box_start = Some(piece.x + margin.right),
+                            (_, false) => extent.box_end = Some(piece.x + piece.width - margin.right),
+                            (_, true) => extent.box_end = Some(piece.x + piece.width - margin.left),
+                        }
+                        None
+                    }
+                    PieceKind::Text { leaf, start, end } => {
+                        let rect = Rect {
+                            left: to_physical_x(piece.x, piece.width),
+                            right: to_physical_x(piece.x, piece.width) + piece.width,
+                            top,
+                            bottom,
+                        };
+                        leaf_fragments.push(PlacedFragment::new(leaf, line_index, line, line_offset, rect, start..end));
+                        Some(Line { start: top, end: bottom })
+                    }
+                    PieceKind::Atomic { leaf, .. } => {
+                        let margin = self.leaves[leaf].margin;
+                        let (start_margin, end_margin) =
+                            if is_rtl { (margin.right, margin.left) } else { (margin.left, margin.right) };
+                        let x = piece.x + start_margin;
+                        let width = piece.width - start_margin - end_margin;
+                        let rect = Rect {
+                            left: to_physical_x(x, width),
+                            right: to_physical_x(x, width) + width,
+                            top: top + margin.top,
+                            bottom: bottom - margin.bottom,
+                        };
+                        leaf_fragments.push(PlacedFragment::new(leaf, line_index, line, line_offset, rect, 0..0));
+                        Some(Line { start: rect.top, end: rect.bottom })
+                    }
+                    PieceKind::Absolute { leaf } => {
+                        let x = to_physical_x(piece.x, 0.0);
+                        let rect = Rect { left: x, right: x, top: line.top, bottom: line.top };
+                        leaf_fragments.push(PlacedFragment::new(leaf, line_index, line, line_offset, rect, 0..0));
+                        None
+                    }
+                };
+
+                // Grow the fragments of the boxes which contain the piece
+                let mut ancestor = match piece.kind {
+                    PieceKind::BoxStart(index) | PieceKind::BoxEnd(index) => self.boxes[index].parent,
+                    _ => piece.parent,
+                };
+                let is_edge = matches!(piece.kind, PieceKind::BoxStart(_) | PieceKind::BoxEnd(_));
+                let piece_x = Line { start: piece.x, end: piece.x + piece.width };
+                if is_edge {
+                    // The edges of a box are part of the box's own fragment, but are content of its ancestors
+                    if let Some(index) = piece.parent {
+                        let extent = extents[index].get_or_insert(FragmentExtent::EMPTY);
+                        extent.content_x = union_of_lines(extent.content_x, piece_x);
+                    }
+                }
+                while let Some(index) = ancestor {
+                    let extent = extents[index].get_or_insert(FragmentExtent::EMPTY);
+                    extent.content_x = union_of_lines(extent.content_x, piece_x);
+                    if let Some(content) = content {
+                        extent.content_y = Some(extent.content_y.map_or(content, |y| union_of_lines(y, content)));
+                    }
+                    ancestor = self.boxes[index].parent;
+                }
+            }
+
+            // Record the fragment of each box on the line
+            for (index, extent) in extents.iter().enumerate() {
+                let Some(extent) = extent else { continue };
+                let inline_box = &self.boxes[index];
+                let start = extent.box_start.unwrap_or(extent.content_x.start);
+                let end = f32_max(extent.box_end.unwrap_or(extent.content_x.end), start);
+                let content_y =
+                    extent.content_y.unwrap_or(Line { start: line.top + line.baseline, end: line.top + line.baseline });
+                let left = to_physical_x(start, end - start);
+                let rect = Rect {
+                    left,
+                    right: left + end - start,
+                    top: content_y.start - inline_box.padding.top - inline_box.border.top,
+                    bottom: content_y.end + inline_box.padding.bottom + inline_box.border.bottom,
+                };
+                box_fragments.push(PlacedFragment::new(index, line_index, line, line_offset, rect, 0..0));
+            }
+        }
+
+        // Compute the position of each inline box relative to the container, so that the positions of its children can be
+        // made relative to it
+        let box_rects: Vec<Rect<f32>> = (0..self.boxes.len())
+            .map(|index| union_of_fragments(&box_fragments, index).map_or(Rect::ZERO, |rect| offset_rect(rect, origin)))
+            .collect();
+        let parent_location = |parent: Option<usize>| match parent {
+            Some(index) => Point { x: box_rects[index].left, y: box_rects[index].top },
+            None => Point::ZERO,
+        };
+
+        for (index, inline_box) in self.boxes.iter().enumerate() {
+            let rect = box_rects[index];
+            let parent_location = parent_location(inline_box.parent);
+            let size = Size { width: rect.right - rect.left, height: rect.bottom - rect.top };
+            tree.set_unrounded_layout(
+                inline_box.node_id,
+                &Layout {
+                    order: inline_box.order,
+                    location: Point { x: rect.left - parent_location.x, y: rect.top - parent_location.y },
+                    size,
+                    #[cfg(feature = "content_size")]
+                    content_size: size,
+                    scrollbar_size: Size::ZERO,
+                    border: inline_box.border,
+                    padding: inline_box.padding,
+                    margin: inline_box.margin,
+                    visibility: inline_box.visibility,
+                    ..Layout::new()
+                },
+            );
+            set_inline_fragments(tree, inline_box.node_id, &box_fragments, index, origin, rect);
+        }
+
+        for (index, leaf) in self.leaves.iter().enumerate().filter(|(_, leaf)| leaf.kind != LeafKind::Float) {
+            let parent_location = parent_location(leaf.parent);
+            let Some(rect) = union_of_fragments(&leaf_fragments, index) else {
+                tree.set_unrounded_layout(
+                    leaf.node_id,
+                    &Layout { visibility: leaf.visibility, ..Layout::with_order(leaf.order) },
+                );
+                continue;
+            };
+            let rect = offset_rect(rect, origin);
+            let location = Point { x: rect.left - parent_location.x, y: rect.top - parent_location.y };
+
+            match leaf.kind {
+                LeafKind::Inline => {
+                    let size = Size { width: rect.right - rect.left, height: rect.bottom - rect.top };
+                    tree.set_unrounded_layout(
+                        leaf.node_id,
+                        &Layout {
+                            location,
+                            size,
+                            #[cfg(feature = "content_size")]
+                            content_size: size,
+                            visibility: leaf.visibility,
+                            ..Layout::with_order(leaf.order)
+                        },
+                    );
+                    set_inline_fragments(tree, leaf.node_id, &leaf_fragments, index, origin, rect);
+                }
+                LeafKind::Atomic => {
+                    #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+                    let Some((inputs, output)) = self.pieces.iter().find_map(|piece| match piece.kind {
+                        PieceKind::Atomic { leaf, inputs, output } if leaf == index => Some((inputs, output)),
+                        _ => None,
+                    }) else {
+                        continue;
+                    };
+                    let size = Size { width: rect.right - rect.left, height: rect.bottom - rect.top };
+
+                    // The containing block of the absolutely positioned descendants of a static leaf is the padding box
+                    // of its nearest positioned inline box, or that of the container's if there is none
+                    if leaf.position == Position::Static {
+                        let mut ancestor = leaf.parent;
+                        while let Some(index) = ancestor.filter(|&index| self.boxes[index].position == Position::Static)
+                        {
+                            ancestor = self.boxes[index].parent;
+                        }
+                        static_children.push(StaticChild {
+                            node_id: leaf.node_id,
+                            inputs,
+                            location: Point { x: rect.left, y: rect.top },
+                            size,
+                            inline_containing_block: ancestor.map(|index| {
+                                let (box_rect, border) = (box_rects[index], self.boxes[index].border);
+                                Rect {
+                                    left: box_rect.left + border.left,
+                                    right: box_rect.right - border.right,
+                                    top: box_rect.top + border.top,
+                                    bottom: box_rect.bottom - border.bottom,
+                                }
+                            }),
+                        });
+                    }
+
+                    tree.set_unrounded_layout(
+                        leaf.node_id,
+                        &Layout {
+                            order: leaf.order,
+                            location,
+                            size,
+                            #[cfg(feature = "content_size")]
+                            content_size: output.content_size,
+                            scrollbar_size: Size::ZERO,
+                            border: leaf.border,
+                            padding: leaf.padding,
+                            margin: leaf.margin,
+                            visibility: leaf.visibility,
+                            ..Layout::new()
+                        },
+                    );
+                }
+                LeafKind::Float => {}
+                LeafKind::Absolute => {
+                    let output = tree.perform_child_layout(
+                        leaf.node_id,
+                        Size::NONE,
+                        Size { width: Some(line_width), height: None },
+                        Size::MAX_CONTENT,
+                        SizingMode::InherentSize,
+                        Line::FALSE,
+                    );
+                    let x_offset = match self.direction {
+                        Direction::Ltr => leaf.margin.left,
+                        Direction::Rtl => -leaf.margin.right - output.size.width,
+                    };
+                    tree.set_unrounded_layout(
+                        leaf.node_id,
+                        &Layout {
+                            order: leaf.order,
+                            location: Point { x: location.x + x_offset, y: location.y + leaf.margin.top },
+                            size: output.size,
+                            #[cfg(feature = "content_size")]
+                            content_size: output.content_size,
+                            scrollbar_size: Size::ZERO,
+                            border: leaf.border,
+                            padding: leaf.padding,
+                            margin: leaf.margin,
+                            visibility: leaf.visibility,
+                            ..Layout::new()
+                        },
+                    );
+                }
+            }
+        }
+
+        // Floats are positioned relative to the container rather than to the lines
+        for float in &self.floats {
+            let leaf = &self.leaves[float.leaf];
+            let location = self.float_location(float, line_offsets);
+            if leaf.position == Position::Static {
+                static_children.push(StaticChild {
+                    node_id: leaf.node_id,
+                    inputs: float.inputs,
+                    location,
+                    size: float.output.size,
+                    inline_containing_block: None,
+                });
+            }
+            tree.set_unrounded_layout(
+                leaf.node_id,
+                &Layout {
+                    order: leaf.order,
+                    location,
+                    size: float.output.size,
+                    #[cfg(feature = "content_size")]
+                    content_size: float.output.content_size,
+                    scrollbar_size: float.output.scrollbar_size,
+                    border: leaf.border,
+                    padding: leaf.padding,
+                    margin: leaf.margin,
+                    visibility: leaf.visibility,
+                    ..Layout::new()
+                },
+            );
+        }
+
+        for &(node, order) in &self.hidden {
+            tree.set_unrounded_layout(node, &Layout::with_order(order));
+            tree.perform_child_layout(
+                node,
+                Size::NONE,
+                Size::NONE,
+                Size::MAX_CONTENT,
+                SizingMode::InherentSize,
+                Line::FALSE,
+            );
+        }
+    }
+}
+
+impl FragmentExtent {
+    /// A fragment with no content
+    const EMPTY: Self = Self {
+        box_start: None,
+        box_end: None,
+        content_x: Line { start: f32::INFINITY, end: f32::NEG_INFINITY },
+        content_y: None,
+    };
+}
+
+/// A fragment of an inline box or leaf which has been placed on a line
+#[derive(Clone)]
+struct PlacedFragment {
+    /// The index of the inline box or leaf which the fragment belongs to
+    owner: usize,
+    /// The index of the line on which the fragment is placed
+    line: u32,
+    /// The border box of the fragment, relative to the top-left corner of the first line (after the line has been moved
+    /// into its column, in multi-column containers)
+    rect: Rect<f32>,
+    /// The baseline of the line, relative to the top of the first line
+    baseline: f32,
+    /// For text runs, the range of the run's text in the fragment
+    text: Range<usize>,
+}
+
+impl PlacedFragment {
+    /// Create a fragment of the inline box or leaf `owner` on the specified line, which is moved by `line_offset`
+    fn new(
+        owner: usize,
+        line_index: usize,
+        line: &LineBox,
+        line_offset: Point<f32>,
+        rect: Rect<f32>,
+        text: Range<usize>,
+    ) -> Self {
+        let rect = offset_rect(rect, line_offset);
+        Self { owner, line: line_index as u32, rect, baseline: line.top + line.baseline + line_offset.y, text }
+    }
+}
+
+/// Set the inline fragments of `node` (the inline box or leaf `owner`) relative to its border box `node_rect`
+fn set_inline_fragments(
+    tree: &mut impl LayoutBlockContainer,
+    node: NodeId,
+    fragments: &[PlacedFragment],
+    owner: usize,
+    origin: Point<f32>,
+    node_rect: Rect<f32>,
+) {
+    let fragments: Vec<InlineFragment> = fragments
+        .iter()
+        .filter(|fragment| fragment.owner == owner)
+        .map(|fragment| {
+            let rect = offset_rect(fragment.rect, origin);
+            InlineFragment {
+                line: fragment.line,
+                location: Point { x: rect.left - node_rect.left, y: rect.top - node_rect.top },
+                size: Size { width: rect.right - rect.left, height: rect.bottom - rect.top },
+                baseline: fragment.baseline + origin.y - rect.top,
+                text_start: fragment.text.start,
+                text_end: fragment.text.end,
+            }
+        })
+        .collect();
+    tree.set_inline_fragments(node, &fragments);
+}
+
+/// The smallest line which contains both lines
+fn union_of_lines(a: Line<f32>, b: Line<f32>) -> Line<f32> {
+    Line { start: f32_min(a.start, b.start), end: f32_max(a.end, b.end) }
+}
+
+/// The smallest rect which contains all of the fragments of the inline box or leaf `owner`, or `None` if there are
+/// no such fragments
+fn union_of_fragments(fragments: &[PlacedFragment], owner: usize) -> Option<Rect<f32>> {
+    let mut rects = fragments.iter().filter(|fragment| fragment.owner == owner).map(|fragment| fragment.rect);
+    let first = rects.next()?;
+    Some(rects.fold(first, |union, rect| Rect {
+        left: f32_min(union.left, rect.left),
+        right: f32_max(union.right, rect.right),
+        top: f32_min(union.top, rect.top),
+        bottom: f32_max(union.bottom, rect.bottom),
+    }))
+}
+
+/// Offsets a rect by a point
+fn offset_rect(rect: Rect<f32>, offset: Point<f32>) -> Rect<f32> {
+    Rect {
+        left: rect.left + offset.x,
+        right: rect.right + offset.x,
+        top: rect.top + offset.y,
+        bottom: rect.bottom + offset.y,
+    }
+}
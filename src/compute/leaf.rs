@@ -1,7 +1,9 @@
 //! Computes size using styles and measure functions
 
-use crate::geometry::{Point, Size};
-use crate::style::{AvailableSpace, Overflow, Position};
+use super::common::scrollbars::{compute_with_scrollbars, Scrollbars};
+use super::common::sizing_keywords::{compute_with_sizing_keywords, SizingKeywords};
+use crate::geometry::{Point, Rect, Size};
+use crate::style::{AvailableSpace, CompactLength, RelativeLengthBasis};
 use crate::tree::{CollapsibleMarginSet, RunMode};
 use crate::tree::{LayoutInput, LayoutOutput, SizingMode};
 use crate::util::debug::debug_log;
@@ -12,15 +14,44 @@ use crate::{BoxSizing, CoreStyle};
 use core::unreachable;
 
 /// Compute the size of a leaf node (node with no children)
+///
+/// The measure function may be called more than once if the node's size styles use intrinsic sizing keywords, which
+/// are resolved by measuring the node under the corresponding available space.
+///
+/// Viewport-relative and font-relative lengths in the node's styles are resolved against `relative_length_basis`
+/// (which can be obtained with [`LayoutPartialTree::get_relative_length_basis`](crate::LayoutPartialTree::get_relative_length_basis)),
+/// and `calc()` values with `resolve_calc_value`.
 pub fn compute_leaf_layout<MeasureFunction>(
     inputs: LayoutInput,
     style: &impl CoreStyle,
+    relative_length_basis: RelativeLengthBasis,
     resolve_calc_value: impl Fn(u64, f32) -> f32,
-    measure_function: MeasureFunction,
+    mut measure_function: MeasureFunction,
 ) -> LayoutOutput
 where
-    MeasureFunction: FnOnce(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32>,
+    MeasureFunction: FnMut(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32>,
 {
+    let resolve_calc_value = |val, basis| match relative_length_basis.resolve(CompactLength::from_calc_value(val)) {
+        Some(size) => size,
+        None => resolve_calc_value(val, basis),
+    };
+    let sizing_keywords = SizingKeywords::new(style, inputs.parent_size, resolve_calc_value);
+    let scrollbars = Scrollbars::new(style, inputs.parent_size.width, resolve_calc_value);
+    compute_with_sizing_keywords(sizing_keywords, inputs, |inputs| {
+        compute_with_scrollbars(scrollbars, |scrollbar_gutter| {
+            compute_inner(inputs, style, resolve_calc_value, &mut measure_function, scrollbar_gutter)
+        })
+    })
+}
+
+/// Compute the size of a leaf node, treating intrinsic sizing keywords in its size styles as `auto`
+fn compute_inner(
+    inputs: LayoutInput,
+    style: &impl CoreStyle,
+    resolve_calc_value: impl Fn(u64, f32) -> f32,
+    mut measure_function: impl FnMut(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32>,
+    scrollbar_gutter: Rect<f32>,
+) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, available_space, sizing_mode, run_mode, .. } = inputs;
 
     // Note: both horizontal and vertical percentage padding/borders are resolved against the container's inline size (i.e. width).
@@ -61,22 +92,12 @@ where
         }
     };
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow().transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width(),
-        _ => 0.0,
-    });
-    // TODO: make side configurable based on the `direction` property
-    let mut content_box_inset = padding_border;
-    content_box_inset.right += scrollbar_gutter.x;
-    content_box_inset.bottom += scrollbar_gutter.y;
+    let content_box_inset = padding_border + scrollbar_gutter;
 
     let has_styles_preventing_being_collapsed_through = !style.is_block()
         || style.overflow().x.is_scroll_container()
         || style.overflow().y.is_scroll_container()
-        || style.position() == Position::Absolute
+        || style.position().is_absolutely_positioned()
         || padding.top > 0.0
         || padding.bottom > 0.0
         || border.top > 0.0
@@ -99,6 +120,7 @@ where
                 size,
                 #[cfg(feature = "content_size")]
                 content_size: Size::ZERO,
+                scrollbar_size: Size::ZERO,
                 first_baselines: Point::NONE,
                 top_margin: CollapsibleMarginSet::ZERO,
                 bottom_margin: CollapsibleMarginSet::ZERO,
@@ -156,6 +178,7 @@ where
         size,
         #[cfg(feature = "content_size")]
         content_size: measured_size + padding.sum_axes(),
+        scrollbar_size: Size::ZERO,
         first_baselines: Point::NONE,
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
@@ -9,6 +9,7 @@
 //! | [`compute_flexbox_layout`]        | Layout a Flexbox container and it's direct children                                                                                                                                                |
 //! | [`compute_grid_layout`]           | Layout a CSS Grid container and it's direct children                                                                                                                                               |
 //! | [`compute_block_layout`]          | Layout a Block container and it's direct children                                                                                                                                                  |
+//! | [`compute_table_layout`]          | Layout a Table container and it's row groups, rows, cells and captions                                                                                                                             |
 //! | [`compute_leaf_layout`]           | Applies common properties like padding/border/aspect-ratio to a node before deferring to a passed closure to determine it's size. Can be applied to nodes like text or image nodes.                |
 //! | [`compute_root_layout`]           | Layout the root node of a tree (regardless of it's layout mode). This function is typically called once to begin a layout run.                                                                     |                                                                      |
 //! | [`compute_hidden_layout`]         | Mark a node as hidden during layout (like `Display::None`)                                                                                                                                         |
@@ -18,21 +19,37 @@
 //!
 //! | Function                          | Requires                                                                                                                                                                                           | Purpose                                                              |
 //! | ---                               | ---                                                                                                                                                                                                | ---                                                                  |
+//! | [`compute_root_layout_with_positions`] | [`LayoutPartialTree`] + [`RoundTree`]                                                                                                                                                    | Layout the root node with [`compute_root_layout`], then run [`compute_fixed_and_sticky_positions`]                      |
+//! | [`compute_fixed_and_sticky_positions`] | [`LayoutPartialTree`] + [`RoundTree`]                                                                                                                                                    | Position `Fixed`, `Sticky` and anchored nodes against the viewport, scroll offsets and anchors                          |
 //! | [`round_layout`]                  | [`RoundTree`]                                                                                                                                                                                      | Round a tree of float-valued layouts to integer pixels               |
 //! | [`print_tree`](crate::print_tree) | [`PrintTree`](crate::PrintTree)                                                                                                                                                                    | Print a debug representation of a node tree and it's computed layout |
 //!
 pub(crate) mod common;
+pub(crate) mod fixed_and_sticky;
 pub(crate) mod leaf;
 
 #[cfg(feature = "block_layout")]
 pub(crate) mod block;
 
+#[cfg(feature = "block_layout")]
+pub(crate) mod float;
+
+#[cfg(feature = "block_layout")]
+pub(crate) mod inline;
+
+#[cfg(feature = "block_layout")]
+pub(crate) mod multicol;
+
 #[cfg(feature = "flexbox")]
 pub(crate) mod flexbox;
 
 #[cfg(feature = "grid")]
 pub(crate) mod grid;
 
+#[cfg(feature = "table_layout")]
+pub(crate) mod table;
+
+pub use fixed_and_sticky::compute_fixed_and_sticky_positions;
 pub use leaf::compute_leaf_layout;
 
 #[cfg(feature = "block_layout")]
@@ -42,12 +59,16 @@ pub use self::block::compute_block_layout;
 pub use self::flexbox::compute_flexbox_layout;
 
 #[cfg(feature = "grid")]
-pub use self::grid::compute_grid_layout;
+pub use self::grid::{compute_grid_layout, SubgridTracks};
+
+#[cfg(feature = "table_layout")]
+pub use self::table::compute_table_layout;
 
 use crate::geometry::{Line, Point, Size};
-use crate::style::{AvailableSpace, CoreStyle, Overflow};
+use crate::style::{AvailableSpace, CoreStyle};
 use crate::tree::{
-    Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutPartialTreeExt, NodeId, RoundTree, SizingMode,
+    ContainingBlock, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutPartialTreeExt, NodeId, RequestedAxis,
+    RoundTree, RunMode, SizingMode,
 };
 use crate::util::debug::{debug_log, debug_log_node, debug_pop_node, debug_push_node};
 use crate::util::sys::round;
@@ -55,6 +76,10 @@ use crate::util::ResolveOrZero;
 use crate::{BoxSizing, CacheTree, MaybeMath, MaybeResolve};
 
 /// Compute layout for the root node in the tree
+///
+/// Viewport-relative lengths in the styles of the tree are resolved against [`LayoutPartialTree::get_viewport_size`].
+/// Fixed, sticky and anchored nodes, scroll offsets and scrollable overflow are not resolved, as that requires access to
+/// the whole tree: see [`compute_root_layout_with_positions`].
 pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, available_space: Size<AvailableSpace>) {
     let mut known_dimensions = Size::NONE;
 
@@ -66,26 +91,26 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
         if style.is_block() {
             // Pull these out earlier to avoid borrowing issues
             let aspect_ratio = style.aspect_ratio();
-            let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
-            let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
-            let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+            let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(root, val, basis));
+            let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(root, val, basis));
+            let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(root, val, basis));
             let padding_border_size = (padding + border).sum_axes();
             let box_sizing_adjustment =
                 if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
 
             let min_size = style
                 .min_size()
-                .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+                .maybe_resolve(parent_size, |val, basis| tree.calc(root, val, basis))
                 .maybe_apply_aspect_ratio(aspect_ratio)
                 .maybe_add(box_sizing_adjustment);
             let max_size = style
                 .max_size()
-                .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+                .maybe_resolve(parent_size, |val, basis| tree.calc(root, val, basis))
                 .maybe_apply_aspect_ratio(aspect_ratio)
                 .maybe_add(box_sizing_adjustment);
             let clamped_style_size = style
                 .size()
-                .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+                .maybe_resolve(parent_size, |val, basis| tree.calc(root, val, basis))
                 .maybe_apply_aspect_ratio(aspect_ratio)
                 .maybe_add(box_sizing_adjustment)
                 .maybe_clamp(min_size, max_size);
@@ -96,9 +121,13 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
                 _ => None,
             });
 
-            // Block nodes automatically stretch fit their width to fit available space if available space is definite
+            // Block nodes automatically stretch fit their width to fit available space if available space is definite,
+            // unless their width is an intrinsic sizing keyword (which is resolved by the block layout algorithm)
             let available_space_based_size = Size {
-                width: available_space.width.into_option().maybe_sub(margin.horizontal_axis_sum()),
+                width: match style.size().width.is_intrinsic_sizing_keyword() {
+                    true => None,
+                    false => available_space.width.into_option().maybe_sub(margin.horizontal_axis_sum()),
+                },
                 height: None,
             };
 
@@ -112,27 +141,30 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
         }
     }
 
-    // Recursively compute node layout
-    let output = tree.perform_child_layout(
+    // Recursively compute node layout. The root is the containing block of its absolutely positioned descendants
+    // regardless of its position.
+    let output = tree.compute_child_layout(
         root,
-        known_dimensions,
-        available_space.into_options(),
-        available_space,
-        SizingMode::InherentSize,
-        Line::FALSE,
+        LayoutInput {
+            known_dimensions,
+            parent_size: available_space.into_options(),
+            available_space,
+            sizing_mode: SizingMode::InherentSize,
+            axis: RequestedAxis::Both,
+            run_mode: RunMode::PerformLayout,
+            vertical_margins_are_collapsible: Line::FALSE,
+            containing_block: ContainingBlock::Own,
+        },
     );
 
     let style = tree.get_core_container_style(root);
     let padding =
-        style.padding().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis));
+        style.padding().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(root, val, basis));
     let border =
-        style.border().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis));
+        style.border().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(root, val, basis));
     let margin =
-        style.margin().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis));
-    let scrollbar_size = Size {
-        width: if style.overflow().y == Overflow::Scroll { style.scrollbar_width() } else { 0.0 },
-        height: if style.overflow().x == Overflow::Scroll { style.scrollbar_width() } else { 0.0 },
-    };
+        style.margin().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(root, val, basis));
+    let visibility = style.visibility();
     drop(style);
 
     tree.set_unrounded_layout(
@@ -143,15 +175,42 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
             size: output.size,
             #[cfg(feature = "content_size")]
             content_size: output.content_size,
-            scrollbar_size,
+            scrollbar_size: output.scrollbar_size,
             padding,
             border,
             // TODO: support auto margins for root node?
             margin,
+            visibility,
+            ..Layout::new()
         },
     );
 }
 
+/// Compute layout for the root node in the tree with [`compute_root_layout`], and then resolve the parts of the layout
+/// which depend on scroll offsets, on the viewport and on anchors with [`compute_fixed_and_sticky_positions`]
+///
+/// Unlike [`compute_root_layout`], this requires access to the whole tree, but it fills in the fields of [`Layout`] which
+/// are only known once the whole tree has been laid out ([`Layout::scroll_offset`], [`Layout::scrolled_location`] and
+/// the scrollable overflow rectangle). This is what [`TaffyTree`](crate::TaffyTree) uses to compute layout.
+///
+/// Fixed nodes are positioned against `viewport_size`, or if it is `None` against the available space (or the size of
+/// the root node in axes in which the available space is not definite).
+pub fn compute_root_layout_with_positions(
+    tree: &mut (impl LayoutPartialTree + RoundTree),
+    root: NodeId,
+    available_space: Size<AvailableSpace>,
+    viewport_size: Option<Size<f32>>,
+) {
+    compute_root_layout(tree, root, available_space);
+
+    let root_size = tree.get_unrounded_layout(root).size;
+    let viewport_size = viewport_size.unwrap_or(Size {
+        width: available_space.width.into_option().unwrap_or(root_size.width),
+        height: available_space.height.into_option().unwrap_or(root_size.height),
+    });
+    compute_fixed_and_sticky_positions(tree, root, viewport_size);
+}
+
 /// Attempts to find a cached layout for the specified node and layout inputs.
 ///
 /// Uses the provided closure to compute the layout (and then stores the result in the cache) if no cached layout is found.
@@ -168,8 +227,12 @@ where
     debug_push_node!(node);
     let LayoutInput { known_dimensions, available_space, run_mode, .. } = inputs;
 
-    // First we check if we have a cached result for the given input
-    let cache_entry = tree.cache_get(node, known_dimensions, available_space, run_mode);
+    // First we check if we have a cached result for the given input. The cache is not keyed by the containing block, so
+    // a layout against an ancestor's containing block is always recomputed (its result is still cached for sizing).
+    let cache_entry = match inputs.containing_block {
+        ContainingBlock::Ancestor(_) => None,
+        _ => tree.cache_get(node, known_dimensions, available_space, run_mode),
+    };
     if let Some(cached_size_and_baselines) = cache_entry {
         debug_log_node!(known_dimensions, inputs.parent_size, available_space, run_mode, inputs.sizing_mode);
         debug_log!("RESULT (CACHED)", dbg:cached_size_and_baselines.size);
@@ -215,6 +278,18 @@ pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
 
         layout.location.x = round(unrounded_layout.location.x);
         layout.location.y = round(unrounded_layout.location.y);
+        // Round such that subtracting the sticky offset from the rounded location gives the rounded normal position
+        layout.sticky_offset.x =
+            layout.location.x - round(unrounded_layout.location.x - unrounded_layout.sticky_offset.x);
+        layout.sticky_offset.y =
+            layout.location.y - round(unrounded_layout.location.y - unrounded_layout.sticky_offset.y);
+        // Round such that subtracting the rounded scroll adjustment from the rounded location gives the scrolled location
+        layout.scroll_offset.x = round(unrounded_layout.scroll_offset.x);
+        layout.scroll_offset.y = round(unrounded_layout.scroll_offset.y);
+        layout.scrolled_location.x =
+            layout.location.x - round(unrounded_layout.location.x - unrounded_layout.scrolled_location.x);
+        layout.scrolled_location.y =
+            layout.location.y - round(unrounded_layout.location.y - unrounded_layout.scrolled_location.y);
         layout.size.width = round(cumulative_x + unrounded_layout.size.width) - round(cumulative_x);
         layout.size.height = round(cumulative_y + unrounded_layout.size.height) - round(cumulative_y);
         layout.scrollbar_size.width = round(unrounded_layout.scrollbar_size.width);
@@ -233,7 +308,7 @@ pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
             - round(cumulative_y + unrounded_layout.size.height - unrounded_layout.padding.bottom);
 
         #[cfg(feature = "content_size")]
-        round_content_size(&mut layout, unrounded_layout.content_size, cumulative_x, cumulative_y);
+        round_content_size(&mut layout, &unrounded_layout, cumulative_x, cumulative_y);
 
         tree.set_final_layout(node_id, &layout);
 
@@ -246,16 +321,21 @@ pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
 
     #[cfg(feature = "content_size")]
     #[inline(always)]
-    /// Round content size variables.
+    /// Round content size and scrollable overflow variables.
     /// This is split into a separate function to make it easier to feature flag.
-    fn round_content_size(
-        layout: &mut Layout,
-        unrounded_content_size: Size<f32>,
-        cumulative_x: f32,
-        cumulative_y: f32,
-    ) {
-        layout.content_size.width = round(cumulative_x + unrounded_content_size.width) - round(cumulative_x);
-        layout.content_size.height = round(cumulative_y + unrounded_content_size.height) - round(cumulative_y);
+    fn round_content_size(layout: &mut Layout, unrounded_layout: &Layout, cumulative_x: f32, cumulative_y: f32) {
+        layout.content_size.width = round(cumulative_x + unrounded_layout.content_size.width) - round(cumulative_x);
+        layout.content_size.height = round(cumulative_y + unrounded_layout.content_size.height) - round(cumulative_y);
+
+        // Round the edges of the scrollable overflow rectangle in the same way as those of the node's border box
+        let overflow_x = cumulative_x + unrounded_layout.scrollable_overflow_location.x;
+        let overflow_y = cumulative_y + unrounded_layout.scrollable_overflow_location.y;
+        layout.scrollable_overflow_location.x = round(overflow_x) - round(cumulative_x);
+        layout.scrollable_overflow_location.y = round(overflow_y) - round(cumulative_y);
+        layout.scrollable_overflow_size.width =
+            round(overflow_x + unrounded_layout.scrollable_overflow_size.width) - round(overflow_x);
+        layout.scrollable_overflow_size.height =
+            round(overflow_y + unrounded_layout.scrollable_overflow_size.height) - round(overflow_y);
     }
 }
 
@@ -280,6 +360,8 @@ pub fn compute_hidden_layout(tree: &mut (impl LayoutPartialTree + CacheTree), no
 pub mod detailed_info {
     #[cfg(feature = "grid")]
     pub use super::grid::DetailedGridInfo;
+    #[cfg(feature = "block_layout")]
+    pub use super::multicol::{DetailedMulticolInfo, DetailedMulticolItemInfo, MulticolFragment};
 }
 
 #[cfg(test)]
@@ -0,0 +1,262 @@
+//! Computes the column geometry of multi-column containers, and the fragmentation of their content into columns.
+//! See <https://www.w3.org/TR/css-multicol-1/>.
+//!
+//! The content of a multi-column container is laid out by the [block layout algorithm](super::block) as a single
+//! column, and is then divided into columns of equal height. The height of the columns is balanced: it is the smallest
+//! height at which the content fits into the used number of columns (or the height of the container, if that is smaller,
+//! in which case the remaining content is placed in overflow columns after the last column).
+//!
+//! Columns may be broken between the container's block-level children and between the lines of its inline-level
+//! content. Block-level children are not fragmented themselves: a child which is taller than a column is sliced across
+//! consecutive columns, and is positioned at its first fragment (see [`MulticolFragment`]).
+#[cfg(feature = "detailed_layout_info")]
+use crate::geometry::Size;
+use crate::geometry::{Line, Point};
+use crate::style::{AvailableSpace, Direction};
+use crate::util::sys::{f32_max, f32_min, Vec};
+
+/// The tolerance used when comparing the heights of content and columns
+const EPSILON: f32 = 0.001;
+
+/// The used number and width of the columns of a multi-column container
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ColumnGeometry {
+    /// The used number of columns
+    pub count: u16,
+    /// The used width of each column
+    pub width: f32,
+    /// The width of the gap between adjacent columns
+    pub gap: f32,
+    /// The width of the container's content box
+    content_width: f32,
+}
+
+impl ColumnGeometry {
+    /// Determine the used number and width of the columns from the width of the container's content box.
+    /// See <https://www.w3.org/TR/css-multicol-1/#pseudo-algorithm>
+    pub(crate) fn resolve(
+        content_width: f32,
+        column_count: Option<u16>,
+        column_width: Option<f32>,
+        gap: f32,
+    ) -> ColumnGeometry {
+        let content_width = f32_max(content_width, 0.0);
+        let count = match (column_count, column_width) {
+            (Some(count), None) => count.max(1),
+            (count, Some(column_width)) => {
+                // Casting truncates (and saturates), flooring the number of columns which fit
+                let fitting_count = f32_max((content_width + gap) / f32_max(column_width + gap, EPSILON), 1.0) as u16;
+                count.map_or(fitting_count, |count| count.max(1).min(fitting_count))
+            }
+            (None, None) => 1,
+        };
+        let width = f32_max((content_width + gap) / count as f32 - gap, 0.0);
+        ColumnGeometry { count, width, gap, content_width }
+    }
+
+    /// The offset of the column with the specified index from a column which is laid out at the left edge of the
+    /// content box. Columns are placed from the inline-start edge of the content box, and overflow columns (whose
+    /// index is greater than or equal to the used column count) continue in the same direction.
+    pub(crate) fn inline_offset(&self, column: usize, direction: Direction) -> f32 {
+        let offset = column as f32 * (self.width + self.gap);
+        match direction {
+            Direction::Ltr => offset,
+            Direction::Rtl => self.content_width - self.width - offset,
+        }
+    }
+}
+
+/// Compute the intrinsic width of the content box of a multi-column container from the min-content and max-content
+/// widths of its content. See <https://www.w3.org/TR/css-multicol-1/#cw>
+pub(crate) fn compute_intrinsic_width(
+    column_count: Option<u16>,
+    column_width: Option<f32>,
+    gap: f32,
+    min_content_width: f32,
+    max_content_width: f32,
+    available_width: AvailableSpace,
+) -> f32 {
+    let min_content_width = column_width.map_or(min_content_width, |width| f32_min(width, min_content_width));
+    let count = column_count.unwrap_or(1).max(1) as f32;
+    let max_content_width = count * f32_max(column_width.unwrap_or(0.0), max_content_width) + (count - 1.0) * gap;
+    match available_width {
+        AvailableSpace::MinContent => min_content_width,
+        AvailableSpace::MaxContent => max_content_width,
+        AvailableSpace::Definite(width) => f32_min(max_content_width, f32_max(min_content_width, width)),
+    }
+}
+
+/// The division of the content of a multi-column container into columns
+#[derive(Debug, Clone)]
+pub(crate) struct ColumnBreaks {
+    /// The height of each column
+    pub height: f32,
+    /// The offset in the unfragmented flow of the container's content at which each column starts
+    starts: Vec<f32>,
+}
+
+impl ColumnBreaks {
+    /// Divide unbreakable pieces of content (the block-level children and lines of the container, as the extents of
+    /// their border boxes in the unfragmented flow) into `column_count` columns of balanced height, starting at the
+    /// offset `flow_start`. The height of the columns is limited to `max_height`, if specified.
+    pub(crate) fn balance(units: &[Line<f32>], flow_start: f32, column_count: u16, max_height: Option<f32>) -> Self {
+        let flow_end = units.iter().fold(flow_start, |end, unit| f32_max(end, unit.end));
+        let mut height = (flow_end - flow_start) / column_count.max(1) as f32;
+        if let Some(max_height) = max_height {
+            height = f32_max(f32_min(height, max_height), 0.0);
+        }
+
+        // Grow the columns by the smallest amount which would allow another piece of content to fit into an earlier
+        // column until the content fits into the used number of columns
+        loop {
+            let (starts, shortage) = Self::fill(units, flow_start, height);
+            let is_at_max_height = matches!(max_height, Some(max_height) if height >= max_height);
+            if starts.len() <= column_count as usize || shortage.is_none() || is_at_max_height {
+                return ColumnBreaks { height, starts };
+            }
+            height += shortage.unwrap_or(0.0);
+            if let Some(max_height) = max_height {
+                height = f32_min(height, max_height);
+            }
+        }
+    }
+
+    /// Fill columns of the specified height with the content in order, returning the offset at which each column starts
+    /// and the smallest amount by which the height of the columns would have to grow to avoid a break
+    fn fill(units: &[Line<f32>], flow_start: f32, height: f32) -> (Vec<f32>, Option<f32>) {
+        let mut starts = Vec::new();
+        starts.push(flow_start);
+        let mut column_start = flow_start;
+        let mut column_has_content = false;
+        let mut shortage: Option<f32> = None;
+        for unit in units {
+            let overflow = unit.end - column_start - height;
+            if overflow > EPSILON {
+                if column_has_content {
+                    shortage = Some(shortage.map_or(overflow, |shortage| f32_min(shortage, overflow)));
+                    column_start = f32_max(unit.start, column_start);
+                    starts.push(column_start);
+                }
+
+                // Content which is taller than a column is sliced across as many columns as it needs
+                let overflow = unit.end - column_start - height;
+                if overflow > EPSILON && height > EPSILON {
+                    shortage = Some(shortage.map_or(overflow, |shortage| f32_min(shortage, overflow)));
+                    while unit.end - column_start - height > EPSILON {
+                        column_start += height;
+                        starts.push(column_start);
+                    }
+                }
+            }
+            column_has_content = true;
+        }
+        (starts, shortage)
+    }
+
+    /// The number of columns which contain content
+    #[cfg(feature = "detailed_layout_info")]
+    pub(crate) fn column_count(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// The index of the column which contains the specified offset in the unfragmented flow
+    pub(crate) fn column_at(&self, offset: f32) -> usize {
+        self.starts.iter().rposition(|&start| start <= offset + EPSILON).unwrap_or(0)
+    }
+
+    /// The offset of content at the specified offset in the unfragmented flow from its position in the unfragmented
+    /// flow to its position in its column
+    pub(crate) fn offset_of(&self, offset: f32, geometry: &ColumnGeometry, direction: Direction) -> Point<f32> {
+        let column = self.column_at(offset);
+        Point { x: geometry.inline_offset(column, direction), y: self.starts[0] - self.starts[column] }
+    }
+
+    /// The fragments of a piece of content with the specified extent in the unfragmented flow, as the index of the
+    /// column containing each fragment and the extent of the fragment in the unfragmented flow
+    #[cfg(feature = "detailed_layout_info")]
+    fn fragments(&self, extent: Line<f32>) -> impl Iterator<Item = (usize, Line<f32>)> + '_ {
+        let first_column = self.column_at(extent.start);
+        (first_column..self.starts.len()).map_while(move |column| {
+            let start = if column == first_column { extent.start } else { self.starts[column] };
+            let end = match self.starts.get(column + 1) {
+                Some(&next_start) => f32_min(extent.end, next_start),
+                None => extent.end,
+            };
+            (column == first_column || start < extent.end).then_some((column, Line { start, end: f32_max(end, start) }))
+        })
+    }
+
+    /// The fragments of a child of the container whose border box has the specified width and extent in the
+    /// unfragmented flow, relative to the child's position in its first column
+    #[cfg(feature = "detailed_layout_info")]
+    pub(crate) fn child_fragments(
+        &self,
+        width: f32,
+        extent: Line<f32>,
+        geometry: &ColumnGeometry,
+        direction: Direction,
+    ) -> Vec<MulticolFragment> {
+        let first_offset = self.offset_of(extent.start, geometry, direction);
+        self.fragments(extent)
+            .map(|(column, fragment)| {
+                let x = geometry.inline_offset(column, direction) - first_offset.x;
+                let y = fragment.start + self.starts[0] - self.starts[column] - first_offset.y - extent.start;
+                MulticolFragment {
+                    column: column as u16,
+                    location: Point { x, y },
+                    size: Size { width, height: fragment.end - fragment.start },
+                    source_location: Point { x: 0.0, y: fragment.start - extent.start },
+                }
+            })
+            .collect()
+    }
+}
+
+/// Information about the columns of a multi-column container, and the fragments of its children
+#[derive(Debug, Clone, PartialEq)]
+#[cfg(feature = "detailed_layout_info")]
+pub struct DetailedMulticolInfo {
+    /// The used number of columns, as determined by the container's `column_count` and `column_width`
+    pub column_count: u16,
+    /// The number of columns which contain content. If the content does not fit into the used number of columns
+    /// within the height of the container then this is larger than `column_count`, and the additional overflow
+    /// columns are placed after the last column in the inline direction.
+    pub filled_column_count: u16,
+    /// The used width (inline size) of each column
+    pub column_width: f32,
+    /// The width (inline size) of the gap between adjacent columns
+    pub column_gap: f32,
+    /// The height (block size) of each column
+    pub column_height: f32,
+    /// The fragments of each child of the container, in child order. Only in-flow block-level children and floats
+    /// have fragments: the lines of inline-level content are placed into columns whole, and the fragments of
+    /// inline-level children are reported by [`LayoutBlockContainer::set_inline_fragments`](crate::LayoutBlockContainer::set_inline_fragments).
+    pub items: Vec<DetailedMulticolItemInfo>,
+}
+
+/// The fragments of a child of a multi-column container
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg(feature = "detailed_layout_info")]
+pub struct DetailedMulticolItemInfo {
+    /// The fragments of the child, in the order of the columns which contain them
+    pub fragments: Vec<MulticolFragment>,
+}
+
+/// A fragment of a child of a multi-column container: the part of the child which is placed in one column.
+///
+/// The child's layout is the layout of its first fragment, and is not affected by the fragmentation. When a child is
+/// taller than the space remaining in its column, the rest of it is shown in subsequent fragments: each fragment shows
+/// the part of the child's border box with the fragment's size at `source_location` at the fragment's `location`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg(feature = "detailed_layout_info")]
+pub struct MulticolFragment {
+    /// The index of the column which contains the fragment
+    pub column: u16,
+    /// The top-left corner of the fragment, relative to the top-left corner of the child's layout
+    pub location: Point<f32>,
+    /// The width and height of the fragment
+    pub size: Size<f32>,
+    /// The top-left corner of the part of the child which is shown in the fragment, relative to the top-left corner of
+    /// the child's layout
+    pub source_location: Point<f32>,
+}
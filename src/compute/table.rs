@@ -0,0 +1,1235 @@
+//! Computes the CSS table layout algorithm.
+//!
+//! The children of a table are sorted into captions, row groups and rows, and the children of each row are its cells.
+//! Runs of consecutive children of a table or row group which are not captions, row groups or rows are wrapped in
+//! anonymous rows. Cells are placed into the columns of the table's grid in order, skipping any slots which are
+//! occupied by cells spanning down from earlier rows. Cells do not span beyond the end of their row group.
+//!
+//! Column widths are determined by the auto or fixed table layout algorithm
+//! (<https://www.w3.org/TR/CSS21/tables.html#width-layout>) from the min-content and max-content widths of the cells,
+//! and the height of each row is the height of its tallest cell. Rows and row groups are positioned by the table and
+//! are not laid out themselves. Cells are block containers whose content is aligned within the cell according to the
+//! cell's `vertical_align` style (see [`LayoutTableContainer::offset_cell_content`]).
+//!
+//! The following simplifications are made:
+//!   - Captions are placed within the table's content box, above or below the grid, rather than in a wrapper box
+//!   - In the collapsing border model, each line of the grid is as wide as the widest border along the whole line, and
+//!     the content of each cell is positioned within the cell according to the cell's own border widths. The grid is
+//!     inset from the edges of the table by half of the width of the outer lines, but the border of the table's own
+//!     layout (which is set by its parent) is not changed.
+//!   - Absolutely positioned children of rows and row groups are laid out as if they were cells
+use core::ops::Range;
+
+use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Size};
+use crate::style::{
+    AvailableSpace, BorderCollapse, CaptionSide, CoreStyle, Direction, Overflow, Position, TableChildStyle,
+    TableContainerStyle, TableLayout, TablePart, VerticalAlign, Visibility,
+};
+use crate::style_helpers::{TaffyMaxContent, TaffyMinContent};
+use crate::tree::{
+    CollapsibleMarginSet, ContainingBlock, Layout, LayoutInput, LayoutOutput, RequestedAxis, RunMode, SizingMode,
+};
+use crate::tree::{LayoutPartialTreeExt, LayoutTableContainer, NodeId};
+use crate::util::debug::debug_log;
+use crate::util::sys::{f32_max, f32_min, Vec};
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+use crate::{BlockItemStyle, BoxGenerationMode, BoxSizing};
+
+use super::common::containing_block::{
+    ancestor_containing_block, perform_static_child_layouts, AbsoluteContainingBlock, StaticChild,
+};
+#[cfg(feature = "content_size")]
+use super::common::content_size::compute_content_size_contribution;
+use super::common::sizing_keywords::{compute_with_sizing_keywords, SizingKeywords};
+use super::common::writing_mode::compute_with_transposed_axes;
+
+/// A caption of the table
+struct TableCaption {
+    /// The identifier for the associated node
+    node_id: NodeId,
+    /// The index of the node within the children of the table
+    order: u32,
+    /// Which side of the grid the caption is placed on
+    side: CaptionSide,
+}
+
+/// A row group of the table
+struct TableRowGroup {
+    /// The identifier for the associated node
+    node_id: NodeId,
+    /// The index of the node within the children of the table
+    order: u32,
+    /// The rows of the group
+    rows: Range<usize>,
+    /// The position of the group relative to the table
+    location: Point<f32>,
+    /// The size of the group
+    size: Size<f32>,
+}
+
+/// A row of the table's grid
+struct TableRow {
+    /// The identifier for the associated node, or `None` for an anonymous row which wraps a run of cells
+    node_id: Option<NodeId>,
+    /// The index of the node within the children of its parent
+    order: u32,
+    /// The index of the row group which the row belongs to, if any
+    group: Option<usize>,
+    /// The end of the run of rows which cells in this row can span: the end of the row's group, or for rows which are
+    /// not in a group, the next group
+    span_end: usize,
+    /// The height of the row specified by its style
+    style_height: Option<f32>,
+    /// The computed height of the row
+    height: f32,
+    /// The distance from the top of the row to the baseline of its baseline-aligned cells, if it has any
+    baseline: Option<f32>,
+    /// The largest distance from the baseline of the row to the bottom of one of its baseline-aligned cells
+    below_baseline: f32,
+    /// The position of the row relative to the table
+    location: Point<f32>,
+}
+
+/// A cell of the table's grid
+struct TableCell {
+    /// The identifier for the associated node
+    node_id: NodeId,
+    /// The index of the node within the children of its parent
+    order: u32,
+    /// The row the cell starts in
+    row: usize,
+    /// The column the cell starts in
+    column: usize,
+    /// The number of rows the cell spans
+    row_span: usize,
+    /// The number of columns the cell spans
+    col_span: usize,
+    /// How the content of the cell is aligned within the cell
+    vertical_align: VerticalAlign,
+    /// The position style of the cell
+    position: Position,
+    /// The overflow style of the cell
+    overflow: Point<Overflow>,
+    /// The visibility style of the cell
+    visibility: Visibility,
+    /// The resolved padding of the cell
+    padding: Rect<f32>,
+    /// The resolved border of the cell, as specified by its style
+    style_border: Rect<f32>,
+    /// The border of the cell. In the collapsing border model this is half of the width of each grid line.
+    border: Rect<f32>,
+    /// The border box width specified by the cell's style
+    style_width: Option<f32>,
+    /// The border box height specified by the cell's style
+    style_height: Option<f32>,
+    /// The min-content width of the cell
+    min_width: f32,
+    /// The max-content width of the cell
+    max_width: f32,
+    /// The height of the cell's content, laid out at the width of its columns
+    content_height: f32,
+    /// The distance from the top of the cell to its first baseline, if it has one
+    baseline: Option<f32>,
+}
+
+impl TableCell {
+    /// The difference between the sum of the cell's borders and the sum of the borders specified by its style
+    fn border_adjustment(&self) -> Size<f32> {
+        self.border.sum_axes() - self.style_border.sum_axes()
+    }
+}
+
+/// The parts of a table, sorted into the structure of its grid
+#[derive(Default)]
+struct TableStructure {
+    /// The table's captions
+    captions: Vec<TableCaption>,
+    /// The table's row groups
+    groups: Vec<TableRowGroup>,
+    /// The rows of the grid, in order
+    rows: Vec<TableRow>,
+    /// The cells of the grid, in order
+    cells: Vec<TableCell>,
+    /// The number of columns in the grid
+    column_count: usize,
+    /// Descendants which generate no box, with their index within the children of their parent
+    hidden: Vec<(NodeId, u32)>,
+    /// Absolutely positioned children of the table, with their index within the children of the table
+    absolutes: Vec<(NodeId, u32)>,
+}
+
+/// Computes the layout of [`LayoutTableContainer`] according to the table layout algorithm
+pub fn compute_table_layout(
+    tree: &mut impl LayoutTableContainer,
+    node_id: NodeId,
+    inputs: LayoutInput,
+) -> LayoutOutput {
+    let style = tree.get_table_container_style(node_id);
+    let writing_mode = style.writing_mode();
+    let sizing_keywords = SizingKeywords::new(&style, inputs.parent_size, |val, basis| tree.calc(node_id, val, basis));
+    drop(style);
+
+    compute_with_sizing_keywords(sizing_keywords, inputs, |inputs| match writing_mode.is_vertical() {
+        true => compute_with_transposed_axes(tree, node_id, inputs, writing_mode, compute_horizontal_tb),
+        false => compute_horizontal_tb(tree, node_id, inputs),
+    })
+}
+
+/// Computes the layout of [`LayoutTableContainer`] according to the table layout algorithm, treating the x axis as the inline axis
+fn compute_horizontal_tb(tree: &mut impl LayoutTableContainer, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
+    let LayoutInput { known_dimensions, parent_size, run_mode, .. } = inputs;
+    let style = tree.get_table_container_style(node_id);
+
+    // Pull these out earlier to avoid borrowing issues
+    let aspect_ratio = style.aspect_ratio();
+    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
+    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
+    let padding_border_size = (padding + border).sum_axes();
+    let box_sizing_adjustment =
+        if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
+
+    let min_size = style
+        .min_size()
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size()
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
+        .maybe_apply_aspect_ratio(aspect_ratio)
+        .maybe_add(box_sizing_adjustment);
+    let clamped_style_size = if inputs.sizing_mode == SizingMode::InherentSize {
+        style
+            .size()
+            .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment)
+            .maybe_clamp(min_size, max_size)
+    } else {
+        Size::NONE
+    };
+
+    drop(style);
+
+    // If both min and max in a given axis are set and max <= min then this determines the size in that axis
+    let min_max_definite_size = min_size.zip_map(max_size, |min, max| match (min, max) {
+        (Some(min), Some(max)) if max <= min => Some(min),
+        _ => None,
+    });
+
+    let styled_based_known_dimensions =
+        known_dimensions.or(min_max_definite_size).or(clamped_style_size).maybe_max(padding_border_size);
+
+    // Short-circuit layout if the container's size is fully determined by the container's size and the run mode
+    // is ComputeSize (and thus the container's size is all that we're interested in)
+    if run_mode == RunMode::ComputeSize {
+        if let Size { width: Some(width), height: Some(height) } = styled_based_known_dimensions {
+            return LayoutOutput::from_outer_size(Size { width, height });
+        }
+    }
+
+    debug_log!("TABLE");
+    compute_inner(tree, node_id, LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs })
+}
+
+/// Computes the layout of [`LayoutTableContainer`] according to the table layout algorithm
+fn compute_inner(tree: &mut impl LayoutTableContainer, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
+    let LayoutInput { known_dimensions, parent_size, available_space, run_mode, containing_block, .. } = inputs;
+
+    let style = tree.get_table_container_style(node_id);
+    let direction = style.direction();
+    let position = style.position();
+    let border_collapse = style.border_collapse();
+    let table_layout = style.table_layout();
+    let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
+    let mut padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
+    let mut border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(node_id, val, basis));
+    let spacing = match border_collapse {
+        BorderCollapse::Separate => {
+            style.border_spacing().resolve_or_zero(Size::NONE, |val, basis| tree.calc(node_id, val, basis))
+        }
+        BorderCollapse::Collapse => Size::ZERO,
+    };
+    let box_sizing_adjustment =
+        if style.box_sizing() == BoxSizing::ContentBox { (padding + border).sum_axes() } else { Size::ZERO };
+    let min_size = style
+        .min_size()
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
+        .maybe_add(box_sizing_adjustment);
+    let max_size = style
+        .max_size()
+        .maybe_resolve(parent_size, |val, basis| tree.calc(node_id, val, basis))
+        .maybe_add(box_sizing_adjustment);
+
+    drop(style);
+
+    // 1. Sort the table's children into captions, row groups, rows and cells, and place the cells into the grid
+    let inner_width = known_dimensions.width.maybe_sub((padding + border).horizontal_axis_sum());
+    let mut structure = generate_table_structure(tree, node_id);
+    resolve_cell_styles(tree, &mut structure.cells, inner_width);
+    place_cells(&mut structure);
+    let TableStructure { mut captions, mut groups, mut rows, mut cells, column_count, hidden, absolutes } = structure;
+
+    // 2. Collapse the borders of the cells and the table
+    if border_collapse == BorderCollapse::Collapse {
+        border = collapse_borders(&mut cells, rows.len(), column_count, border, direction);
+        padding = Rect::zero();
+    }
+    let padding_border = padding + border;
+    let padding_border_size = padding_border.sum_axes();
+
+    // 3. Determine the min-content and max-content widths of the cells and columns
+    let spacing_width = if column_count > 0 { spacing.width * (column_count + 1) as f32 } else { 0.0 };
+    let is_fixed_layout = table_layout == TableLayout::Fixed && known_dimensions.width.is_some();
+    let (column_min_widths, column_max_widths) = match is_fixed_layout {
+        true => {
+            let grid_width = known_dimensions.width.unwrap() - padding_border_size.width - spacing_width;
+            let widths = compute_fixed_column_widths(&cells, column_count, grid_width, spacing.width);
+            (widths.clone(), widths)
+        }
+        false => {
+            compute_cell_intrinsic_widths(tree, &mut cells, inner_width);
+            compute_auto_column_widths(&cells, column_count, spacing.width)
+        }
+    };
+
+    // 4. Determine the width of the table
+    let caption_min_width = captions
+        .iter()
+        .map(|caption| {
+            tree.measure_child_size(
+                caption.node_id,
+                Size::NONE,
+                Size { width: inner_width, height: None },
+                Size::MIN_CONTENT,
+                SizingMode::InherentSize,
+                AbsoluteAxis::Horizontal,
+                Line::FALSE,
+            ) + caption_margin(tree, caption.node_id, inner_width).horizontal_axis_sum()
+        })
+        .fold(0.0, f32_max);
+    let grid_min_width = column_min_widths.iter().sum::<f32>() + spacing_width;
+    let grid_max_width = column_max_widths.iter().sum::<f32>() + spacing_width;
+    let table_min_width = f32_max(grid_min_width, caption_min_width) + padding_border_size.width;
+    let table_max_width = f32_max(grid_max_width, caption_min_width) + padding_border_size.width;
+    let table_width = known_dimensions
+        .width
+        .unwrap_or_else(|| {
+            let width = match available_space.width {
+                AvailableSpace::Definite(available_width) => {
+                    f32_min(table_max_width, available_width - margin.horizontal_axis_sum())
+                }
+                AvailableSpace::MinContent => table_min_width,
+                AvailableSpace::MaxContent => table_max_width,
+            };
+            width.maybe_clamp(min_size.width, max_size.width)
+        })
+        .max(table_min_width);
+    let content_width = table_width - padding_border_size.width;
+
+    // 5. Distribute the width of the table between its columns
+    let column_widths = distribute_table_width(&column_min_widths, &column_max_widths, content_width - spacing_width);
+    let mut column_offsets = Vec::with_capacity(column_count);
+    let mut offset = spacing.width;
+    for width in column_widths.iter() {
+        column_offsets.push(offset);
+        offset += width + spacing.width;
+    }
+    let span_width = |cell: &TableCell| {
+        column_widths[cell.column..cell.column + cell.col_span].iter().sum::<f32>()
+            + spacing.width * (cell.col_span - 1) as f32
+    };
+
+    // 6. Lay out the cells at the width of their columns, and determine the height of each row
+    for cell in cells.iter_mut() {
+        let cell_width = span_width(cell);
+        let output = tree.perform_child_layout(
+            cell.node_id,
+            Size { width: Some(cell_width), height: None },
+            Size { width: Some(content_width), height: None },
+            Size { width: AvailableSpace::Definite(cell_width), height: AvailableSpace::MaxContent },
+            SizingMode::ContentSize,
+            Line::FALSE,
+        );
+        cell.content_height = output.size.height + cell.border_adjustment().height;
+        cell.baseline = output.first_baselines.y.map(|baseline| baseline + cell.border.top - cell.style_border.top);
+    }
+    compute_row_heights(&mut rows, &cells, spacing.height);
+
+    // 7. Lay out the captions at the width of the table's content box
+    let caption_layouts: Vec<(Rect<f32>, LayoutInput, LayoutOutput)> = captions
+        .iter()
+        .map(|caption| {
+            let caption_margin = caption_margin(tree, caption.node_id, Some(content_width));
+            let caption_width = f32_max(content_width - caption_margin.horizontal_axis_sum(), 0.0);
+            let inputs = LayoutInput {
+                known_dimensions: Size { width: Some(caption_width), height: None },
+                parent_size: Size { width: Some(content_width), height: None },
+                available_space: Size {
+                    width: AvailableSpace::Definite(caption_width),
+                    height: AvailableSpace::MaxContent,
+                },
+                sizing_mode: SizingMode::InherentSize,
+                axis: RequestedAxis::Both,
+                run_mode: RunMode::PerformLayout,
+                vertical_margins_are_collapsible: Line::FALSE,
+                containing_block: ContainingBlock::Deferred,
+            };
+            let output = tree.compute_child_layout(caption.node_id, inputs);
+            (caption_margin, inputs, output)
+        })
+        .collect();
+
+    // 8. Determine the height of the table, and distribute any extra height between the rows
+    let spacing_height = if rows.is_empty() { 0.0 } else { spacing.height * (rows.len() + 1) as f32 };
+    let captions_height: f32 =
+        caption_layouts.iter().map(|(margin, _, output)| margin.vertical_axis_sum() + output.size.height).sum();
+    let grid_height = rows.iter().map(|row| row.height).sum::<f32>() + spacing_height;
+    let min_table_height = grid_height + captions_height + padding_border_size.height;
+    let table_height = known_dimensions
+        .height
+        .unwrap_or_else(|| min_table_height.maybe_clamp(min_size.height, max_size.height))
+        .max(min_table_height);
+    let extra_height = table_height - min_table_height;
+    if extra_height > 0.0 && !rows.is_empty() {
+        let row_count = rows.len() as f32;
+        rows.iter_mut().for_each(|row| row.height += extra_height / row_count);
+    }
+    let final_outer_size = Size { width: table_width, height: table_height };
+
+    // Short-circuit if computing size
+    if run_mode == RunMode::ComputeSize {
+        return LayoutOutput::from_outer_size(final_outer_size);
+    }
+
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut content_size = Size::ZERO;
+
+    // Absolutely positioned children (and the absolutely positioned descendants of static children) are laid out once
+    // their containing block is known
+    let containing_block = AbsoluteContainingBlock::resolve(containing_block, position, border);
+    let mut static_captions = Vec::new();
+
+    // 9. Position the captions and the rows
+    let content_box = Rect {
+        left: padding_border.left,
+        right: table_width - padding_border.right,
+        top: padding_border.top,
+        bottom: table_height - padding_border.bottom,
+    };
+    let mut y = content_box.top;
+    for side in [CaptionSide::Top, CaptionSide::Bottom] {
+        if side == CaptionSide::Bottom {
+            let mut row_y = y + spacing.height;
+            for row in rows.iter_mut() {
+                row.location = Point { x: content_box.left + spacing.width, y: row_y };
+                row_y += row.height + spacing.height;
+            }
+            y += rows.iter().map(|row| row.height).sum::<f32>() + spacing_height;
+        }
+        for (caption, (caption_margin, inputs, output)) in captions.iter_mut().zip(caption_layouts.iter()) {
+            if caption.side != side {
+                continue;
+            }
+            let location = Point { x: content_box.left + caption_margin.left, y: y + caption_margin.top };
+            y += caption_margin.vertical_axis_sum() + output.size.height;
+            let style = tree.get_table_child_style(caption.node_id);
+            let visibility = style.visibility();
+            let caption_padding = style
+                .padding()
+                .resolve_or_zero(Some(content_width), |val, basis| tree.calc(caption.node_id, val, basis));
+            let caption_border = style
+                .border()
+                .resolve_or_zero(Some(content_width), |val, basis| tree.calc(caption.node_id, val, basis));
+            #[cfg(feature = "content_size")]
+            let overflow = style.overflow();
+            if style.position() == Position::Static {
+                static_captions.push(StaticChild {
+                    node_id: caption.node_id,
+                    inputs: *inputs,
+                    location,
+                    size: output.size,
+                    inline_containing_block: None,
+                });
+            }
+            drop(style);
+            tree.set_unrounded_layout(
+                caption.node_id,
+                &Layout {
+                    order: caption.order,
+                    location,
+                    size: output.size,
+                    #[cfg(feature = "content_size")]
+                    content_size: output.content_size,
+                    scrollbar_size: Size::ZERO,
+                    border: caption_border,
+                    padding: caption_padding,
+                    margin: *caption_margin,
+                    visibility,
+                    ..Layout::new()
+                },
+            );
+            #[cfg(feature = "content_size")]
+            {
+                content_size = content_size.f32_max(compute_content_size_contribution(
+                    location,
+                    output.size,
+                    output.content_size,
+                    overflow,
+                ));
+            }
+        }
+    }
+
+    perform_static_child_layouts(tree, &static_captions, containing_block, final_outer_size);
+
+    // 10. Set the layouts of the row groups and rows
+    let row_width = f32_max(content_box.right - content_box.left - 2.0 * spacing.width, 0.0);
+    for group in groups.iter_mut() {
+        let (top, bottom) = match (rows[group.rows.clone()].first(), rows[group.rows.clone()].last()) {
+            (Some(first), Some(last)) => (first.location.y, last.location.y + last.height),
+            _ => (content_box.top, content_box.top),
+        };
+        group.location = Point { x: content_box.left + spacing.width, y: top };
+        group.size = Size { width: row_width, height: bottom - top };
+        set_table_part_layout(tree, group.node_id, group.order, group.location, group.size);
+    }
+    for row in rows.iter() {
+        if let Some(row_node_id) = row.node_id {
+            let parent_location = row.group.map(|group| groups[group].location).unwrap_or(Point::ZERO);
+            let location = Point { x: row.location.x - parent_location.x, y: row.location.y - parent_location.y };
+            set_table_part_layout(
+                tree,
+                row_node_id,
+                row.order,
+                location,
+                Size { width: row_width, height: row.height },
+            );
+        }
+    }
+
+    // 11. Perform final layout on the cells, and align their content within them
+    for cell in cells.iter() {
+        let row = &rows[cell.row];
+        let size = Size {
+            width: span_width(cell),
+            height: rows[cell.row..cell.row + cell.row_span].iter().map(|row| row.height).sum::<f32>()
+                + spacing.height * (cell.row_span - 1) as f32,
+        };
+        let x = match direction {
+            Direction::Ltr => content_box.left + column_offsets[cell.column],
+            Direction::Rtl => content_box.right - column_offsets[cell.column] - size.width,
+        };
+        let table_location = Point { x, y: row.location.y };
+        let content_offset = match cell.vertical_align {
+            VerticalAlign::Top => 0.0,
+            VerticalAlign::Middle => (size.height - cell.content_height) / 2.0,
+            VerticalAlign::Bottom => size.height - cell.content_height,
+            VerticalAlign::Baseline => match (row.baseline, cell.baseline, cell.row_span) {
+                (Some(row_baseline), Some(cell_baseline), 1) => row_baseline - cell_baseline,
+                _ => 0.0,
+            },
+        };
+
+        // The containing block of the absolutely positioned descendants of a static cell is the padding box of its
+        // row or row group if they are positioned, or otherwise that of the table's absolutely positioned children.
+        // The cell's content is moved down by the content offset after it has been laid out, so the containing block
+        // is moved up by the same amount.
+        let cell_containing_block = match cell.position {
+            Position::Static => {
+                let is_positioned = |node_id| tree.get_table_child_style(node_id).position().is_positioned();
+                let edges = |location: Point<f32>, size: Size<f32>| Rect {
+                    left: location.x,
+                    right: location.x + size.width,
+                    top: location.y,
+                    bottom: location.y + size.height,
+                };
+                let row_edges = row
+                    .node_id
+                    .filter(|&node_id| is_positioned(node_id))
+                    .map(|_| edges(row.location, Size { width: row_width, height: row.height }));
+                let group_edges = row
+                    .group
+                    .map(|group| &groups[group])
+                    .filter(|group| is_positioned(group.node_id))
+                    .map(|group| edges(group.location, group.size));
+                match row_edges.or(group_edges).or(containing_block.map(|cb| cb.edges(final_outer_size))) {
+                    Some(edges) => ancestor_containing_block(
+                        edges,
+                        Point { x: table_location.x, y: table_location.y + f32_max(content_offset, 0.0) },
+                        size,
+                    ),
+                    None => ContainingBlock::Deferred,
+                }
+            }
+            _ => ContainingBlock::Deferred,
+        };
+        let output = tree.perform_child_layout_with_containing_block(
+            cell.node_id,
+            size.map(Some),
+            Size { width: Some(content_width), height: None },
+            size.map(AvailableSpace::Definite),
+            SizingMode::InherentSize,
+            Line::FALSE,
+            cell_containing_block,
+        );
+        if content_offset > 0.0 {
+            tree.offset_cell_content(cell.node_id, Point { x: 0.0, y: content_offset });
+        }
+
+        // Cells are positioned relative to their row, or if they are in an anonymous row, to the row's parent
+        let parent_location = match (row.node_id, row.group) {
+            (Some(_), _) => row.location,
+            (None, Some(group)) => groups[group].location,
+            (None, None) => Point::ZERO,
+        };
+        let location = Point { x: table_location.x - parent_location.x, y: table_location.y - parent_location.y };
+        tree.set_unrounded_layout(
+            cell.node_id,
+            &Layout {
+                order: cell.order,
+                location,
+                size,
+                #[cfg(feature = "content_size")]
+                content_size: output.content_size,
+                scrollbar_size: output.scrollbar_size,
+                border: cell.border,
+                padding: cell.padding,
+                margin: Rect::zero(),
+                visibility: cell.visibility,
+                ..Layout::new()
+            },
+        );
+
+        #[cfg(feature = "content_size")]
+        {
+            content_size = content_size.f32_max(compute_content_size_contribution(
+                table_location,
+                size,
+                output.content_size,
+                cell.overflow,
+            ));
+        }
+        #[cfg(not(feature = "content_size"))]
+        let _ = output;
+    }
+
+    // 12. Layout absolutely positioned children
+    let absolute_content_size = match containing_block {
+        Some(containing_block) => perform_absolute_layout_on_absolute_children(
+            tree,
+            &absolutes,
+            containing_block,
+            final_outer_size,
+            content_box,
+            direction,
+        ),
+        None => Size::ZERO,
+    };
+
+    // 13. Perform hidden layout on hidden descendants
+    for (child, order) in hidden {
+        tree.set_unrounded_layout(child, &Layout::with_order(order));
+        tree.perform_child_layout(
+            child,
+            Size::NONE,
+            Size::NONE,
+            Size::MAX_CONTENT,
+            SizingMode::InherentSize,
+            Line::FALSE,
+        );
+    }
+
+    #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+    let content_size = content_size.f32_max(absolute_content_size);
+
+    // The baseline of the table is the baseline of its first row
+    let first_baseline = rows.first().and_then(|row| row.baseline.map(|baseline| row.location.y + baseline));
+
+    LayoutOutput {
+        size: final_outer_size,
+        #[cfg(feature = "content_size")]
+        content_size,
+        scrollbar_size: Size::ZERO,
+        first_baselines: Point { x: None, y: first_baseline },
+        // The margins of a table never collapse with the margins of its contents
+        top_margin: CollapsibleMarginSet::from_margin(margin.top),
+        bottom_margin: CollapsibleMarginSet::from_margin(margin.bottom),
+        margins_can_collapse_through: false,
+    }
+}
+
+/// Sort the children of the table into captions, row groups, rows and cells
+fn generate_table_structure(tree: &impl LayoutTableContainer, node_id: NodeId) -> TableStructure {
+    let mut structure = TableStructure::default();
+    // Whether the last row is an anonymous row to which cells which directly follow it are added
+    let mut open_anonymous_row = false;
+    // The first row which is not in a row group and whose `span_end` has not yet been set
+    let mut ungrouped_rows_start = 0;
+
+    for (order, child) in tree.child_ids(node_id).enumerate() {
+        let order = order as u32;
+        let style = tree.get_table_child_style(child);
+        if style.box_generation_mode() == BoxGenerationMode::None {
+            structure.hidden.push((child, order));
+            continue;
+        }
+        if style.position().is_absolutely_positioned() {
+            structure.absolutes.push((child, order));
+            continue;
+        }
+
+        match style.table_part() {
+            TablePart::Caption => {
+                structure.captions.push(TableCaption { node_id: child, order, side: style.caption_side() });
+                open_anonymous_row = false;
+            }
+            TablePart::RowGroup => {
+                drop(style);
+                // Cells in the rows before the group do not span into the group
+                let group_start = structure.rows.len();
+                structure.rows[ungrouped_rows_start..].iter_mut().for_each(|row| row.span_end = group_start);
+
+                let group = structure.groups.len();
+                let mut open_anonymous_group_row = false;
+                for (order, group_child) in tree.child_ids(child).enumerate() {
+                    let order = order as u32;
+                    let style = tree.get_table_child_style(group_child);
+                    if style.box_generation_mode() == BoxGenerationMode::None {
+                        structure.hidden.push((group_child, order));
+                    } else if style.table_part() == TablePart::Row {
+                        drop(style);
+                        push_row(tree, &mut structure, Some(group_child), order, Some(group));
+                        open_anonymous_group_row = false;
+                    } else {
+                        drop(style);
+                        if !open_anonymous_group_row {
+                            push_row(tree, &mut structure, None, order, Some(group));
+                            open_anonymous_group_row = true;
+                        }
+                        push_cell(&mut structure, group_child, order);
+                    }
+                }
+
+                let group_end = structure.rows.len();
+                structure.rows[group_start..].iter_mut().for_each(|row| row.span_end = group_end);
+                structure.groups.push(TableRowGroup {
+                    node_id: child,
+                    order,
+                    rows: group_start..group_end,
+                    location: Point::ZERO,
+                    size: Size::ZERO,
+                });
+                ungrouped_rows_start = group_end;
+                open_anonymous_row = false;
+            }
+            TablePart::Row => {
+                drop(style);
+                push_row(tree, &mut structure, Some(child), order, None);
+                open_anonymous_row = false;
+            }
+            TablePart::Cell => {
+                drop(style);
+                if !open_anonymous_row {
+                    push_row(tree, &mut structure, None, order, None);
+                    open_anonymous_row = true;
+                }
+                push_cell(&mut structure, child, order);
+            }
+        }
+    }
+
+    let rows_end = structure.rows.len();
+    structure.rows[ungrouped_rows_start..].iter_mut().for_each(|row| row.span_end = rows_end);
+    structure
+}
+
+/// Add a row to the table. If the row is generated by a node then its children are added as its cells.
+fn push_row(
+    tree: &impl LayoutTableContainer,
+    structure: &mut TableStructure,
+    node_id: Option<NodeId>,
+    order: u32,
+    group: Option<usize>,
+) {
+    let style_height = node_id.and_then(|node_id| {
+        tree.get_table_child_style(node_id)
+            .size()
+            .height
+            .maybe_resolve(None, |val, basis| tree.calc(node_id, val, basis))
+    });
+    structure.rows.push(TableRow {
+        node_id,
+        order,
+        group,
+        span_end: 0,
+        style_height,
+        height: 0.0,
+        baseline: None,
+        below_baseline: 0.0,
+        location: Point::ZERO,
+    });
+
+    if let Some(node_id) = node_id {
+        for (order, child) in tree.child_ids(node_id).enumerate() {
+            let order = order as u32;
+            match tree.get_table_child_style(child).box_generation_mode() {
+                BoxGenerationMode::None => structure.hidden.push((child, order)),
+                _ => push_cell(structure, child, order),
+            }
+        }
+    }
+}
+
+/// Add a cell to the last row of the table. Its styles are resolved later.
+fn push_cell(structure: &mut TableStructure, node_id: NodeId, order: u32) {
+    structure.cells.push(TableCell {
+        node_id,
+        order,
+        row: structure.rows.len() - 1,
+        column: 0,
+        row_span: 1,
+        col_span: 1,
+        vertical_align: VerticalAlign::Baseline,
+        position: Position::Relative,
+        overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
+        visibility: Visibility::Visible,
+        padding: Rect::zero(),
+        style_border: Rect::zero(),
+        border: Rect::zero(),
+        style_width: None,
+        style_height: None,
+        min_width: 0.0,
+        max_width: 0.0,
+        content_height: 0.0,
+        baseline: None,
+    });
+}
+
+/// Resolve the styles of the cells which affect their size and placement within the grid
+fn resolve_cell_styles(tree: &impl LayoutTableContainer, cells: &mut [TableCell], inner_width: Option<f32>) {
+    for cell in cells.iter_mut() {
+        let style = tree.get_table_child_style(cell.node_id);
+        cell.row_span = style.row_span().max(1) as usize;
+        cell.col_span = style.col_span().max(1) as usize;
+        cell.vertical_align = style.vertical_align();
+        cell.position = style.position();
+        cell.overflow = style.overflow();
+        cell.visibility = style.visibility();
+        cell.padding = style.padding().resolve_or_zero(inner_width, |val, basis| tree.calc(cell.node_id, val, basis));
+        cell.style_border =
+            style.border().resolve_or_zero(inner_width, |val, basis| tree.calc(cell.node_id, val, basis));
+        cell.border = cell.style_border;
+        let box_sizing_adjustment = if style.box_sizing() == BoxSizing::ContentBox {
+            (cell.padding + cell.style_border).sum_axes()
+        } else {
+            Size::ZERO
+        };
+        let style_size = style
+            .size()
+            .maybe_resolve(Size { width: inner_width, height: None }, |val, basis| tree.calc(cell.node_id, val, basis))
+            .maybe_add(box_sizing_adjustment);
+        cell.style_width = style_size.width;
+        cell.style_height = style_size.height;
+    }
+}
+
+/// Place each cell into the first free column of its row, and clamp the row spans of the cells to their row group
+fn place_cells(structure: &mut TableStructure) {
+    // For each column, the first row which is not occupied by a cell in an earlier row
+    let mut occupied_until: Vec<usize> = Vec::new();
+    let mut row = usize::MAX;
+    let mut column = 0;
+    for cell in structure.cells.iter_mut() {
+        if cell.row != row {
+            row = cell.row;
+            column = 0;
+        }
+        while column < occupied_until.len() && occupied_until[column] > row {
+            column += 1;
+        }
+        cell.column = column;
+        cell.row_span = cell.row_span.min(structure.rows[row].span_end - row);
+
+        let column_end = column + cell.col_span;
+        if occupied_until.len() < column_end {
+            occupied_until.resize(column_end, 0);
+        }
+        occupied_until[column..column_end].iter_mut().for_each(|until| *until = row + cell.row_span);
+        column = column_end;
+    }
+    structure.column_count = occupied_until.len();
+}
+
+/// Collapse the borders of the cells and the table in the collapsing border model. Each line of the grid is as wide
+/// as the widest border along it, and the cells and table are given half of the width of each line at their edges.
+/// Returns the border of the table.
+fn collapse_borders(
+    cells: &mut [TableCell],
+    row_count: usize,
+    column_count: usize,
+    table_border: Rect<f32>,
+    direction: Direction,
+) -> Rect<f32> {
+    // The borders on the inline-start and inline-end sides of a box
+    let inline_borders = |border: Rect<f32>| match direction {
+        Direction::Ltr => (border.left, border.right),
+        Direction::Rtl => (border.right, border.left),
+    };
+
+    let (table_start, table_end) = inline_borders(table_border);
+    let mut column_lines: Vec<f32> = (0..=column_count).map(|_| 0.0).collect();
+    let mut row_lines: Vec<f32> = (0..=row_count).map(|_| 0.0).collect();
+    column_lines[0] = table_start;
+    column_lines[column_count] = f32_max(column_lines[column_count], table_end);
+    row_lines[0] = table_border.top;
+    row_lines[row_count] = f32_max(row_lines[row_count], table_border.bottom);
+
+    for cell in cells.iter() {
+        let (start, end) = inline_borders(cell.style_border);
+        let column_end = cell.column + cell.col_span;
+        let row_end = cell.row + cell.row_span;
+        column_lines[cell.column] = f32_max(column_lines[cell.column], start);
+        column_lines[column_end] = f32_max(column_lines[column_end], end);
+        row_lines[cell.row] = f32_max(row_lines[cell.row], cell.style_border.top);
+        row_lines[row_end] = f32_max(row_lines[row_end], cell.style_border.bottom);
+    }
+
+    let physical_border = |start: f32, end: f32, top: f32, bottom: f32| match direction {
+        Direction::Ltr => Rect { left: start, right: end, top, bottom },
+        Direction::Rtl => Rect { left: end, right: start, top, bottom },
+    };
+    for cell in cells.iter_mut() {
+        cell.border = physical_border(
+            column_lines[cell.column] / 2.0,
+            column_lines[cell.column + cell.col_span] / 2.0,
+            row_lines[cell.row] / 2.0,
+            row_lines[cell.row + cell.row_span] / 2.0,
+        );
+    }
+    physical_border(
+        column_lines[0] / 2.0,
+        column_lines[column_count] / 2.0,
+        row_lines[0] / 2.0,
+        row_lines[row_count] / 2.0,
+    )
+}
+
+/// Compute the min-content and max-content widths of each cell. A width specified by the cell's style is used instead
+/// of its max-content width, unless it is narrower than the cell's min-content width.
+fn compute_cell_intrinsic_widths(
+    tree: &mut impl LayoutTableContainer,
+    cells: &mut [TableCell],
+    inner_width: Option<f32>,
+) {
+    for cell in cells.iter_mut() {
+        let mut measure = |available_width| {
+            tree.measure_child_size(
+                cell.node_id,
+                Size::NONE,
+                Size { width: inner_width, height: None },
+                Size { width: available_width, height: AvailableSpace::MaxContent },
+                SizingMode::ContentSize,
+                AbsoluteAxis::Horizontal,
+                Line::FALSE,
+            )
+        };
+        let min_content_width = measure(AvailableSpace::MinContent);
+        let max_content_width = measure(AvailableSpace::MaxContent);
+        let border_adjustment = cell.border_adjustment().width;
+        cell.min_width = f32_max(min_content_width + border_adjustment, 0.0);
+        cell.max_width = match cell.style_width {
+            Some(style_width) => style_width + border_adjustment,
+            None => max_content_width + border_adjustment,
+        };
+        cell.min_width = cell.style_width.map_or(cell.min_width, |width| f32_max(cell.min_width, width));
+        cell.max_width = f32_max(cell.max_width, cell.min_width);
+    }
+}
+
+/// Determine the min-content and max-content widths of the columns in the auto table layout algorithm. Each column is
+/// as wide as the widest cell which only spans that column. The columns spanned by each cell which spans multiple
+/// columns are then widened (in order of increasing span) until they are as wide as the cell.
+fn compute_auto_column_widths(cells: &[TableCell], column_count: usize, spacing: f32) -> (Vec<f32>, Vec<f32>) {
+    let mut min_widths: Vec<f32> = (0..column_count).map(|_| 0.0).collect();
+    let mut max_widths: Vec<f32> = (0..column_count).map(|_| 0.0).collect();
+
+    for cell in cells.iter().filter(|cell| cell.col_span == 1) {
+        min_widths[cell.column] = f32_max(min_widths[cell.column], cell.min_width);
+        max_widths[cell.column] = f32_max(max_widths[cell.column], cell.max_width);
+    }
+
+    let mut spanning_cells: Vec<&TableCell> = cells.iter().filter(|cell| cell.col_span > 1).collect();
+    spanning_cells.sort_by_key(|cell| cell.col_span);
+    for cell in spanning_cells {
+        let columns = cell.column..cell.column + cell.col_span;
+        let spanned_spacing = spacing * (cell.col_span - 1) as f32;
+        let weights: Vec<f32> = max_widths[columns.clone()].to_vec();
+        distribute_spanning_width(&mut min_widths[columns.clone()], &weights, cell.min_width - spanned_spacing);
+        distribute_spanning_width(&mut max_widths[columns], &weights, cell.max_width - spanned_spacing);
+    }
+
+    for (min_width, max_width) in min_widths.iter().zip(max_widths.iter_mut()) {
+        *max_width = f32_max(*max_width, *min_width);
+    }
+    (min_widths, max_widths)
+}
+
+/// Widen `widths` until their sum is at least `required`. The extra width is distributed in proportion to `weights`,
+/// or equally if all of the weights are zero.
+fn distribute_spanning_width(widths: &mut [f32], weights: &[f32], required: f32) {
+    let existing: f32 = widths.iter().sum();
+    if required <= existing {
+        return;
+    }
+    let extra = required - existing;
+    let total_weight: f32 = weights.iter().sum();
+    let count = widths.len() as f32;
+    for (width, weight) in widths.iter_mut().zip(weights) {
+        *width += match total_weight > 0.0 {
+            true => extra * weight / total_weight,
+            false => extra / count,
+        };
+    }
+}
+
+/// Determine the widths of the columns in the fixed table layout algorithm. Columns take the widths specified by the
+/// cells in the first row, and the remaining width of the grid is divided equally between the other columns.
+fn compute_fixed_column_widths(cells: &[TableCell], column_count: usize, grid_width: f32, spacing: f32) -> Vec<f32> {
+    let mut widths: Vec<Option<f32>> = (0..column_count).map(|_| None).collect();
+    for cell in cells.iter().filter(|cell| cell.row == 0) {
+        if let Some(style_width) = cell.style_width {
+            let cell_width = style_width + cell.border_adjustment().width - spacing * (cell.col_span - 1) as f32;
+            let column_width = f32_max(cell_width, 0.0) / cell.col_span as f32;
+            widths[cell.column..cell.column + cell.col_span].iter_mut().for_each(|width| *width = Some(column_width));
+        }
+    }
+
+    let specified_width: f32 = widths.iter().flatten().sum();
+    let unspecified_count = widths.iter().filter(|width| width.is_none()).count();
+    let remaining_width = f32_max(grid_width - specified_width, 0.0);
+    if unspecified_count == 0 && specified_width > 0.0 && remaining_width > 0.0 {
+        // Every column has a specified width, so the columns are widened in proportion to their widths
+        let scale = grid_width / specified_width;
+        return widths.iter().map(|width| width.unwrap_or(0.0) * scale).collect();
+    }
+    widths.iter().map(|width| width.unwrap_or(remaining_width / unspecified_count as f32)).collect()
+}
+
+/// Distribute `grid_width` between the columns. If the grid is wider than the sum of the max-content widths of the
+/// columns, then the extra width is distributed in proportion to their max-content widths. If it is narrower, then
+/// each column is sized between its min-content and max-content widths in the same proportion.
+fn distribute_table_width(min_widths: &[f32], max_widths: &[f32], grid_width: f32) -> Vec<f32> {
+    let min_sum: f32 = min_widths.iter().sum();
+    let max_sum: f32 = max_widths.iter().sum();
+    let count = max_widths.len() as f32;
+    if grid_width >= max_sum {
+        let extra = grid_width - max_sum;
+        max_widths
+            .iter()
+            .map(|max| match max_sum > 0.0 {
+                true => max + extra * max / max_sum,
+                false => max + extra / count,
+            })
+            .collect()
+    } else if grid_width > min_sum {
+        let ratio = (grid_width - min_sum) / (max_sum - min_sum);
+        min_widths.iter().zip(max_widths).map(|(min, max)| min + (max - min) * ratio).collect()
+    } else {
+        min_widths.to_vec()
+    }
+}
+
+/// Determine the height of each row from the heights of its cells. Rows are at least as tall as their tallest cell and
+/// as the height specified by their style, and are tall enough to align the baselines of their baseline-aligned cells.
+/// The rows spanned by cells which span multiple rows are then grown equally until they are as tall as the cell.
+fn compute_row_heights(rows: &mut [TableRow], cells: &[TableCell], spacing: f32) {
+    let cell_height = |cell: &TableCell| match cell.style_height {
+        Some(style_height) => f32_max(cell.content_height, style_height + cell.border_adjustment().height),
+        None => cell.content_height,
+    };
+
+    for row in rows.iter_mut() {
+        row.height = row.style_height.unwrap_or(0.0);
+    }
+    for cell in cells.iter().filter(|cell| cell.row_span == 1) {
+        let row = &mut rows[cell.row];
+        let height = cell_height(cell);
+        row.height = f32_max(row.height, height);
+        if let (VerticalAlign::Baseline, Some(baseline)) = (cell.vertical_align, cell.baseline) {
+            row.baseline = Some(row.baseline.map_or(baseline, |row_baseline| f32_max(row_baseline, baseline)));
+            row.below_baseline = f32_max(row.below_baseline, height - baseline);
+        }
+    }
+    for row in rows.iter_mut() {
+        if let Some(baseline) = row.baseline {
+            row.height = f32_max(row.height, baseline + row.below_baseline);
+        }
+    }
+
+    let mut spanning_cells: Vec<&TableCell> = cells.iter().filter(|cell| cell.row_span > 1).collect();
+    spanning_cells.sort_by_key(|cell| cell.row_span);
+    for cell in spanning_cells {
+        let spanned_rows = &mut rows[cell.row..cell.row + cell.row_span];
+        let existing = spanned_rows.iter().map(|row| row.height).sum::<f32>() + spacing * (cell.row_span - 1) as f32;
+        let deficit = cell_height(cell) - existing;
+        if deficit > 0.0 {
+            spanned_rows.iter_mut().for_each(|row| row.height += deficit / cell.row_span as f32);
+        }
+    }
+}
+
+/// Resolve the margins of a caption. Auto margins are treated as zero.
+fn caption_margin(tree: &impl LayoutTableContainer, node_id: NodeId, inner_width: Option<f32>) -> Rect<f32> {
+    tree.get_table_child_style(node_id)
+        .margin()
+        .resolve_or_zero(inner_width, |val, basis| tree.calc(node_id, val, basis))
+}
+
+/// Set the layout of a row group or row. These are positioned by the table and are not laid out themselves.
+fn set_table_part_layout(
+    tree: &mut impl LayoutTableContainer,
+    node_id: NodeId,
+    order: u32,
+    location: Point<f32>,
+    size: Size<f32>,
+) {
+    let visibility = tree.get_table_child_style(node_id).visibility();
+    tree.set_unrounded_layout(
+        node_id,
+        &Layout {
+            order,
+            location,
+            size,
+            #[cfg(feature = "content_size")]
+            content_size: size,
+            scrollbar_size: Size::ZERO,
+            border: Rect::zero(),
+            padding: Rect::zero(),
+            margin: Rect::zero(),
+            visibility,
+            ..Layout::new()
+        },
+    );
+}
+
+/// Perform layout on the absolutely positioned children of the table, which are positioned against their containing
+/// block. Children whose insets are auto in an axis are placed at the inline-start or top edge of the table's content
+/// box.
+fn perform_absolute_layout_on_absolute_children(
+    tree: &mut impl LayoutTableContainer,
+    absolutes: &[(NodeId, u32)],
+    containing_block: AbsoluteContainingBlock,
+    container_size: Size<f32>,
+    content_box: Rect<f32>,
+    direction: Direction,
+) -> Size<f32> {
+    let area_size = containing_block.size(container_size);
+    let area_offset = Point { x: containing_block.insets.left, y: containing_block.insets.top };
+
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut absolute_content_size = Size::ZERO;
+
+    for &(node_id, order) in absolutes {
+        let style = tree.get_table_child_style(node_id);
+        let aspect_ratio = style.aspect_ratio();
+        let margin = style.margin().resolve_or_zero(Some(area_size.width), |val, basis| tree.calc(node_id, val, basis));
+        let padding =
+            style.padding().resolve_or_zero(Some(area_size.width), |val, basis| tree.calc(node_id, val, basis));
+        let border = style.border().resolve_or_zero(Some(area_size.width), |val, basis| tree.calc(node_id, val, basis));
+        let padding_border_sum = (padding + border).sum_axes();
+        let box_sizing_adjustment =
+            if style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+        let inset = style.inset();
+        let left = inset.left.maybe_resolve(area_size.width, |val, basis| tree.calc(node_id, val, basis));
+        let right = inset.right.maybe_resolve(area_size.width, |val, basis| tree.calc(node_id, val, basis));
+        let top = inset.top.maybe_resolve(area_size.height, |val, basis| tree.calc(node_id, val, basis));
+        let bottom = inset.bottom.maybe_resolve(area_size.height, |val, basis| tree.calc(node_id, val, basis));
+        let min_size = style
+            .min_size()
+            .maybe_resolve(area_size, |val, basis| tree.calc(node_id, val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment)
+            .or(padding_border_sum.map(Some))
+            .maybe_max(padding_border_sum);
+        let max_size = style
+            .max_size()
+            .maybe_resolve(area_size, |val, basis| tree.calc(node_id, val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment);
+        let mut known_dimensions = style
+            .size()
+            .maybe_resolve(area_size, |val, basis| tree.calc(node_id, val, basis))
+            .maybe_apply_aspect_ratio(aspect_ratio)
+            .maybe_add(box_sizing_adjustment)
+            .maybe_clamp(min_size, max_size);
+        let overflow = style.overflow();
+        let visibility = style.visibility();
+        drop(style);
+
+        // Fill in the size from the insets if both insets in an axis are set
+        if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
+            known_dimensions.width = Some(f32_max(area_size.width - margin.horizontal_axis_sum() - left - right, 0.0));
+            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+        }
+        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
+            known_dimensions.height = Some(f32_max(area_size.height - margin.vertical_axis_sum() - top - bottom, 0.0));
+            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+        }
+
+        let layout_output = tree.perform_child_layout(
+            node_id,
+            known_dimensions,
+            area_size.map(Some),
+            area_size.map(AvailableSpace::Definite),
+            SizingMode::ContentSize,
+            Line::FALSE,
+        );
+        let size = known_dimensions.unwrap_or(layout_output.size).maybe_clamp(min_size, max_size);
+
+        let left_position = left.map(|left| area_offset.x + left + margin.left);
+        let right_position = right.map(|right| area_offset.x + area_size.width - right - margin.right - size.width);
+        let location = Point {
+            x: match direction {
+                Direction::Ltr => left_position.or(right_position).unwrap_or(content_box.left + margin.left),
+                Direction::Rtl => {
+                    right_position.or(left_position).unwrap_or(content_box.right - margin.right - size.width)
+                }
+            },
+            y: top
+                .map(|top| area_offset.y + top + margin.top)
+                .or(bottom.map(|bottom| area_offset.y + area_size.height - bottom - margin.bottom - size.height))
+                .unwrap_or(content_box.top + margin.top),
+        };
+        tree.set_unrounded_layout(
+            node_id,
+            &Layout {
+                order,
+                location,
+                size,
+                #[cfg(feature = "content_size")]
+                content_size: layout_output.content_size,
+                scrollbar_size: layout_output.scrollbar_size,
+                border,
+                padding,
+                margin,
+                visibility,
+                ..Layout::new()
+            },
+        );
+
+        // Only the containing block's content size includes the child
+        #[cfg(feature = "content_size")]
+        if containing_block.is_own {
+            absolute_content_size = absolute_content_size.f32_max(compute_content_size_contribution(
+                location,
+                size,
+                layout_output.content_size,
+                overflow,
+            ));
+        }
+    }
+
+    absolute_content_size
+}
@@ -1,5 +1,6 @@
 //! Geometric primitives useful for layout
 
+use crate::style::{Direction, WritingMode};
 use crate::util::sys::f32_max;
 use crate::CompactLength;
 use crate::{style::Dimension, util::sys::f32_min};
@@ -71,7 +72,7 @@ impl AbstractAxis {
     }
 
     /// Convert an `AbstractAxis` into an `AbsoluteAxis` naively assuming that the Inline axis is Horizontal
-    /// This is currently always true, but will change if Taffy ever implements the `writing_mode` property
+    /// This is always true within Taffy's layout algorithms, as nodes with a vertical `writing_mode` are laid out with their axes transposed
     #[inline]
     pub fn as_abs_naive(&self) -> AbsoluteAxis {
         match self {
@@ -79,6 +80,15 @@ impl AbstractAxis {
             AbstractAxis::Block => AbsoluteAxis::Vertical,
         }
     }
+
+    /// Convert an `AbstractAxis` into an `AbsoluteAxis` according to the specified `WritingMode`
+    #[inline]
+    pub fn as_abs(&self, writing_mode: WritingMode) -> AbsoluteAxis {
+        match self {
+            AbstractAxis::Inline => writing_mode.inline_axis(),
+            AbstractAxis::Block => writing_mode.inline_axis().other_axis(),
+        }
+    }
 }
 
 /// Container that holds an item in each absolute axis without specifying
@@ -687,35 +697,103 @@ impl<T> Point<T> {
             AbstractAxis::Block => self.y = value,
         }
     }
+}
 
-    /// Gets the component in the main layout axis
-    ///
-    /// Whether this is the x or y depends on the `direction` provided
-    #[cfg(feature = "flexbox")]
-    pub(crate) fn main(self, direction: FlexDirection) -> T {
-        if direction.is_row() {
-            self.x
-        } else {
-            self.y
+impl<T> Size<T> {
+    /// Swap the width and height components
+    pub fn transpose(self) -> Size<T> {
+        Size { width: self.height, height: self.width }
+    }
+}
+
+impl<T> From<Point<T>> for Size<T> {
+    fn from(value: Point<T>) -> Self {
+        Size { width: value.x, height: value.y }
+    }
+}
+
+/// A rectangle (or set of edge values such as margins) expressed in terms of flow-relative directions
+///
+/// The physical edge that each field corresponds to depends on the [`WritingMode`] and [`Direction`]
+/// it is resolved against. See <https://www.w3.org/TR/css-writing-modes-3/#logical-directions>
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LogicalRect<T> {
+    /// The value at the start edge of the inline axis
+    pub inline_start: T,
+    /// The value at the end edge of the inline axis
+    pub inline_end: T,
+    /// The value at the start edge of the block axis
+    pub block_start: T,
+    /// The value at the end edge of the block axis
+    pub block_end: T,
+}
+
+impl<T> LogicalRect<T> {
+    /// Converts this flow-relative rect into a physical [`Rect`]
+    pub fn to_physical(self, writing_mode: WritingMode, direction: Direction) -> Rect<T> {
+        let (inline_min, inline_max) = match direction {
+            Direction::Ltr => (self.inline_start, self.inline_end),
+            Direction::Rtl => (self.inline_end, self.inline_start),
+        };
+        match writing_mode {
+            WritingMode::HorizontalTb => {
+                Rect { left: inline_min, right: inline_max, top: self.block_start, bottom: self.block_end }
+            }
+            WritingMode::VerticalLr => {
+                Rect { left: self.block_start, right: self.block_end, top: inline_min, bottom: inline_max }
+            }
+            WritingMode::VerticalRl => {
+                Rect { left: self.block_end, right: self.block_start, top: inline_min, bottom: inline_max }
+            }
         }
     }
+}
 
-    /// Gets the component in the cross layout axis
-    ///
-    /// Whether this is the x or y depends on the `direction` provided
-    #[cfg(feature = "flexbox")]
-    pub(crate) fn cross(self, direction: FlexDirection) -> T {
-        if direction.is_row() {
-            self.y
-        } else {
-            self.x
+impl<T> Rect<T> {
+    /// Converts this physical rect into a flow-relative [`LogicalRect`]
+    pub fn to_logical(self, writing_mode: WritingMode, direction: Direction) -> LogicalRect<T> {
+        let (inline_min, inline_max, block_start, block_end) = match writing_mode {
+            WritingMode::HorizontalTb => (self.left, self.right, self.top, self.bottom),
+            WritingMode::VerticalLr => (self.top, self.bottom, self.left, self.right),
+            WritingMode::VerticalRl => (self.top, self.bottom, self.right, self.left),
+        };
+        match direction {
+            Direction::Ltr => LogicalRect { inline_start: inline_min, inline_end: inline_max, block_start, block_end },
+            Direction::Rtl => LogicalRect { inline_start: inline_max, inline_end: inline_min, block_start, block_end },
         }
     }
 }
 
-impl<T> From<Point<T>> for Size<T> {
-    fn from(value: Point<T>) -> Self {
-        Size { width: value.x, height: value.y }
+/// A size expressed in terms of flow-relative dimensions
+///
+/// Whether each field corresponds to the width or the height depends on the [`WritingMode`] it is resolved against.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LogicalSize<T> {
+    /// The extent in the inline axis
+    pub inline: T,
+    /// The extent in the block axis
+    pub block: T,
+}
+
+impl<T> LogicalSize<T> {
+    /// Converts this flow-relative size into a physical [`Size`]
+    pub fn to_physical(self, writing_mode: WritingMode) -> Size<T> {
+        match writing_mode.is_vertical() {
+            true => Size { width: self.block, height: self.inline },
+            false => Size { width: self.inline, height: self.block },
+        }
+    }
+}
+
+impl<T> Size<T> {
+    /// Converts this physical size into a flow-relative [`LogicalSize`]
+    pub fn to_logical(self, writing_mode: WritingMode) -> LogicalSize<T> {
+        match writing_mode.is_vertical() {
+            true => LogicalSize { inline: self.height, block: self.width },
+            false => LogicalSize { inline: self.width, block: self.height },
+        }
     }
 }
 
@@ -1,7 +1,7 @@
 //! # Taffy
 //!
 //! Taffy is a flexible, high-performance library for **UI layout**.
-//! It currently implements the Flexbox, Grid and Block layout algorithms from the CSS specification. Support for other paradigms is planned.
+//! It currently implements the Flexbox, Grid, Block and Table layout algorithms from the CSS specification. Support for other paradigms is planned.
 //! For more information on this and other future development plans see the [roadmap issue](https://github.com/DioxusLabs/taffy/issues/345).
 //!
 //! ## Architecture
@@ -101,11 +101,18 @@ pub use crate::compute::compute_flexbox_layout;
 #[cfg(feature = "grid")]
 #[doc(inline)]
 pub use crate::compute::compute_grid_layout;
+#[cfg(feature = "table_layout")]
+#[doc(inline)]
+pub use crate::compute::compute_table_layout;
 #[cfg(feature = "detailed_layout_info")]
 pub use crate::compute::detailed_info::*;
+#[cfg(feature = "grid")]
+#[doc(inline)]
+pub use crate::compute::SubgridTracks;
 #[doc(inline)]
 pub use crate::compute::{
-    compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
+    compute_cached_layout, compute_fixed_and_sticky_positions, compute_hidden_layout, compute_leaf_layout,
+    compute_root_layout, compute_root_layout_with_positions, round_layout,
 };
 #[doc(inline)]
 pub use crate::style::Style;
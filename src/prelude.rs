@@ -3,8 +3,9 @@
 pub use crate::{
     geometry::{Line, Rect, Size},
     style::{
-        AlignContent, AlignItems, AlignSelf, AvailableSpace, BoxSizing, CompactLength, Dimension, Display,
-        JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto, Position, Style,
+        AlignContent, AlignItems, AlignSelf, AlignmentSafety, AvailableSpace, BoxSizing, CompactLength, Dimension,
+        Direction, Display, JustifyContent, JustifyItems, JustifySelf, LengthPercentage, LengthPercentageAuto,
+        Position, Style, Visibility, WritingMode,
     },
     style_helpers::{
         auto, fit_content, length, max_content, min_content, percent, zero, FromFr, FromLength, FromPercent, TaffyAuto,
@@ -13,17 +14,27 @@ pub use crate::{
     tree::{Layout, LayoutPartialTree, NodeId, PrintTree, RoundTree, TraversePartialTree, TraverseTree},
 };
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use crate::style::CalcExpr;
+
+#[cfg(feature = "block_layout")]
+pub use crate::style::{Clear, DisplayOutside, Float, TextAlign, VerticalAlign};
+
 #[cfg(feature = "flexbox")]
 pub use crate::style::{FlexDirection, FlexWrap};
 
+#[cfg(feature = "table_layout")]
+pub use crate::style::{BorderCollapse, CaptionSide, TableLayout};
+
 #[cfg(feature = "grid")]
 pub use crate::style::{
-    GridAutoFlow, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
+    GridAutoFlow, GridPlacement, GridTemplateArea, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
     NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
 #[cfg(feature = "grid")]
 pub use crate::style_helpers::{
-    evenly_sized_tracks, flex, fr, line, minmax, repeat, span, TaffyGridLine, TaffyGridSpan,
+    evenly_sized_tracks, flex, fr, line, masonry, minmax, named_area, named_line, named_span, repeat, span, subgrid,
+    TaffyGridLine, TaffyGridNamedArea, TaffyGridNamedLine, TaffyGridNamedSpan, TaffyGridSpan,
 };
 
 #[cfg(feature = "taffy_tree")]
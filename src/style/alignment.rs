@@ -29,6 +29,22 @@ pub enum AlignItems {
     /// Stretch to fill the container
     Stretch,
 }
+
+impl AlignItems {
+    /// Swaps `Start` and `End`, leaving all other values unchanged.
+    ///
+    /// Used when the physical direction of the axis being aligned in is reversed by the `direction` property
+    #[cfg(feature = "flexbox")]
+    #[inline(always)]
+    pub(crate) fn with_start_and_end_swapped(self) -> Self {
+        match self {
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+            other => other,
+        }
+    }
+}
+
 /// Used to control how child nodes are aligned.
 /// Does not apply to Flexbox, and will be ignored if specified on a flex container
 /// For Grid it controls alignment in the inline axis
@@ -89,9 +105,50 @@ pub enum AlignContent {
     SpaceAround,
 }
 
+impl AlignContent {
+    /// Swaps `Start` and `End`, leaving all other values unchanged.
+    ///
+    /// Used when the physical direction of the axis being aligned in is reversed by the `direction` property
+    #[cfg(feature = "flexbox")]
+    #[inline(always)]
+    pub(crate) fn with_start_and_end_swapped(self) -> Self {
+        match self {
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+            other => other,
+        }
+    }
+}
+
 /// Sets the distribution of space between and around content items
 /// For Flexbox it controls alignment in the main axis
 /// For Grid it controls alignment in the inline axis
 ///
 /// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-content)
 pub type JustifyContent = AlignContent;
+
+/// Controls how the item(s) being aligned are aligned if they overflow their alignment container. This is the
+/// `<overflow-position>` modifier of the alignment properties (e.g. `align-items: safe center`).
+///
+/// Each alignment style has a corresponding safety style (e.g. [`AlignItems`] and `align_items_safety`).
+///
+/// [Specification](https://www.w3.org/TR/css-align-3/#overflow-values)
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlignmentSafety {
+    /// The alignment is honoured even if the item(s) overflow the alignment container, which may cause them to
+    /// overflow its start edge (beyond which they cannot be scrolled to)
+    #[default]
+    Unsafe,
+    /// If the item(s) overflow the alignment container then they are aligned as if the alignment were `start`
+    Safe,
+}
+
+impl AlignmentSafety {
+    /// Returns true if the alignment is `Safe`
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    #[inline(always)]
+    pub(crate) fn is_safe(self) -> bool {
+        self == Self::Safe
+    }
+}
@@ -0,0 +1,129 @@
+//! Style types for anchor positioning, which positions `Position::Absolute` and `Position::Fixed` nodes relative to
+//! an arbitrary other node (their "anchor") rather than only relative to their containing block
+use crate::geometry::Point;
+
+/// An edge (or the center) of an anchor, referenced by an `anchor()` inset value
+///
+/// Like in CSS, the side must be in the same axis as the inset it is used in (`Top`, `Bottom` or `Center` in the
+/// `top` and `bottom` insets, and `Left`, `Right` or `Center` in the `left` and `right` insets). Otherwise the inset
+/// is treated as `auto`.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/anchor>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnchorSide {
+    /// The top edge of the anchor's border box
+    Top = 0,
+    /// The right edge of the anchor's border box
+    Right = 1,
+    /// The bottom edge of the anchor's border box
+    Bottom = 2,
+    /// The left edge of the anchor's border box
+    Left = 3,
+    /// The center of the anchor's border box, in the axis of the inset
+    Center = 4,
+}
+
+impl AnchorSide {
+    /// Convert the value stored by [`CompactLength::anchor`](super::CompactLength::anchor) back into an `AnchorSide`
+    pub(crate) const fn from_index(index: u64) -> Self {
+        match index {
+            0 => Self::Top,
+            1 => Self::Right,
+            2 => Self::Bottom,
+            3 => Self::Left,
+            _ => Self::Center,
+        }
+    }
+
+    /// The opposite side of the anchor
+    pub(crate) fn flipped(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Right => Self::Left,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Center => Self::Center,
+        }
+    }
+}
+
+/// A dimension of an anchor, referenced by an `anchor-size()` size value
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/anchor-size>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnchorSize {
+    /// The width of the anchor's border box
+    Width = 0,
+    /// The height of the anchor's border box
+    Height = 1,
+}
+
+impl AnchorSize {
+    /// Convert the value stored by [`CompactLength::anchor_size`](super::CompactLength::anchor_size) back into an
+    /// `AnchorSize`
+    pub(crate) const fn from_index(index: u64) -> Self {
+        match index {
+            0 => Self::Width,
+            _ => Self::Height,
+        }
+    }
+}
+
+/// The region of the containing block that an anchored node is placed in, in one axis
+///
+/// The edges of the anchor divide the containing block into three tracks in each axis: the `Start` track (between the
+/// left or top edge of the containing block and the anchor), the `Center` track (spanned by the anchor) and the `End`
+/// track (between the anchor and the right or bottom edge of the containing block). The region becomes the containing
+/// block of the node, and unless its insets are set in an axis, the node is aligned towards the anchor in that axis.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/position-area>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PositionArea {
+    /// The track before the anchor. The node is aligned to the end of the track.
+    Start,
+    /// The track spanned by the anchor. The node is centered in the track.
+    Center,
+    /// The track after the anchor. The node is aligned to the start of the track.
+    End,
+    /// The start and center tracks. The node is aligned to the end of the region.
+    SpanStart,
+    /// The center and end tracks. The node is aligned to the start of the region.
+    SpanEnd,
+    /// All three tracks. The node is centered in the region.
+    SpanAll,
+}
+
+impl PositionArea {
+    /// The region on the opposite side of the anchor
+    pub(crate) fn flipped(self) -> Self {
+        match self {
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+            Self::SpanStart => Self::SpanEnd,
+            Self::SpanEnd => Self::SpanStart,
+            Self::Center | Self::SpanAll => self,
+        }
+    }
+}
+
+/// An alternative position for an anchored node, which is tried if the node overflows its containing block
+///
+/// The alternatives in the node's `position_try_fallbacks` are tried in order, and the first one with which the node
+/// fits within its containing block is used. If none of them fit, the node's own styles are used.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/position-try-fallbacks>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PositionTryFallback {
+    /// Swap the insets, margins, `anchor()` sides and `position_area` of the node in its block axis
+    FlipBlock,
+    /// Swap the insets, margins, `anchor()` sides and `position_area` of the node in its inline axis
+    FlipInline,
+    /// Apply both `FlipBlock` and `FlipInline`
+    FlipBoth,
+    /// Replace the `position_area` of the node
+    PositionArea(Point<PositionArea>),
+}
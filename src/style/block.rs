@@ -1,5 +1,5 @@
 //! Style types for Block layout
-use crate::{CoreStyle, Style};
+use crate::{CoreStyle, Dimension, LengthPercentage, Style};
 
 /// The set of styles required for a Block layout container
 pub trait BlockContainerStyle: CoreStyle {
@@ -8,6 +8,28 @@ pub trait BlockContainerStyle: CoreStyle {
     fn text_align(&self) -> TextAlign {
         Style::DEFAULT.text_align
     }
+    /// The number of columns into which the container's content is flowed. `None` (the default) means that the number
+    /// of columns is determined by [`BlockContainerStyle::column_width`].
+    #[inline(always)]
+    fn column_count(&self) -> Option<u16> {
+        Style::DEFAULT.column_count
+    }
+    /// The ideal width of the columns into which the container's content is flowed
+    #[inline(always)]
+    fn column_width(&self) -> Dimension {
+        Style::DEFAULT.column_width
+    }
+    /// The size of the gap between the columns of a multi-column container
+    #[inline(always)]
+    fn column_gap(&self) -> LengthPercentage {
+        Style::DEFAULT.gap.width
+    }
+    /// Whether the container is a multi-column container: one whose content is flowed into columns because it has a
+    /// non-auto `column_count` or `column_width`
+    #[inline(always)]
+    fn is_multicol_container(&self) -> bool {
+        self.column_count().is_some() || !self.column_width().is_auto()
+    }
 }
 
 /// The set of styles required for a Block layout item (child of a Block container)
@@ -17,6 +39,103 @@ pub trait BlockItemStyle: CoreStyle {
     fn is_table(&self) -> bool {
         false
     }
+    /// Whether the item is laid out as a block-level box or as part of its parent's lines of inline-level content
+    #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        DisplayOutside::Block
+    }
+    /// How the item is aligned within its line box when it is inline-level
+    #[inline(always)]
+    fn vertical_align(&self) -> VerticalAlign {
+        Style::DEFAULT.vertical_align
+    }
+    /// Whether the item is taken out of flow and floated to one side of its container
+    #[inline(always)]
+    fn float(&self) -> Float {
+        Style::DEFAULT.float
+    }
+    /// Which earlier floats the item is moved below
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        Style::DEFAULT.clear
+    }
+}
+
+/// How a child of a block container participates in the layout of its parent.
+///
+/// Consecutive inline-level children of a block container are laid out in lines. If the container also has block-level
+/// children, then each such run of inline-level children is wrapped in an anonymous block box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DisplayOutside {
+    /// The item generates a block-level box
+    #[default]
+    Block,
+    /// The item generates an inline box. Its children are laid out as part of the lines of the parent's inline
+    /// formatting context. If it has no children, it is a text run which is split across lines using
+    /// [`LayoutBlockContainer::break_text_run`](crate::LayoutBlockContainer::break_text_run).
+    Inline,
+    /// The item generates an atomic inline-level box, which is laid out as a single unbreakable box within a line
+    InlineBlock,
+}
+
+/// How an inline-level box is aligned within its line box
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/vertical-align>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VerticalAlign {
+    /// Aligns the baseline of the box with the baseline of the line box
+    #[default]
+    Baseline,
+    /// Aligns the top of the box's margin box with the top of the line box
+    Top,
+    /// Centers the box's margin box within the line box
+    Middle,
+    /// Aligns the bottom of the box's margin box with the bottom of the line box
+    Bottom,
+}
+
+/// Whether a child of a block container is floated to one side of its container. Content which follows a float flows
+/// around it.
+///
+/// The sides are line-relative: in vertical writing modes `Left` floats the item towards the line-left edge.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/float>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Float {
+    /// The item is not floated
+    #[default]
+    None,
+    /// The item is floated to the left edge of its container
+    Left,
+    /// The item is floated to the right edge of its container
+    Right,
+}
+
+/// Which earlier floats of the same container a block-level or floated item must be placed below
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/clear>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Clear {
+    /// The item is not moved below any floats
+    #[default]
+    None,
+    /// The item is moved below left floats
+    Left,
+    /// The item is moved below right floats
+    Right,
+    /// The item is moved below all floats
+    Both,
+}
+
+impl Clear {
+    /// Whether the item must be placed below floats of the specified kind
+    pub(crate) fn clears(self, float: Float) -> bool {
+        matches!((self, float), (Clear::Both, _) | (Clear::Left, Float::Left) | (Clear::Right, Float::Right))
+    }
 }
 
 /// Used by block layout to implement the legacy behaviour of `<center>` and `<div align="left | right | center">`
@@ -32,4 +151,12 @@ pub enum TextAlign {
     LegacyRight,
     /// Corresponds to `-webkit-center` or `-moz-center` in browsers
     LegacyCenter,
+    /// Aligns inline-level content to the inline-end edge of the container. Block-level children are not affected.
+    End,
+    /// Aligns inline-level content to the left edge of the container. Block-level children are not affected.
+    Left,
+    /// Aligns inline-level content to the right edge of the container. Block-level children are not affected.
+    Right,
+    /// Centers inline-level content within the container. Block-level children are not affected.
+    Center,
 }
@@ -0,0 +1,116 @@
+//! Style type for representing `calc()` expressions
+use crate::style_helpers::{FromLength, FromPercent};
+use crate::util::sys::{f32_max, f32_min, Box, Vec};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A `calc()` expression: a sum of lengths and percentages, which may be scaled by numbers and combined with the
+/// `min()`, `max()` and `clamp()` functions. See <https://www.w3.org/TR/css-values-4/#calc-func>
+///
+/// Percentages in the expression are resolved against the same basis as a plain percentage used in its place.
+///
+/// Styles refer to expressions by an opaque handle. [`TaffyTree::new_calc`](crate::TaffyTree::new_calc) stores an
+/// expression in the tree and returns a handle which can be passed to [`LengthPercentage::calc`](super::LengthPercentage::calc),
+/// [`LengthPercentageAuto::calc`](super::LengthPercentageAuto::calc) and [`Dimension::calc`](super::Dimension::calc).
+/// Users of the low-level API can evaluate expressions in their implementation of
+/// [`LayoutPartialTree::resolve_calc_value`](crate::LayoutPartialTree::resolve_calc_value) with [`CalcExpr::resolve`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CalcExpr {
+    /// An absolute length in some abstract units
+    Length(f32),
+    /// A percentage length relative to the basis against which the expression is resolved.
+    /// Stored as a fraction, e.g. 50% is represented as 0.5
+    Percent(f32),
+    /// The sum of the terms
+    Sum(Vec<CalcExpr>),
+    /// The expression multiplied by a number
+    Product(Box<CalcExpr>, f32),
+    /// The smallest of the terms: `min()`
+    Min(Vec<CalcExpr>),
+    /// The largest of the terms: `max()`
+    Max(Vec<CalcExpr>),
+    /// The value (second) clamped between the minimum (first) and the maximum (third): `clamp()`.
+    /// The minimum takes precedence if it is larger than the maximum.
+    Clamp(Box<CalcExpr>, Box<CalcExpr>, Box<CalcExpr>),
+}
+
+impl FromLength for CalcExpr {
+    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
+        Self::Length(value.into())
+    }
+}
+impl FromPercent for CalcExpr {
+    fn from_percent<Input: Into<f32> + Copy>(value: Input) -> Self {
+        Self::Percent(value.into())
+    }
+}
+
+impl CalcExpr {
+    /// The smallest of the terms: `min()`
+    pub fn min(terms: impl IntoIterator<Item = CalcExpr>) -> Self {
+        Self::Min(terms.into_iter().collect())
+    }
+
+    /// The largest of the terms: `max()`
+    pub fn max(terms: impl IntoIterator<Item = CalcExpr>) -> Self {
+        Self::Max(terms.into_iter().collect())
+    }
+
+    /// The value clamped between the minimum and the maximum: `clamp()`
+    pub fn clamp(min: CalcExpr, value: CalcExpr, max: CalcExpr) -> Self {
+        Self::Clamp(Box::new(min), Box::new(value), Box::new(max))
+    }
+
+    /// Evaluate the expression, resolving percentages against `basis`
+    pub fn resolve(&self, basis: f32) -> f32 {
+        match self {
+            Self::Length(length) => *length,
+            Self::Percent(fraction) => fraction * basis,
+            Self::Sum(terms) => terms.iter().map(|term| term.resolve(basis)).sum(),
+            Self::Product(expr, factor) => expr.resolve(basis) * factor,
+            Self::Min(terms) => terms.iter().map(|term| term.resolve(basis)).reduce(f32_min).unwrap_or(0.0),
+            Self::Max(terms) => terms.iter().map(|term| term.resolve(basis)).reduce(f32_max).unwrap_or(0.0),
+            Self::Clamp(min, value, max) => {
+                f32_max(min.resolve(basis), f32_min(value.resolve(basis), max.resolve(basis)))
+            }
+        }
+    }
+}
+
+impl Add for CalcExpr {
+    type Output = CalcExpr;
+
+    fn add(self, rhs: CalcExpr) -> CalcExpr {
+        match self {
+            Self::Sum(mut terms) => {
+                terms.push(rhs);
+                Self::Sum(terms)
+            }
+            lhs => Self::Sum([lhs, rhs].into_iter().collect()),
+        }
+    }
+}
+
+impl Sub for CalcExpr {
+    type Output = CalcExpr;
+
+    fn sub(self, rhs: CalcExpr) -> CalcExpr {
+        self + -rhs
+    }
+}
+
+impl Mul<f32> for CalcExpr {
+    type Output = CalcExpr;
+
+    fn mul(self, rhs: f32) -> CalcExpr {
+        Self::Product(Box::new(self), rhs)
+    }
+}
+
+impl Neg for CalcExpr {
+    type Output = CalcExpr;
+
+    fn neg(self) -> CalcExpr {
+        self * -1.0
+    }
+}
@@ -1,9 +1,11 @@
 //! A tagged-pointer abstraction that allows size styles in Taffy to be represented
 //! in just 64 bits. Wrapped by types in the `super::dimension` and `super::grid` modules.
-use super::LengthPercentage;
+use super::{AnchorSide, AnchorSize, FontMetrics, LengthPercentage};
+use crate::geometry::Size;
 use crate::style_helpers::{
     FromFr, FromLength, FromPercent, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
 };
+use crate::util::sys::{f32_max, f32_min};
 use compat::{f32_from_bits, f32_to_bits};
 
 /// A representation of a length as a compact 64-bit tagged pointer
@@ -41,6 +43,37 @@ impl CompactLength {
     pub const FIT_CONTENT_PX_TAG: u64 = 0b00010111;
     /// The tag indicating a fit-content value with percent limit
     pub const FIT_CONTENT_PERCENT_TAG: u64 = 0b00011111;
+    /// The tag indicating a stretch value
+    pub const STRETCH_TAG: u64 = 0b00100111;
+
+    // Viewport-relative tags
+
+    /// The tag indicating a vw value
+    pub const VW_TAG: u64 = 0b00000101;
+    /// The tag indicating a vh value
+    pub const VH_TAG: u64 = 0b00001101;
+    /// The tag indicating a vmin value
+    pub const VMIN_TAG: u64 = 0b00010101;
+    /// The tag indicating a vmax value
+    pub const VMAX_TAG: u64 = 0b00011101;
+
+    // Font-relative tags
+
+    /// The tag indicating an em value
+    pub const EM_TAG: u64 = 0b00100101;
+    /// The tag indicating a rem value
+    pub const REM_TAG: u64 = 0b00101101;
+    /// The tag indicating a ch value
+    pub const CH_TAG: u64 = 0b00110101;
+    /// The tag indicating an lh value
+    pub const LH_TAG: u64 = 0b00111101;
+
+    // Anchor tags
+
+    /// The tag indicating an `anchor()` value
+    pub const ANCHOR_TAG: u64 = 0b0000_0110;
+    /// The tag indicating an `anchor-size()` value
+    pub const ANCHOR_SIZE_TAG: u64 = 0b0000_1110;
 }
 
 impl CompactLength {
@@ -77,6 +110,77 @@ impl CompactLength {
         Self(Self::AUTO_TAG)
     }
 
+    /// A length relative to the width of the viewport (`vw` units in CSS): 1 unit is 1% of the viewport's width.
+    ///
+    /// Viewport-relative values are resolved by the layout algorithms against
+    /// [`LayoutPartialTree::get_viewport_size`](crate::LayoutPartialTree::get_viewport_size)
+    #[inline(always)]
+    pub const fn vw(val: f32) -> Self {
+        Self(((f32_to_bits(val) as u64) << 32) | Self::VW_TAG)
+    }
+
+    /// A length relative to the height of the viewport (`vh` units in CSS): 1 unit is 1% of the viewport's height
+    #[inline(always)]
+    pub const fn vh(val: f32) -> Self {
+        Self(((f32_to_bits(val) as u64) << 32) | Self::VH_TAG)
+    }
+
+    /// A length relative to the smaller dimension of the viewport (`vmin` units in CSS)
+    #[inline(always)]
+    pub const fn vmin(val: f32) -> Self {
+        Self(((f32_to_bits(val) as u64) << 32) | Self::VMIN_TAG)
+    }
+
+    /// A length relative to the larger dimension of the viewport (`vmax` units in CSS)
+    #[inline(always)]
+    pub const fn vmax(val: f32) -> Self {
+        Self(((f32_to_bits(val) as u64) << 32) | Self::VMAX_TAG)
+    }
+
+    /// A length relative to the font size of the node (`em` units in CSS)
+    ///
+    /// Font-relative values are resolved by the layout algorithms against
+    /// [`LayoutPartialTree::get_font_metrics`](crate::LayoutPartialTree::get_font_metrics)
+    #[inline(always)]
+    pub const fn em(val: f32) -> Self {
+        Self(((f32_to_bits(val) as u64) << 32) | Self::EM_TAG)
+    }
+
+    /// A length relative to the font size of the root node (`rem` units in CSS)
+    #[inline(always)]
+    pub const fn rem(val: f32) -> Self {
+        Self(((f32_to_bits(val) as u64) << 32) | Self::REM_TAG)
+    }
+
+    /// A length relative to the advance of the "0" glyph in the node's font (`ch` units in CSS)
+    #[inline(always)]
+    pub const fn ch(val: f32) -> Self {
+        Self(((f32_to_bits(val) as u64) << 32) | Self::CH_TAG)
+    }
+
+    /// A length relative to the line height of the node (`lh` units in CSS)
+    #[inline(always)]
+    pub const fn lh(val: f32) -> Self {
+        Self(((f32_to_bits(val) as u64) << 32) | Self::LH_TAG)
+    }
+
+    /// The position of a side of the node's anchor (`anchor()` in CSS). Only valid in the insets of `Position::Absolute`
+    /// and `Position::Fixed` nodes.
+    ///
+    /// Anchor values are resolved by [`compute_fixed_and_sticky_positions`](crate::compute_fixed_and_sticky_positions),
+    /// and are treated as `auto` by the layout algorithms
+    #[inline(always)]
+    pub const fn anchor(side: AnchorSide) -> Self {
+        Self(((side as u64) << 32) | Self::ANCHOR_TAG)
+    }
+
+    /// A dimension of the node's anchor (`anchor-size()` in CSS). Only valid in the sizes of `Position::Absolute` and
+    /// `Position::Fixed` nodes.
+    #[inline(always)]
+    pub const fn anchor_size(size: AnchorSize) -> Self {
+        Self(((size as u64) << 32) | Self::ANCHOR_SIZE_TAG)
+    }
+
     /// The dimension as a fraction of the total available grid space (`fr` units in CSS)
     /// Specified value is the numerator of the fraction. Denominator is the sum of all fraction specified in that grid dimension
     /// Spec: <https://www.w3.org/TR/css3-grid-layout/#fr-unit>
@@ -127,6 +231,13 @@ impl CompactLength {
         Self(((f32_to_bits(limit) as u64) << 32) | Self::FIT_CONTENT_PERCENT_TAG)
     }
 
+    /// The size should fill the available space: it is the size of the containing block (or other available space) minus
+    /// the node's margins
+    #[inline(always)]
+    pub const fn stretch() -> Self {
+        Self(Self::STRETCH_TAG)
+    }
+
     /// Get the primary tag
     #[inline(always)]
     pub const fn tag(self) -> u64 {
@@ -147,6 +258,12 @@ impl CompactLength {
         self.0
     }
 
+    /// Create a `CompactLength` from a value returned by [`CompactLength::calc_value`]
+    #[inline(always)]
+    pub(crate) const fn from_calc_value(val: u64) -> Self {
+        Self(val)
+    }
+
     /// Returns true if the value is 0 px
     #[inline(always)]
     pub const fn is_calc(self) -> bool {
@@ -189,6 +306,25 @@ impl CompactLength {
         matches!(self.tag(), Self::FIT_CONTENT_PX_TAG | Self::FIT_CONTENT_PERCENT_TAG)
     }
 
+    /// Returns true if the value is stretch
+    #[inline(always)]
+    pub const fn is_stretch(self) -> bool {
+        matches!(self.tag(), Self::STRETCH_TAG)
+    }
+
+    /// Returns true if the value is min-content, max-content, fit-content(...) or stretch
+    #[inline(always)]
+    pub const fn is_sizing_keyword(self) -> bool {
+        matches!(
+            self.tag(),
+            Self::MIN_CONTENT_TAG
+                | Self::MAX_CONTENT_TAG
+                | Self::FIT_CONTENT_PX_TAG
+                | Self::FIT_CONTENT_PERCENT_TAG
+                | Self::STRETCH_TAG
+        )
+    }
+
     /// Returns true if the value is max-content or a fit-content(...) value
     #[inline(always)]
     pub const fn is_max_or_fit_content(self) -> bool {
@@ -234,6 +370,49 @@ impl CompactLength {
         self.tag() == Self::FR_TAG
     }
 
+    /// Returns true if the value is a vw, vh, vmin or vmax value
+    #[inline(always)]
+    pub const fn is_viewport_relative(self) -> bool {
+        matches!(self.tag(), Self::VW_TAG | Self::VH_TAG | Self::VMIN_TAG | Self::VMAX_TAG)
+    }
+
+    /// Returns true if the value is an em, rem, ch or lh value
+    #[inline(always)]
+    pub const fn is_font_relative(self) -> bool {
+        matches!(self.tag(), Self::EM_TAG | Self::REM_TAG | Self::CH_TAG | Self::LH_TAG)
+    }
+
+    /// Returns true if the value is a viewport-relative or font-relative length. Such values are resolved by the calc
+    /// resolver, and do not depend on the size of the parent node.
+    #[inline(always)]
+    pub const fn is_relative_length(self) -> bool {
+        self.is_viewport_relative() || self.is_font_relative()
+    }
+
+    /// Returns true if the value is an `anchor()` or `anchor-size()` value
+    #[inline(always)]
+    pub const fn is_anchor(self) -> bool {
+        matches!(self.tag(), Self::ANCHOR_TAG | Self::ANCHOR_SIZE_TAG)
+    }
+
+    /// The side of the anchor referenced by an `anchor()` value, or `None` for other values
+    #[inline(always)]
+    pub const fn anchor_side(self) -> Option<AnchorSide> {
+        match self.tag() {
+            Self::ANCHOR_TAG => Some(AnchorSide::from_index(self.0 >> 32)),
+            _ => None,
+        }
+    }
+
+    /// The dimension of the anchor referenced by an `anchor-size()` value, or `None` for other values
+    #[inline(always)]
+    pub const fn anchor_dimension(self) -> Option<AnchorSize> {
+        match self.tag() {
+            Self::ANCHOR_SIZE_TAG => Some(AnchorSize::from_index(self.0 >> 32)),
+            _ => None,
+        }
+    }
+
     /// Whether the track sizing functions depends on the size of the parent node
     #[inline(always)]
     pub const fn uses_percentage(self) -> bool {
@@ -251,6 +430,34 @@ impl CompactLength {
             _ => None,
         }
     }
+
+    /// Resolve viewport-relative values against the passed viewport size, returning Some(value)
+    /// Values which are not viewport-relative always return None.
+    #[inline(always)]
+    pub fn resolved_viewport_size(self, viewport_size: Size<f32>) -> Option<f32> {
+        let basis = match self.tag() {
+            CompactLength::VW_TAG => viewport_size.width,
+            CompactLength::VH_TAG => viewport_size.height,
+            CompactLength::VMIN_TAG => f32_min(viewport_size.width, viewport_size.height),
+            CompactLength::VMAX_TAG => f32_max(viewport_size.width, viewport_size.height),
+            _ => return None,
+        };
+        Some(self.value() * basis / 100.0)
+    }
+
+    /// Resolve font-relative values against the passed font metrics (and root font size for `rem` values),
+    /// returning Some(value). Values which are not font-relative always return None.
+    #[inline(always)]
+    pub fn resolved_font_relative_size(self, font_metrics: FontMetrics, root_font_size: f32) -> Option<f32> {
+        let basis = match self.tag() {
+            CompactLength::EM_TAG => font_metrics.font_size,
+            CompactLength::REM_TAG => root_font_size,
+            CompactLength::CH_TAG => font_metrics.zero_advance,
+            CompactLength::LH_TAG => font_metrics.line_height,
+            _ => return None,
+        };
+        Some(self.value() * basis)
+    }
 }
 
 impl TaffyZero for CompactLength {
@@ -1,7 +1,9 @@
 //! Style types for representing lengths / sizes
-use super::CompactLength;
+use super::{AnchorSide, AnchorSize, CompactLength};
 use crate::geometry::Rect;
-use crate::style_helpers::{FromLength, FromPercent, TaffyAuto, TaffyZero};
+use crate::style_helpers::{
+    FromLength, FromPercent, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
+};
 
 /// A unit of linear measurement
 ///
@@ -38,6 +40,54 @@ impl LengthPercentage {
         Self(CompactLength::percent(val))
     }
 
+    /// A length relative to the width of the viewport (`vw` units in CSS): 1 unit is 1% of the viewport's width
+    #[inline(always)]
+    pub const fn vw(val: f32) -> Self {
+        Self(CompactLength::vw(val))
+    }
+
+    /// A length relative to the height of the viewport (`vh` units in CSS): 1 unit is 1% of the viewport's height
+    #[inline(always)]
+    pub const fn vh(val: f32) -> Self {
+        Self(CompactLength::vh(val))
+    }
+
+    /// A length relative to the smaller dimension of the viewport (`vmin` units in CSS)
+    #[inline(always)]
+    pub const fn vmin(val: f32) -> Self {
+        Self(CompactLength::vmin(val))
+    }
+
+    /// A length relative to the larger dimension of the viewport (`vmax` units in CSS)
+    #[inline(always)]
+    pub const fn vmax(val: f32) -> Self {
+        Self(CompactLength::vmax(val))
+    }
+
+    /// A length relative to the font size of the node (`em` units in CSS)
+    #[inline(always)]
+    pub const fn em(val: f32) -> Self {
+        Self(CompactLength::em(val))
+    }
+
+    /// A length relative to the font size of the root node (`rem` units in CSS)
+    #[inline(always)]
+    pub const fn rem(val: f32) -> Self {
+        Self(CompactLength::rem(val))
+    }
+
+    /// A length relative to the advance of the "0" glyph in the node's font (`ch` units in CSS)
+    #[inline(always)]
+    pub const fn ch(val: f32) -> Self {
+        Self(CompactLength::ch(val))
+    }
+
+    /// A length relative to the line height of the node (`lh` units in CSS)
+    #[inline(always)]
+    pub const fn lh(val: f32) -> Self {
+        Self(CompactLength::lh(val))
+    }
+
     /// A `calc()` value. The value passed here is treated as an opaque handle to
     /// the actual calc representation and may be a pointer, index, etc.
     ///
@@ -105,6 +155,61 @@ impl LengthPercentageAuto {
         Self(CompactLength::percent(val))
     }
 
+    /// A length relative to the width of the viewport (`vw` units in CSS): 1 unit is 1% of the viewport's width
+    #[inline(always)]
+    pub const fn vw(val: f32) -> Self {
+        Self(CompactLength::vw(val))
+    }
+
+    /// A length relative to the height of the viewport (`vh` units in CSS): 1 unit is 1% of the viewport's height
+    #[inline(always)]
+    pub const fn vh(val: f32) -> Self {
+        Self(CompactLength::vh(val))
+    }
+
+    /// A length relative to the smaller dimension of the viewport (`vmin` units in CSS)
+    #[inline(always)]
+    pub const fn vmin(val: f32) -> Self {
+        Self(CompactLength::vmin(val))
+    }
+
+    /// A length relative to the larger dimension of the viewport (`vmax` units in CSS)
+    #[inline(always)]
+    pub const fn vmax(val: f32) -> Self {
+        Self(CompactLength::vmax(val))
+    }
+
+    /// A length relative to the font size of the node (`em` units in CSS)
+    #[inline(always)]
+    pub const fn em(val: f32) -> Self {
+        Self(CompactLength::em(val))
+    }
+
+    /// A length relative to the font size of the root node (`rem` units in CSS)
+    #[inline(always)]
+    pub const fn rem(val: f32) -> Self {
+        Self(CompactLength::rem(val))
+    }
+
+    /// A length relative to the advance of the "0" glyph in the node's font (`ch` units in CSS)
+    #[inline(always)]
+    pub const fn ch(val: f32) -> Self {
+        Self(CompactLength::ch(val))
+    }
+
+    /// A length relative to the line height of the node (`lh` units in CSS)
+    #[inline(always)]
+    pub const fn lh(val: f32) -> Self {
+        Self(CompactLength::lh(val))
+    }
+
+    /// The position of a side of the node's anchor (`anchor()` in CSS). Only valid in the `inset` of `Position::Absolute`
+    /// and `Position::Fixed` nodes which have a `position_anchor`, and otherwise treated as `auto`.
+    #[inline(always)]
+    pub const fn anchor(side: AnchorSide) -> Self {
+        Self(CompactLength::anchor(side))
+    }
+
     /// The dimension should be automatically computed according to algorithm-specific rules
     /// regarding the default size of boxes.
     #[inline(always)]
@@ -137,14 +242,17 @@ impl LengthPercentageAuto {
     /// Returns:
     ///   - Some(length) for Length variants
     ///   - Some(resolved) using the provided context for Percent variants
-    ///   - None for Auto variants
+    ///   - Some(resolved) using `calc_resolver` for calc, viewport-relative and font-relative variants (so the resolver
+    ///     must resolve relative lengths against a [`RelativeLengthBasis`](crate::RelativeLengthBasis))
+    ///   - None for Auto and anchor variants
     #[inline(always)]
     pub fn resolve_to_option(self, context: f32, calc_resolver: impl Fn(u64, f32) -> f32) -> Option<f32> {
         match self.0.tag() {
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => Some(context * self.0.value()),
             CompactLength::AUTO_TAG => None,
-            _ if self.0.is_calc() => Some(calc_resolver(self.0.calc_value(), context)),
+            _ if self.0.is_anchor() => None,
+            _ if self.0.is_calc() || self.0.is_relative_length() => Some(calc_resolver(self.0.calc_value(), context)),
             _ => unreachable!("LengthPercentageAuto values cannot be constructed with other tags"),
         }
     }
@@ -178,6 +286,17 @@ impl FromPercent for Dimension {
         Self::percent(value.into())
     }
 }
+impl TaffyMinContent for Dimension {
+    const MIN_CONTENT: Self = Self(CompactLength::MIN_CONTENT);
+}
+impl TaffyMaxContent for Dimension {
+    const MAX_CONTENT: Self = Self(CompactLength::MAX_CONTENT);
+}
+impl TaffyFitContent for Dimension {
+    fn fit_content(argument: LengthPercentage) -> Self {
+        Self(CompactLength::fit_content(argument))
+    }
+}
 impl From<LengthPercentage> for Dimension {
     fn from(input: LengthPercentage) -> Self {
         Self(input.0)
@@ -205,6 +324,61 @@ impl Dimension {
         Self(CompactLength::percent(val))
     }
 
+    /// A length relative to the width of the viewport (`vw` units in CSS): 1 unit is 1% of the viewport's width
+    #[inline(always)]
+    pub const fn vw(val: f32) -> Self {
+        Self(CompactLength::vw(val))
+    }
+
+    /// A length relative to the height of the viewport (`vh` units in CSS): 1 unit is 1% of the viewport's height
+    #[inline(always)]
+    pub const fn vh(val: f32) -> Self {
+        Self(CompactLength::vh(val))
+    }
+
+    /// A length relative to the smaller dimension of the viewport (`vmin` units in CSS)
+    #[inline(always)]
+    pub const fn vmin(val: f32) -> Self {
+        Self(CompactLength::vmin(val))
+    }
+
+    /// A length relative to the larger dimension of the viewport (`vmax` units in CSS)
+    #[inline(always)]
+    pub const fn vmax(val: f32) -> Self {
+        Self(CompactLength::vmax(val))
+    }
+
+    /// A length relative to the font size of the node (`em` units in CSS)
+    #[inline(always)]
+    pub const fn em(val: f32) -> Self {
+        Self(CompactLength::em(val))
+    }
+
+    /// A length relative to the font size of the root node (`rem` units in CSS)
+    #[inline(always)]
+    pub const fn rem(val: f32) -> Self {
+        Self(CompactLength::rem(val))
+    }
+
+    /// A length relative to the advance of the "0" glyph in the node's font (`ch` units in CSS)
+    #[inline(always)]
+    pub const fn ch(val: f32) -> Self {
+        Self(CompactLength::ch(val))
+    }
+
+    /// A length relative to the line height of the node (`lh` units in CSS)
+    #[inline(always)]
+    pub const fn lh(val: f32) -> Self {
+        Self(CompactLength::lh(val))
+    }
+
+    /// A dimension of the node's anchor (`anchor-size()` in CSS). Only valid in the sizes of `Position::Absolute` and
+    /// `Position::Fixed` nodes which have a `position_anchor`, and otherwise treated as `auto`.
+    #[inline(always)]
+    pub const fn anchor_size(size: AnchorSize) -> Self {
+        Self(CompactLength::anchor_size(size))
+    }
+
     /// The dimension should be automatically computed according to algorithm-specific rules
     /// regarding the default size of boxes.
     #[inline(always)]
@@ -221,6 +395,55 @@ impl Dimension {
         Self(CompactLength::calc(ptr))
     }
 
+    /// The size should be the "min-content" size.
+    /// This is the smallest size that can fit the node's contents with ALL soft line-wrapping opportunities taken
+    #[inline(always)]
+    pub const fn min_content() -> Self {
+        Self(CompactLength::min_content())
+    }
+
+    /// The size should be the "max-content" size.
+    /// This is the smallest size that can fit the node's contents with NO soft line-wrapping opportunities taken
+    #[inline(always)]
+    pub const fn max_content() -> Self {
+        Self(CompactLength::max_content())
+    }
+
+    /// The size should be computed according to the "fit content" formula:
+    ///    `max(min_content, min(max_content, limit))`
+    /// where:
+    ///    - `min_content` is the [min-content](Self::min_content) size
+    ///    - `max_content` is the [max-content](Self::max_content) size
+    ///    - `limit` is a LENGTH value passed to this function
+    ///
+    /// The effect of this is that the node takes the size of `limit` clamped
+    /// by the min-content and max-content sizes.
+    #[inline(always)]
+    pub const fn fit_content_px(limit: f32) -> Self {
+        Self(CompactLength::fit_content_px(limit))
+    }
+
+    /// The size should be computed according to the "fit content" formula:
+    ///    `max(min_content, min(max_content, limit))`
+    /// where:
+    ///    - `min_content` is the [min-content](Self::min_content) size
+    ///    - `max_content` is the [max-content](Self::max_content) size
+    ///    - `limit` is a PERCENTAGE value passed to this function
+    ///
+    /// The effect of this is that the node takes the size of `limit` clamped
+    /// by the min-content and max-content sizes.
+    #[inline(always)]
+    pub const fn fit_content_percent(limit: f32) -> Self {
+        Self(CompactLength::fit_content_percent(limit))
+    }
+
+    /// The size should fill the available space: it is the size of the containing block (or other available space)
+    /// minus the node's margins. If the available space is indefinite then the size is treated as auto.
+    #[inline(always)]
+    pub const fn stretch() -> Self {
+        Self(CompactLength::stretch())
+    }
+
     /// Create a LengthPercentageAuto from a raw `CompactLength`.
     /// # Safety
     /// CompactLength must represent a valid variant for LengthPercentageAuto
@@ -234,6 +457,25 @@ impl Dimension {
         self.0
     }
 
+    /// Returns true if value is a sizing keyword: min-content, max-content, fit-content(...) or stretch
+    #[inline(always)]
+    pub fn is_sizing_keyword(self) -> bool {
+        self.0.is_sizing_keyword()
+    }
+
+    /// Returns true if value is the stretch keyword
+    #[inline(always)]
+    pub fn is_stretch(self) -> bool {
+        self.0.is_stretch()
+    }
+
+    /// Returns true if value is an intrinsic sizing keyword, which sizes the node according to its content:
+    /// min-content, max-content or fit-content(...)
+    #[inline(always)]
+    pub fn is_intrinsic_sizing_keyword(self) -> bool {
+        self.0.is_min_content() || self.0.is_max_or_fit_content()
+    }
+
     /// Get Length value if value is Length variant
     #[cfg(feature = "grid")]
     pub fn into_option(self) -> Option<f32> {
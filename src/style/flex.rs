@@ -1,5 +1,7 @@
 //! Style types for Flexbox layout
-use super::{AlignContent, AlignItems, AlignSelf, CoreStyle, Dimension, JustifyContent, LengthPercentage, Style};
+use super::{
+    AlignContent, AlignItems, AlignSelf, AlignmentSafety, CoreStyle, Dimension, JustifyContent, LengthPercentage, Style,
+};
 use crate::geometry::Size;
 
 /// The set of styles required for a Flexbox container
@@ -28,16 +30,31 @@ pub trait FlexboxContainerStyle: CoreStyle {
     fn align_content(&self) -> Option<AlignContent> {
         Style::DEFAULT.align_content
     }
+    /// How content contained within this item is aligned in the cross/block axis if it overflows this item
+    #[inline(always)]
+    fn align_content_safety(&self) -> AlignmentSafety {
+        Style::DEFAULT.align_content_safety
+    }
     /// How this node's children aligned in the cross/block axis?
     #[inline(always)]
     fn align_items(&self) -> Option<AlignItems> {
         Style::DEFAULT.align_items
     }
+    /// How this node's children are aligned in the cross/block axis if they overflow the area they are aligned within
+    #[inline(always)]
+    fn align_items_safety(&self) -> AlignmentSafety {
+        Style::DEFAULT.align_items_safety
+    }
     /// How this node's children should be aligned in the inline axis
     #[inline(always)]
     fn justify_content(&self) -> Option<JustifyContent> {
         Style::DEFAULT.justify_content
     }
+    /// How content contained within this item is aligned in the main/inline axis if it overflows this item
+    #[inline(always)]
+    fn justify_content_safety(&self) -> AlignmentSafety {
+        Style::DEFAULT.justify_content_safety
+    }
 }
 
 /// The set of styles required for a Flexbox item (child of a Flexbox container)
@@ -64,6 +81,19 @@ pub trait FlexboxItemStyle: CoreStyle {
     fn align_self(&self) -> Option<AlignSelf> {
         Style::DEFAULT.align_self
     }
+    /// How this node is aligned in the cross/block axis if it overflows the area it is aligned within
+    /// Falls back to the parents `align_items_safety` if not set
+    #[inline(always)]
+    fn align_self_safety(&self) -> Option<AlignmentSafety> {
+        Style::DEFAULT.align_self_safety
+    }
+
+    /// The order in which this item is laid out relative to its siblings.
+    /// Items with an equal `order` are laid out in document order.
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        Style::DEFAULT.order
+    }
 }
 
 use crate::geometry::AbsoluteAxis;
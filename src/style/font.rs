@@ -0,0 +1,53 @@
+//! Style types for representing the font metrics (and viewport size) which relative lengths are resolved against
+use super::CompactLength;
+use crate::geometry::Size;
+
+/// The computed font metrics of a node, which font-relative lengths (`em`, `ch` and `lh` units) are resolved against.
+/// The root node's font size is used to resolve `rem` units.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FontMetrics {
+    /// The computed font size of the node (the basis of `em` units)
+    pub font_size: f32,
+    /// The computed line height of the node (the basis of `lh` units)
+    pub line_height: f32,
+    /// The advance of the "0" glyph in the node's font (the basis of `ch` units)
+    pub zero_advance: f32,
+}
+
+impl FontMetrics {
+    /// The default font metrics: a 16px font with a line height of 1.2em and a "0" glyph 0.5em wide
+    pub const DEFAULT: Self = Self::from_font_size(16.0);
+
+    /// Font metrics for the given font size, with a line height of 1.2em and a "0" glyph 0.5em wide
+    pub const fn from_font_size(font_size: f32) -> Self {
+        Self { font_size, line_height: font_size * 1.2, zero_advance: font_size * 0.5 }
+    }
+}
+
+impl Default for FontMetrics {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The sizes against which the viewport-relative and font-relative lengths in the styles of a node are resolved
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RelativeLengthBasis {
+    /// The size of the viewport (the basis of `vw`, `vh`, `vmin` and `vmax` units)
+    pub viewport_size: Size<f32>,
+    /// The computed font metrics of the node (the basis of `em`, `ch` and `lh` units)
+    pub font_metrics: FontMetrics,
+    /// The computed font size of the root node (the basis of `rem` units)
+    pub root_font_size: f32,
+}
+
+impl RelativeLengthBasis {
+    /// Resolve viewport-relative and font-relative values, returning Some(value). Other values always return None.
+    #[inline(always)]
+    pub fn resolve(self, length: CompactLength) -> Option<f32> {
+        length
+            .resolved_viewport_size(self.viewport_size)
+            .or_else(|| length.resolved_font_relative_size(self.font_metrics, self.root_font_size))
+    }
+}
@@ -1,18 +1,30 @@
 //! Style types for CSS Grid layout
 use super::{
-    AlignContent, AlignItems, AlignSelf, CompactLength, CoreStyle, Dimension, JustifyContent, LengthPercentage,
-    LengthPercentageAuto, Style,
+    AlignContent, AlignItems, AlignSelf, AlignmentSafety, CompactLength, CoreStyle, Dimension, JustifyContent,
+    LengthPercentage, LengthPercentageAuto, Style,
 };
 use crate::compute::grid::{GridCoordinate, GridLine, OriginZeroLine};
 use crate::geometry::{AbsoluteAxis, AbstractAxis, Line, MinMax, Size};
 use crate::style_helpers::*;
-use crate::util::sys::GridTrackVec;
+use crate::util::sys::{Arc, GridTrackVec};
 use core::borrow::Borrow;
 use core::cmp::{max, min};
 use core::convert::Infallible;
+use core::fmt::Debug;
+
+/// A string type which is cheap to clone, used for the names of grid lines and grid areas (CSS `<custom-ident>`s).
+/// Styles return grid placements by value, so types which copy the string when cloned (such as `String`) should be
+/// avoided. [`DefaultCheapStr`] is used by [`Style`], and `&'static str` keeps [`GenericGridPlacement`] `Copy`.
+pub trait CheapCloneStr: AsRef<str> + Clone + PartialEq + Eq + Debug {}
+impl<T: AsRef<str> + Clone + PartialEq + Eq + Debug> CheapCloneStr for T {}
+
+/// The [`CheapCloneStr`] used for the names of grid lines and grid areas by [`Style`]
+pub type DefaultCheapStr = Arc<str>;
 
 /// The set of styles required for a CSS Grid container
 pub trait GridContainerStyle: CoreStyle {
+    /// The type of the names of grid lines and grid areas
+    type CustomIdent: CheapCloneStr;
     /// The type returned by grid_template_rows and grid_template_columns
     type TemplateTrackList<'a>: Borrow<[TrackSizingFunction]>
     where
@@ -34,6 +46,22 @@ pub trait GridContainerStyle: CoreStyle {
     /// Defined the size of implicitly created columns
     fn grid_auto_columns(&self) -> Self::AutoTrackList<'_>;
 
+    /// Defines the names of the lines between the grid rows
+    #[inline(always)]
+    fn grid_template_row_names(&self) -> &[GridTrackVec<Self::CustomIdent>] {
+        &[]
+    }
+    /// Defines the names of the lines between the grid columns
+    #[inline(always)]
+    fn grid_template_column_names(&self) -> &[GridTrackVec<Self::CustomIdent>] {
+        &[]
+    }
+    /// Defines named grid areas which items can be placed into by name
+    #[inline(always)]
+    fn grid_template_areas(&self) -> &[GridTemplateArea<Self::CustomIdent>] {
+        &[]
+    }
+
     /// Controls how items get placed into the grid for auto-placed items
     #[inline(always)]
     fn grid_auto_flow(&self) -> GridAutoFlow {
@@ -53,21 +81,53 @@ pub trait GridContainerStyle: CoreStyle {
     fn align_content(&self) -> Option<AlignContent> {
         Style::DEFAULT.align_content
     }
+    /// How content contained within this item is aligned in the cross/block axis if it overflows this item
+    #[inline(always)]
+    fn align_content_safety(&self) -> AlignmentSafety {
+        Style::DEFAULT.align_content_safety
+    }
     /// How should contained within this item be aligned in the main/inline axis
     #[inline(always)]
     fn justify_content(&self) -> Option<JustifyContent> {
         Style::DEFAULT.justify_content
     }
+    /// How content contained within this item is aligned in the main/inline axis if it overflows this item
+    #[inline(always)]
+    fn justify_content_safety(&self) -> AlignmentSafety {
+        Style::DEFAULT.justify_content_safety
+    }
+    /// How the items within each track of a masonry grid should be aligned in the block axis, if the rows are the
+    /// masonry axis
+    #[inline(always)]
+    fn align_tracks(&self) -> Option<AlignContent> {
+        Style::DEFAULT.align_tracks
+    }
+    /// How the items within each track of a masonry grid should be aligned in the inline axis, if the columns are the
+    /// masonry axis
+    #[inline(always)]
+    fn justify_tracks(&self) -> Option<JustifyContent> {
+        Style::DEFAULT.justify_tracks
+    }
     /// How this node's children aligned in the cross/block axis?
     #[inline(always)]
     fn align_items(&self) -> Option<AlignItems> {
         Style::DEFAULT.align_items
     }
+    /// How this node's children are aligned in the cross/block axis if they overflow the area they are aligned within
+    #[inline(always)]
+    fn align_items_safety(&self) -> AlignmentSafety {
+        Style::DEFAULT.align_items_safety
+    }
     /// How this node's children should be aligned in the inline axis
     #[inline(always)]
     fn justify_items(&self) -> Option<AlignItems> {
         Style::DEFAULT.justify_items
     }
+    /// How this node's children are aligned in the inline axis if they overflow the area they are aligned within
+    #[inline(always)]
+    fn justify_items_safety(&self) -> AlignmentSafety {
+        Style::DEFAULT.justify_items_safety
+    }
 
     /// Get a grid item's row or column placement depending on the axis passed
     #[inline(always)]
@@ -78,6 +138,15 @@ pub trait GridContainerStyle: CoreStyle {
         }
     }
 
+    /// Get a grid container's row or column line names depending on the axis passed
+    #[inline(always)]
+    fn grid_template_line_names(&self, axis: AbsoluteAxis) -> &[GridTrackVec<Self::CustomIdent>] {
+        match axis {
+            AbsoluteAxis::Horizontal => self.grid_template_column_names(),
+            AbsoluteAxis::Vertical => self.grid_template_row_names(),
+        }
+    }
+
     /// Get a grid container's align-content or justify-content alignment depending on the axis passed
     #[inline(always)]
     fn grid_align_content(&self, axis: AbstractAxis) -> AlignContent {
@@ -90,15 +159,18 @@ pub trait GridContainerStyle: CoreStyle {
 
 /// The set of styles required for a CSS Grid item (child of a CSS Grid container)
 pub trait GridItemStyle: CoreStyle {
+    /// The type of the names of the grid lines and grid areas referred to by the item's placement
+    type CustomIdent: CheapCloneStr;
+
     /// Defines which row in the grid the item should start and end at
     #[inline(always)]
-    fn grid_row(&self) -> Line<GridPlacement> {
-        Style::DEFAULT.grid_row
+    fn grid_row(&self) -> Line<GridPlacement<Self::CustomIdent>> {
+        Line { start: GridPlacement::Auto, end: GridPlacement::Auto }
     }
     /// Defines which column in the grid the item should start and end at
     #[inline(always)]
-    fn grid_column(&self) -> Line<GridPlacement> {
-        Style::DEFAULT.grid_column
+    fn grid_column(&self) -> Line<GridPlacement<Self::CustomIdent>> {
+        Line { start: GridPlacement::Auto, end: GridPlacement::Auto }
     }
 
     /// How this node should be aligned in the cross/block axis
@@ -107,16 +179,42 @@ pub trait GridItemStyle: CoreStyle {
     fn align_self(&self) -> Option<AlignSelf> {
         Style::DEFAULT.align_self
     }
+    /// How this node is aligned in the cross/block axis if it overflows the area it is aligned within
+    /// Falls back to the parents `align_items_safety` if not set
+    #[inline(always)]
+    fn align_self_safety(&self) -> Option<AlignmentSafety> {
+        Style::DEFAULT.align_self_safety
+    }
     /// How this node should be aligned in the inline axis
     /// Falls back to the parents [`super::JustifyItems`] if not set
     #[inline(always)]
     fn justify_self(&self) -> Option<AlignSelf> {
         Style::DEFAULT.justify_self
     }
+    /// How this node is aligned in the inline axis if it overflows the area it is aligned within
+    /// Falls back to the parents `justify_items_safety` if not set
+    #[inline(always)]
+    fn justify_self_safety(&self) -> Option<AlignmentSafety> {
+        Style::DEFAULT.justify_self_safety
+    }
+
+    /// The order in which this item is placed into the grid relative to its siblings.
+    /// Items with an equal `order` are placed in document order.
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        Style::DEFAULT.order
+    }
+
+    /// Whether the item is a subgrid in the given axis. That is, whether it is itself a grid container whose
+    /// template in that axis is [`TrackSizingFunction::Subgrid`].
+    #[inline(always)]
+    fn is_subgrid(&self, _axis: AbsoluteAxis) -> bool {
+        false
+    }
 
     /// Get a grid item's row or column placement depending on the axis passed
     #[inline(always)]
-    fn grid_placement(&self, axis: AbsoluteAxis) -> Line<GridPlacement> {
+    fn grid_placement(&self, axis: AbsoluteAxis) -> Line<GridPlacement<Self::CustomIdent>> {
         match axis {
             AbsoluteAxis::Horizontal => self.grid_column(),
             AbsoluteAxis::Vertical => self.grid_row(),
@@ -173,81 +271,149 @@ impl GridAutoFlow {
 /// A grid line placement specification which is generic over the coordinate system that it uses to define
 /// grid line positions.
 ///
-/// GenericGridPlacement<GridLine> is aliased as GridPlacement and is exposed to users of Taffy to define styles.
-/// GenericGridPlacement<OriginZeroLine> is aliased as OriginZeroGridPlacement and is used internally for placement computations.
+/// `GenericGridPlacement<GridLine>` is aliased as [`GridPlacement`] and is exposed to users of Taffy to define styles.
+/// Named placements are resolved into numeric origin-zero placements before being used in placement computations.
+/// Line names are of type `S`, and the placement is `Copy` if `S` is.
 ///
-/// See [`crate::compute::grid::type::coordinates`] for documentation on the different coordinate systems.
+/// See the `compute::grid::types::coordinates` module for documentation on the different coordinate systems.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum GenericGridPlacement<LineType: GridCoordinate> {
+pub enum GenericGridPlacement<LineType: GridCoordinate, S: CheapCloneStr = DefaultCheapStr> {
     /// Place item according to the auto-placement algorithm, and the parent's grid_auto_flow property
     Auto,
     /// Place item at specified line (column or row) index
     Line(LineType),
     /// Item should span specified number of tracks (columns or rows)
     Span(u16),
+    /// Place item at the nth line (column or row) with the specified name. Negative indices count backwards from
+    /// the end of the explicit grid. If there are fewer than n lines with that name then all implicit lines are
+    /// assumed to have that name. An index of zero is invalid and will be treated as `Auto`.
+    NamedLine(S, i16),
+    /// Item should span until the nth line (column or row) with the specified name, counting from the item's other edge.
+    /// If the other edge is not a definite line then this is treated as a span of 1.
+    NamedSpan(S, u16),
+    /// Place item at the `<name>-start` line (when used as a start placement) or the `<name>-end` line (when used
+    /// as an end placement). Named grid areas implicitly create such lines. If there is no such line then this
+    /// is treated as `NamedLine(name, 1)`.
+    NamedArea(S),
 }
 
 /// A grid line placement using the normalized OriginZero coordinates to specify line positions.
-pub(crate) type OriginZeroGridPlacement = GenericGridPlacement<OriginZeroLine>;
+///
+/// This is the resolved form of a [`GridPlacement`]: any named lines will already have been resolved to line indices.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum OriginZeroGridPlacement {
+    /// Place item according to the auto-placement algorithm, and the parent's grid_auto_flow property
+    Auto,
+    /// Place item at specified line (column or row) index
+    Line(OriginZeroLine),
+    /// Item should span specified number of tracks (columns or rows)
+    Span(u16),
+}
 
-/// A grid line placement specification. Used for grid-[row/column]-[start/end]. Named tracks are not implemented.
+/// A grid line placement specification. Used for grid-[row/column]-[start/end].
 ///
 /// Defaults to `GridPlacement::Auto`
 ///
 /// [Specification](https://www.w3.org/TR/css3-grid-layout/#typedef-grid-row-start-grid-line)
-pub type GridPlacement = GenericGridPlacement<GridLine>;
-impl TaffyAuto for GridPlacement {
+pub type GridPlacement<S = DefaultCheapStr> = GenericGridPlacement<GridLine, S>;
+impl<S: CheapCloneStr> TaffyAuto for GridPlacement<S> {
     const AUTO: Self = Self::Auto;
 }
-impl TaffyGridLine for GridPlacement {
+impl<S: CheapCloneStr> TaffyGridLine for GridPlacement<S> {
     fn from_line_index(index: i16) -> Self {
         GridPlacement::Line(GridLine::from(index))
     }
 }
-impl TaffyGridLine for Line<GridPlacement> {
+impl<S: CheapCloneStr> TaffyGridLine for Line<GridPlacement<S>> {
     fn from_line_index(index: i16) -> Self {
         Line { start: GridPlacement::from_line_index(index), end: GridPlacement::Auto }
     }
 }
-impl TaffyGridSpan for GridPlacement {
+impl<S: CheapCloneStr> TaffyGridSpan for GridPlacement<S> {
     fn from_span(span: u16) -> Self {
         GridPlacement::Span(span)
     }
 }
-impl TaffyGridSpan for Line<GridPlacement> {
+impl<S: CheapCloneStr> TaffyGridSpan for Line<GridPlacement<S>> {
     fn from_span(span: u16) -> Self {
         Line { start: GridPlacement::from_span(span), end: GridPlacement::Auto }
     }
 }
+impl<S: CheapCloneStr + for<'a> From<&'a str>> TaffyGridNamedLine for GridPlacement<S> {
+    fn from_named_line(name: &str, index: i16) -> Self {
+        GridPlacement::NamedLine(name.into(), index)
+    }
+}
+impl<S: CheapCloneStr + for<'a> From<&'a str>> TaffyGridNamedLine for Line<GridPlacement<S>> {
+    fn from_named_line(name: &str, index: i16) -> Self {
+        Line { start: GridPlacement::from_named_line(name, index), end: GridPlacement::Auto }
+    }
+}
+impl<S: CheapCloneStr + for<'a> From<&'a str>> TaffyGridNamedSpan for GridPlacement<S> {
+    fn from_named_span(name: &str, span: u16) -> Self {
+        GridPlacement::NamedSpan(name.into(), span)
+    }
+}
+impl<S: CheapCloneStr + for<'a> From<&'a str>> TaffyGridNamedSpan for Line<GridPlacement<S>> {
+    fn from_named_span(name: &str, span: u16) -> Self {
+        Line { start: GridPlacement::from_named_span(name, span), end: GridPlacement::Auto }
+    }
+}
+impl<S: CheapCloneStr + for<'a> From<&'a str>> TaffyGridNamedArea for GridPlacement<S> {
+    fn from_named_area(name: &str) -> Self {
+        GridPlacement::NamedArea(name.into())
+    }
+}
+impl<S: CheapCloneStr + for<'a> From<&'a str>> TaffyGridNamedArea for Line<GridPlacement<S>> {
+    fn from_named_area(name: &str) -> Self {
+        let name = S::from(name);
+        Line { start: GridPlacement::NamedArea(name.clone()), end: GridPlacement::NamedArea(name) }
+    }
+}
 
-impl Default for GridPlacement {
+impl<S: CheapCloneStr> Default for GridPlacement<S> {
     fn default() -> Self {
         Self::Auto
     }
 }
 
-impl GridPlacement {
-    /// Apply a mapping function if the [`GridPlacement`] is a `Track`. Otherwise return `self` unmodified.
-    pub fn into_origin_zero_placement(self, explicit_track_count: u16) -> OriginZeroGridPlacement {
+impl<S: CheapCloneStr> GridPlacement<S> {
+    /// Whether the placement resolves to a definite line, regardless of the names of the lines in the grid
+    /// (0 is an invalid line in GridLine coordinates, and falls back to "auto" which is indefinite)
+    #[inline]
+    fn is_definite_line(&self) -> bool {
         match self {
-            Self::Auto => OriginZeroGridPlacement::Auto,
-            Self::Span(span) => OriginZeroGridPlacement::Span(span),
-            // Grid line zero is an invalid index, so it gets treated as Auto
-            // See: https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row-start#values
-            Self::Line(line) => match line.as_i16() {
-                0 => OriginZeroGridPlacement::Auto,
-                _ => OriginZeroGridPlacement::Line(line.into_origin_zero_line(explicit_track_count)),
-            },
+            Self::Line(line) => line.as_i16() != 0,
+            Self::NamedLine(_, index) => *index != 0,
+            Self::NamedArea(_) => true,
+            Self::Auto | Self::Span(_) | Self::NamedSpan(_, _) => false,
         }
     }
 }
 
-impl<T: GridCoordinate> Line<GenericGridPlacement<T>> {
+impl<S: CheapCloneStr> Line<GridPlacement<S>> {
+    #[inline]
+    /// Whether the track position is definite in this axis (or the item will need auto placement)
+    /// The track position is definite if least one of the start and end positions is a NON-ZERO track index,
+    /// a named line with a NON-ZERO index, or a named area
+    pub fn is_definite(&self) -> bool {
+        self.start.is_definite_line() || self.end.is_definite_line()
+    }
+}
+
+impl Line<OriginZeroGridPlacement> {
+    #[inline]
+    /// Whether the track position is definite in this axis (or the item will need auto placement)
+    /// The track position is definite if least one of the start and end positions is a track index
+    pub fn is_definite(&self) -> bool {
+        matches!((self.start, self.end), (OriginZeroGridPlacement::Line(_), _) | (_, OriginZeroGridPlacement::Line(_)))
+    }
+
     /// Resolves the span for an indefinite placement (a placement that does not consist of two `Track`s).
     /// Panics if called on a definite placement
     pub fn indefinite_span(&self) -> u16 {
-        use GenericGridPlacement as GP;
+        use OriginZeroGridPlacement as GP;
         match (self.start, self.end) {
             (GP::Line(_), GP::Auto) => 1,
             (GP::Auto, GP::Line(_)) => 1,
@@ -260,37 +426,6 @@ impl<T: GridCoordinate> Line<GenericGridPlacement<T>> {
             (GP::Line(_), GP::Line(_)) => panic!("indefinite_span should only be called on indefinite grid tracks"),
         }
     }
-}
-
-impl Line<GridPlacement> {
-    #[inline]
-    /// Whether the track position is definite in this axis (or the item will need auto placement)
-    /// The track position is definite if least one of the start and end positions is a NON-ZERO track index
-    /// (0 is an invalid line in GridLine coordinates, and falls back to "auto" which is indefinite)
-    pub fn is_definite(&self) -> bool {
-        match (self.start, self.end) {
-            (GenericGridPlacement::Line(line), _) if line.as_i16() != 0 => true,
-            (_, GenericGridPlacement::Line(line)) if line.as_i16() != 0 => true,
-            _ => false,
-        }
-    }
-
-    /// Apply a mapping function if the [`GridPlacement`] is a `Track`. Otherwise return `self` unmodified.
-    pub fn into_origin_zero(&self, explicit_track_count: u16) -> Line<OriginZeroGridPlacement> {
-        Line {
-            start: self.start.into_origin_zero_placement(explicit_track_count),
-            end: self.end.into_origin_zero_placement(explicit_track_count),
-        }
-    }
-}
-
-impl Line<OriginZeroGridPlacement> {
-    #[inline]
-    /// Whether the track position is definite in this axis (or the item will need auto placement)
-    /// The track position is definite if least one of the start and end positions is a track index
-    pub fn is_definite(&self) -> bool {
-        matches!((self.start, self.end), (GenericGridPlacement::Line(_), _) | (_, GenericGridPlacement::Line(_)))
-    }
 
     /// If at least one of the of the start and end positions is a track index then the other end can be resolved
     /// into a track index purely based on the information contained with the placement specification
@@ -354,12 +489,126 @@ impl Line<OriginZeroGridPlacement> {
 }
 
 /// Represents the start and end points of a GridItem within a given axis
-impl Default for Line<GridPlacement> {
+impl<S: CheapCloneStr> Default for Line<GridPlacement<S>> {
     fn default() -> Self {
         Line { start: GridPlacement::Auto, end: GridPlacement::Auto }
     }
 }
 
+/// A named grid area, defined by the grid lines (in CSS Grid Line coordinates) which bound it.
+///
+/// Each area implicitly names the lines which bound it `<name>-start` and `<name>-end` in both axes, and the explicit grid
+/// is extended to contain every area. Items can be placed into an area using
+/// [`GenericGridPlacement::NamedArea`].
+///
+/// [MDN](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-areas)
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridTemplateArea<S: CheapCloneStr = DefaultCheapStr> {
+    /// The name of the area
+    pub name: S,
+    /// The row line at which the area starts (1-based)
+    pub row_start: u16,
+    /// The row line at which the area ends (1-based)
+    pub row_end: u16,
+    /// The column line at which the area starts (1-based)
+    pub column_start: u16,
+    /// The column line at which the area ends (1-based)
+    pub column_end: u16,
+}
+
+impl<S: CheapCloneStr> GridTemplateArea<S> {
+    /// Parses named areas from the rows of an area map, using the syntax of the strings in the CSS `grid-template-areas`
+    /// property. Cells are separated by whitespace, and cells consisting only of `.` characters do not belong to any area.
+    ///
+    /// ```
+    /// # use taffy::style::{GridTemplateArea, Style};
+    /// let style = Style {
+    ///     grid_template_areas: GridTemplateArea::parse(&["header header", "sidebar main", ". footer"]).unwrap(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(style.grid_template_areas.len(), 4);
+    /// assert_eq!((style.grid_template_areas[0].column_start, style.grid_template_areas[0].column_end), (1, 3));
+    /// ```
+    ///
+    /// Every row must contain the same (non-zero) number of cells, and the cells of each named area must form a single
+    /// filled-in rectangle.
+    pub fn parse<'a>(rows: &[&'a str]) -> Result<GridTrackVec<GridTemplateArea<S>>, InvalidGridTemplateAreas>
+    where
+        S: From<&'a str>,
+    {
+        let mut areas: GridTrackVec<GridTemplateArea<S>> = GridTrackVec::new();
+        let mut area_cell_counts: GridTrackVec<u16> = GridTrackVec::new();
+        let mut column_count = None;
+
+        for (row_index, row) in rows.iter().enumerate() {
+            let mut row_cell_count = 0;
+            for (column_index, cell) in row.split_ascii_whitespace().enumerate() {
+                row_cell_count += 1;
+                if cell.chars().all(|c| c == '.') {
+                    continue;
+                }
+
+                let (row_line, column_line) = (row_index as u16 + 1, column_index as u16 + 1);
+                match areas.iter().position(|area| area.name.as_ref() == cell) {
+                    Some(index) => {
+                        let area = &mut areas[index];
+                        area.row_start = min(area.row_start, row_line);
+                        area.row_end = max(area.row_end, row_line + 1);
+                        area.column_start = min(area.column_start, column_line);
+                        area.column_end = max(area.column_end, column_line + 1);
+                        area_cell_counts[index] += 1;
+                    }
+                    None => {
+                        areas.push(GridTemplateArea {
+                            name: cell.into(),
+                            row_start: row_line,
+                            row_end: row_line + 1,
+                            column_start: column_line,
+                            column_end: column_line + 1,
+                        });
+                        area_cell_counts.push(1);
+                    }
+                }
+            }
+
+            if row_cell_count == 0 || *column_count.get_or_insert(row_cell_count) != row_cell_count {
+                return Err(InvalidGridTemplateAreas);
+            }
+        }
+
+        // An area is rectangular if its cells exactly fill its bounding box
+        let all_areas_are_rectangular = areas.iter().zip(area_cell_counts.iter()).all(|(area, &cell_count)| {
+            (area.row_end - area.row_start) * (area.column_end - area.column_start) == cell_count
+        });
+        if !all_areas_are_rectangular {
+            return Err(InvalidGridTemplateAreas);
+        }
+
+        Ok(areas)
+    }
+
+    /// The lines (in CSS Grid Line coordinates) which bound the area in the specified axis
+    pub fn lines(&self, axis: AbsoluteAxis) -> Line<u16> {
+        match axis {
+            AbsoluteAxis::Horizontal => Line { start: self.column_start, end: self.column_end },
+            AbsoluteAxis::Vertical => Line { start: self.row_start, end: self.row_end },
+        }
+    }
+}
+
+/// Error returned by [`GridTemplateArea::parse`] when the rows of the area map contain differing numbers of cells
+/// or when a named area is not rectangular
+#[derive(Debug)]
+pub struct InvalidGridTemplateAreas;
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidGridTemplateAreas {}
+impl core::fmt::Display for InvalidGridTemplateAreas {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("every row of a grid area map must contain the same number of cells and every named area must be a rectangle")
+    }
+}
+
 /// Maximum track sizing function
 ///
 /// Specifies the maximum size of a grid track. A grid track will automatically size between it's minimum and maximum size based
@@ -572,6 +821,7 @@ impl MaxTrackSizingFunction {
         match self.0.tag() {
             CompactLength::LENGTH_TAG => true,
             CompactLength::PERCENT_TAG => parent_size.is_some(),
+            _ if self.0.is_relative_length() => true,
             _ if self.0.is_calc() => parent_size.is_some(),
             _ => false,
         }
@@ -585,6 +835,7 @@ impl MaxTrackSizingFunction {
         match self.0.tag() {
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => parent_size.map(|size| self.0.value() * size),
+            _ if self.0.is_relative_length() => Some(calc_resolver(self.0.calc_value(), parent_size.unwrap_or(0.0))),
             _ if self.0.is_calc() => parent_size.map(|size| calc_resolver(self.0.calc_value(), size)),
             _ => None,
         }
@@ -768,6 +1019,7 @@ impl MinTrackSizingFunction {
         match self.0.tag() {
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => parent_size.map(|size| self.0.value() * size),
+            _ if self.0.is_relative_length() => Some(calc_resolver(self.0.calc_value(), parent_size.unwrap_or(0.0))),
             _ if self.0.is_calc() => parent_size.map(|size| calc_resolver(self.0.calc_value(), size)),
             _ => None,
         }
@@ -911,12 +1163,30 @@ pub enum TrackSizingFunction {
     /// Automatically generate grid tracks to fit the available space using the specified definite track lengths
     /// Only valid if every track in template (not just the repetition) has a fixed size.
     Repeat(GridTrackRepetition, GridTrackVec<NonRepeatedTrackSizingFunction>),
+    /// Adopt the tracks (and gaps) of the parent grid that the node spans, making the node a subgrid in this axis.
+    /// Only valid as the sole entry of a template, and only takes effect if the node's parent is a grid container.
+    /// Otherwise the template is treated as if it were empty.
+    Subgrid,
+    /// Lay out the grid's items using masonry layout in this axis: rather than being placed into rows (or columns),
+    /// each item is packed into the tracks of the other axis which are currently shortest. Only valid as the sole entry
+    /// of a template. If both templates are `masonry` then the columns template is treated as if it were empty.
+    Masonry,
 }
 impl TrackSizingFunction {
     /// Whether the track definition is a auto-repeated fragment
     pub fn is_auto_repetition(&self) -> bool {
         matches!(self, Self::Repeat(GridTrackRepetition::AutoFit | GridTrackRepetition::AutoFill, _))
     }
+
+    /// Whether a track template makes a grid a subgrid in that axis
+    pub fn is_subgrid_template(template: &[TrackSizingFunction]) -> bool {
+        matches!(template, [Self::Subgrid])
+    }
+
+    /// Whether a track template makes the grid's layout masonry layout in that axis
+    pub fn is_masonry_template(template: &[TrackSizingFunction]) -> bool {
+        matches!(template, [Self::Masonry])
+    }
 }
 impl TaffyAuto for TrackSizingFunction {
     const AUTO: Self = Self::Single(NonRepeatedTrackSizingFunction::AUTO);
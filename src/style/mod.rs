@@ -1,34 +1,50 @@
 //! A typed representation of [CSS style properties](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) in Rust. Used as input to layout computation.
 mod alignment;
+mod anchor;
 mod available_space;
 mod compact_length;
 mod dimension;
+mod font;
 
 #[cfg(feature = "block_layout")]
 mod block;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod calc;
 #[cfg(feature = "flexbox")]
 mod flex;
 #[cfg(feature = "grid")]
 mod grid;
+#[cfg(feature = "table_layout")]
+mod table;
 
-pub use self::alignment::{AlignContent, AlignItems, AlignSelf, JustifyContent, JustifyItems, JustifySelf};
+pub use self::alignment::{
+    AlignContent, AlignItems, AlignSelf, AlignmentSafety, JustifyContent, JustifyItems, JustifySelf,
+};
+pub use self::anchor::{AnchorSide, AnchorSize, PositionArea, PositionTryFallback};
 pub use self::available_space::AvailableSpace;
 pub use self::compact_length::CompactLength;
 pub use self::dimension::{Dimension, LengthPercentage, LengthPercentageAuto};
+pub use self::font::{FontMetrics, RelativeLengthBasis};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::calc::CalcExpr;
 
 #[cfg(feature = "block_layout")]
-pub use self::block::{BlockContainerStyle, BlockItemStyle, TextAlign};
+pub use self::block::{BlockContainerStyle, BlockItemStyle, Clear, DisplayOutside, Float, TextAlign, VerticalAlign};
 #[cfg(feature = "flexbox")]
 pub use self::flex::{FlexDirection, FlexWrap, FlexboxContainerStyle, FlexboxItemStyle};
 #[cfg(feature = "grid")]
-pub(crate) use self::grid::{GenericGridPlacement, OriginZeroGridPlacement};
+pub(crate) use self::grid::OriginZeroGridPlacement;
 #[cfg(feature = "grid")]
 pub use self::grid::{
-    GridAutoFlow, GridContainerStyle, GridItemStyle, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction,
+    CheapCloneStr, DefaultCheapStr, GenericGridPlacement, GridAutoFlow, GridContainerStyle, GridItemStyle,
+    GridPlacement, GridTemplateArea, GridTrackRepetition, InvalidGridTemplateAreas, MaxTrackSizingFunction,
     MinTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction,
 };
+#[cfg(feature = "table_layout")]
+pub use self::table::{BorderCollapse, CaptionSide, TableChildStyle, TableContainerStyle, TableLayout, TablePart};
 
-use crate::geometry::{Point, Rect, Size};
+use crate::geometry::{AbsoluteAxis, LogicalRect, LogicalSize, Point, Rect, Size};
 use crate::style_helpers::TaffyAuto as _;
 
 #[cfg(feature = "grid")]
@@ -37,6 +53,8 @@ use crate::geometry::Line;
 use crate::style_helpers;
 #[cfg(feature = "grid")]
 use crate::util::sys::GridTrackVec;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::util::sys::{String, Vec};
 
 /// The core set of styles that are shared between all CSS layout nodes
 ///
@@ -59,6 +77,21 @@ pub trait CoreStyle {
     fn box_sizing(&self) -> BoxSizing {
         BoxSizing::BorderBox
     }
+    /// The direction of inline-level content (left-to-right or right-to-left)
+    #[inline(always)]
+    fn direction(&self) -> Direction {
+        Style::DEFAULT.direction
+    }
+    /// The writing mode of the node, which determines the physical orientation of its inline and block axes
+    #[inline(always)]
+    fn writing_mode(&self) -> WritingMode {
+        Style::DEFAULT.writing_mode
+    }
+    /// Whether the node is visible, and whether it is removed from the layout of its flex container
+    #[inline(always)]
+    fn visibility(&self) -> Visibility {
+        Style::DEFAULT.visibility
+    }
 
     // Overflow properties
     /// How children overflowing their container should affect layout
@@ -67,9 +100,15 @@ pub trait CoreStyle {
         Style::DEFAULT.overflow
     }
     /// How much space (in points) should be reserved for the scrollbars of `Overflow::Scroll` and `Overflow::Auto` nodes.
+    /// The `width` is the thickness of the vertical scrollbar and the `height` is the thickness of the horizontal scrollbar.
     #[inline(always)]
-    fn scrollbar_width(&self) -> f32 {
-        0.0
+    fn scrollbar_width(&self) -> Size<f32> {
+        Style::DEFAULT.scrollbar_width
+    }
+    /// Whether space is reserved for the vertical scrollbar of a scroll container even when it has no scrollbar
+    #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        Style::DEFAULT.scrollbar_gutter
     }
 
     // Position properties
@@ -83,6 +122,26 @@ pub trait CoreStyle {
     fn inset(&self) -> Rect<LengthPercentageAuto> {
         Style::DEFAULT.inset
     }
+    /// The name by which `Position::Absolute` and `Position::Fixed` nodes can refer to this node as their anchor
+    #[inline(always)]
+    fn anchor_name(&self) -> Option<&str> {
+        None
+    }
+    /// The name of the anchor that the `anchor()` insets, `anchor-size()` sizes and `position_area` of this node refer to
+    #[inline(always)]
+    fn position_anchor(&self) -> Option<&str> {
+        None
+    }
+    /// The region of its containing block, relative to its anchor, that this node is placed in
+    #[inline(always)]
+    fn position_area(&self) -> Option<Point<PositionArea>> {
+        Style::DEFAULT.position_area
+    }
+    /// The alternative positions that are tried in order if this node overflows its containing block
+    #[inline(always)]
+    fn position_try_fallbacks(&self) -> &[PositionTryFallback] {
+        &[]
+    }
 
     // Size properies
     /// Sets the initial size of the item
@@ -140,6 +199,34 @@ pub enum Display {
     /// The children will follow the CSS Grid layout algorithm
     #[cfg(feature = "grid")]
     Grid,
+    /// The node does not generate a box, and its children are laid out as if they were children of the node's parent.
+    /// The node itself is given a zero-sized layout at the origin of its parent.
+    Contents,
+    /// The node generates an inline box: its children (or if it has no children, its text) are laid out within the
+    /// lines of its parent block container. Outside of a block container it is laid out like `Display::Block`.
+    #[cfg(feature = "block_layout")]
+    Inline,
+    /// The node generates an atomic inline-level box which is laid out within the lines of its parent block container
+    /// and whose children follow the block layout algorithm. Outside of a block container it is laid out like `Display::Block`.
+    #[cfg(feature = "block_layout")]
+    InlineBlock,
+    /// The children will follow the table layout algorithm. Children which are not captions or row groups are laid
+    /// out as rows, and any children of those rows which are not cells are wrapped in anonymous rows.
+    #[cfg(feature = "table_layout")]
+    Table,
+    /// The node is a group of rows within a table (like `<thead>`, `<tbody>` or `<tfoot>`)
+    #[cfg(feature = "table_layout")]
+    TableRowGroup,
+    /// The node is a row of cells within a table or table row group
+    #[cfg(feature = "table_layout")]
+    TableRow,
+    /// The node is a cell within a table row. Its children follow the block layout algorithm.
+    #[cfg(feature = "table_layout")]
+    TableCell,
+    /// The node is a caption of a table, placed above or below the table's grid according to its `caption_side`.
+    /// Its children follow the block layout algorithm.
+    #[cfg(feature = "table_layout")]
+    TableCaption,
     /// The node is hidden, and it's children will also be hidden
     None,
 }
@@ -171,9 +258,24 @@ impl Default for Display {
 impl core::fmt::Display for Display {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
+            Display::Contents => write!(f, "CONTENTS"),
             Display::None => write!(f, "NONE"),
             #[cfg(feature = "block_layout")]
             Display::Block => write!(f, "BLOCK"),
+            #[cfg(feature = "block_layout")]
+            Display::Inline => write!(f, "INLINE"),
+            #[cfg(feature = "block_layout")]
+            Display::InlineBlock => write!(f, "INLINE-BLOCK"),
+            #[cfg(feature = "table_layout")]
+            Display::Table => write!(f, "TABLE"),
+            #[cfg(feature = "table_layout")]
+            Display::TableRowGroup => write!(f, "TABLE-ROW-GROUP"),
+            #[cfg(feature = "table_layout")]
+            Display::TableRow => write!(f, "TABLE-ROW"),
+            #[cfg(feature = "table_layout")]
+            Display::TableCell => write!(f, "TABLE-CELL"),
+            #[cfg(feature = "table_layout")]
+            Display::TableCaption => write!(f, "TABLE-CAPTION"),
             #[cfg(feature = "flexbox")]
             Display::Flex => write!(f, "FLEX"),
             #[cfg(feature = "grid")]
@@ -189,6 +291,11 @@ impl core::fmt::Display for Display {
 pub enum BoxGenerationMode {
     /// The node generates a box in the regular way
     Normal,
+    /// The node generates no box, but its descendants generate boxes as if they were children of the node's parent.
+    ///
+    /// Layout algorithms expect the tree to present the children of such a node in place of the node itself when
+    /// traversing the children of the node's parent. See [`TraversePartialTree`](crate::TraversePartialTree).
+    Contents,
     /// The node and it's descendants generate no boxes (they are hidden)
     None,
 }
@@ -212,19 +319,58 @@ impl Default for BoxGenerationMode {
 /// WARNING: this enum follows the behavior of [CSS's `position` property](https://developer.mozilla.org/en-US/docs/Web/CSS/position),
 /// which can be unintuitive.
 ///
-/// [`Position::Relative`] is the default value, in contrast to the default behavior in CSS.
+/// [`Position::Relative`] is the default value, in contrast to the default behavior in CSS. As every node is then
+/// positioned, `Position::Absolute` nodes are positioned relative to their parent unless it is `Position::Static`.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Position {
     /// The offset is computed relative to the final position given by the layout algorithm.
     /// Offsets do not affect the position of any other items; they are effectively a correction factor applied at the end.
     Relative,
-    /// The offset is computed relative to this item's closest positioned ancestor, if any.
-    /// Otherwise, it is placed relative to the origin.
+    /// The item is laid out like `Relative`, but its offsets are ignored. Unlike the other positioning schemes, the item
+    /// is not a "positioned" node, so it is not the containing block of its `Position::Absolute` children.
+    Static,
+    /// The offset is computed relative to the padding box of this item's closest positioned (non-`Static`) ancestor,
+    /// or the root node if it has none. The item's `location` is still reported relative to its parent.
     /// No space is created for the item in the page layout, and its size will not be altered.
     ///
+    /// Items are positioned by their parent's layout algorithm, to which the containing block of a `Static` parent is
+    /// passed in [`LayoutInput::containing_block`](crate::LayoutInput::containing_block). Items which are anchored to
+    /// another node with `position_anchor` are laid out again by
+    /// [`compute_fixed_and_sticky_positions`](crate::compute_fixed_and_sticky_positions), which
+    /// [`TaffyTree`](crate::TaffyTree) runs after computing layout.
+    ///
     /// WARNING: to opt-out of layouting entirely, you must use [`Display::None`] instead on your [`Style`] object.
     Absolute,
+    /// The offset is computed relative to the viewport (the available space of the root node), regardless of which node
+    /// the item is a descendant of. Ancestor scroll offsets do not move the item. Like `Absolute`, no space is created
+    /// for the item in the page layout.
+    ///
+    /// The item is positioned by [`compute_fixed_and_sticky_positions`](crate::compute_fixed_and_sticky_positions),
+    /// which [`TaffyTree`](crate::TaffyTree) runs after computing layout. Until then it is positioned like `Absolute`.
+    Fixed,
+    /// The item is laid out like `Relative`, but rather than shifting the item its offsets limit how close it may come to
+    /// the edges of the scrollport of its nearest scroll container (or the viewport) as that container is scrolled. The item
+    /// is not moved outside of the content box of its parent.
+    ///
+    /// The constrained position is computed by [`compute_fixed_and_sticky_positions`](crate::compute_fixed_and_sticky_positions),
+    /// which [`TaffyTree`](crate::TaffyTree) runs after computing layout.
+    Sticky,
+}
+
+impl Position {
+    /// Returns true for positioning schemes that take the item out of flow (`Position::Absolute` and `Position::Fixed`)
+    #[inline(always)]
+    pub(crate) fn is_absolutely_positioned(self) -> bool {
+        matches!(self, Self::Absolute | Self::Fixed)
+    }
+
+    /// Returns true for positioning schemes that make the item the containing block of its absolutely positioned
+    /// children (every scheme except `Position::Static`)
+    #[inline(always)]
+    pub(crate) fn is_positioned(self) -> bool {
+        self != Self::Static
+    }
 }
 
 impl Default for Position {
@@ -261,6 +407,126 @@ impl Default for BoxSizing {
     }
 }
 
+/// The direction of inline-level content (and of the inline axis of the node's own layout)
+///
+/// This affects:
+///
+///   - Which edge the main axis of a `row` flexbox container starts from
+///   - Which edge grid columns are placed from
+///   - The physical meaning of `start`/`end` alignment in the inline axis
+///   - Which of the `left` and `right` insets takes precedence when both are set
+///   - Which side the vertical scrollbar gutter is reserved on
+///
+/// Unlike in CSS, `direction` is not inherited in Taffy: it must be set on each node that it should apply to.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/direction>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Direction {
+    /// Inline-level content flows from left to right
+    #[default]
+    Ltr,
+    /// Inline-level content flows from right to left
+    Rtl,
+}
+
+impl Direction {
+    /// Returns true if the direction is `Rtl`
+    #[inline(always)]
+    pub(crate) fn is_rtl(self) -> bool {
+        matches!(self, Self::Rtl)
+    }
+}
+
+/// The writing mode of a node. This determines whether the node's inline axis (the axis along which text flows,
+/// and along which `row` flexbox containers and grid columns are laid out) is horizontal or vertical, and in
+/// which direction its block axis (the axis along which block-level boxes are stacked) progresses.
+///
+/// The `direction` style continues to control which end of the inline axis is the start edge. In vertical
+/// writing modes this is the top edge for `Direction::Ltr` and the bottom edge for `Direction::Rtl`.
+///
+/// Physical styles (`size`, `margin`, `padding`, `border`, `inset`, etc) keep their physical meaning regardless of
+/// the writing mode. The `Style::logical_*` accessors may be used to read and write them in terms of the writing mode instead.
+///
+/// Unlike in CSS, `writing_mode` is not inherited in Taffy: it must be set on each node that it should apply to.
+/// Nodes whose writing mode is orthogonal to that of their parent are supported. However, block layout margins are
+/// never collapsed across the boundary between a vertical node and its parent or children.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/writing-mode>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WritingMode {
+    /// The inline axis is horizontal and blocks are stacked from top to bottom
+    #[default]
+    HorizontalTb,
+    /// The inline axis is vertical and blocks are stacked from right to left
+    VerticalRl,
+    /// The inline axis is vertical and blocks are stacked from left to right
+    VerticalLr,
+}
+
+impl WritingMode {
+    /// Returns true if the inline axis is vertical (`VerticalRl` or `VerticalLr`)
+    #[inline(always)]
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::VerticalRl | Self::VerticalLr)
+    }
+
+    /// Returns the physical axis that corresponds to the inline axis
+    #[inline(always)]
+    pub fn inline_axis(self) -> AbsoluteAxis {
+        match self.is_vertical() {
+            true => AbsoluteAxis::Vertical,
+            false => AbsoluteAxis::Horizontal,
+        }
+    }
+}
+
+/// Whether a node is visible, and how an invisible node affects layout
+///
+/// `Hidden` nodes are laid out exactly like `Visible` nodes, and `Collapse` nodes are laid out like `Hidden` nodes
+/// except when they are flex items (see below). A node's visibility is reported in its [`Layout`](crate::Layout) so
+/// that renderers can skip painting it.
+///
+/// `Collapse` flex items are removed from the layout of their flex container, but leave behind a "strut" which
+/// keeps the cross size of the flex line that they were in stable. Collapsed flex items are given a zero-sized layout.
+///
+/// Unlike in CSS, `visibility` is not inherited in Taffy, and a `Visible` node inside a `Hidden` node is not made visible.
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/visibility>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Visibility {
+    /// The node is painted
+    #[default]
+    Visible,
+    /// The node is not painted, but still affects layout
+    Hidden,
+    /// The node is not painted. Flex items are additionally removed from layout (leaving behind a strut)
+    Collapse,
+}
+
+/// Whether space is reserved for the scrollbar of a scroll container before it has a scrollbar
+///
+/// Like in CSS, this only affects the gutter of the vertical scrollbar (which is placed on the inline-end side of
+/// the node), and only applies to nodes whose `overflow` in the y axis is `Hidden`, `Scroll` or `Auto`. The size of
+/// the gutter is the `width` of the `scrollbar_width` property.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/scrollbar-gutter>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScrollbarGutter {
+    /// Space is only reserved for the scrollbar when the node has a scrollbar
+    #[default]
+    Auto,
+    /// Space is always reserved for the scrollbar, so that the layout of the content doesn't change when a scrollbar
+    /// appears
+    Stable,
+    /// Like `Stable`, but a gutter of the same size is also reserved on the opposite (inline-start) side of the node,
+    /// so that the content remains centered
+    StableBothEdges,
+}
+
 /// How children overflowing their container should affect layout
 ///
 /// In CSS the primary effect of this property is to control whether contents of a parent container that overflow that container should
@@ -269,6 +535,7 @@ impl Default for BoxSizing {
 ///
 ///   - The automatic minimum size Flexbox/CSS Grid items with non-`Visible` overflow is `0` rather than being content based
 ///   - `Overflow::Scroll` nodes have space in the layout reserved for a scrollbar (width controlled by the `scrollbar_width` property)
+///   - `Overflow::Auto` nodes have space reserved for a scrollbar only if their content overflows them
 ///
 /// In Taffy, we only implement the layout related secondary effects as we are not concerned with drawing/painting. The amount of space reserved for
 /// a scrollbar is controlled by the `scrollbar_width` property. If this is `0` then `Scroll` behaves identically to `Hidden`.
@@ -291,6 +558,11 @@ pub enum Overflow {
     /// for a scrollbar. The amount of space reserved is controlled by the `scrollbar_width` property.
     /// Content that overflows this node should *not* contribute to the scroll region of its parent.
     Scroll,
+    /// The automatic minimum size of this node as a flexbox/grid item should be `0`. Space is reserved for a scrollbar
+    /// only if the content of this node overflows it in the scrolling axis, in which case the node is laid out a second
+    /// time with the scrollbar. The amount of space reserved is controlled by the `scrollbar_width` property.
+    /// Content that overflows this node should *not* contribute to the scroll region of its parent.
+    Auto,
 }
 
 impl Overflow {
@@ -300,7 +572,7 @@ impl Overflow {
     pub(crate) fn is_scroll_container(self) -> bool {
         match self {
             Self::Visible | Self::Clip => false,
-            Self::Hidden | Self::Scroll => true,
+            Self::Hidden | Self::Scroll | Self::Auto => true,
         }
     }
 
@@ -336,16 +608,25 @@ pub struct Style {
     /// What layout strategy should be used?
     pub display: Display,
     /// Whether a child is display:table or not. This affects children of block layouts.
-    /// This should really be part of `Display`, but it is currently seperate because table layout isn't implemented
+    /// With the `table_layout` feature enabled, nodes with `Display::Table` are always treated as tables.
     pub item_is_table: bool,
     /// Should size styles apply to the content box or the border box of the node
     pub box_sizing: BoxSizing,
+    /// The direction of inline-level content (left-to-right or right-to-left)
+    pub direction: Direction,
+    /// The writing mode of the node, which determines the physical orientation of its inline and block axes
+    pub writing_mode: WritingMode,
+    /// Whether the node is visible, and whether it is removed from the layout of its flex container
+    pub visibility: Visibility,
 
     // Overflow properties
     /// How children overflowing their container should affect layout
     pub overflow: Point<Overflow>,
     /// How much space (in points) should be reserved for the scrollbars of `Overflow::Scroll` and `Overflow::Auto` nodes.
-    pub scrollbar_width: f32,
+    /// The `width` is the thickness of the vertical scrollbar and the `height` is the thickness of the horizontal scrollbar.
+    pub scrollbar_width: Size<f32>,
+    /// Whether space is reserved for the vertical scrollbar of a scroll container even when it has no scrollbar
+    pub scrollbar_gutter: ScrollbarGutter,
 
     // Position properties
     /// What should the `position` value of this struct use as a base offset?
@@ -354,6 +635,21 @@ pub struct Style {
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
     pub inset: Rect<LengthPercentageAuto>,
 
+    // Anchor positioning properties
+    /// The name by which `Position::Absolute` and `Position::Fixed` nodes can refer to this node as their anchor
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub anchor_name: Option<String>,
+    /// The name of the anchor that the `anchor()` insets, `anchor-size()` sizes and `position_area` of this node refer
+    /// to. Only `Position::Absolute` and `Position::Fixed` nodes can be anchored, and only to nodes which come before
+    /// them in the tree.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub position_anchor: Option<String>,
+    /// The region of its containing block, relative to its anchor, that this node is placed in
+    pub position_area: Option<Point<PositionArea>>,
+    /// The alternative positions that are tried in order if this node overflows its containing block
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub position_try_fallbacks: Vec<PositionTryFallback>,
+
     // Size properties
     /// Sets the initial size of the item
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
@@ -384,32 +680,108 @@ pub struct Style {
     /// How this node's children aligned in the cross/block axis?
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub align_items: Option<AlignItems>,
+    /// How this node's children are aligned in the cross/block axis if they overflow the area they are aligned within
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    pub align_items_safety: AlignmentSafety,
     /// How this node should be aligned in the cross/block axis
     /// Falls back to the parents [`AlignItems`] if not set
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub align_self: Option<AlignSelf>,
+    /// How this node is aligned in the cross/block axis if it overflows the area it is aligned within
+    /// Falls back to the parents `align_items_safety` if not set
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    pub align_self_safety: Option<AlignmentSafety>,
     /// How this node's children should be aligned in the inline axis
     #[cfg(feature = "grid")]
     pub justify_items: Option<AlignItems>,
+    /// How this node's children are aligned in the inline axis if they overflow the area they are aligned within
+    #[cfg(feature = "grid")]
+    pub justify_items_safety: AlignmentSafety,
     /// How this node should be aligned in the inline axis
     /// Falls back to the parents [`JustifyItems`] if not set
     #[cfg(feature = "grid")]
     pub justify_self: Option<AlignSelf>,
+    /// How this node is aligned in the inline axis if it overflows the area it is aligned within
+    /// Falls back to the parents `justify_items_safety` if not set
+    #[cfg(feature = "grid")]
+    pub justify_self_safety: Option<AlignmentSafety>,
     /// How should content contained within this item be aligned in the cross/block axis
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub align_content: Option<AlignContent>,
+    /// How content contained within this item is aligned in the cross/block axis if it overflows this item
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    pub align_content_safety: AlignmentSafety,
     /// How should content contained within this item be aligned in the main/inline axis
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub justify_content: Option<JustifyContent>,
-    /// How large should the gaps between items in a grid or flex container be?
+    /// How content contained within this item is aligned in the main/inline axis if it overflows this item
     #[cfg(any(feature = "flexbox", feature = "grid"))]
+    pub justify_content_safety: AlignmentSafety,
+    /// How the items within each track of a masonry grid should be aligned in the block axis, if the rows are the
+    /// masonry axis. Defaults to `start`.
+    #[cfg(feature = "grid")]
+    pub align_tracks: Option<AlignContent>,
+    /// How the items within each track of a masonry grid should be aligned in the inline axis, if the columns are the
+    /// masonry axis. Defaults to `start`.
+    #[cfg(feature = "grid")]
+    pub justify_tracks: Option<JustifyContent>,
+    /// How large should the gaps between items in a grid or flex container (or between the columns of a multi-column
+    /// container) be?
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::zero"))]
     pub gap: Size<LengthPercentage>,
 
+    // Flexbox and grid item properties
+    /// Controls the order in which this item is laid out relative to its siblings in a flexbox or grid container.
+    /// Items with an equal `order` are laid out in document order.
+    ///
+    /// 0 is the default value. Negative values are permitted.
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    pub order: i32,
+
     // Block container properties
     /// How items elements should aligned in the inline axis
     #[cfg(feature = "block_layout")]
     pub text_align: TextAlign,
+    /// The number of columns into which a block container's content is flowed
+    #[cfg(feature = "block_layout")]
+    pub column_count: Option<u16>,
+    /// The ideal width of the columns into which a block container's content is flowed
+    #[cfg(feature = "block_layout")]
+    pub column_width: Dimension,
+
+    // Inline-level item properties
+    /// How an inline-level item is aligned within its line box
+    #[cfg(feature = "block_layout")]
+    pub vertical_align: VerticalAlign,
+    /// Whether a child of a block container is floated to one side of its container
+    #[cfg(feature = "block_layout")]
+    pub float: Float,
+    /// Which earlier floats a child of a block container is placed below
+    #[cfg(feature = "block_layout")]
+    pub clear: Clear,
+
+    // Table container properties
+    /// Whether the borders of adjacent cells are separated or collapsed into a single border
+    #[cfg(feature = "table_layout")]
+    pub border_collapse: BorderCollapse,
+    /// The horizontal and vertical space between the borders of adjacent cells, when borders are separated
+    #[cfg(feature = "table_layout")]
+    pub border_spacing: Size<LengthPercentage>,
+    /// Which algorithm is used to determine the widths of the table's columns
+    #[cfg(feature = "table_layout")]
+    pub table_layout: TableLayout,
+
+    // Table child properties
+    /// Which side of the table a caption is placed on
+    #[cfg(feature = "table_layout")]
+    pub caption_side: CaptionSide,
+    /// The number of columns which a table cell spans
+    #[cfg(feature = "table_layout")]
+    pub col_span: u16,
+    /// The number of rows which a table cell spans
+    #[cfg(feature = "table_layout")]
+    pub row_span: u16,
 
     // Flexbox container properties
     /// Which direction does the main axis flow in?
@@ -441,6 +813,17 @@ pub struct Style {
     /// Defines the track sizing functions (widths) of the grid columns
     #[cfg(feature = "grid")]
     pub grid_template_columns: GridTrackVec<TrackSizingFunction>,
+    /// Defines the names of the lines between the grid rows. The nth entry names the line before the nth entry of
+    /// `grid_template_rows` and the final entry names the line after the last row. Lines inside a `repeat()` cannot be named.
+    #[cfg(feature = "grid")]
+    pub grid_template_row_names: GridTrackVec<GridTrackVec<DefaultCheapStr>>,
+    /// Defines the names of the lines between the grid columns. The nth entry names the line before the nth entry of
+    /// `grid_template_columns` and the final entry names the line after the last column. Lines inside a `repeat()` cannot be named.
+    #[cfg(feature = "grid")]
+    pub grid_template_column_names: GridTrackVec<GridTrackVec<DefaultCheapStr>>,
+    /// Defines named grid areas. See [`GridTemplateArea::parse`] for creating these from an area map.
+    #[cfg(feature = "grid")]
+    pub grid_template_areas: GridTrackVec<GridTemplateArea>,
     /// Defines the size of implicitly created rows
     #[cfg(feature = "grid")]
     pub grid_auto_rows: GridTrackVec<NonRepeatedTrackSizingFunction>,
@@ -466,10 +849,21 @@ impl Style {
         display: Display::DEFAULT,
         item_is_table: false,
         box_sizing: BoxSizing::BorderBox,
+        direction: Direction::Ltr,
+        writing_mode: WritingMode::HorizontalTb,
+        visibility: Visibility::Visible,
         overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
-        scrollbar_width: 0.0,
+        scrollbar_width: Size::ZERO,
+        scrollbar_gutter: ScrollbarGutter::Auto,
         position: Position::Relative,
         inset: Rect::auto(),
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        anchor_name: None,
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        position_anchor: None,
+        position_area: None,
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        position_try_fallbacks: Vec::new(),
         margin: Rect::zero(),
         padding: Rect::zero(),
         border: Rect::zero(),
@@ -477,24 +871,65 @@ impl Style {
         min_size: Size::auto(),
         max_size: Size::auto(),
         aspect_ratio: None,
-        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
         gap: Size::zero(),
         // Alignment
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         align_items: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
+        align_items_safety: AlignmentSafety::Unsafe,
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
         align_self: None,
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        align_self_safety: None,
         #[cfg(feature = "grid")]
         justify_items: None,
         #[cfg(feature = "grid")]
+        justify_items_safety: AlignmentSafety::Unsafe,
+        #[cfg(feature = "grid")]
         justify_self: None,
+        #[cfg(feature = "grid")]
+        justify_self_safety: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         align_content: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
+        align_content_safety: AlignmentSafety::Unsafe,
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
         justify_content: None,
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        justify_content_safety: AlignmentSafety::Unsafe,
+        #[cfg(feature = "grid")]
+        align_tracks: None,
+        #[cfg(feature = "grid")]
+        justify_tracks: None,
+        // Item ordering
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        order: 0,
         // Block
         #[cfg(feature = "block_layout")]
         text_align: TextAlign::Auto,
+        #[cfg(feature = "block_layout")]
+        column_count: None,
+        #[cfg(feature = "block_layout")]
+        column_width: Dimension::AUTO,
+        #[cfg(feature = "block_layout")]
+        vertical_align: VerticalAlign::Baseline,
+        #[cfg(feature = "block_layout")]
+        float: Float::None,
+        #[cfg(feature = "block_layout")]
+        clear: Clear::None,
+        #[cfg(feature = "table_layout")]
+        border_collapse: BorderCollapse::Separate,
+        #[cfg(feature = "table_layout")]
+        border_spacing: Size::zero(),
+        #[cfg(feature = "table_layout")]
+        table_layout: TableLayout::Auto,
+        #[cfg(feature = "table_layout")]
+        caption_side: CaptionSide::Top,
+        #[cfg(feature = "table_layout")]
+        col_span: 1,
+        #[cfg(feature = "table_layout")]
+        row_span: 1,
         // Flexbox
         #[cfg(feature = "flexbox")]
         flex_direction: FlexDirection::Row,
@@ -512,6 +947,12 @@ impl Style {
         #[cfg(feature = "grid")]
         grid_template_columns: GridTrackVec::new(),
         #[cfg(feature = "grid")]
+        grid_template_row_names: GridTrackVec::new(),
+        #[cfg(feature = "grid")]
+        grid_template_column_names: GridTrackVec::new(),
+        #[cfg(feature = "grid")]
+        grid_template_areas: GridTrackVec::new(),
+        #[cfg(feature = "grid")]
         grid_auto_rows: GridTrackVec::new(),
         #[cfg(feature = "grid")]
         grid_auto_columns: GridTrackVec::new(),
@@ -524,6 +965,44 @@ impl Style {
     };
 }
 
+impl Style {
+    /// The margin of the node in terms of its own `writing_mode` and `direction`
+    pub fn logical_margin(&self) -> LogicalRect<LengthPercentageAuto> {
+        self.margin.to_logical(self.writing_mode, self.direction)
+    }
+    /// Sets the margin of the node in terms of its own `writing_mode` and `direction`
+    pub fn set_logical_margin(&mut self, margin: LogicalRect<LengthPercentageAuto>) {
+        self.margin = margin.to_physical(self.writing_mode, self.direction);
+    }
+
+    /// The padding of the node in terms of its own `writing_mode` and `direction`
+    pub fn logical_padding(&self) -> LogicalRect<LengthPercentage> {
+        self.padding.to_logical(self.writing_mode, self.direction)
+    }
+    /// Sets the padding of the node in terms of its own `writing_mode` and `direction`
+    pub fn set_logical_padding(&mut self, padding: LogicalRect<LengthPercentage>) {
+        self.padding = padding.to_physical(self.writing_mode, self.direction);
+    }
+
+    /// The inset of the node in terms of its own `writing_mode` and `direction`
+    pub fn logical_inset(&self) -> LogicalRect<LengthPercentageAuto> {
+        self.inset.to_logical(self.writing_mode, self.direction)
+    }
+    /// Sets the inset of the node in terms of its own `writing_mode` and `direction`
+    pub fn set_logical_inset(&mut self, inset: LogicalRect<LengthPercentageAuto>) {
+        self.inset = inset.to_physical(self.writing_mode, self.direction);
+    }
+
+    /// The size of the node in terms of its own `writing_mode`
+    pub fn logical_size(&self) -> LogicalSize<Dimension> {
+        self.size.to_logical(self.writing_mode)
+    }
+    /// Sets the size of the node in terms of its own `writing_mode`
+    pub fn set_logical_size(&mut self, size: LogicalSize<Dimension>) {
+        self.size = size.to_physical(self.writing_mode);
+    }
+}
+
 impl Default for Style {
     fn default() -> Self {
         Style::DEFAULT
@@ -534,6 +1013,7 @@ impl CoreStyle for Style {
     #[inline(always)]
     fn box_generation_mode(&self) -> BoxGenerationMode {
         match self.display {
+            Display::Contents => BoxGenerationMode::Contents,
             Display::None => BoxGenerationMode::None,
             _ => BoxGenerationMode::Normal,
         }
@@ -541,21 +1021,38 @@ impl CoreStyle for Style {
     #[inline(always)]
     #[cfg(feature = "block_layout")]
     fn is_block(&self) -> bool {
-        matches!(self.display, Display::Block)
+        // Inline boxes which are not laid out as part of the lines of a block container are laid out as block boxes
+        matches!(self.display, Display::Block | Display::Inline)
     }
     #[inline(always)]
     fn box_sizing(&self) -> BoxSizing {
         self.box_sizing
     }
     #[inline(always)]
+    fn direction(&self) -> Direction {
+        self.direction
+    }
+    #[inline(always)]
+    fn writing_mode(&self) -> WritingMode {
+        self.writing_mode
+    }
+    #[inline(always)]
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+    #[inline(always)]
     fn overflow(&self) -> Point<Overflow> {
         self.overflow
     }
     #[inline(always)]
-    fn scrollbar_width(&self) -> f32 {
+    fn scrollbar_width(&self) -> Size<f32> {
         self.scrollbar_width
     }
     #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        self.scrollbar_gutter
+    }
+    #[inline(always)]
     fn position(&self) -> Position {
         self.position
     }
@@ -563,6 +1060,25 @@ impl CoreStyle for Style {
     fn inset(&self) -> Rect<LengthPercentageAuto> {
         self.inset
     }
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline(always)]
+    fn anchor_name(&self) -> Option<&str> {
+        self.anchor_name.as_deref()
+    }
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline(always)]
+    fn position_anchor(&self) -> Option<&str> {
+        self.position_anchor.as_deref()
+    }
+    #[inline(always)]
+    fn position_area(&self) -> Option<Point<PositionArea>> {
+        self.position_area
+    }
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[inline(always)]
+    fn position_try_fallbacks(&self) -> &[PositionTryFallback] {
+        &self.position_try_fallbacks
+    }
     #[inline(always)]
     fn size(&self) -> Size<Dimension> {
         self.size
@@ -607,14 +1123,30 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).box_sizing()
     }
     #[inline(always)]
+    fn direction(&self) -> Direction {
+        (*self).direction()
+    }
+    #[inline(always)]
+    fn writing_mode(&self) -> WritingMode {
+        (*self).writing_mode()
+    }
+    #[inline(always)]
+    fn visibility(&self) -> Visibility {
+        (*self).visibility()
+    }
+    #[inline(always)]
     fn overflow(&self) -> Point<Overflow> {
         (*self).overflow()
     }
     #[inline(always)]
-    fn scrollbar_width(&self) -> f32 {
+    fn scrollbar_width(&self) -> Size<f32> {
         (*self).scrollbar_width()
     }
     #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        (*self).scrollbar_gutter()
+    }
+    #[inline(always)]
     fn position(&self) -> Position {
         (*self).position()
     }
@@ -623,6 +1155,22 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).inset()
     }
     #[inline(always)]
+    fn anchor_name(&self) -> Option<&str> {
+        (*self).anchor_name()
+    }
+    #[inline(always)]
+    fn position_anchor(&self) -> Option<&str> {
+        (*self).position_anchor()
+    }
+    #[inline(always)]
+    fn position_area(&self) -> Option<Point<PositionArea>> {
+        (*self).position_area()
+    }
+    #[inline(always)]
+    fn position_try_fallbacks(&self) -> &[PositionTryFallback] {
+        (*self).position_try_fallbacks()
+    }
+    #[inline(always)]
     fn size(&self) -> Size<Dimension> {
         (*self).size()
     }
@@ -658,6 +1206,18 @@ impl BlockContainerStyle for &Style {
     fn text_align(&self) -> TextAlign {
         self.text_align
     }
+    #[inline(always)]
+    fn column_count(&self) -> Option<u16> {
+        self.column_count
+    }
+    #[inline(always)]
+    fn column_width(&self) -> Dimension {
+        self.column_width
+    }
+    #[inline(always)]
+    fn column_gap(&self) -> LengthPercentage {
+        self.gap.width
+    }
 }
 
 #[cfg(feature = "block_layout")]
@@ -666,14 +1226,50 @@ impl<T: BlockContainerStyle> BlockContainerStyle for &'_ T {
     fn text_align(&self) -> TextAlign {
         (*self).text_align()
     }
+    #[inline(always)]
+    fn column_count(&self) -> Option<u16> {
+        (*self).column_count()
+    }
+    #[inline(always)]
+    fn column_width(&self) -> Dimension {
+        (*self).column_width()
+    }
+    #[inline(always)]
+    fn column_gap(&self) -> LengthPercentage {
+        (*self).column_gap()
+    }
 }
 
 #[cfg(feature = "block_layout")]
 impl BlockItemStyle for Style {
     #[inline(always)]
     fn is_table(&self) -> bool {
+        #[cfg(feature = "table_layout")]
+        if self.display == Display::Table {
+            return true;
+        }
         self.item_is_table
     }
+    #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        match self.display {
+            Display::Inline => DisplayOutside::Inline,
+            Display::InlineBlock => DisplayOutside::InlineBlock,
+            _ => DisplayOutside::Block,
+        }
+    }
+    #[inline(always)]
+    fn vertical_align(&self) -> VerticalAlign {
+        self.vertical_align
+    }
+    #[inline(always)]
+    fn float(&self) -> Float {
+        self.float
+    }
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        self.clear
+    }
 }
 
 #[cfg(feature = "block_layout")]
@@ -682,6 +1278,99 @@ impl<T: BlockItemStyle> BlockItemStyle for &'_ T {
     fn is_table(&self) -> bool {
         (*self).is_table()
     }
+    #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        (*self).display_outside()
+    }
+    #[inline(always)]
+    fn vertical_align(&self) -> VerticalAlign {
+        (*self).vertical_align()
+    }
+    #[inline(always)]
+    fn float(&self) -> Float {
+        (*self).float()
+    }
+    #[inline(always)]
+    fn clear(&self) -> Clear {
+        (*self).clear()
+    }
+}
+
+#[cfg(feature = "table_layout")]
+impl TableContainerStyle for Style {
+    #[inline(always)]
+    fn border_collapse(&self) -> BorderCollapse {
+        self.border_collapse
+    }
+    #[inline(always)]
+    fn border_spacing(&self) -> Size<LengthPercentage> {
+        self.border_spacing
+    }
+    #[inline(always)]
+    fn table_layout(&self) -> TableLayout {
+        self.table_layout
+    }
+}
+
+#[cfg(feature = "table_layout")]
+impl<T: TableContainerStyle> TableContainerStyle for &'_ T {
+    #[inline(always)]
+    fn border_collapse(&self) -> BorderCollapse {
+        (*self).border_collapse()
+    }
+    #[inline(always)]
+    fn border_spacing(&self) -> Size<LengthPercentage> {
+        (*self).border_spacing()
+    }
+    #[inline(always)]
+    fn table_layout(&self) -> TableLayout {
+        (*self).table_layout()
+    }
+}
+
+#[cfg(feature = "table_layout")]
+impl TableChildStyle for Style {
+    #[inline(always)]
+    fn table_part(&self) -> TablePart {
+        match self.display {
+            Display::TableRowGroup => TablePart::RowGroup,
+            Display::TableRow => TablePart::Row,
+            Display::TableCaption => TablePart::Caption,
+            _ => TablePart::Cell,
+        }
+    }
+    #[inline(always)]
+    fn col_span(&self) -> u16 {
+        self.col_span
+    }
+    #[inline(always)]
+    fn row_span(&self) -> u16 {
+        self.row_span
+    }
+    #[inline(always)]
+    fn caption_side(&self) -> CaptionSide {
+        self.caption_side
+    }
+}
+
+#[cfg(feature = "table_layout")]
+impl<T: TableChildStyle> TableChildStyle for &'_ T {
+    #[inline(always)]
+    fn table_part(&self) -> TablePart {
+        (*self).table_part()
+    }
+    #[inline(always)]
+    fn col_span(&self) -> u16 {
+        (*self).col_span()
+    }
+    #[inline(always)]
+    fn row_span(&self) -> u16 {
+        (*self).row_span()
+    }
+    #[inline(always)]
+    fn caption_side(&self) -> CaptionSide {
+        (*self).caption_side()
+    }
 }
 
 #[cfg(feature = "flexbox")]
@@ -703,13 +1392,25 @@ impl FlexboxContainerStyle for Style {
         self.align_content
     }
     #[inline(always)]
+    fn align_content_safety(&self) -> AlignmentSafety {
+        self.align_content_safety
+    }
+    #[inline(always)]
     fn align_items(&self) -> Option<AlignItems> {
         self.align_items
     }
     #[inline(always)]
+    fn align_items_safety(&self) -> AlignmentSafety {
+        self.align_items_safety
+    }
+    #[inline(always)]
     fn justify_content(&self) -> Option<JustifyContent> {
         self.justify_content
     }
+    #[inline(always)]
+    fn justify_content_safety(&self) -> AlignmentSafety {
+        self.justify_content_safety
+    }
 }
 
 #[cfg(feature = "flexbox")]
@@ -731,13 +1432,25 @@ impl<T: FlexboxContainerStyle> FlexboxContainerStyle for &'_ T {
         (*self).align_content()
     }
     #[inline(always)]
+    fn align_content_safety(&self) -> AlignmentSafety {
+        (*self).align_content_safety()
+    }
+    #[inline(always)]
     fn align_items(&self) -> Option<AlignItems> {
         (*self).align_items()
     }
     #[inline(always)]
+    fn align_items_safety(&self) -> AlignmentSafety {
+        (*self).align_items_safety()
+    }
+    #[inline(always)]
     fn justify_content(&self) -> Option<JustifyContent> {
         (*self).justify_content()
     }
+    #[inline(always)]
+    fn justify_content_safety(&self) -> AlignmentSafety {
+        (*self).justify_content_safety()
+    }
 }
 
 #[cfg(feature = "flexbox")]
@@ -758,6 +1471,14 @@ impl FlexboxItemStyle for Style {
     fn align_self(&self) -> Option<AlignSelf> {
         self.align_self
     }
+    #[inline(always)]
+    fn align_self_safety(&self) -> Option<AlignmentSafety> {
+        self.align_self_safety
+    }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        self.order
+    }
 }
 
 #[cfg(feature = "flexbox")]
@@ -778,10 +1499,19 @@ impl<T: FlexboxItemStyle> FlexboxItemStyle for &'_ T {
     fn align_self(&self) -> Option<AlignSelf> {
         (*self).align_self()
     }
+    #[inline(always)]
+    fn align_self_safety(&self) -> Option<AlignmentSafety> {
+        (*self).align_self_safety()
+    }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        (*self).order()
+    }
 }
 
 #[cfg(feature = "grid")]
 impl GridContainerStyle for Style {
+    type CustomIdent = DefaultCheapStr;
     type TemplateTrackList<'a>
         = &'a [TrackSizingFunction]
     where
@@ -808,6 +1538,18 @@ impl GridContainerStyle for Style {
         &self.grid_auto_columns
     }
     #[inline(always)]
+    fn grid_template_row_names(&self) -> &[GridTrackVec<Self::CustomIdent>] {
+        &self.grid_template_row_names
+    }
+    #[inline(always)]
+    fn grid_template_column_names(&self) -> &[GridTrackVec<Self::CustomIdent>] {
+        &self.grid_template_column_names
+    }
+    #[inline(always)]
+    fn grid_template_areas(&self) -> &[GridTemplateArea<Self::CustomIdent>] {
+        &self.grid_template_areas
+    }
+    #[inline(always)]
     fn grid_auto_flow(&self) -> GridAutoFlow {
         self.grid_auto_flow
     }
@@ -820,21 +1562,46 @@ impl GridContainerStyle for Style {
         self.align_content
     }
     #[inline(always)]
+    fn align_content_safety(&self) -> AlignmentSafety {
+        self.align_content_safety
+    }
+    #[inline(always)]
     fn justify_content(&self) -> Option<JustifyContent> {
         self.justify_content
     }
     #[inline(always)]
+    fn justify_content_safety(&self) -> AlignmentSafety {
+        self.justify_content_safety
+    }
+    #[inline(always)]
+    fn align_tracks(&self) -> Option<AlignContent> {
+        self.align_tracks
+    }
+    #[inline(always)]
+    fn justify_tracks(&self) -> Option<JustifyContent> {
+        self.justify_tracks
+    }
+    #[inline(always)]
     fn align_items(&self) -> Option<AlignItems> {
         self.align_items
     }
     #[inline(always)]
+    fn align_items_safety(&self) -> AlignmentSafety {
+        self.align_items_safety
+    }
+    #[inline(always)]
     fn justify_items(&self) -> Option<AlignItems> {
         self.justify_items
     }
+    #[inline(always)]
+    fn justify_items_safety(&self) -> AlignmentSafety {
+        self.justify_items_safety
+    }
 }
 
 #[cfg(feature = "grid")]
 impl<T: GridContainerStyle> GridContainerStyle for &'_ T {
+    type CustomIdent = T::CustomIdent;
     type TemplateTrackList<'a>
         = T::TemplateTrackList<'a>
     where
@@ -861,6 +1628,18 @@ impl<T: GridContainerStyle> GridContainerStyle for &'_ T {
         (*self).grid_auto_columns()
     }
     #[inline(always)]
+    fn grid_template_row_names(&self) -> &[GridTrackVec<Self::CustomIdent>] {
+        (*self).grid_template_row_names()
+    }
+    #[inline(always)]
+    fn grid_template_column_names(&self) -> &[GridTrackVec<Self::CustomIdent>] {
+        (*self).grid_template_column_names()
+    }
+    #[inline(always)]
+    fn grid_template_areas(&self) -> &[GridTemplateArea<Self::CustomIdent>] {
+        (*self).grid_template_areas()
+    }
+    #[inline(always)]
     fn grid_auto_flow(&self) -> GridAutoFlow {
         (*self).grid_auto_flow()
     }
@@ -873,47 +1652,95 @@ impl<T: GridContainerStyle> GridContainerStyle for &'_ T {
         (*self).align_content()
     }
     #[inline(always)]
+    fn align_content_safety(&self) -> AlignmentSafety {
+        (*self).align_content_safety()
+    }
+    #[inline(always)]
     fn justify_content(&self) -> Option<JustifyContent> {
         (*self).justify_content()
     }
     #[inline(always)]
+    fn justify_content_safety(&self) -> AlignmentSafety {
+        (*self).justify_content_safety()
+    }
+    #[inline(always)]
+    fn align_tracks(&self) -> Option<AlignContent> {
+        (*self).align_tracks()
+    }
+    #[inline(always)]
+    fn justify_tracks(&self) -> Option<JustifyContent> {
+        (*self).justify_tracks()
+    }
+    #[inline(always)]
     fn align_items(&self) -> Option<AlignItems> {
         (*self).align_items()
     }
     #[inline(always)]
+    fn align_items_safety(&self) -> AlignmentSafety {
+        (*self).align_items_safety()
+    }
+    #[inline(always)]
     fn justify_items(&self) -> Option<AlignItems> {
         (*self).justify_items()
     }
+    #[inline(always)]
+    fn justify_items_safety(&self) -> AlignmentSafety {
+        (*self).justify_items_safety()
+    }
 }
 
 #[cfg(feature = "grid")]
 impl GridItemStyle for &'_ Style {
+    type CustomIdent = DefaultCheapStr;
+
     #[inline(always)]
     fn grid_row(&self) -> Line<GridPlacement> {
-        self.grid_row
+        self.grid_row.clone()
     }
     #[inline(always)]
     fn grid_column(&self) -> Line<GridPlacement> {
-        self.grid_column
+        self.grid_column.clone()
     }
     #[inline(always)]
     fn align_self(&self) -> Option<AlignSelf> {
         self.align_self
     }
     #[inline(always)]
+    fn align_self_safety(&self) -> Option<AlignmentSafety> {
+        self.align_self_safety
+    }
+    #[inline(always)]
     fn justify_self(&self) -> Option<AlignSelf> {
         self.justify_self
     }
+    #[inline(always)]
+    fn justify_self_safety(&self) -> Option<AlignmentSafety> {
+        self.justify_self_safety
+    }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        self.order
+    }
+    #[inline(always)]
+    fn is_subgrid(&self, axis: AbsoluteAxis) -> bool {
+        let template = match axis {
+            AbsoluteAxis::Horizontal => &self.grid_template_columns,
+            AbsoluteAxis::Vertical => &self.grid_template_rows,
+        };
+        self.display == Display::Grid && TrackSizingFunction::is_subgrid_template(template)
+    }
 }
 
 #[cfg(feature = "grid")]
 impl<T: GridItemStyle> GridItemStyle for &'_ T {
+    type CustomIdent = T::CustomIdent;
+
     #[inline(always)]
-    fn grid_row(&self) -> Line<GridPlacement> {
+    fn grid_row(&self) -> Line<GridPlacement<Self::CustomIdent>> {
         (*self).grid_row()
     }
     #[inline(always)]
-    fn grid_column(&self) -> Line<GridPlacement> {
+    fn grid_column(&self) -> Line<GridPlacement<Self::CustomIdent>> {
         (*self).grid_column()
     }
     #[inline(always)]
@@ -921,9 +1748,25 @@ impl<T: GridItemStyle> GridItemStyle for &'_ T {
         (*self).align_self()
     }
     #[inline(always)]
+    fn align_self_safety(&self) -> Option<AlignmentSafety> {
+        (*self).align_self_safety()
+    }
+    #[inline(always)]
     fn justify_self(&self) -> Option<AlignSelf> {
         (*self).justify_self()
     }
+    #[inline(always)]
+    fn justify_self_safety(&self) -> Option<AlignmentSafety> {
+        (*self).justify_self_safety()
+    }
+    #[inline(always)]
+    fn order(&self) -> i32 {
+        (*self).order()
+    }
+    #[inline(always)]
+    fn is_subgrid(&self, axis: AbsoluteAxis) -> bool {
+        (*self).is_subgrid(axis)
+    }
 }
 
 #[cfg(test)]
@@ -940,8 +1783,12 @@ mod tests {
             display: Default::default(),
             item_is_table: false,
             box_sizing: Default::default(),
+            direction: Default::default(),
+            writing_mode: Default::default(),
+            visibility: Default::default(),
             overflow: Default::default(),
-            scrollbar_width: 0.0,
+            scrollbar_width: Size::ZERO,
+            scrollbar_gutter: Default::default(),
             position: Default::default(),
             #[cfg(feature = "flexbox")]
             flex_direction: Default::default(),
@@ -950,22 +1797,69 @@ mod tests {
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             align_items: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
+            align_items_safety: Default::default(),
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
             align_self: Default::default(),
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
+            align_self_safety: Default::default(),
             #[cfg(feature = "grid")]
             justify_items: Default::default(),
             #[cfg(feature = "grid")]
+            justify_items_safety: Default::default(),
+            #[cfg(feature = "grid")]
             justify_self: Default::default(),
+            #[cfg(feature = "grid")]
+            justify_self_safety: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             align_content: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
+            align_content_safety: Default::default(),
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
             justify_content: Default::default(),
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
+            justify_content_safety: Default::default(),
+            #[cfg(feature = "grid")]
+            align_tracks: Default::default(),
+            #[cfg(feature = "grid")]
+            justify_tracks: Default::default(),
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
+            order: 0,
             inset: Rect::auto(),
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            anchor_name: None,
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            position_anchor: None,
+            position_area: None,
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            position_try_fallbacks: Vec::new(),
             margin: Rect::zero(),
             padding: Rect::zero(),
             border: Rect::zero(),
             gap: Size::zero(),
             #[cfg(feature = "block_layout")]
             text_align: Default::default(),
+            #[cfg(feature = "block_layout")]
+            column_count: None,
+            #[cfg(feature = "block_layout")]
+            column_width: super::Dimension::AUTO,
+            #[cfg(feature = "block_layout")]
+            vertical_align: Default::default(),
+            #[cfg(feature = "block_layout")]
+            float: Default::default(),
+            #[cfg(feature = "block_layout")]
+            clear: Default::default(),
+            #[cfg(feature = "table_layout")]
+            border_collapse: Default::default(),
+            #[cfg(feature = "table_layout")]
+            border_spacing: Size::zero(),
+            #[cfg(feature = "table_layout")]
+            table_layout: Default::default(),
+            #[cfg(feature = "table_layout")]
+            caption_side: Default::default(),
+            #[cfg(feature = "table_layout")]
+            col_span: 1,
+            #[cfg(feature = "table_layout")]
+            row_span: 1,
             #[cfg(feature = "flexbox")]
             flex_grow: 0.0,
             #[cfg(feature = "flexbox")]
@@ -981,6 +1875,12 @@ mod tests {
             #[cfg(feature = "grid")]
             grid_template_columns: Default::default(),
             #[cfg(feature = "grid")]
+            grid_template_row_names: Default::default(),
+            #[cfg(feature = "grid")]
+            grid_template_column_names: Default::default(),
+            #[cfg(feature = "grid")]
+            grid_template_areas: Default::default(),
+            #[cfg(feature = "grid")]
             grid_auto_rows: Default::default(),
             #[cfg(feature = "grid")]
             grid_auto_columns: Default::default(),
@@ -1023,6 +1923,8 @@ mod tests {
         // Display and Position
         assert_type_size::<Display>(1);
         assert_type_size::<BoxSizing>(1);
+        assert_type_size::<Direction>(1);
+        assert_type_size::<WritingMode>(1);
         assert_type_size::<Position>(1);
         assert_type_size::<Overflow>(1);
 
@@ -1057,10 +1959,10 @@ mod tests {
         assert_type_size::<Vec<TrackSizingFunction>>(24);
 
         // CSS Grid Item
-        assert_type_size::<GridPlacement>(4);
-        assert_type_size::<Line<GridPlacement>>(8);
+        assert_type_size::<GridPlacement>(24);
+        assert_type_size::<Line<GridPlacement>>(48);
 
         // Overall
-        assert_type_size::<Style>(352);
+        assert_type_size::<Style>(632);
     }
 }
@@ -0,0 +1,103 @@
+//! Style types for Table layout
+use crate::{BlockItemStyle, CoreStyle, LengthPercentage, Size, Style};
+
+/// The set of styles required for a Table layout container
+pub trait TableContainerStyle: CoreStyle {
+    /// Whether the borders of adjacent cells are separated or collapsed into a single border
+    #[inline(always)]
+    fn border_collapse(&self) -> BorderCollapse {
+        Style::DEFAULT.border_collapse
+    }
+    /// The horizontal and vertical space between the borders of adjacent cells (in the separated borders model)
+    #[inline(always)]
+    fn border_spacing(&self) -> Size<LengthPercentage> {
+        Style::DEFAULT.border_spacing
+    }
+    /// Which algorithm is used to determine the widths of the table's columns
+    #[inline(always)]
+    fn table_layout(&self) -> TableLayout {
+        Style::DEFAULT.table_layout
+    }
+}
+
+/// The set of styles required for the parts of a Table layout container: its captions, row groups, rows and cells.
+/// Cells are block containers, and are aligned within their rows according to [`BlockItemStyle::vertical_align`].
+pub trait TableChildStyle: BlockItemStyle {
+    /// Which part of the table the node is
+    #[inline(always)]
+    fn table_part(&self) -> TablePart {
+        TablePart::Cell
+    }
+    /// The number of columns which a cell spans
+    #[inline(always)]
+    fn col_span(&self) -> u16 {
+        Style::DEFAULT.col_span
+    }
+    /// The number of rows which a cell spans
+    #[inline(always)]
+    fn row_span(&self) -> u16 {
+        Style::DEFAULT.row_span
+    }
+    /// Which side of the table a caption is placed on
+    #[inline(always)]
+    fn caption_side(&self) -> CaptionSide {
+        Style::DEFAULT.caption_side
+    }
+}
+
+/// Which part of a table a child (or grandchild) of a table container is.
+///
+/// Children of rows are always cells, and any other children of a table or row group are wrapped in anonymous rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TablePart {
+    /// A group of rows (like `<thead>`, `<tbody>` or `<tfoot>`)
+    RowGroup,
+    /// A row of cells (like `<tr>`)
+    Row,
+    /// A cell (like `<td>` or `<th>`)
+    #[default]
+    Cell,
+    /// A caption (like `<caption>`), which is placed above or below the table's grid
+    Caption,
+}
+
+/// Whether the borders of adjacent table cells are separated or collapsed
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/border-collapse>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BorderCollapse {
+    /// Each cell has its own borders, separated by the table's `border_spacing`
+    #[default]
+    Separate,
+    /// Adjacent cells share a single border, whose width is the widest of the borders which meet there
+    Collapse,
+}
+
+/// Which algorithm is used to determine the widths of a table's columns
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/table-layout>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableLayout {
+    /// Column widths are distributed according to the min-content and max-content widths of all of the cells
+    #[default]
+    Auto,
+    /// Column widths are determined by the widths of the cells in the first row, and the remaining space is divided
+    /// equally between the other columns. Only applies to tables with a definite width.
+    Fixed,
+}
+
+/// Which side of a table a caption is placed on
+///
+/// See <https://developer.mozilla.org/en-US/docs/Web/CSS/caption-side>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CaptionSide {
+    /// The caption is placed above the table's grid
+    #[default]
+    Top,
+    /// The caption is placed below the table's grid
+    Bottom,
+}
@@ -37,6 +37,24 @@ pub fn evenly_sized_tracks(count: u16) -> Vec<TrackSizingFunction> {
     tracks
 }
 
+#[cfg(feature = "grid")]
+/// Returns a grid template which makes the grid a subgrid in that axis, adopting the tracks of its parent grid
+pub fn subgrid() -> Vec<TrackSizingFunction> {
+    use crate::util::sys::new_vec_with_capacity;
+    let mut tracks = new_vec_with_capacity(1);
+    tracks.push(TrackSizingFunction::Subgrid);
+    tracks
+}
+
+#[cfg(feature = "grid")]
+/// Returns a grid template which makes that axis the masonry axis of the grid
+pub fn masonry() -> Vec<TrackSizingFunction> {
+    use crate::util::sys::new_vec_with_capacity;
+    let mut tracks = new_vec_with_capacity(1);
+    tracks.push(TrackSizingFunction::Masonry);
+    tracks
+}
+
 /// Specifies a grid line to place a grid item between in CSS Grid Line coordinates:
 ///  - Positive indices count upwards from the start (top or left) of the explicit grid
 ///  - Negative indices count downwards from the end (bottom or right) of the explicit grid
@@ -60,6 +78,45 @@ pub trait TaffyGridSpan {
     fn from_span(span: u16) -> Self;
 }
 
+/// Specifies the nth grid line with the specified name to place a grid item between:
+///  - Positive indices count upwards from the start (top or left) of the explicit grid
+///  - Negative indices count downwards from the end (bottom or right) of the explicit grid
+///  - ZERO IS INVALID index, and will be treated as a GridPlacement::Auto.
+#[cfg(feature = "grid")]
+pub fn named_line<T: TaffyGridNamedLine>(name: &str, index: i16) -> T {
+    T::from_named_line(name, index)
+}
+/// Trait to abstract over named grid line values
+#[cfg(feature = "grid")]
+pub trait TaffyGridNamedLine {
+    /// Converts a line name and an i16 into Self
+    fn from_named_line(name: &str, index: i16) -> Self;
+}
+
+/// Returns a GridPlacement::NamedSpan
+#[cfg(feature = "grid")]
+pub fn named_span<T: TaffyGridNamedSpan>(name: &str, span: u16) -> T {
+    T::from_named_span(name, span)
+}
+/// Trait to abstract over named grid span values
+#[cfg(feature = "grid")]
+pub trait TaffyGridNamedSpan {
+    /// Converts a line name and a u16 into Self
+    fn from_named_span(name: &str, span: u16) -> Self;
+}
+
+/// Returns a GridPlacement::NamedArea. When used for a `Line<GridPlacement>` this places the item into the named area.
+#[cfg(feature = "grid")]
+pub fn named_area<T: TaffyGridNamedArea>(name: &str) -> T {
+    T::from_named_area(name)
+}
+/// Trait to abstract over named grid area values
+#[cfg(feature = "grid")]
+pub trait TaffyGridNamedArea {
+    /// Converts an area name into Self
+    fn from_named_area(name: &str) -> Self;
+}
+
 /// Returns a MinMax with min value of min and max value of max
 #[cfg(feature = "grid")]
 pub fn minmax<Output>(min: MinTrackSizingFunction, max: MaxTrackSizingFunction) -> Output
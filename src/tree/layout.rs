@@ -1,6 +1,6 @@
 //! Final data structures that represent the high-level UI layout
 use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Size};
-use crate::style::AvailableSpace;
+use crate::style::{AvailableSpace, Visibility};
 use crate::style_helpers::TaffyMaxContent;
 use crate::util::sys::{f32_max, f32_min};
 
@@ -27,6 +27,22 @@ pub enum SizingMode {
     InherentSize,
 }
 
+/// The containing block of the absolutely positioned children of a `Position::Static` node, as passed in by its parent.
+/// Nodes which are not `Position::Static` are the containing block of their own absolutely positioned children and
+/// ignore this.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum ContainingBlock {
+    /// The node's own padding box. Used for the root node.
+    Own,
+    /// The containing block belongs to an ancestor whose layout is not known yet. Absolutely positioned children
+    /// are not laid out, and the parent is expected to lay the node out again with [`ContainingBlock::Ancestor`].
+    Deferred,
+    /// The padding box of an ancestor, given as the distance of each of its edges inward from the corresponding
+    /// edge of the node's border box (negative where the containing block extends beyond the node).
+    Ancestor(Rect<f32>),
+}
+
 /// A set of margins that are available for collapsing with for block layout's margin collapsing
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -133,6 +149,8 @@ pub struct LayoutInput {
     pub available_space: Size<AvailableSpace>,
     /// Specific to CSS Block layout. Used for correctly computing margin collapsing. You probably want to set this to `Line::FALSE`.
     pub vertical_margins_are_collapsible: Line<bool>,
+    /// The containing block of the node's absolutely positioned children if the node is `Position::Static`
+    pub containing_block: ContainingBlock,
 }
 
 impl LayoutInput {
@@ -147,6 +165,7 @@ impl LayoutInput {
         sizing_mode: SizingMode::InherentSize,
         axis: RequestedAxis::Both,
         vertical_margins_are_collapsible: Line::FALSE,
+        containing_block: ContainingBlock::Own,
     };
 }
 
@@ -164,6 +183,9 @@ pub struct LayoutOutput {
     #[cfg(feature = "content_size")]
     /// The size of the content within the node
     pub content_size: Size<f32>,
+    /// The size of the scrollbars for which the node has reserved space in each dimension. If there is no scrollbar
+    /// then the size will be zero.
+    pub scrollbar_size: Size<f32>,
     /// The first baseline of the node in each dimension, if any
     pub first_baselines: Point<Option<f32>>,
     /// Top margin that can be collapsed with. This is used for CSS block layout and can be set to
@@ -183,6 +205,7 @@ impl LayoutOutput {
         size: Size::ZERO,
         #[cfg(feature = "content_size")]
         content_size: Size::ZERO,
+        scrollbar_size: Size::ZERO,
         first_baselines: Point::NONE,
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
@@ -202,6 +225,7 @@ impl LayoutOutput {
             size,
             #[cfg(feature = "content_size")]
             content_size,
+            scrollbar_size: Size::ZERO,
             first_baselines,
             top_margin: CollapsibleMarginSet::ZERO,
             bottom_margin: CollapsibleMarginSet::ZERO,
@@ -237,6 +261,19 @@ pub struct Layout {
     /// The width and height of the content inside the node. This may be larger than the size of the node in the case of
     /// overflowing content and is useful for computing a "scroll width/height" for scrollable nodes
     pub content_size: Size<f32>,
+    #[cfg(feature = "content_size")]
+    /// The top-left corner of the node's scrollable overflow rectangle, relative to the top-left corner of the node's
+    /// border box. This is negative if content overflows the top or left edge of the node (for example because of
+    /// negative margins, or in right-to-left or reversed layouts). Content which overflows the start edges of a scroll
+    /// container cannot be scrolled to.
+    pub scrollable_overflow_location: Point<f32>,
+    #[cfg(feature = "content_size")]
+    /// The width and height of the node's scrollable overflow rectangle, which covers the node's padding box and the
+    /// border boxes of the descendants that it is the containing block of (along with their own scrollable overflow, in
+    /// the axes in which they don't clip it). For scroll containers, the area covered by the margin boxes of the in-flow
+    /// children is extended by the padding on its end sides. Computed by
+    /// [`compute_fixed_and_sticky_positions`](crate::compute_fixed_and_sticky_positions).
+    pub scrollable_overflow_size: Size<f32>,
     /// The size of the scrollbars in each dimension. If there is no scrollbar then the size will be zero.
     pub scrollbar_size: Size<f32>,
     /// The size of the borders of the node
@@ -245,6 +282,21 @@ pub struct Layout {
     pub padding: Rect<f32>,
     /// The size of the margin of the node
     pub margin: Rect<f32>,
+    /// The `visibility` style of the node. Nodes which are not `Visibility::Visible` should not be painted.
+    pub visibility: Visibility,
+    /// The offset that has been applied to the `location` of a `Position::Sticky` node to keep it within its scrollport.
+    /// Subtracting this from `location` gives the position of the node in the normal flow. Zero for other nodes.
+    pub sticky_offset: Point<f32>,
+    /// The offset by which the content of the node is scrolled: the node's scroll offset (see
+    /// [`LayoutPartialTree::get_scroll_offset`](crate::LayoutPartialTree::get_scroll_offset)) clamped to the range through
+    /// which it can be scrolled. Zero for nodes which are not scroll containers, except for the root node, whose scroll
+    /// offset scrolls the viewport.
+    pub scroll_offset: Point<f32>,
+    /// The top-left corner of the node once the content of its parent has been scrolled: `location` minus the
+    /// `scroll_offset` of the parent. An absolutely positioned node whose parent is not its containing block is not moved
+    /// by the scroll offsets of the nodes between them, so the scroll offsets of those nodes are added back. This is
+    /// where the node is displayed relative to its parent, and should be used for painting and hit testing.
+    pub scrolled_location: Point<f32>,
 }
 
 impl Default for Layout {
@@ -267,10 +319,18 @@ impl Layout {
             size: Size::zero(),
             #[cfg(feature = "content_size")]
             content_size: Size::zero(),
+            #[cfg(feature = "content_size")]
+            scrollable_overflow_location: Point::ZERO,
+            #[cfg(feature = "content_size")]
+            scrollable_overflow_size: Size::zero(),
             scrollbar_size: Size::zero(),
             border: Rect::zero(),
             padding: Rect::zero(),
             margin: Rect::zero(),
+            visibility: Visibility::Visible,
+            sticky_offset: Point::ZERO,
+            scroll_offset: Point::ZERO,
+            scrolled_location: Point::ZERO,
         }
     }
 
@@ -286,10 +346,18 @@ impl Layout {
             location: Point::ZERO,
             #[cfg(feature = "content_size")]
             content_size: Size::zero(),
+            #[cfg(feature = "content_size")]
+            scrollable_overflow_location: Point::ZERO,
+            #[cfg(feature = "content_size")]
+            scrollable_overflow_size: Size::zero(),
             scrollbar_size: Size::zero(),
             border: Rect::zero(),
             padding: Rect::zero(),
             margin: Rect::zero(),
+            visibility: Visibility::Visible,
+            sticky_offset: Point::ZERO,
+            scroll_offset: Point::ZERO,
+            scrolled_location: Point::ZERO,
         }
     }
 
@@ -345,6 +413,44 @@ impl Layout {
     }
 }
 
+/// A fragment of a text run which fits on a single line. Returned by
+/// [`LayoutBlockContainer::break_text_run`](crate::LayoutBlockContainer::break_text_run).
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TextRunBreak {
+    /// The offset into the text run at which the fragment ends, and at which the next fragment of the run starts.
+    /// Offsets are in units of the implementer's choosing (for example bytes), starting from zero.
+    pub end: usize,
+    /// Whether the fragment reaches the end of the text run. If not, the line is broken after the fragment.
+    pub is_end_of_run: bool,
+    /// The advance width of the fragment, excluding any whitespace that hangs at the end of the line
+    pub width: f32,
+    /// The distance from the top of the fragment's line-height box to its baseline
+    pub ascent: f32,
+    /// The distance from the baseline to the bottom of the fragment's line-height box
+    pub descent: f32,
+}
+
+/// The part of an inline box or text run which is placed on a single line. Set using
+/// [`LayoutBlockContainer::set_inline_fragments`](crate::LayoutBlockContainer::set_inline_fragments).
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InlineFragment {
+    /// The index of the line on which the fragment is placed, counted from the first line of the inline formatting context
+    pub line: u32,
+    /// The top-left corner of the fragment, relative to the top-left corner of the node's layout
+    pub location: Point<f32>,
+    /// The width and height of the fragment. For inline boxes this is the fragment's border box.
+    pub size: Size<f32>,
+    /// The distance from the top of the fragment (or in vertical writing modes, its block-start edge) to the baseline of
+    /// its line
+    pub baseline: f32,
+    /// For text runs, the offset into the run at which the fragment's text starts. Zero for inline boxes.
+    pub text_start: usize,
+    /// For text runs, the offset into the run at which the fragment's text ends. Zero for inline boxes.
+    pub text_end: usize,
+}
+
 /// The additional information from layout algorithm
 #[cfg(feature = "detailed_layout_info")]
 #[derive(Debug, Clone, PartialEq)]
@@ -352,6 +458,9 @@ pub enum DetailedLayoutInfo {
     /// Enum variant for [`DetailedGridInfo`](crate::compute::grid::DetailedGridInfo)
     #[cfg(feature = "grid")]
     Grid(Box<crate::compute::grid::DetailedGridInfo>),
+    /// Enum variant for [`DetailedMulticolInfo`](crate::compute::detailed_info::DetailedMulticolInfo)
+    #[cfg(feature = "block_layout")]
+    Multicol(Box<crate::compute::multicol::DetailedMulticolInfo>),
     /// For node that hasn't had any detailed information yet
     None,
 }
@@ -10,7 +10,10 @@ mod node;
 pub mod traits;
 
 pub use cache::Cache;
-pub use layout::{CollapsibleMarginSet, Layout, LayoutInput, LayoutOutput, RequestedAxis, RunMode, SizingMode};
+pub use layout::{
+    CollapsibleMarginSet, ContainingBlock, InlineFragment, Layout, LayoutInput, LayoutOutput, RequestedAxis, RunMode,
+    SizingMode, TextRunBreak,
+};
 pub use node::NodeId;
 pub(crate) use traits::LayoutPartialTreeExt;
 pub use traits::{LayoutPartialTree, PrintTree, RoundTree, TraversePartialTree, TraverseTree};
@@ -24,6 +27,9 @@ pub use traits::LayoutGridContainer;
 #[cfg(feature = "block_layout")]
 pub use traits::LayoutBlockContainer;
 
+#[cfg(feature = "table_layout")]
+pub use traits::LayoutTableContainer;
+
 #[cfg(feature = "taffy_tree")]
 mod taffy_tree;
 #[cfg(feature = "taffy_tree")]
@@ -5,17 +5,23 @@ use slotmap::SecondaryMap;
 use slotmap::SparseSecondaryMap as SecondaryMap;
 use slotmap::{DefaultKey, SlotMap};
 
-use crate::geometry::Size;
-use crate::style::{AvailableSpace, Display, Style};
+use crate::geometry::{Point, Size};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::style::CalcExpr;
+use crate::style::{AvailableSpace, CompactLength, Display, FontMetrics, Style};
+#[cfg(feature = "grid")]
+use crate::style::{DefaultCheapStr, TrackSizingFunction};
 use crate::tree::{
-    Cache, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, NodeId, PrintTree, RoundTree, RunMode,
+    Cache, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, NodeId, PrintTree, RoundTree, RunMode, TextRunBreak,
     TraversePartialTree, TraverseTree,
 };
 use crate::util::debug::{debug_log, debug_log_node};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::util::sys::BTreeMap;
 use crate::util::sys::{new_vec_with_capacity, ChildrenVec, Vec};
 
 use crate::compute::{
-    compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
+    compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout_with_positions, round_layout,
 };
 use crate::CacheTree;
 #[cfg(feature = "block_layout")]
@@ -24,11 +30,21 @@ use crate::{compute::compute_block_layout, LayoutBlockContainer};
 use crate::{compute::compute_flexbox_layout, LayoutFlexboxContainer};
 #[cfg(feature = "grid")]
 use crate::{compute::compute_grid_layout, LayoutGridContainer};
+#[cfg(feature = "table_layout")]
+use crate::{compute::compute_table_layout, LayoutTableContainer};
 
 #[cfg(all(feature = "detailed_layout_info", feature = "grid"))]
 use crate::compute::grid::DetailedGridInfo;
+#[cfg(feature = "grid")]
+use crate::compute::grid::SubgridTracks;
+#[cfg(all(feature = "detailed_layout_info", feature = "block_layout"))]
+use crate::compute::multicol::DetailedMulticolInfo;
+#[cfg(feature = "grid")]
+use crate::geometry::AbstractAxis;
 #[cfg(feature = "detailed_layout_info")]
 use crate::tree::layout::DetailedLayoutInfo;
+#[cfg(feature = "block_layout")]
+use crate::tree::InlineFragment;
 
 /// The error Taffy generates on invalid operations
 pub type TaffyResult<T> = Result<T, TaffyError>;
@@ -106,9 +122,27 @@ struct NodeData {
     /// The cached results of the layout computation
     pub(crate) cache: Cache,
 
+    /// The offset by which the node's contents have been scrolled
+    pub(crate) scroll_offset: Point<f32>,
+
+    /// The computed font metrics of the node. `None` if the node inherits the font metrics of its parent.
+    pub(crate) font_metrics: Option<FontMetrics>,
+
+    /// The children of the node as seen by the layout algorithms, in which the children of `Display::Contents` children
+    /// take the place of those children. `None` if the node has no `Display::Contents` children.
+    pub(crate) layout_children: Option<ChildrenVec<NodeId>>,
+
+    /// The fragments of the node on each line, if the node is an inline box or a text run
+    #[cfg(feature = "block_layout")]
+    pub(crate) inline_fragments: Vec<InlineFragment>,
+
     /// The computation result from layout algorithm
     #[cfg(feature = "detailed_layout_info")]
     pub(crate) detailed_layout_info: DetailedLayoutInfo,
+
+    /// The tracks adopted from the parent grid in each axis in which the node is a subgrid
+    #[cfg(feature = "grid")]
+    pub(crate) subgrid_tracks: Size<Option<SubgridTracks>>,
 }
 
 impl NodeData {
@@ -118,11 +152,18 @@ impl NodeData {
         Self {
             style,
             cache: Cache::new(),
+            scroll_offset: Point::ZERO,
+            font_metrics: None,
+            layout_children: None,
+            #[cfg(feature = "block_layout")]
+            inline_fragments: Vec::new(),
             unrounded_layout: Layout::new(),
             final_layout: Layout::new(),
             has_context: false,
             #[cfg(feature = "detailed_layout_info")]
             detailed_layout_info: DetailedLayoutInfo::None,
+            #[cfg(feature = "grid")]
+            subgrid_tracks: Size { width: None, height: None },
         }
     }
 
@@ -156,6 +197,23 @@ pub struct TaffyTree<NodeContext = ()> {
     /// The indexes in the outer vector correspond to the position of the child [`NodeData`]
     parents: SlotMap<DefaultKey, Option<NodeId>>,
 
+    /// The `calc()` expressions stored in the tree, keyed by the handles returned by [`TaffyTree::new_calc`]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    calc_exprs: BTreeMap<u64, CalcExpr>,
+
+    /// The handle that the next call to [`TaffyTree::new_calc`] will return. Handles are never reused.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    next_calc_handle: u64,
+
+    /// The size of the viewport set by [`TaffyTree::set_viewport_size`]
+    viewport_size: Option<Size<f32>>,
+
+    /// The size of the viewport against which viewport-relative lengths were resolved in the last layout
+    resolved_viewport_size: Size<f32>,
+
+    /// The font size of the root node against which `rem` lengths were resolved in the last layout
+    root_font_size: f32,
+
     /// Layout mode configuration
     config: TaffyConfig,
 }
@@ -240,9 +298,24 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
 
         match (num_children, display) {
             (_, Display::None) => "NONE",
+            (_, Display::Contents) => "CONTENTS",
             (0, _) => "LEAF",
             #[cfg(feature = "block_layout")]
             (_, Display::Block) => "BLOCK",
+            #[cfg(feature = "block_layout")]
+            (_, Display::Inline) => "INLINE",
+            #[cfg(feature = "block_layout")]
+            (_, Display::InlineBlock) => "INLINE-BLOCK",
+            #[cfg(feature = "table_layout")]
+            (_, Display::Table) => "TABLE",
+            #[cfg(feature = "table_layout")]
+            (_, Display::TableRowGroup) => "TABLE-ROW-GROUP",
+            #[cfg(feature = "table_layout")]
+            (_, Display::TableRow) => "TABLE-ROW",
+            #[cfg(feature = "table_layout")]
+            (_, Display::TableCell) => "TABLE-CELL",
+            #[cfg(feature = "table_layout")]
+            (_, Display::TableCaption) => "TABLE-CAPTION",
             #[cfg(feature = "flexbox")]
             (_, Display::Flex) => {
                 use crate::FlexDirection;
@@ -266,25 +339,84 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
     }
 }
 
+/// Evaluate the `calc()` expression with the specified handle (as returned by [`TaffyTree::new_calc`]), resolving
+/// percentages against `basis`. Handles which do not refer to an expression in the tree (because the expression has
+/// been removed, or because the handle was not created by this tree) are a bug: they panic in debug builds and
+/// resolve to zero otherwise.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn resolve_calc_value(calc_exprs: &BTreeMap<u64, CalcExpr>, handle: u64, basis: f32) -> f32 {
+    let expr = calc_exprs.get(&handle);
+    debug_assert!(expr.is_some(), "calc() handle {handle:#x} does not refer to an expression in this TaffyTree");
+    expr.map_or(0.0, |expr| expr.resolve(basis))
+}
+
+/// Calls `visit` with each length in `style`
+fn for_each_length(style: &Style, mut visit: impl FnMut(CompactLength)) {
+    for rect in [style.inset, style.margin] {
+        [rect.left, rect.right, rect.top, rect.bottom].into_iter().for_each(|value| visit(value.into_raw()));
+    }
+    for rect in [style.padding, style.border] {
+        [rect.left, rect.right, rect.top, rect.bottom].into_iter().for_each(|value| visit(value.into_raw()));
+    }
+    #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
+    [style.gap.width, style.gap.height].into_iter().for_each(|value| visit(value.into_raw()));
+    #[cfg(feature = "table_layout")]
+    [style.border_spacing.width, style.border_spacing.height].into_iter().for_each(|value| visit(value.into_raw()));
+    for size in [style.size, style.min_size, style.max_size] {
+        [size.width, size.height].into_iter().for_each(|value| visit(value.into_raw()));
+    }
+    #[cfg(feature = "block_layout")]
+    visit(style.column_width.into_raw());
+    #[cfg(feature = "flexbox")]
+    visit(style.flex_basis.into_raw());
+
+    #[cfg(feature = "grid")]
+    {
+        let tracks =
+            style.grid_template_rows.iter().chain(&style.grid_template_columns).flat_map(|track| match track {
+                TrackSizingFunction::Single(track) => core::slice::from_ref(track),
+                TrackSizingFunction::Repeat(_, tracks) => tracks.as_slice(),
+                TrackSizingFunction::Subgrid | TrackSizingFunction::Masonry => &[],
+            });
+        for track in tracks.chain(&style.grid_auto_rows).chain(&style.grid_auto_columns) {
+            visit(track.min.into_raw());
+            visit(track.max.into_raw());
+        }
+    }
+}
+
+/// Whether any of the lengths in `style` satisfy `predicate`
+fn style_uses_length(style: &Style, predicate: impl Fn(CompactLength) -> bool) -> bool {
+    let mut uses_length = false;
+    for_each_length(style, |length| uses_length |= predicate(length));
+    uses_length
+}
+
 /// View over the Taffy tree that holds the tree itself along with a reference to the context
 /// and implements LayoutTree. This allows the context to be stored outside of the TaffyTree struct
 /// which makes the lifetimes of the context much more flexible.
-pub(crate) struct TaffyView<'t, NodeContext, MeasureFunction>
+pub(crate) struct TaffyView<'t, NodeContext, MeasureFunction, TextFunction>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
 {
     /// A reference to the TaffyTree
     pub(crate) taffy: &'t mut TaffyTree<NodeContext>,
     /// The context provided for passing to measure functions if layout is run over this struct
     pub(crate) measure_function: MeasureFunction,
+    /// The function used to break text runs into lines if layout is run over this struct
+    #[cfg_attr(not(feature = "block_layout"), allow(dead_code))]
+    pub(crate) text_function: TextFunction,
 }
 
 // TraversePartialTree impl for TaffyView
-impl<NodeContext, MeasureFunction> TraversePartialTree for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, TextFunction> TraversePartialTree
+    for TaffyView<'_, NodeContext, MeasureFunction, TextFunction>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
 {
     type ChildIter<'a>
         = TaffyTreeChildIter<'a>
@@ -293,32 +425,37 @@ where
 
     #[inline(always)]
     fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
-        self.taffy.child_ids(parent_node_id)
+        TaffyTreeChildIter(self.taffy.layout_children(parent_node_id).iter())
     }
 
     #[inline(always)]
     fn child_count(&self, parent_node_id: NodeId) -> usize {
-        self.taffy.child_count(parent_node_id)
+        self.taffy.layout_children(parent_node_id).len()
     }
 
     #[inline(always)]
     fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
-        self.taffy.get_child_id(parent_node_id, child_index)
+        self.taffy.layout_children(parent_node_id)[child_index]
     }
 }
 
 // TraverseTree impl for TaffyView
-impl<NodeContext, MeasureFunction> TraverseTree for TaffyView<'_, NodeContext, MeasureFunction> where
+impl<NodeContext, MeasureFunction, TextFunction> TraverseTree
+    for TaffyView<'_, NodeContext, MeasureFunction, TextFunction>
+where
     MeasureFunction:
-        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
 {
 }
 
 // LayoutPartialTree impl for TaffyView
-impl<NodeContext, MeasureFunction> LayoutPartialTree for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, TextFunction> LayoutPartialTree
+    for TaffyView<'_, NodeContext, MeasureFunction, TextFunction>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
 {
     type CoreContainerStyle<'a>
         = &'a Style
@@ -336,8 +473,12 @@ where
     }
 
     #[inline(always)]
-    fn resolve_calc_value(&self, _val: u64, _basis: f32) -> f32 {
-        0.0
+    #[cfg_attr(not(any(feature = "std", feature = "alloc")), allow(unused_variables))]
+    fn resolve_calc_value(&self, val: u64, basis: f32) -> f32 {
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        return resolve_calc_value(&self.taffy.calc_exprs, val, basis);
+        #[cfg(not(any(feature = "std", feature = "alloc")))]
+        return 0.0;
     }
 
     #[inline(always)]
@@ -355,7 +496,12 @@ where
         //
         // If there was no cache match and a new result needs to be computed then that result will be added to the cache
         compute_cached_layout(self, node, inputs, |tree, node, inputs| {
-            let display_mode = tree.taffy.nodes[node.into()].style.display;
+            let display_mode = match tree.taffy.nodes[node.into()].style.display {
+                // The children of other `Display::Contents` nodes are hoisted into their parent, so the only such
+                // node which is laid out is the root node. It generates a box like a node with the default display.
+                Display::Contents => Display::DEFAULT,
+                display_mode => display_mode,
+            };
             let has_children = tree.child_count(node) > 0;
 
             debug_log!(display_mode);
@@ -370,32 +516,77 @@ where
             // Dispatch to a layout algorithm based on the node's display style and whether the node has children or not.
             match (display_mode, has_children) {
                 (Display::None, _) => compute_hidden_layout(tree, node),
+                (Display::Contents, _) => unreachable!("Display::Contents is replaced by the default display mode"),
                 #[cfg(feature = "block_layout")]
                 (Display::Block, true) => compute_block_layout(tree, node, inputs),
+                // Inline boxes are laid out as part of their parent's lines. If they are laid out on their own (for
+                // example as the child of a flex container) then they are block containers.
+                #[cfg(feature = "block_layout")]
+                (Display::Inline | Display::InlineBlock, true) => compute_block_layout(tree, node, inputs),
+                #[cfg(feature = "table_layout")]
+                (Display::Table, true) => compute_table_layout(tree, node, inputs),
+                // The parts of a table are laid out by the table. If they are laid out on their own (for example as
+                // the child of a flex container) then they are block containers.
+                #[cfg(feature = "table_layout")]
+                (Display::TableRowGroup | Display::TableRow | Display::TableCell | Display::TableCaption, true) => {
+                    compute_block_layout(tree, node, inputs)
+                }
                 #[cfg(feature = "flexbox")]
                 (Display::Flex, true) => compute_flexbox_layout(tree, node, inputs),
                 #[cfg(feature = "grid")]
                 (Display::Grid, true) => compute_grid_layout(tree, node, inputs),
                 (_, false) => {
                     let node_key = node.into();
+                    let relative_length_basis = tree.get_relative_length_basis(node);
                     let style = &tree.taffy.nodes[node_key].style;
                     let has_context = tree.taffy.nodes[node_key].has_context;
-                    let node_context = has_context.then(|| tree.taffy.node_context_data.get_mut(node_key)).flatten();
+                    let mut node_context =
+                        has_context.then(|| tree.taffy.node_context_data.get_mut(node_key)).flatten();
                     let measure_function = |known_dimensions, available_space| {
+                        let node_context = node_context.as_deref_mut();
                         (tree.measure_function)(known_dimensions, available_space, node, node_context, style)
                     };
-                    // TODO: implement calc() in high-level API
-                    compute_leaf_layout(inputs, style, |_, _| 0.0, measure_function)
+                    #[cfg(any(feature = "std", feature = "alloc"))]
+                    let calc_exprs = &tree.taffy.calc_exprs;
+                    #[cfg_attr(not(any(feature = "std", feature = "alloc")), allow(unused_variables))]
+                    let resolve_calc_value = |val, basis| {
+                        #[cfg(any(feature = "std", feature = "alloc"))]
+                        return resolve_calc_value(calc_exprs, val, basis);
+                        #[cfg(not(any(feature = "std", feature = "alloc")))]
+                        return 0.0;
+                    };
+                    compute_leaf_layout(inputs, style, relative_length_basis, resolve_calc_value, measure_function)
                 }
             }
         })
     }
+
+    #[inline(always)]
+    fn get_scroll_offset(&self, node_id: NodeId) -> Point<f32> {
+        self.taffy.nodes[node_id.into()].scroll_offset
+    }
+
+    #[inline(always)]
+    fn get_viewport_size(&self) -> Size<f32> {
+        self.taffy.resolved_viewport_size
+    }
+
+    #[inline(always)]
+    fn get_font_metrics(&self, node_id: NodeId) -> FontMetrics {
+        self.taffy.inherited_font_metrics(node_id)
+    }
+
+    #[inline(always)]
+    fn get_root_font_size(&self) -> f32 {
+        self.taffy.root_font_size
+    }
 }
 
-impl<NodeContext, MeasureFunction> CacheTree for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, TextFunction> CacheTree for TaffyView<'_, NodeContext, MeasureFunction, TextFunction>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
 {
     fn cache_get(
         &self,
@@ -424,10 +615,12 @@ where
 }
 
 #[cfg(feature = "block_layout")]
-impl<NodeContext, MeasureFunction> LayoutBlockContainer for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, TextFunction> LayoutBlockContainer
+    for TaffyView<'_, NodeContext, MeasureFunction, TextFunction>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
 {
     type BlockContainerStyle<'a>
         = &'a Style
@@ -447,13 +640,82 @@ where
     fn get_block_child_style(&self, child_node_id: NodeId) -> Self::BlockItemStyle<'_> {
         self.get_core_container_style(child_node_id)
     }
+
+    #[inline(always)]
+    fn break_text_run(
+        &mut self,
+        node_id: NodeId,
+        start: usize,
+        available_width: Option<f32>,
+        line_is_empty: bool,
+    ) -> Option<TextRunBreak> {
+        let node_key = node_id.into();
+        let style = &self.taffy.nodes[node_key].style;
+        let has_context = self.taffy.nodes[node_key].has_context;
+        let node_context = has_context.then(|| self.taffy.node_context_data.get_mut(node_key)).flatten();
+        (self.text_function)(start, available_width, line_is_empty, node_id, node_context, style)
+    }
+
+    #[inline(always)]
+    fn set_inline_fragments(&mut self, node_id: NodeId, fragments: &[InlineFragment]) {
+        let inline_fragments = &mut self.taffy.nodes[node_id.into()].inline_fragments;
+        inline_fragments.clear();
+        inline_fragments.extend(fragments.iter().copied());
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "detailed_layout_info")]
+    fn set_detailed_multicol_info(&mut self, node_id: NodeId, detailed_multicol_info: DetailedMulticolInfo) {
+        self.taffy.nodes[node_id.into()].detailed_layout_info =
+            DetailedLayoutInfo::Multicol(Box::new(detailed_multicol_info));
+    }
+}
+
+#[cfg(feature = "table_layout")]
+impl<NodeContext, MeasureFunction, TextFunction> LayoutTableContainer
+    for TaffyView<'_, NodeContext, MeasureFunction, TextFunction>
+where
+    MeasureFunction:
+        FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
+{
+    type TableContainerStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+    type TableChildStyle<'a>
+        = &'a Style
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_table_container_style(&self, node_id: NodeId) -> Self::TableContainerStyle<'_> {
+        &self.taffy.nodes[node_id.into()].style
+    }
+
+    #[inline(always)]
+    fn get_table_child_style(&self, child_node_id: NodeId) -> Self::TableChildStyle<'_> {
+        &self.taffy.nodes[child_node_id.into()].style
+    }
+
+    fn offset_cell_content(&mut self, cell_node_id: NodeId, offset: Point<f32>) {
+        for index in 0..self.taffy.layout_children(cell_node_id).len() {
+            let child = self.taffy.layout_children(cell_node_id)[index];
+            let layout = &mut self.taffy.nodes[child.into()].unrounded_layout;
+            layout.location = layout.location + offset;
+        }
+        // The offset layouts of the children would not be reset if the cell's cached layout was reused
+        self.taffy.nodes[cell_node_id.into()].cache.clear();
+    }
 }
 
 #[cfg(feature = "flexbox")]
-impl<NodeContext, MeasureFunction> LayoutFlexboxContainer for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, TextFunction> LayoutFlexboxContainer
+    for TaffyView<'_, NodeContext, MeasureFunction, TextFunction>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
 {
     type FlexboxContainerStyle<'a>
         = &'a Style
@@ -476,11 +738,14 @@ where
 }
 
 #[cfg(feature = "grid")]
-impl<NodeContext, MeasureFunction> LayoutGridContainer for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, TextFunction> LayoutGridContainer
+    for TaffyView<'_, NodeContext, MeasureFunction, TextFunction>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
 {
+    type CustomIdent = DefaultCheapStr;
     type GridContainerStyle<'a>
         = &'a Style
     where
@@ -505,13 +770,35 @@ where
     fn set_detailed_grid_info(&mut self, node_id: NodeId, detailed_grid_info: DetailedGridInfo) {
         self.taffy.nodes[node_id.into()].detailed_layout_info = DetailedLayoutInfo::Grid(Box::new(detailed_grid_info));
     }
+
+    #[inline(always)]
+    fn get_subgrid_tracks(&self, node_id: NodeId, axis: AbstractAxis) -> Option<&SubgridTracks> {
+        let subgrid_tracks = &self.taffy.nodes[node_id.into()].subgrid_tracks;
+        match axis {
+            AbstractAxis::Inline => subgrid_tracks.width.as_ref(),
+            AbstractAxis::Block => subgrid_tracks.height.as_ref(),
+        }
+    }
+
+    fn set_subgrid_tracks(&mut self, node_id: NodeId, axis: AbstractAxis, tracks: SubgridTracks) {
+        let node = &mut self.taffy.nodes[node_id.into()];
+        let subgrid_tracks = match axis {
+            AbstractAxis::Inline => &mut node.subgrid_tracks.width,
+            AbstractAxis::Block => &mut node.subgrid_tracks.height,
+        };
+        if subgrid_tracks.as_ref() != Some(&tracks) {
+            *subgrid_tracks = Some(tracks);
+            node.cache.clear();
+        }
+    }
 }
 
 // RoundTree impl for TaffyView
-impl<NodeContext, MeasureFunction> RoundTree for TaffyView<'_, NodeContext, MeasureFunction>
+impl<NodeContext, MeasureFunction, TextFunction> RoundTree for TaffyView<'_, NodeContext, MeasureFunction, TextFunction>
 where
     MeasureFunction:
         FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
 {
     #[inline(always)]
     fn get_unrounded_layout(&self, node: NodeId) -> &Layout {
@@ -544,6 +831,14 @@ impl<NodeContext> TaffyTree<NodeContext> {
             children: SlotMap::with_capacity(capacity),
             parents: SlotMap::with_capacity(capacity),
             node_context_data: SecondaryMap::with_capacity(capacity),
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            calc_exprs: BTreeMap::new(),
+            // Handles must be non-zero, with the low 3 bits (which are used as a tag by `CompactLength`) unset
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            next_calc_handle: 1 << 3,
+            viewport_size: None,
+            resolved_viewport_size: Size::ZERO,
+            root_font_size: FontMetrics::DEFAULT.font_size,
             config: TaffyConfig::default(),
         }
     }
@@ -593,15 +888,42 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
         let _ = self.children.insert(children.iter().copied().collect::<_>());
         let _ = self.parents.insert(None);
+        self.update_layout_children(id);
 
         Ok(id)
     }
 
-    /// Drops all nodes in the tree
+    /// Stores a `calc()` expression in the tree, and returns an opaque handle to it which can be passed to
+    /// [`LengthPercentage::calc`](crate::LengthPercentage::calc), [`LengthPercentageAuto::calc`](crate::LengthPercentageAuto::calc)
+    /// and [`Dimension::calc`](crate::Dimension::calc) to use the expression in the styles of the nodes of this tree.
+    ///
+    /// The expression is owned by the tree and is kept until it is removed with [`TaffyTree::remove_calc`] or the tree
+    /// is cleared, after which its handle must not be used again. An expression can be shared by any number of styles,
+    /// so it only needs to be created once. Handles are never reused, including after the tree is cleared.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn new_calc(&mut self, expr: CalcExpr) -> *const () {
+        let handle = self.next_calc_handle;
+        // Advance past the tag bits of `CompactLength`
+        self.next_calc_handle += 1 << 3;
+        self.calc_exprs.insert(handle, expr);
+        usize::try_from(handle).expect("calc() handles exhausted") as *const ()
+    }
+
+    /// Removes a `calc()` expression created by [`TaffyTree::new_calc`] from the tree, and returns it (or `None` if the
+    /// handle does not refer to an expression in the tree). The expression must no longer be used by the style of any
+    /// node in the tree.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn remove_calc(&mut self, handle: *const ()) -> Option<CalcExpr> {
+        self.calc_exprs.remove(&(handle as usize as u64))
+    }
+
+    /// Drops all nodes and `calc()` expressions in the tree
     pub fn clear(&mut self) {
         self.nodes.clear();
         self.children.clear();
         self.parents.clear();
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        self.calc_exprs.clear();
     }
 
     /// Remove a specific node from the tree and drop it
@@ -612,6 +934,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
         if let Some(parent) = self.parents[key] {
             if let Some(children) = self.children.get_mut(parent.into()) {
                 children.retain(|f| *f != node);
+                self.update_layout_children(parent);
             }
         }
 
@@ -669,6 +992,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
         let child_key = child.into();
         self.parents[child_key] = Some(parent);
         self.children[parent_key].push(child);
+        self.update_layout_children(parent);
         self.mark_dirty(parent)?;
 
         Ok(())
@@ -685,6 +1009,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
         self.parents[child.into()] = Some(parent);
         self.children[parent_key].insert(child_index, child);
+        self.update_layout_children(parent);
         self.mark_dirty(parent)?;
 
         Ok(())
@@ -695,8 +1020,11 @@ impl<NodeContext> TaffyTree<NodeContext> {
         let parent_key = parent.into();
 
         // Remove node as parent from all its current children.
-        for child in &self.children[parent_key] {
-            self.parents[(*child).into()] = None;
+        for index in 0..self.children[parent_key].len() {
+            let child = self.children[parent_key][index];
+            self.parents[child.into()] = None;
+            #[cfg(feature = "grid")]
+            self.clear_subgrid_tracks(child);
         }
 
         // Build up relation node <-> child
@@ -711,6 +1039,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
         let parent_children = &mut self.children[parent_key];
         parent_children.clear();
         children.iter().for_each(|child| parent_children.push(*child));
+        self.update_layout_children(parent);
 
         self.mark_dirty(parent)?;
 
@@ -737,6 +1066,9 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
         let child = self.children[parent_key].remove(child_index);
         self.parents[child.into()] = None;
+        #[cfg(feature = "grid")]
+        self.clear_subgrid_tracks(child);
+        self.update_layout_children(parent);
 
         self.mark_dirty(parent)?;
 
@@ -762,6 +1094,9 @@ impl<NodeContext> TaffyTree<NodeContext> {
         self.parents[new_child.into()] = Some(parent);
         let old_child = core::mem::replace(&mut self.children[parent_key][child_index], new_child);
         self.parents[old_child.into()] = None;
+        #[cfg(feature = "grid")]
+        self.clear_subgrid_tracks(old_child);
+        self.update_layout_children(parent);
 
         self.mark_dirty(parent)?;
 
@@ -799,16 +1134,135 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
     /// Sets the [`Style`] of the provided `node`
     pub fn set_style(&mut self, node: NodeId, style: Style) -> TaffyResult<()> {
-        self.nodes[node.into()].style = style;
+        // Subgrid tracks are set again when the node's parent is laid out, but the node's children only have
+        // their tracks set again if the node is still a grid with the same orientation
+        #[cfg(feature = "grid")]
+        {
+            let old_style = &self.nodes[node.into()].style;
+            if old_style.display != style.display || old_style.writing_mode != style.writing_mode {
+                for index in 0..self.children[node.into()].len() {
+                    self.clear_subgrid_tracks(self.children[node.into()][index]);
+                }
+            }
+            self.clear_subgrid_tracks(node);
+        }
+
+        let old_style = core::mem::replace(&mut self.nodes[node.into()].style, style);
+        let is_contents = self.nodes[node.into()].style.display == Display::Contents;
+        if (old_style.display == Display::Contents) != is_contents {
+            if let Some(parent) = self.parents[node.into()] {
+                self.update_layout_children(parent);
+            }
+        }
         self.mark_dirty(node)?;
         Ok(())
     }
 
+    /// Discard the tracks that a subgrid adopted from its parent grid, as its parent may no longer be a grid
+    #[cfg(feature = "grid")]
+    fn clear_subgrid_tracks(&mut self, node: NodeId) {
+        let node = &mut self.nodes[node.into()];
+        if node.subgrid_tracks.width.is_some() || node.subgrid_tracks.height.is_some() {
+            node.subgrid_tracks = Size { width: None, height: None };
+            node.cache.clear();
+        }
+    }
+
     /// Gets the [`Style`] of the provided `node`
     pub fn style(&self, node: NodeId) -> TaffyResult<&Style> {
         Ok(&self.nodes[node.into()].style)
     }
 
+    /// Sets the offset by which the contents of the provided `node` have been scrolled
+    ///
+    /// Scroll offsets are only taken into account for scroll containers and the root node (whose scroll offset scrolls the
+    /// viewport), and take effect the next time that layout is computed, when they are clamped to the range through which
+    /// the node can be scrolled. The clamped offset is reported in [`Layout::scroll_offset`], and the position of each
+    /// child of the node once it has been scrolled in [`Layout::scrolled_location`]. Scroll offsets also affect the
+    /// position of `Position::Sticky` and `Position::Fixed` nodes (and of `Position::Absolute` nodes whose containing
+    /// block is an ancestor of the scrolled node). Setting a scroll offset does not mark the node as dirty.
+    pub fn set_scroll_offset(&mut self, node: NodeId, scroll_offset: Point<f32>) -> TaffyResult<()> {
+        self.nodes[node.into()].scroll_offset = scroll_offset;
+        Ok(())
+    }
+
+    /// Gets the offset by which the contents of the provided `node` have been scrolled, as passed to
+    /// [`TaffyTree::set_scroll_offset`] (before it is clamped)
+    pub fn scroll_offset(&self, node: NodeId) -> TaffyResult<Point<f32>> {
+        Ok(self.nodes[node.into()].scroll_offset)
+    }
+
+    /// Sets the computed font metrics of the provided `node`, against which font-relative lengths in the styles of the
+    /// node and of the descendants which inherit them are resolved. Passing `None` makes the node inherit the font
+    /// metrics of its parent, which is the default. Nodes without an ancestor with font metrics use
+    /// [`FontMetrics::DEFAULT`].
+    ///
+    /// This marks the node as dirty and clears the cached layouts of its descendants.
+    pub fn set_font_metrics(&mut self, node: NodeId, font_metrics: Option<FontMetrics>) -> TaffyResult<()> {
+        /// WARNING: this will stack-overflow if the tree contains a cycle
+        fn clear_descendant_caches(
+            nodes: &mut SlotMap<DefaultKey, NodeData>,
+            children: &SlotMap<DefaultKey, ChildrenVec<NodeId>>,
+            node_key: DefaultKey,
+        ) {
+            for child in &children[node_key] {
+                nodes[(*child).into()].cache.clear();
+                clear_descendant_caches(nodes, children, (*child).into());
+            }
+        }
+
+        self.nodes[node.into()].font_metrics = font_metrics;
+        clear_descendant_caches(&mut self.nodes, &self.children, node.into());
+        self.mark_dirty(node)
+    }
+
+    /// Sets the size of the viewport, against which viewport-relative lengths (such as
+    /// [`Dimension::vw`](crate::Dimension::vw)) are resolved and `Position::Fixed` nodes are positioned. Passing `None`
+    /// (the default) uses the available space passed to [`TaffyTree::compute_layout`] in the axes in which it is
+    /// definite. Otherwise viewport-relative lengths resolve to zero and fixed nodes are positioned against the root node.
+    ///
+    /// The viewport size takes effect the next time that layout is computed, when the nodes whose styles use
+    /// viewport-relative lengths are marked as dirty if it has changed.
+    pub fn set_viewport_size(&mut self, viewport_size: Option<Size<f32>>) {
+        self.viewport_size = viewport_size;
+    }
+
+    /// Gets the size of the viewport set by [`TaffyTree::set_viewport_size`]
+    pub fn viewport_size(&self) -> Option<Size<f32>> {
+        self.viewport_size
+    }
+
+    /// Marks the nodes whose styles use a length which satisfies `predicate` as dirty
+    fn mark_dirty_where(&mut self, predicate: impl Fn(CompactLength) -> bool) {
+        let dirty_nodes: Vec<DefaultKey> = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| style_uses_length(&node.style, &predicate))
+            .map(|(key, _)| key)
+            .collect();
+        for key in dirty_nodes {
+            let _ = self.mark_dirty(NodeId::from(key));
+        }
+    }
+
+    /// Gets the computed font metrics of the provided `node`, which are inherited from its closest ancestor with font
+    /// metrics if they have not been set on the node itself
+    pub fn font_metrics(&self, node: NodeId) -> TaffyResult<FontMetrics> {
+        Ok(self.inherited_font_metrics(node))
+    }
+
+    /// Gets the font metrics of the node itself or of its closest ancestor with font metrics
+    fn inherited_font_metrics(&self, node: NodeId) -> FontMetrics {
+        let mut current = Some(node);
+        while let Some(node) = current {
+            if let Some(font_metrics) = self.nodes[node.into()].font_metrics {
+                return font_metrics;
+            }
+            current = self.parents[node.into()];
+        }
+        FontMetrics::DEFAULT
+    }
+
     /// Return this node layout relative to its parent
     pub fn layout(&self, node: NodeId) -> TaffyResult<&Layout> {
         if self.config.use_rounding {
@@ -823,6 +1277,13 @@ impl<NodeContext> TaffyTree<NodeContext> {
         &self.nodes[node.into()].unrounded_layout
     }
 
+    /// Returns the fragments of the provided `node` on each line of its inline formatting context, if it is an inline
+    /// box or a text run. Fragments are positioned relative to the node's unrounded layout.
+    #[cfg(feature = "block_layout")]
+    pub fn inline_fragments(&self, node: NodeId) -> TaffyResult<&[InlineFragment]> {
+        Ok(&self.nodes[node.into()].inline_fragments)
+    }
+
     /// Get the "detailed layout info" for a node.
     ///
     /// Currently this is only implemented for CSS Grid containers where it contains
@@ -859,6 +1320,64 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(self.nodes[node.into()].cache.is_empty())
     }
 
+    /// The children of the provided `node` as seen by the layout algorithms
+    #[inline(always)]
+    fn layout_children(&self, node: NodeId) -> &[NodeId] {
+        match &self.nodes[node.into()].layout_children {
+            Some(layout_children) => layout_children,
+            None => &self.children[node.into()],
+        }
+    }
+
+    /// Updates the layout children of `node` after its children (or whether they are `Display::Contents`) have changed,
+    /// along with those of the ancestors into which its children are hoisted through `Display::Contents` nodes
+    fn update_layout_children(&mut self, mut node: NodeId) {
+        loop {
+            self.resolve_layout_children(node);
+            match self.parents[node.into()] {
+                Some(parent) if self.nodes[node.into()].style.display == Display::Contents => node = parent,
+                _ => break,
+            }
+        }
+    }
+
+    /// Resolves the layout children of `node`, hoisting the children of `Display::Contents` nodes into it.
+    /// `Display::Contents` nodes are not laid out, so this also gives its `Display::Contents` children a zero-sized
+    /// layout.
+    fn resolve_layout_children(&mut self, node: NodeId) {
+        /// Appends the children of `node` to `layout_children`, replacing `Display::Contents` nodes with their children
+        fn push_layout_children<NodeContext>(
+            tree: &TaffyTree<NodeContext>,
+            node: NodeId,
+            layout_children: &mut ChildrenVec<NodeId>,
+        ) {
+            for &child in tree.children[node.into()].iter() {
+                match tree.nodes[child.into()].style.display {
+                    Display::Contents => push_layout_children(tree, child, layout_children),
+                    _ => layout_children.push(child),
+                }
+            }
+        }
+
+        let has_contents_children = self.children[node.into()]
+            .iter()
+            .any(|child| self.nodes[(*child).into()].style.display == Display::Contents);
+        self.nodes[node.into()].layout_children = has_contents_children.then(|| {
+            let mut layout_children = ChildrenVec::new();
+            push_layout_children(self, node, &mut layout_children);
+            layout_children
+        });
+
+        for order in 0..self.children[node.into()].len() {
+            let child = self.children[node.into()][order];
+            if self.nodes[child.into()].style.display == Display::Contents {
+                let layout = Layout::with_order(order as u32);
+                self.nodes[child.into()].unrounded_layout = layout;
+                self.nodes[child.into()].final_layout = layout;
+            }
+        }
+    }
+
     /// Updates the stored layout of the provided `node` and its children
     pub fn compute_layout_with_measure<MeasureFunction>(
         &mut self,
@@ -869,10 +1388,51 @@ impl<NodeContext> TaffyTree<NodeContext> {
     where
         MeasureFunction:
             FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+    {
+        self.compute_layout_with_text_shaping(node_id, available_space, measure_function, |_, _, _, _, _, _| None)
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, using `text_function` to break the text
+    /// runs of inline formatting contexts into lines.
+    ///
+    /// `text_function` is called with the arguments of [`LayoutBlockContainer::break_text_run`], followed by the node,
+    /// its context and its style. It should return `None` for nodes which are not text runs. The fragments of each text
+    /// run and inline box can be retrieved after layout using [`TaffyTree::inline_fragments`].
+    pub fn compute_layout_with_text_shaping<MeasureFunction, TextFunction>(
+        &mut self,
+        node_id: NodeId,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+        text_function: TextFunction,
+    ) -> Result<(), TaffyError>
+    where
+        MeasureFunction:
+            FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>, &Style) -> Size<f32>,
+        TextFunction: FnMut(usize, Option<f32>, bool, NodeId, Option<&mut NodeContext>, &Style) -> Option<TextRunBreak>,
     {
         let use_rounding = self.config.use_rounding;
-        let mut taffy_view = TaffyView { taffy: self, measure_function };
-        compute_root_layout(&mut taffy_view, node_id, available_space);
+
+        // Viewport-relative lengths are resolved against the viewport size (or the available space if it has not been
+        // set, or zero if that is not definite), and `rem` lengths against the font size of the root node. The nodes
+        // which use them are marked as dirty when either changes, as their sizes may have changed.
+        let viewport_size =
+            self.viewport_size.unwrap_or(available_space.map(|space| space.into_option().unwrap_or(0.0)));
+        if viewport_size != self.resolved_viewport_size {
+            self.resolved_viewport_size = viewport_size;
+            self.mark_dirty_where(CompactLength::is_viewport_relative);
+        }
+        let root_font_size = self.inherited_font_metrics(node_id).font_size;
+        if root_font_size != self.root_font_size {
+            self.root_font_size = root_font_size;
+            self.mark_dirty_where(|length| length.tag() == CompactLength::REM_TAG);
+        }
+
+        // Fixed nodes are positioned against the viewport (or the available space if it has not been set, or the size
+        // of the root node if that is not definite)
+        let viewport_size = self.viewport_size;
+        let mut taffy_view = TaffyView { taffy: self, measure_function, text_function };
+        compute_root_layout_with_positions(&mut taffy_view, node_id, available_space, viewport_size);
+
         if use_rounding {
             round_layout(&mut taffy_view, node_id);
         }
@@ -880,6 +1440,10 @@ impl<NodeContext> TaffyTree<NodeContext> {
     }
 
     /// Updates the stored layout of the provided `node` and its children
+    ///
+    /// Unless a viewport size has been set with [`TaffyTree::set_viewport_size`], the available space is also used as
+    /// the size of the viewport: viewport-relative lengths (such as [`Dimension::vw`](crate::Dimension::vw)) are
+    /// resolved against it, so resizing the viewport only requires the layout to be recomputed with the new size.
     pub fn compute_layout(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> Result<(), TaffyError> {
         self.compute_layout_with_measure(node, available_space, |_, _, _, _, _| Size::ZERO)
     }
@@ -893,7 +1457,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
     /// Returns an instance of LayoutTree representing the TaffyTree
     #[cfg(test)]
     pub(crate) fn as_layout_tree(&mut self) -> impl LayoutPartialTree + CacheTree + '_ {
-        TaffyView { taffy: self, measure_function: |_, _, _, _, _| Size::ZERO }
+        TaffyView { taffy: self, measure_function: |_, _, _, _, _| Size::ZERO, text_function: |_, _, _, _, _, _| None }
     }
 }
 
@@ -27,7 +27,7 @@
 //! | Trait                 | Requires                | Enables                                                                                                                                                                                                                                                                                                                                                                                                                   |
 //! | ---                   | ---                     | ---                                                                                                                                                                                                                                                                                                                                                                                                                       |
 //! | [`LayoutPartialTree`] | [`TraversePartialTree`] | [`compute_flexbox_layout`](crate::compute_flexbox_layout)<br />[`compute_grid_layout`](crate::compute_grid_layout)<br />[`compute_block_layout`](crate::compute_block_layout)<br />[`compute_root_layout`](crate::compute_root_layout)<br />[`compute_leaf_layout`](crate::compute_leaf_layout)<br />[`compute_hidden_layout`](crate::compute_hidden_layout)<br />[`compute_cached_layout`](crate::compute_cached_layout) |
-//! | [`RoundTree`]         | [`TraverseTree`]        | [`round_layout`](crate::round_layout)                                                                                                                                                                                                                                                                                                                                                                                     |
+//! | [`RoundTree`]         | [`TraverseTree`]        | [`round_layout`](crate::round_layout)<br />[`compute_root_layout_with_positions`](crate::compute_root_layout_with_positions) (with [`LayoutPartialTree`])                                                                                                                                                                                                                                                                 |
 //! | [`PrintTree`]         | [`TraverseTree`]        | [`print_tree`](crate::print_tree)                                                                                                                                                                                                                                                                                                                                                                                         |
 //!
 //! ## All of the traits on one page
@@ -126,24 +126,39 @@
 //! }
 //! ```
 //!
-use super::{Layout, LayoutInput, LayoutOutput, NodeId, RequestedAxis, RunMode, SizingMode};
+use super::{ContainingBlock, Layout, LayoutInput, LayoutOutput, NodeId, RequestedAxis, RunMode, SizingMode};
+#[cfg(feature = "block_layout")]
+use super::{InlineFragment, TextRunBreak};
 #[cfg(feature = "detailed_layout_info")]
 use crate::debug::debug_log;
-use crate::geometry::{AbsoluteAxis, Line, Size};
-use crate::style::{AvailableSpace, CoreStyle};
+use crate::geometry::{AbsoluteAxis, Line, Point, Size};
+use crate::style::{AvailableSpace, CompactLength, CoreStyle, FontMetrics, RelativeLengthBasis};
+#[cfg(feature = "grid")]
+use crate::style::{CheapCloneStr, GridContainerStyle, GridItemStyle};
 #[cfg(feature = "flexbox")]
 use crate::style::{FlexboxContainerStyle, FlexboxItemStyle};
-#[cfg(feature = "grid")]
-use crate::style::{GridContainerStyle, GridItemStyle};
 #[cfg(feature = "block_layout")]
 use crate::{BlockContainerStyle, BlockItemStyle};
+#[cfg(feature = "table_layout")]
+use crate::{TableChildStyle, TableContainerStyle};
 
 #[cfg(all(feature = "grid", feature = "detailed_layout_info"))]
 use crate::compute::grid::DetailedGridInfo;
+#[cfg(feature = "grid")]
+use crate::compute::grid::SubgridTracks;
+#[cfg(all(feature = "block_layout", feature = "detailed_layout_info"))]
+use crate::compute::multicol::DetailedMulticolInfo;
+#[cfg(feature = "grid")]
+use crate::geometry::AbstractAxis;
 
 /// Taffy's abstraction for downward tree traversal.
 ///
 /// However, this trait does *not* require access to any node's other than a single container node's immediate children unless you also intend to implement `TraverseTree`.
+///
+/// Taffy's layout algorithms lay out every child that they are given as a box. If a child has a style with a
+/// [`BoxGenerationMode::Contents`](crate::BoxGenerationMode::Contents) box generation mode (i.e. `Display::Contents`), then
+/// it should not be returned as a child when layout is being computed: its own children should be returned in its place
+/// (recursively), and the node itself given a zero-sized layout.
 pub trait TraversePartialTree {
     /// Type representing an iterator of the children of a node
     type ChildIter<'a>: Iterator<Item = NodeId>
@@ -180,7 +195,11 @@ pub trait LayoutPartialTree: TraversePartialTree {
     /// Get core style
     fn get_core_container_style(&self, node_id: NodeId) -> Self::CoreContainerStyle<'_>;
 
-    /// Resolve calc value
+    /// Resolve the `calc()` value with the handle `val`, resolving percentages against `basis`
+    ///
+    /// This is only called with the handles of `calc()` values: viewport-relative and font-relative lengths are resolved
+    /// by the layout algorithms against [`get_viewport_size`](Self::get_viewport_size),
+    /// [`get_font_metrics`](Self::get_font_metrics) and [`get_root_font_size`](Self::get_root_font_size).
     fn resolve_calc_value(&self, val: u64, basis: f32) -> f32;
 
     /// Set the node's unrounded layout
@@ -188,6 +207,55 @@ pub trait LayoutPartialTree: TraversePartialTree {
 
     /// Compute the specified node's size or full layout given the specified constraints
     fn compute_child_layout(&mut self, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput;
+
+    /// Get the offset by which the node's contents have been scrolled
+    ///
+    /// Implementing this method is optional. It is used by [`compute_fixed_and_sticky_positions`](crate::compute_fixed_and_sticky_positions)
+    /// (which [`compute_root_layout_with_positions`](crate::compute_root_layout_with_positions) runs after layout),
+    /// which clamps it to the range through which the node can be scrolled, records the clamped offset and the scrolled
+    /// positions of the node's children in the [`Layout`], and positions `Position::Sticky` and `Position::Fixed` nodes
+    /// within scrolled ancestors. It is only taken into account for scroll containers and the root node (whose scroll
+    /// offset scrolls the viewport).
+    fn get_scroll_offset(&self, _node_id: NodeId) -> Point<f32> {
+        Point::ZERO
+    }
+
+    /// Get the size of the viewport
+    ///
+    /// Implementing this method is optional. Viewport-relative lengths (such as [`Dimension::vw`](crate::Dimension::vw))
+    /// are resolved against it by the layout algorithms. Nodes whose styles use viewport-relative lengths must have their
+    /// cached layouts cleared when the size of the viewport changes.
+    fn get_viewport_size(&self) -> Size<f32> {
+        Size::ZERO
+    }
+
+    /// Get the computed font metrics of the node
+    ///
+    /// Implementing this method is optional. Font-relative lengths (such as [`Dimension::em`](crate::Dimension::em))
+    /// in the node's styles are resolved against it by the layout algorithms.
+    fn get_font_metrics(&self, _node_id: NodeId) -> FontMetrics {
+        FontMetrics::DEFAULT
+    }
+
+    /// Get the computed font size of the root node
+    ///
+    /// Implementing this method is optional. `rem` lengths (such as [`Dimension::rem`](crate::Dimension::rem)) are
+    /// resolved against it by the layout algorithms.
+    fn get_root_font_size(&self) -> f32 {
+        FontMetrics::DEFAULT.font_size
+    }
+
+    /// Get the sizes against which viewport-relative and font-relative lengths in the node's styles are resolved
+    ///
+    /// This combines [`get_viewport_size`](Self::get_viewport_size), [`get_font_metrics`](Self::get_font_metrics) and
+    /// [`get_root_font_size`](Self::get_root_font_size), and is passed to [`compute_leaf_layout`](crate::compute_leaf_layout).
+    fn get_relative_length_basis(&self, node_id: NodeId) -> RelativeLengthBasis {
+        RelativeLengthBasis {
+            viewport_size: self.get_viewport_size(),
+            font_metrics: self.get_font_metrics(node_id),
+            root_font_size: self.get_root_font_size(),
+        }
+    }
 }
 
 /// Trait used by the `compute_cached_layout` method which allows cached layout results to be stored and retrieved.
@@ -260,13 +328,16 @@ pub trait LayoutFlexboxContainer: LayoutPartialTree {
 #[cfg(feature = "grid")]
 /// Extends [`LayoutPartialTree`] with getters for the styles required for CSS Grid layout
 pub trait LayoutGridContainer: LayoutPartialTree {
+    /// The type of the names of grid lines and grid areas in the container's and items' styles
+    type CustomIdent: CheapCloneStr;
+
     /// The style type representing the CSS Grid container's styles
-    type GridContainerStyle<'a>: GridContainerStyle
+    type GridContainerStyle<'a>: GridContainerStyle<CustomIdent = Self::CustomIdent>
     where
         Self: 'a;
 
     /// The style type representing each CSS Grid item's styles
-    type GridItemStyle<'a>: GridItemStyle
+    type GridItemStyle<'a>: GridItemStyle<CustomIdent = Self::CustomIdent>
     where
         Self: 'a;
 
@@ -284,6 +355,21 @@ pub trait LayoutGridContainer: LayoutPartialTree {
     fn set_detailed_grid_info(&mut self, _node_id: NodeId, _detailed_grid_info: DetailedGridInfo) {
         debug_log!("LayoutGridContainer::set_detailed_grid_info called");
     }
+
+    /// Get the tracks that a subgrid has adopted from its parent grid in the given axis
+    ///
+    /// Implementing this method (along with [`LayoutGridContainer::set_subgrid_tracks`]) is optional. If it is not
+    /// implemented then subgrids are laid out as if their template in the subgridded axis were empty.
+    fn get_subgrid_tracks(&self, _node_id: NodeId, _axis: AbstractAxis) -> Option<&SubgridTracks> {
+        None
+    }
+
+    /// Set the tracks that a subgrid adopts from its parent grid in the given axis
+    ///
+    /// This is called by the parent grid before the subgrid is measured or laid out. Implementations should store
+    /// the tracks so that they can be returned from [`LayoutGridContainer::get_subgrid_tracks`], and should clear
+    /// the subgrid's layout cache if they differ from the tracks that were previously stored.
+    fn set_subgrid_tracks(&mut self, _node_id: NodeId, _axis: AbstractAxis, _tracks: SubgridTracks) {}
 }
 
 #[cfg(feature = "block_layout")]
@@ -303,6 +389,72 @@ pub trait LayoutBlockContainer: LayoutPartialTree {
 
     /// Get the child's styles
     fn get_block_child_style(&self, child_node_id: NodeId) -> Self::BlockItemStyle<'_>;
+
+    /// Breaks off the next fragment of the text run of `node_id` (a childless node whose
+    /// [`display_outside`](crate::BlockItemStyle::display_outside) is `Inline`), starting at the offset `start`.
+    ///
+    /// `available_width` is the space remaining on the current line, or `None` if lines are not being broken (i.e.
+    /// when computing the max-content size of the content). The returned fragment should be the longest fragment that
+    /// ends at a line break opportunity and fits within the available width. If no such fragment exists, then an empty
+    /// fragment (`end == start`) should be returned, unless `line_is_empty` is true, in which case the fragment must
+    /// contain at least the first unbreakable segment of the remaining text. A fragment that ends at a forced line break
+    /// should set `is_end_of_run` to `false`, so that the line is broken after it.
+    ///
+    /// Returns `None` if the node is not a text run, in which case it is laid out as an atomic inline-level box. The
+    /// default implementation treats every node as an atomic inline-level box.
+    #[inline(always)]
+    fn break_text_run(
+        &mut self,
+        _node_id: NodeId,
+        _start: usize,
+        _available_width: Option<f32>,
+        _line_is_empty: bool,
+    ) -> Option<TextRunBreak> {
+        None
+    }
+
+    /// Set the fragments of an inline box or text run which spans one or more lines. This is called after the node's
+    /// layout has been set. The default implementation discards the fragments.
+    #[inline(always)]
+    fn set_inline_fragments(&mut self, _node_id: NodeId, _fragments: &[InlineFragment]) {}
+
+    /// Set the node's detailed multi-column information
+    ///
+    /// Implementing this method is optional. Doing so allows you to access details about the columns of a multi-column
+    /// container, such as the used width and height of the columns and the fragments of each of its children.
+    #[cfg(feature = "detailed_layout_info")]
+    fn set_detailed_multicol_info(&mut self, _node_id: NodeId, _detailed_multicol_info: DetailedMulticolInfo) {
+        debug_log!("LayoutBlockContainer::set_detailed_multicol_info called");
+    }
+}
+
+#[cfg(feature = "table_layout")]
+/// Extends [`LayoutPartialTree`] with getters for the styles required for CSS Table layout
+pub trait LayoutTableContainer: LayoutPartialTree {
+    /// The style type representing the CSS Table container's styles
+    type TableContainerStyle<'a>: TableContainerStyle
+    where
+        Self: 'a;
+    /// The style type representing the styles of each part of the table (its captions, row groups, rows and cells)
+    type TableChildStyle<'a>: TableChildStyle
+    where
+        Self: 'a;
+
+    /// Get the container's styles
+    fn get_table_container_style(&self, node_id: NodeId) -> Self::TableContainerStyle<'_>;
+
+    /// Get the styles of a child or descendant of the table
+    fn get_table_child_style(&self, child_node_id: NodeId) -> Self::TableChildStyle<'_>;
+
+    /// Move the content of a table cell by `offset`, relative to the cell. This is called after the cell has been laid
+    /// out to align its content within its row according to its [`vertical_align`](crate::BlockItemStyle::vertical_align)
+    /// style.
+    ///
+    /// Implementations should offset the layouts of the cell's children, and should clear the cell's layout cache so
+    /// that the offset is not applied twice if a cached layout is reused. The default implementation does nothing, in
+    /// which case the content of every cell is aligned to the top of the cell.
+    #[inline(always)]
+    fn offset_cell_content(&mut self, _cell_node_id: NodeId, _offset: Point<f32>) {}
 }
 
 // --- PRIVATE TRAITS
@@ -333,13 +485,15 @@ pub(crate) trait LayoutPartialTreeExt: LayoutPartialTree {
                 axis: axis.into(),
                 run_mode: RunMode::ComputeSize,
                 vertical_margins_are_collapsible,
+                containing_block: ContainingBlock::Deferred,
             },
         )
         .size
         .get_abs(axis)
     }
 
-    /// Perform a full layout on the node given the specified constraints
+    /// Perform a full layout on the node given the specified constraints. If the node is `Position::Static`, its
+    /// absolutely positioned children are not laid out (see [`ContainingBlock::Deferred`]).
     #[inline(always)]
     fn perform_child_layout(
         &mut self,
@@ -349,6 +503,31 @@ pub(crate) trait LayoutPartialTreeExt: LayoutPartialTree {
         available_space: Size<AvailableSpace>,
         sizing_mode: SizingMode,
         vertical_margins_are_collapsible: Line<bool>,
+    ) -> LayoutOutput {
+        self.perform_child_layout_with_containing_block(
+            node_id,
+            known_dimensions,
+            parent_size,
+            available_space,
+            sizing_mode,
+            vertical_margins_are_collapsible,
+            ContainingBlock::Deferred,
+        )
+    }
+
+    /// Perform a full layout on the node given the specified constraints and the containing block of its absolutely
+    /// positioned children (which is only used if the node is `Position::Static`)
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    fn perform_child_layout_with_containing_block(
+        &mut self,
+        node_id: NodeId,
+        known_dimensions: Size<Option<f32>>,
+        parent_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        sizing_mode: SizingMode,
+        vertical_margins_are_collapsible: Line<bool>,
+        containing_block: ContainingBlock,
     ) -> LayoutOutput {
         self.compute_child_layout(
             node_id,
@@ -360,13 +539,19 @@ pub(crate) trait LayoutPartialTreeExt: LayoutPartialTree {
                 axis: RequestedAxis::Both,
                 run_mode: RunMode::PerformLayout,
                 vertical_margins_are_collapsible,
+                containing_block,
             },
         )
     }
 
-    /// Alias to `resolve_calc_value` with a shorter function name
+    /// Alias to `resolve_calc_value` with a shorter function name, which also resolves viewport-relative and
+    /// font-relative values in the styles of the node against [`LayoutPartialTree::get_relative_length_basis`]
     #[inline(always)]
-    fn calc(&self, val: u64, basis: f32) -> f32 {
+    fn calc(&self, node_id: NodeId, val: u64, basis: f32) -> f32 {
+        let length = CompactLength::from_calc_value(val);
+        if length.is_relative_length() {
+            return self.get_relative_length_basis(node_id).resolve(length).unwrap();
+        }
         self.resolve_calc_value(val, basis)
     }
 }
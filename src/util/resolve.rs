@@ -32,6 +32,8 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentage {
         match self.0.tag() {
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
+            // Viewport-relative and font-relative values are resolved by the calc resolver, and do not depend on the context
+            _ if self.0.is_relative_length() => Some(calc(self.0.calc_value(), context.unwrap_or(0.0))),
             _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
             _ => unreachable!(),
         }
@@ -40,12 +42,16 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentage {
 
 impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentageAuto {
     /// Converts the given [`LengthPercentageAuto`] into an absolute length
-    /// Can return `None`
+    /// Can return `None`. Anchor values resolve to `None`: they are resolved against the node's anchor by
+    /// [`compute_fixed_and_sticky_positions`](crate::compute_fixed_and_sticky_positions)
     fn maybe_resolve(self, context: Option<f32>, calc: impl Fn(u64, f32) -> f32) -> Option<f32> {
         match self.0.tag() {
             CompactLength::AUTO_TAG => None,
+            _ if self.0.is_anchor() => None,
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
+            // Viewport-relative and font-relative values are resolved by the calc resolver, and do not depend on the context
+            _ if self.0.is_relative_length() => Some(calc(self.0.calc_value(), context.unwrap_or(0.0))),
             _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
             _ => unreachable!(),
         }
@@ -55,12 +61,16 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentageAuto {
 impl MaybeResolve<Option<f32>, Option<f32>> for Dimension {
     /// Converts the given [`Dimension`] into an absolute length
     ///
-    /// Can return `None`
+    /// Can return `None`. Intrinsic sizing keywords resolve to `None`: they depend on the content of the node, and are
+    /// resolved by the layout algorithms. Anchor values also resolve to `None`.
     fn maybe_resolve(self, context: Option<f32>, calc: impl Fn(u64, f32) -> f32) -> Option<f32> {
         match self.0.tag() {
             CompactLength::AUTO_TAG => None,
+            _ if self.0.is_sizing_keyword() || self.0.is_anchor() => None,
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
+            // Viewport-relative and font-relative values are resolved by the calc resolver, and do not depend on the context
+            _ if self.0.is_relative_length() => Some(calc(self.0.calc_value(), context.unwrap_or(0.0))),
             _ if self.0.is_calc() => context.map(|dim| calc(self.0.calc_value(), dim)),
             _ => unreachable!(),
         }
@@ -17,11 +17,20 @@ pub(crate) use self::core::*;
 mod std {
     /// An allocation-backend agnostic vector type
     pub(crate) type Vec<A> = std::vec::Vec<A>;
+    /// An allocation-backend agnostic box type
+    pub(crate) type Box<A> = std::boxed::Box<A>;
     /// A vector of child nodes
     pub(crate) type ChildrenVec<A> = std::vec::Vec<A>;
     #[cfg(feature = "grid")]
     /// A vector of grid tracks
     pub(crate) type GridTrackVec<A> = std::vec::Vec<A>;
+    /// An allocation-backend agnostic string type
+    pub(crate) type String = std::string::String;
+    #[cfg(feature = "grid")]
+    /// An allocation-backend agnostic atomically reference-counted pointer type
+    pub(crate) type Arc<A> = std::sync::Arc<A>;
+    /// An allocation-backend agnostic ordered map type
+    pub(crate) type BTreeMap<K, V> = std::collections::BTreeMap<K, V>;
 
     /// Creates a new vector with the capacity for the specified number of items before it must be resized
     #[must_use]
@@ -78,11 +87,20 @@ mod alloc {
 
     /// An allocation-backend agnostic vector type
     pub(crate) type Vec<A> = alloc::vec::Vec<A>;
+    /// An allocation-backend agnostic box type
+    pub(crate) type Box<A> = alloc::boxed::Box<A>;
     /// A vector of child nodes
     pub(crate) type ChildrenVec<A> = alloc::vec::Vec<A>;
     #[cfg(feature = "grid")]
     /// A vector of grid tracks
     pub(crate) type GridTrackVec<A> = alloc::vec::Vec<A>;
+    /// An allocation-backend agnostic string type
+    pub(crate) type String = alloc::string::String;
+    #[cfg(feature = "grid")]
+    /// An allocation-backend agnostic atomically reference-counted pointer type
+    pub(crate) type Arc<A> = alloc::sync::Arc<A>;
+    /// An allocation-backend agnostic ordered map type
+    pub(crate) type BTreeMap<K, V> = alloc::collections::BTreeMap<K, V>;
 
     /// Creates a new vector with the capacity for the specified number of items before it must be resized
     #[must_use]
@@ -0,0 +1,161 @@
+use taffy::prelude::*;
+use taffy::{AnchorSide, AnchorSize, Point, PositionArea, PositionTryFallback};
+
+/// An absolutely positioned node at (`left`, `top`) with the given size
+fn absolute(left: f32, top: f32, width: f32, height: f32) -> Style {
+    Style {
+        position: Position::Absolute,
+        inset: Rect { left: length(left), right: auto(), top: length(top), bottom: auto() },
+        size: Size { width: length(width), height: length(height) },
+        ..Default::default()
+    }
+}
+
+/// A node anchored to the node named "anchor"
+fn anchored(inset: Rect<LengthPercentageAuto>, size: Size<Dimension>) -> Style {
+    Style { position: Position::Absolute, position_anchor: Some("anchor".into()), inset, size, ..Default::default() }
+}
+
+/// A 200x200 root node whose children are an anchor at (50, 170) of size 40x20 and the given node
+fn anchored_tree(style: Style) -> (TaffyTree<()>, NodeId) {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let anchor =
+        taffy.new_leaf(Style { anchor_name: Some("anchor".into()), ..absolute(50.0, 170.0, 40.0, 20.0) }).unwrap();
+    let node = taffy.new_leaf(style).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style { size: Size { width: length(200.0), height: length(200.0) }, ..Default::default() },
+            &[anchor, node],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    (taffy, node)
+}
+
+#[test]
+fn anchor_insets_are_resolved_against_an_anchor_in_another_subtree() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let anchor =
+        taffy.new_leaf(Style { anchor_name: Some("anchor".into()), ..absolute(50.0, 30.0, 40.0, 20.0) }).unwrap();
+    let node = taffy
+        .new_leaf(anchored(
+            Rect {
+                left: LengthPercentageAuto::anchor(AnchorSide::Right),
+                right: auto(),
+                top: LengthPercentageAuto::anchor(AnchorSide::Bottom),
+                bottom: auto(),
+            },
+            Size { width: length(60.0), height: length(10.0) },
+        ))
+        .unwrap();
+    let container = taffy.new_with_children(absolute(100.0, 50.0, 100.0, 100.0), &[node]).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style { size: Size { width: length(300.0), height: length(300.0) }, ..Default::default() },
+            &[anchor, container],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The node is placed at the bottom-right corner of the anchor, (90, 50), which is reported relative to its parent
+    let layout = taffy.layout(node).unwrap();
+    assert_eq!(layout.location, Point { x: -10.0, y: 0.0 });
+    assert_eq!(layout.size, Size { width: 60.0, height: 10.0 });
+}
+
+#[test]
+fn anchor_size_is_resolved_against_the_size_of_the_anchor() {
+    let (taffy, node) = anchored_tree(anchored(
+        Rect { left: LengthPercentageAuto::anchor(AnchorSide::Left), right: auto(), top: length(0.0), bottom: auto() },
+        Size { width: Dimension::anchor_size(AnchorSize::Width), height: Dimension::anchor_size(AnchorSize::Width) },
+    ));
+
+    let layout = taffy.layout(node).unwrap();
+    assert_eq!(layout.location, Point { x: 50.0, y: 0.0 });
+    assert_eq!(layout.size, Size { width: 40.0, height: 40.0 });
+}
+
+#[test]
+fn position_area_places_the_node_next_to_its_anchor() {
+    let size = Size { width: length(20.0), height: length(30.0) };
+    let cases = [
+        (Point { x: PositionArea::Center, y: PositionArea::Start }, Point { x: 60.0, y: 140.0 }),
+        (Point { x: PositionArea::End, y: PositionArea::Center }, Point { x: 90.0, y: 165.0 }),
+        (Point { x: PositionArea::SpanStart, y: PositionArea::SpanAll }, Point { x: 70.0, y: 85.0 }),
+    ];
+    for (position_area, location) in cases {
+        let (taffy, node) = anchored_tree(Style { position_area: Some(position_area), ..anchored(Rect::auto(), size) });
+
+        assert_eq!(taffy.layout(node).unwrap().location, location, "{position_area:?}");
+    }
+}
+
+#[test]
+fn position_try_fallbacks_are_used_if_the_node_overflows_its_containing_block() {
+    let size = Size { width: length(20.0), height: length(30.0) };
+    let below = Point { x: PositionArea::SpanEnd, y: PositionArea::End };
+    let fallbacks = [
+        PositionTryFallback::FlipBlock,
+        PositionTryFallback::PositionArea(Point { x: PositionArea::SpanEnd, y: PositionArea::Start }),
+    ];
+    for fallback in fallbacks {
+        let (taffy, node) = anchored_tree(Style {
+            position_area: Some(below),
+            position_try_fallbacks: vec![fallback],
+            ..anchored(Rect::auto(), size)
+        });
+
+        // The node doesn't fit below the anchor, so it is placed above it
+        assert_eq!(taffy.layout(node).unwrap().location, Point { x: 50.0, y: 140.0 }, "{fallback:?}");
+    }
+}
+
+#[test]
+fn the_base_position_is_used_if_no_fallback_fits() {
+    let size = Size { width: length(20.0), height: length(180.0) };
+    let below = Rect {
+        left: length(0.0),
+        right: auto(),
+        top: LengthPercentageAuto::anchor(AnchorSide::Bottom),
+        bottom: auto(),
+    };
+    let (taffy, node) =
+        anchored_tree(Style { position_try_fallbacks: vec![PositionTryFallback::FlipBlock], ..anchored(below, size) });
+
+    assert_eq!(taffy.layout(node).unwrap().location.y, 190.0);
+}
+
+#[test]
+fn anchor_insets_are_treated_as_auto_without_an_anchor() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let node = taffy
+        .new_leaf(Style {
+            position_anchor: Some("missing".into()),
+            ..anchored(
+                Rect {
+                    left: LengthPercentageAuto::anchor(AnchorSide::Left),
+                    right: auto(),
+                    top: length(10.0),
+                    bottom: auto(),
+                },
+                Size { width: length(10.0), height: length(10.0) },
+            )
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                padding: Rect { left: length(5.0), right: zero(), top: zero(), bottom: zero() },
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[node],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The node keeps its static position horizontally
+    assert_eq!(taffy.layout(node).unwrap().location, Point { x: 5.0, y: 10.0 });
+}
@@ -0,0 +1,160 @@
+use taffy::prelude::*;
+
+#[test]
+fn calc_expressions_are_evaluated_against_the_basis() {
+    let sum = CalcExpr::Length(20.0) + percent(0.5) - length(5.0);
+    assert_eq!(sum.resolve(100.0), 65.0);
+    assert_eq!((sum * 2.0).resolve(100.0), 130.0);
+    assert_eq!(CalcExpr::min([percent(0.5), length(40.0)]).resolve(100.0), 40.0);
+    assert_eq!(CalcExpr::max([percent(0.5), length(40.0)]).resolve(100.0), 50.0);
+
+    let clamp = CalcExpr::clamp(length(10.0), percent(0.5), length(40.0));
+    assert_eq!(clamp.resolve(10.0), 10.0);
+    assert_eq!(clamp.resolve(60.0), 30.0);
+    assert_eq!(clamp.resolve(100.0), 40.0);
+    // The minimum wins if it is larger than the maximum
+    assert_eq!(CalcExpr::clamp(length(50.0), percent(0.5), length(40.0)).resolve(100.0), 50.0);
+}
+
+#[test]
+fn calc_sizes_of_leaf_nodes_are_resolved() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let width = taffy.new_calc(CalcExpr::Percent(0.5) - length(20.0));
+    let height = taffy.new_calc(CalcExpr::clamp(length(10.0), percent(0.1), length(15.0)));
+    let child = taffy
+        .new_leaf(Style {
+            size: Size { width: Dimension::calc(width), height: Dimension::calc(height) },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(200.0), height: length(300.0) },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().size, Size { width: 80.0, height: 15.0 });
+}
+
+#[test]
+fn calc_padding_and_margins_of_containers_are_resolved() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let padding = LengthPercentage::calc(taffy.new_calc(CalcExpr::Length(10.0) + percent(0.1)));
+    let margin = LengthPercentageAuto::calc(taffy.new_calc(CalcExpr::max([length(5.0), percent(0.05)])));
+    let child =
+        taffy.new_leaf(Style { size: Size { width: auto(), height: length(10.0) }, ..Default::default() }).unwrap();
+    let container = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                padding: Rect { left: padding, right: padding, top: zero(), bottom: zero() },
+                margin: Rect { left: margin, right: zero(), top: zero(), bottom: zero() },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(200.0), height: auto() },
+                ..Default::default()
+            },
+            &[container],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(container).unwrap().location.x, 10.0);
+    assert_eq!(taffy.layout(container).unwrap().size.width, 190.0);
+    assert_eq!(taffy.layout(child).unwrap().location.x, 30.0);
+    assert_eq!(taffy.layout(child).unwrap().size.width, 130.0);
+}
+
+#[test]
+fn calc_expressions_can_be_shared_between_styles() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let width = Dimension::calc(taffy.new_calc(CalcExpr::Percent(0.25) + length(10.0)));
+    let style = Style { size: Size { width, height: length(10.0) }, ..Default::default() };
+    let children = [taffy.new_leaf(style.clone()).unwrap(), taffy.new_leaf(style).unwrap()];
+    let root = taffy
+        .new_with_children(
+            Style { size: Size { width: length(200.0), height: auto() }, ..Default::default() },
+            &children,
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(children[0]).unwrap().size.width, 60.0);
+    assert_eq!(taffy.layout(children[1]).unwrap().location.x, 60.0);
+}
+
+#[test]
+fn calc_expressions_used_by_a_remaining_style_are_kept() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let width = Dimension::calc(taffy.new_calc(CalcExpr::Percent(0.5)));
+    let style = Style { size: Size { width, height: length(10.0) }, ..Default::default() };
+    let first = taffy.new_leaf(style.clone()).unwrap();
+    let second = taffy.new_leaf(style.clone()).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style { size: Size { width: length(200.0), height: auto() }, ..Default::default() },
+            &[first, second],
+        )
+        .unwrap();
+
+    // Replacing a style with itself must not release its expressions
+    taffy.set_style(second, style).unwrap();
+    taffy.set_style(first, Style::default()).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(second).unwrap().size.width, 100.0);
+}
+
+#[test]
+fn calc_handles_are_not_reused_after_clear() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let before = taffy.new_calc(CalcExpr::Length(10.0));
+    taffy.clear();
+    let after = taffy.new_calc(CalcExpr::Length(10.0));
+
+    assert_ne!(before, after);
+}
+
+#[test]
+fn calc_expressions_are_kept_when_a_style_switches_away_from_them_and_back() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let width = Dimension::calc(taffy.new_calc(CalcExpr::Percent(0.5)));
+    let style = Style { size: Size { width, height: length(10.0) }, ..Default::default() };
+    let node = taffy.new_leaf(style.clone()).unwrap();
+    let root = taffy
+        .new_with_children(Style { size: Size { width: length(200.0), height: auto() }, ..Default::default() }, &[node])
+        .unwrap();
+
+    taffy.set_style(node, Style::default()).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    taffy.set_style(node, style).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(node).unwrap().size.width, 100.0);
+}
+
+#[test]
+fn calc_expressions_can_be_removed() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let handle = taffy.new_calc(CalcExpr::Length(10.0));
+
+    assert_eq!(taffy.remove_calc(handle), Some(CalcExpr::Length(10.0)));
+    assert_eq!(taffy.remove_calc(handle), None);
+}
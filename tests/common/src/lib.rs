@@ -1,4 +1,4 @@
-use taffy::{AvailableSpace, NodeId, Size, Style, TaffyTree};
+use taffy::{AvailableSpace, Dimension, Display, NodeId, Size, Style, TaffyTree, TextRunBreak};
 
 /// Creates a `TaffyTree` that uses `TestNodeContext`. The purpose of this function is
 /// to allow `TaffyTree` to be monomophised once in this crate rather than separately for
@@ -7,6 +7,53 @@ pub fn new_test_tree() -> TaffyTree<TestNodeContext> {
     TaffyTree::new()
 }
 
+/// Creates a `Style` with a fixed width and height, which does not shrink when it is a flex item
+pub fn fixed_size(width: f32, height: f32) -> Style {
+    Style {
+        size: Size { width: Dimension::length(width), height: Dimension::length(height) },
+        flex_shrink: 0.0,
+        ..Default::default()
+    }
+}
+
+/// A text shaper in which every character is 10 wide, every line of text is 10 tall with its baseline at 8, and lines
+/// may be broken at spaces
+pub fn break_text(
+    start: usize,
+    available_width: Option<f32>,
+    line_is_empty: bool,
+    _node: NodeId,
+    text: Option<&mut &'static str>,
+    _style: &Style,
+) -> Option<TextRunBreak> {
+    let text = *text?;
+    let width_of = |end: usize| text[start..end].trim_end().len() as f32 * 10.0;
+    let mut end = start;
+    for word in text[start..].split_inclusive(' ') {
+        let fits = match available_width {
+            Some(available_width) => width_of(end + word.len()) <= available_width,
+            None => true,
+        };
+        // An empty line always takes at least one word
+        let must_fit = line_is_empty && end == start;
+        if !fits && !must_fit {
+            break;
+        }
+        end += word.len();
+    }
+    Some(TextRunBreak { end, is_end_of_run: end == text.len(), width: width_of(end), ascent: 8.0, descent: 2.0 })
+}
+
+/// Creates an inline text run whose text is held as its context
+pub fn text(taffy: &mut TaffyTree<&'static str>, text: &'static str) -> NodeId {
+    taffy.new_leaf_with_context(Style { display: Display::Inline, ..Default::default() }, text).unwrap()
+}
+
+/// Computes the layout of a tree whose text runs hold their text as their context, shaping it with [`break_text`]
+pub fn compute_layout(taffy: &mut TaffyTree<&'static str>, root: NodeId, available_space: Size<AvailableSpace>) {
+    taffy.compute_layout_with_text_shaping(root, available_space, |_, _, _, _, _| Size::ZERO, break_text).unwrap();
+}
+
 /// A sharednode context for tests which means that tests compiled with separate crates
 /// and using different styles of measure function. This saves on compile time when running tests.
 #[derive(Debug, Copy, Clone)]
@@ -125,8 +172,7 @@ impl AhemTextMeasureData {
         known_dimensions: taffy::Size<Option<f32>>,
         available_space: taffy::Size<taffy::AvailableSpace>,
     ) -> taffy::Size<f32> {
-        use taffy::prelude::*;
-        use taffy::AbsoluteAxis;
+        use taffy::{AbsoluteAxis, AvailableSpace, Size};
 
         const ZWS: char = '\u{200B}';
         const H_WIDTH: f32 = 10.0;
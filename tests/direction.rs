@@ -0,0 +1,396 @@
+use taffy::prelude::*;
+use taffy::{Overflow, Point, TextAlign};
+use taffy_test_helpers::new_test_tree;
+
+#[test]
+fn flex_row_rtl_lays_out_items_from_the_right() {
+    let mut taffy = new_test_tree();
+    let child_style = |width: f32| Style { size: Size { width: length(width), height: auto() }, ..Default::default() };
+    let child0 = taffy.new_leaf(child_style(10.0)).unwrap();
+    let child1 = taffy.new_leaf(child_style(20.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                direction: Direction::Rtl,
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child0, child1],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child0).unwrap().location.x, 90.0);
+    assert_eq!(taffy.layout(child1).unwrap().location.x, 70.0);
+}
+
+#[test]
+fn flex_row_reverse_rtl_lays_out_items_from_the_left() {
+    let mut taffy = new_test_tree();
+    let child_style = |width: f32| Style { size: Size { width: length(width), height: auto() }, ..Default::default() };
+    let child0 = taffy.new_leaf(child_style(10.0)).unwrap();
+    let child1 = taffy.new_leaf(child_style(20.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                direction: Direction::Rtl,
+                flex_direction: FlexDirection::RowReverse,
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child0, child1],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child0).unwrap().location.x, 0.0);
+    assert_eq!(taffy.layout(child1).unwrap().location.x, 10.0);
+}
+
+#[test]
+fn flex_row_rtl_justify_content() {
+    let mut taffy = new_test_tree();
+    let child_style = |width: f32| Style { size: Size { width: length(width), height: auto() }, ..Default::default() };
+    let child0 = taffy.new_leaf(child_style(10.0)).unwrap();
+    let child1 = taffy.new_leaf(child_style(20.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                direction: Direction::Rtl,
+                justify_content: Some(JustifyContent::End),
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child0, child1],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child0).unwrap().location.x, 20.0);
+    assert_eq!(taffy.layout(child1).unwrap().location.x, 0.0);
+
+    for justify_content in [JustifyContent::Start, JustifyContent::FlexStart] {
+        let mut style = taffy.style(root).unwrap().clone();
+        style.justify_content = Some(justify_content);
+        taffy.set_style(root, style).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child0).unwrap().location.x, 90.0);
+        assert_eq!(taffy.layout(child1).unwrap().location.x, 70.0);
+    }
+}
+
+#[test]
+fn flex_column_rtl_aligns_items_to_the_right() {
+    let mut taffy = new_test_tree();
+    let child_style = |align_self: AlignSelf| Style {
+        size: Size { width: length(10.0), height: length(10.0) },
+        align_self: Some(align_self),
+        ..Default::default()
+    };
+    let child0 = taffy.new_leaf(child_style(AlignSelf::FlexStart)).unwrap();
+    let child1 = taffy.new_leaf(child_style(AlignSelf::Start)).unwrap();
+    let child2 = taffy.new_leaf(child_style(AlignSelf::End)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                direction: Direction::Rtl,
+                flex_direction: FlexDirection::Column,
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child0, child1, child2],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child0).unwrap().location, Point { x: 90.0, y: 0.0 });
+    assert_eq!(taffy.layout(child1).unwrap().location, Point { x: 90.0, y: 10.0 });
+    assert_eq!(taffy.layout(child2).unwrap().location, Point { x: 0.0, y: 20.0 });
+}
+
+#[test]
+fn flex_rtl_absolute_child_prefers_right_inset() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: length(5.0), right: length(15.0), top: auto(), bottom: auto() },
+            size: Size { width: length(10.0), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                direction: Direction::Rtl,
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().location.x, 75.0);
+}
+
+#[test]
+fn flex_rtl_scrollbar_gutter_is_on_the_left() {
+    let mut taffy = new_test_tree();
+    let child =
+        taffy.new_leaf(Style { size: Size { width: length(20.0), height: auto() }, ..Default::default() }).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                direction: Direction::Rtl,
+                overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+                scrollbar_width: Size { width: 10.0, height: 10.0 },
+                justify_content: Some(JustifyContent::End),
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().location.x, 10.0);
+}
+
+#[test]
+fn grid_rtl_places_columns_from_the_right() {
+    let mut taffy = new_test_tree();
+    let child0 = taffy.new_leaf(Style::default()).unwrap();
+    let child1 = taffy.new_leaf(Style::default()).unwrap();
+    let child2 = taffy
+        .new_leaf(Style {
+            grid_column: Line { start: line(1), end: span(2) },
+            grid_row: Line { start: line(2), end: auto() },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                direction: Direction::Rtl,
+                grid_template_columns: vec![length(40.0), length(50.0), length(60.0)],
+                grid_template_rows: vec![length(40.0), length(40.0)],
+                size: Size { width: length(200.0), height: auto() },
+                ..Default::default()
+            },
+            &[child0, child1, child2],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child0).unwrap().location.x, 160.0);
+    assert_eq!(taffy.layout(child0).unwrap().size.width, 40.0);
+    assert_eq!(taffy.layout(child1).unwrap().location.x, 110.0);
+    assert_eq!(taffy.layout(child1).unwrap().size.width, 50.0);
+    assert_eq!(taffy.layout(child2).unwrap().location.x, 110.0);
+    assert_eq!(taffy.layout(child2).unwrap().size.width, 90.0);
+}
+
+#[test]
+fn grid_rtl_justify_self_and_insets() {
+    let mut taffy = new_test_tree();
+    let start = taffy
+        .new_leaf(Style {
+            justify_self: Some(JustifySelf::Start),
+            size: Size { width: length(10.0), height: auto() },
+            ..Default::default()
+        })
+        .unwrap();
+    let end = taffy
+        .new_leaf(Style {
+            justify_self: Some(JustifySelf::End),
+            size: Size { width: length(10.0), height: auto() },
+            ..Default::default()
+        })
+        .unwrap();
+    let relative = taffy
+        .new_leaf(Style {
+            justify_self: Some(JustifySelf::Start),
+            inset: Rect { left: length(1.0), right: length(2.0), top: auto(), bottom: auto() },
+            margin: Rect { left: zero(), right: length(3.0), top: zero(), bottom: zero() },
+            size: Size { width: length(10.0), height: auto() },
+            ..Default::default()
+        })
+        .unwrap();
+    let absolute = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: auto(), right: length(5.0), top: auto(), bottom: auto() },
+            size: Size { width: length(10.0), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                direction: Direction::Rtl,
+                grid_template_columns: vec![length(50.0), length(50.0), length(50.0)],
+                size: Size { width: length(150.0), height: length(50.0) },
+                ..Default::default()
+            },
+            &[start, end, relative, absolute],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(start).unwrap().location.x, 140.0);
+    assert_eq!(taffy.layout(end).unwrap().location.x, 50.0);
+    assert_eq!(taffy.layout(relative).unwrap().location.x, 35.0);
+    assert_eq!(taffy.layout(relative).unwrap().margin.right, 3.0);
+    assert_eq!(taffy.layout(absolute).unwrap().location.x, 135.0);
+}
+
+#[test]
+fn grid_rtl_scrollbar_gutter_is_on_the_left() {
+    let mut taffy = new_test_tree();
+    let child = taffy.new_leaf(Style::default()).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                direction: Direction::Rtl,
+                overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+                scrollbar_width: Size { width: 10.0, height: 10.0 },
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().location.x, 10.0);
+    assert_eq!(taffy.layout(child).unwrap().size.width, 90.0);
+}
+
+#[test]
+fn block_rtl_places_items_against_the_right_edge() {
+    let mut taffy = new_test_tree();
+    let child_style = |width: f32| Style {
+        display: Display::Block,
+        size: Size { width: length(width), height: length(10.0) },
+        ..Default::default()
+    };
+    let child0 = taffy.new_leaf(child_style(30.0)).unwrap();
+    let child1 = taffy
+        .new_leaf(Style {
+            margin: Rect { left: zero(), right: length(5.0), top: zero(), bottom: zero() },
+            inset: Rect { left: length(1.0), right: length(2.0), top: auto(), bottom: auto() },
+            ..child_style(30.0)
+        })
+        .unwrap();
+    let child2 = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: length(5.0), right: length(15.0), top: auto(), bottom: auto() },
+            ..child_style(10.0)
+        })
+        .unwrap();
+    let child3 = taffy.new_leaf(Style { position: Position::Absolute, ..child_style(10.0) }).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                direction: Direction::Rtl,
+                padding: Rect { left: length(4.0), right: length(6.0), top: zero(), bottom: zero() },
+                size: Size { width: length(110.0), height: auto() },
+                ..Default::default()
+            },
+            &[child0, child1, child2, child3],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child0).unwrap().location.x, 74.0);
+    assert_eq!(taffy.layout(child1).unwrap().location.x, 67.0);
+    assert_eq!(taffy.layout(child2).unwrap().location.x, 85.0);
+    assert_eq!(taffy.layout(child3).unwrap().location.x, 94.0);
+}
+
+#[test]
+fn block_rtl_text_align() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style {
+            display: Display::Block,
+            size: Size { width: length(20.0), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                direction: Direction::Rtl,
+                size: Size { width: length(100.0), height: auto() },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    for (text_align, expected_x) in [
+        (TextAlign::Auto, 80.0),
+        (TextAlign::LegacyRight, 80.0),
+        (TextAlign::LegacyLeft, 0.0),
+        (TextAlign::LegacyCenter, 40.0),
+    ] {
+        let mut style = taffy.style(root).unwrap().clone();
+        style.text_align = text_align;
+        taffy.set_style(root, style).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().location.x, expected_x, "text_align: {text_align:?}");
+    }
+}
+
+#[test]
+fn block_rtl_scrollbar_gutter_is_on_the_left() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style {
+            display: Display::Block,
+            size: Size { width: auto(), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                direction: Direction::Rtl,
+                overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+                scrollbar_width: Size { width: 10.0, height: 10.0 },
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().location.x, 10.0);
+    assert_eq!(taffy.layout(child).unwrap().size.width, 90.0);
+}
@@ -0,0 +1,137 @@
+use taffy::prelude::*;
+use taffy::Point;
+use taffy_test_helpers::{fixed_size, new_test_tree};
+
+fn contents() -> Style {
+    Style { display: Display::Contents, ..fixed_size(100.0, 100.0) }
+}
+
+#[test]
+fn contents_children_are_flex_items_of_the_grandparent() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(fixed_size(10.0, 10.0)).unwrap();
+    let second = taffy.new_leaf(fixed_size(20.0, 10.0)).unwrap();
+    let third = taffy.new_leaf(fixed_size(30.0, 10.0)).unwrap();
+    let inner_wrapper = taffy.new_with_children(contents(), &[third]).unwrap();
+    let wrapper = taffy.new_with_children(contents(), &[second, inner_wrapper]).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                padding: Rect { left: length(5.0), right: zero(), top: length(5.0), bottom: zero() },
+                ..Default::default()
+            },
+            &[first, wrapper],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 65.0, height: 15.0 });
+    assert_eq!(taffy.layout(first).unwrap().location, Point { x: 5.0, y: 5.0 });
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 15.0, y: 5.0 });
+    assert_eq!(taffy.layout(third).unwrap().location, Point { x: 35.0, y: 5.0 });
+
+    // The contents nodes generate no box
+    for (node, order) in [(wrapper, 1), (inner_wrapper, 1)] {
+        let layout = taffy.layout(node).unwrap();
+        assert_eq!(layout.location, Point::ZERO);
+        assert_eq!(layout.size, Size::ZERO);
+        assert_eq!(layout.order, order);
+    }
+}
+
+#[test]
+fn contents_children_are_grid_items_of_the_grandparent() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(Style::default()).unwrap();
+    let second = taffy.new_leaf(Style::default()).unwrap();
+    let third = taffy.new_leaf(Style::default()).unwrap();
+    let wrapper = taffy.new_with_children(contents(), &[second, third]).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(10.0), length(20.0)],
+                grid_auto_rows: vec![length(15.0)],
+                ..Default::default()
+            },
+            &[first, wrapper],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 10.0, y: 0.0 });
+    assert_eq!(taffy.layout(third).unwrap().location, Point { x: 0.0, y: 15.0 });
+    assert_eq!(taffy.layout(third).unwrap().size, Size { width: 10.0, height: 15.0 });
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 30.0, height: 30.0 });
+}
+
+#[test]
+fn contents_children_are_block_children_of_the_grandparent() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(fixed_size(10.0, 10.0)).unwrap();
+    let hidden = taffy.new_leaf(Style { display: Display::None, ..fixed_size(10.0, 10.0) }).unwrap();
+    let second = taffy.new_leaf(fixed_size(20.0, 10.5)).unwrap();
+    let wrapper = taffy.new_with_children(contents(), &[hidden, second]).unwrap();
+    let third = taffy.new_leaf(fixed_size(30.0, 10.0)).unwrap();
+    let root = taffy
+        .new_with_children(Style { display: Display::Block, ..Default::default() }, &[first, wrapper, third])
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(hidden).unwrap().size, Size::ZERO);
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 10.0 });
+    // Rounding is relative to the grandparent, as the contents node does not offset its children
+    assert_eq!(taffy.layout(second).unwrap().size, Size { width: 20.0, height: 11.0 });
+    assert_eq!(taffy.layout(third).unwrap().location, Point { x: 0.0, y: 21.0 });
+    assert_eq!(taffy.unrounded_layout(third).location, Point { x: 0.0, y: 20.5 });
+    assert_eq!(taffy.layout(wrapper).unwrap().size, Size::ZERO);
+}
+
+#[test]
+fn changing_display_to_contents_relayouts_the_children() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(fixed_size(10.0, 10.0)).unwrap();
+    let second = taffy.new_leaf(fixed_size(20.0, 10.0)).unwrap();
+    let wrapper = taffy
+        .new_with_children(Style { flex_direction: FlexDirection::Column, ..Default::default() }, &[first, second])
+        .unwrap();
+    let root = taffy.new_with_children(Style::default(), &[wrapper]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 10.0 });
+
+    taffy.set_style(wrapper, contents()).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 10.0, y: 0.0 });
+    assert_eq!(taffy.layout(wrapper).unwrap().size, Size::ZERO);
+
+    taffy.set_style(wrapper, Style::default()).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(wrapper).unwrap().size, Size { width: 30.0, height: 10.0 });
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 10.0, y: 0.0 });
+}
+
+#[test]
+fn adding_a_child_to_a_contents_node_relayouts_its_parent() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(fixed_size(10.0, 10.0)).unwrap();
+    let wrapper = taffy.new_with_children(contents(), &[first]).unwrap();
+    let root = taffy.new_with_children(Style::default(), &[wrapper]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 10.0, height: 10.0 });
+
+    let second = taffy.new_leaf(fixed_size(20.0, 10.0)).unwrap();
+    taffy.add_child(wrapper, second).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 10.0, y: 0.0 });
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 30.0, height: 10.0 });
+
+    taffy.remove_child(wrapper, first).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 20.0, height: 10.0 });
+}
@@ -0,0 +1,187 @@
+use taffy::prelude::*;
+use taffy::{Overflow, Point};
+use taffy_test_helpers::{fixed_size, new_test_tree};
+
+fn viewport() -> Size<AvailableSpace> {
+    Size { width: AvailableSpace::Definite(200.0), height: AvailableSpace::Definite(100.0) }
+}
+
+#[test]
+fn fixed_nodes_are_positioned_against_the_viewport() {
+    let mut taffy = new_test_tree();
+    let fixed = taffy
+        .new_leaf(Style {
+            position: Position::Fixed,
+            size: Size { width: percent(0.5), height: length(10.0) },
+            inset: Rect { left: auto(), right: length(10.0), top: auto(), bottom: percent(0.1) },
+            ..Default::default()
+        })
+        .unwrap();
+    let parent = taffy
+        .new_with_children(
+            Style {
+                margin: Rect { left: length(30.0), right: zero(), top: length(20.0), bottom: zero() },
+                ..fixed_size(50.0, 50.0)
+            },
+            &[fixed],
+        )
+        .unwrap();
+    let root = taffy.new_with_children(Style::default(), &[parent]).unwrap();
+
+    taffy.compute_layout(root, viewport()).unwrap();
+
+    // The node is placed at (90, 80) in the viewport, which is relative to its parent at (30, 20)
+    let layout = taffy.layout(fixed).unwrap();
+    assert_eq!(layout.size, Size { width: 100.0, height: 10.0 });
+    assert_eq!(layout.location, Point { x: 60.0, y: 60.0 });
+}
+
+#[test]
+fn fixed_nodes_are_positioned_against_the_viewport_size() {
+    let mut taffy = new_test_tree();
+    let fixed = taffy
+        .new_leaf(Style {
+            position: Position::Fixed,
+            inset: Rect { left: auto(), right: length(10.0), top: auto(), bottom: length(10.0) },
+            ..fixed_size(10.0, 10.0)
+        })
+        .unwrap();
+    let root = taffy.new_with_children(Style::default(), &[fixed]).unwrap();
+    taffy.set_viewport_size(Some(Size { width: 200.0, height: 100.0 }));
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: 180.0, y: 80.0 });
+}
+
+#[test]
+fn fixed_nodes_keep_their_static_position_in_axes_without_insets() {
+    let mut taffy = new_test_tree();
+    let fixed = taffy
+        .new_leaf(Style {
+            position: Position::Fixed,
+            inset: Rect { left: length(5.0), right: auto(), top: auto(), bottom: auto() },
+            ..fixed_size(10.0, 10.0)
+        })
+        .unwrap();
+    let parent = taffy
+        .new_with_children(
+            Style {
+                padding: Rect { left: length(10.0), right: zero(), top: length(10.0), bottom: zero() },
+                ..fixed_size(50.0, 50.0)
+            },
+            &[fixed],
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                padding: Rect { left: length(20.0), right: zero(), top: zero(), bottom: zero() },
+                ..Default::default()
+            },
+            &[parent],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, viewport()).unwrap();
+
+    assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: -15.0, y: 10.0 });
+}
+
+#[test]
+fn fixed_nodes_are_not_moved_by_scrolling() {
+    let mut taffy = new_test_tree();
+    let fixed = taffy
+        .new_leaf(Style {
+            position: Position::Fixed,
+            inset: Rect { left: zero(), right: auto(), top: zero(), bottom: auto() },
+            ..fixed_size(10.0, 10.0)
+        })
+        .unwrap();
+    let content = taffy.new_leaf(fixed_size(200.0, 500.0)).unwrap();
+    let root = taffy
+        .new_with_children(Style { flex_direction: FlexDirection::Column, ..Default::default() }, &[content, fixed])
+        .unwrap();
+
+    taffy.set_scroll_offset(root, Point { x: 0.0, y: 150.0 }).unwrap();
+    taffy.compute_layout(root, viewport()).unwrap();
+
+    assert_eq!(taffy.scroll_offset(root).unwrap(), Point { x: 0.0, y: 150.0 });
+    assert_eq!(taffy.layout(fixed).unwrap().location, Point { x: 0.0, y: 150.0 });
+}
+
+#[test]
+fn sticky_nodes_stick_to_the_scrollport_within_their_parent() {
+    let mut taffy = new_test_tree();
+    let header = taffy
+        .new_leaf(Style {
+            position: Position::Sticky,
+            inset: Rect { left: auto(), right: auto(), top: zero(), bottom: auto() },
+            ..fixed_size(50.0, 10.0)
+        })
+        .unwrap();
+    let section_content = taffy.new_leaf(fixed_size(50.0, 90.0)).unwrap();
+    let section = taffy
+        .new_with_children(
+            Style { flex_direction: FlexDirection::Column, ..Default::default() },
+            &[header, section_content],
+        )
+        .unwrap();
+    let rest = taffy.new_leaf(fixed_size(50.0, 200.0)).unwrap();
+    let scroller = taffy
+        .new_with_children(
+            Style {
+                flex_direction: FlexDirection::Column,
+                overflow: Point { x: Overflow::Hidden, y: Overflow::Scroll },
+                scrollbar_width: Size::ZERO,
+                margin: Rect { left: zero(), right: zero(), top: length(20.0), bottom: zero() },
+                ..fixed_size(50.0, 50.0)
+            },
+            &[section, rest],
+        )
+        .unwrap();
+    let root = taffy.new_with_children(Style::default(), &[scroller]).unwrap();
+
+    // Not scrolled: the header is in its normal position
+    taffy.compute_layout(root, viewport()).unwrap();
+    assert_eq!(taffy.layout(header).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(header).unwrap().sticky_offset, Point::ZERO);
+
+    // Scrolled by 30: the header sticks to the top of the scrollport
+    taffy.set_scroll_offset(scroller, Point { x: 0.0, y: 30.0 }).unwrap();
+    taffy.compute_layout(root, viewport()).unwrap();
+    assert_eq!(taffy.layout(header).unwrap().location, Point { x: 0.0, y: 30.0 });
+    assert_eq!(taffy.layout(header).unwrap().sticky_offset, Point { x: 0.0, y: 30.0 });
+
+    // Scrolled by 150: the header cannot leave the bottom of its parent
+    taffy.set_scroll_offset(scroller, Point { x: 0.0, y: 150.0 }).unwrap();
+    taffy.compute_layout(root, viewport()).unwrap();
+    assert_eq!(taffy.layout(header).unwrap().location, Point { x: 0.0, y: 90.0 });
+    assert_eq!(taffy.layout(header).unwrap().sticky_offset, Point { x: 0.0, y: 90.0 });
+
+    // Scrolling back restores the normal position
+    taffy.set_scroll_offset(scroller, Point::ZERO).unwrap();
+    taffy.compute_layout(root, viewport()).unwrap();
+    assert_eq!(taffy.layout(header).unwrap().location, Point { x: 0.0, y: 0.0 });
+}
+
+#[test]
+fn sticky_nodes_are_offset_into_the_viewport() {
+    let mut taffy = new_test_tree();
+    let sticky = taffy
+        .new_leaf(Style {
+            position: Position::Sticky,
+            inset: Rect { left: length(10.0), right: auto(), top: length(10.0), bottom: auto() },
+            ..fixed_size(10.0, 10.0)
+        })
+        .unwrap();
+    let root =
+        taffy.new_with_children(Style { display: Display::Block, ..fixed_size(100.0, 100.0) }, &[sticky]).unwrap();
+
+    taffy.compute_layout(root, viewport()).unwrap();
+
+    // The insets only offset the node by as much as is needed to keep it within the viewport, which here is exactly
+    // as much as a relative node would be offset by
+    assert_eq!(taffy.layout(sticky).unwrap().location, Point { x: 10.0, y: 10.0 });
+    assert_eq!(taffy.layout(sticky).unwrap().sticky_offset, Point { x: 10.0, y: 10.0 });
+}
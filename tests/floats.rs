@@ -0,0 +1,199 @@
+use taffy::prelude::*;
+use taffy::Point;
+use taffy_test_helpers::{compute_layout, text};
+
+fn block(width: f32) -> Style {
+    Style { display: Display::Block, size: Size { width: length(width), height: auto() }, ..Default::default() }
+}
+
+fn float(float: Float, width: f32, height: f32) -> Style {
+    Style { float, size: Size { width: length(width), height: length(height) }, ..Default::default() }
+}
+
+fn fixed_height(height: f32) -> Style {
+    Style { size: Size { width: auto(), height: length(height) }, ..Default::default() }
+}
+
+#[test]
+fn lines_are_shortened_beside_floats() {
+    let mut taffy = TaffyTree::new();
+    let image = taffy.new_leaf(float(Float::Left, 30.0, 15.0)).unwrap();
+    let run = text(&mut taffy, "aaa bbb ccc ddd eee fff");
+    let root = taffy.new_with_children(block(100.0), &[image, run]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(image).unwrap().location, Point::ZERO);
+    let fragments = taffy.inline_fragments(run).unwrap();
+    assert_eq!(fragments.len(), 3);
+    assert_eq!(fragments[0].location, Point { x: 30.0, y: 0.0 });
+    assert_eq!((fragments[0].text_start, fragments[0].text_end), (0, 8));
+    assert_eq!(fragments[1].location, Point { x: 30.0, y: 10.0 });
+    // The third line is below the float, so it starts at the left edge of the container
+    assert_eq!(fragments[2].location, Point { x: 0.0, y: 20.0 });
+    assert_eq!(taffy.layout(root).unwrap().size.height, 30.0);
+}
+
+#[test]
+fn lines_whose_content_does_not_fit_beside_floats_are_moved_below_them() {
+    let mut taffy = TaffyTree::new();
+    let image = taffy.new_leaf(float(Float::Right, 80.0, 25.0)).unwrap();
+    let run = text(&mut taffy, "aaa");
+    let root = taffy.new_with_children(block(100.0), &[image, run]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(image).unwrap().location, Point { x: 20.0, y: 0.0 });
+    assert_eq!(taffy.layout(run).unwrap().location, Point { x: 0.0, y: 25.0 });
+    assert_eq!(taffy.layout(root).unwrap().size.height, 35.0);
+}
+
+#[test]
+fn floats_are_placed_beside_earlier_floats() {
+    let mut taffy = TaffyTree::new();
+    let first = taffy.new_leaf(float(Float::Left, 40.0, 10.0)).unwrap();
+    let second = taffy.new_leaf(float(Float::Left, 40.0, 20.0)).unwrap();
+    let third = taffy.new_leaf(float(Float::Left, 40.0, 10.0)).unwrap();
+    let right = taffy.new_leaf(float(Float::Right, 10.0, 10.0)).unwrap();
+    let root = taffy.new_with_children(block(100.0), &[first, second, third, right]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 40.0, y: 0.0 });
+    // The third float does not fit beside the first two, and it cannot be placed to the left of the second while it
+    // is beside it, so it is moved below both
+    assert_eq!(taffy.layout(third).unwrap().location, Point { x: 0.0, y: 20.0 });
+    // A float is never placed above an earlier float
+    assert_eq!(taffy.layout(right).unwrap().location, Point { x: 90.0, y: 20.0 });
+    // The container grows to contain its floats
+    assert_eq!(taffy.layout(root).unwrap().size.height, 30.0);
+}
+
+#[test]
+fn block_children_are_narrowed_beside_floats_and_cleared_below_them() {
+    let mut taffy = TaffyTree::new();
+    let image = taffy.new_leaf(float(Float::Right, 40.0, 20.0)).unwrap();
+    let beside = taffy.new_leaf(fixed_height(10.0)).unwrap();
+    let cleared = taffy.new_leaf(Style { clear: Clear::Both, ..fixed_height(10.0) }).unwrap();
+    let root = taffy.new_with_children(block(100.0), &[image, beside, cleared]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(beside).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(beside).unwrap().size, Size { width: 60.0, height: 10.0 });
+    assert_eq!(taffy.layout(cleared).unwrap().location, Point { x: 0.0, y: 20.0 });
+    assert_eq!(taffy.layout(cleared).unwrap().size, Size { width: 100.0, height: 10.0 });
+    assert_eq!(taffy.layout(root).unwrap().size.height, 30.0);
+}
+
+#[test]
+fn leaves_are_measured_with_the_space_beside_floats() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let image = taffy.new_leaf(float(Float::Left, 30.0, 20.0)).unwrap();
+    let leaf = taffy.new_leaf_with_context(Style::default(), ()).unwrap();
+    let root = taffy.new_with_children(block(100.0), &[image, leaf]).unwrap();
+
+    taffy
+        .compute_layout_with_measure(root, Size::MAX_CONTENT, |known_dimensions, available_space, _, context, _| {
+            match context {
+                Some(()) => Size {
+                    width: known_dimensions.width.unwrap_or(0.0),
+                    height: match available_space.width {
+                        AvailableSpace::Definite(width) => 700.0 / width,
+                        _ => 0.0,
+                    },
+                },
+                None => Size::ZERO,
+            }
+        })
+        .unwrap();
+
+    assert_eq!(taffy.layout(leaf).unwrap().location, Point { x: 30.0, y: 0.0 });
+    assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 70.0, height: 10.0 });
+}
+
+#[test]
+fn clearance_separates_collapsing_margins() {
+    let mut taffy = TaffyTree::new();
+    let first = taffy
+        .new_leaf(Style {
+            margin: Rect { left: zero(), right: zero(), top: zero(), bottom: length(10.0) },
+            ..fixed_height(10.0)
+        })
+        .unwrap();
+    let image = taffy.new_leaf(float(Float::Left, 20.0, 30.0)).unwrap();
+    let cleared = taffy
+        .new_leaf(Style {
+            clear: Clear::Left,
+            margin: Rect { left: zero(), right: zero(), top: length(5.0), bottom: zero() },
+            ..fixed_height(10.0)
+        })
+        .unwrap();
+    let root = taffy.new_with_children(block(100.0), &[first, image, cleared]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    // The float is placed below the bottom margin of the first child
+    assert_eq!(taffy.layout(image).unwrap().location, Point { x: 0.0, y: 20.0 });
+    // Without clearance the cleared child would be at 20 (its top margin collapsing with the margin above), so it is
+    // moved down to the bottom of the float
+    assert_eq!(taffy.layout(cleared).unwrap().location, Point { x: 0.0, y: 50.0 });
+    assert_eq!(taffy.layout(root).unwrap().size.height, 60.0);
+}
+
+#[test]
+fn intrinsic_width_places_floats_beside_content() {
+    let mut taffy = TaffyTree::new();
+    let image = taffy.new_leaf(float(Float::Left, 30.0, 10.0)).unwrap();
+    let run = text(&mut taffy, "aa bb");
+    let root = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[image, run]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 80.0, height: 10.0 });
+    assert_eq!(taffy.layout(run).unwrap().location, Point { x: 30.0, y: 0.0 });
+
+    compute_layout(&mut taffy, root, Size::MIN_CONTENT);
+    assert_eq!(taffy.layout(root).unwrap().size.width, 30.0);
+}
+
+#[test]
+fn floats_between_inline_children_are_placed_below_the_line_on_which_they_occur() {
+    let mut taffy = TaffyTree::new();
+    let before = text(&mut taffy, "aaaa bbbb cccc dddd eeee ffff");
+    let image = taffy.new_leaf(float(Float::Left, 30.0, 30.0)).unwrap();
+    let after = text(&mut taffy, "gggg hhhh");
+    let root = taffy.new_with_children(block(100.0), &[before, image, after]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    // The earlier text fills three lines, and the float does not fit beside the content of the third line
+    assert_eq!(taffy.inline_fragments(before).unwrap().len(), 3);
+    assert_eq!(taffy.layout(image).unwrap().location, Point { x: 0.0, y: 30.0 });
+    let fragments = taffy.inline_fragments(after).unwrap();
+    assert_eq!(fragments.len(), 2);
+    assert_eq!(taffy.layout(after).unwrap().location, Point { x: 30.0, y: 30.0 });
+    assert_eq!(fragments[1].location, Point { x: 0.0, y: 10.0 });
+    assert_eq!(taffy.layout(root).unwrap().size.height, 60.0);
+}
+
+#[test]
+fn floats_between_inline_children_are_placed_beside_the_line_on_which_they_occur() {
+    let mut taffy = TaffyTree::new();
+    let before = text(&mut taffy, "aaaa");
+    let image = taffy.new_leaf(float(Float::Right, 30.0, 15.0)).unwrap();
+    let after = text(&mut taffy, "bb cc");
+    let root = taffy.new_with_children(block(100.0), &[before, image, after]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(before).unwrap().location, Point::ZERO);
+    assert_eq!(taffy.layout(image).unwrap().location, Point { x: 70.0, y: 0.0 });
+    // The line on which the float occurs is shortened by it, so the later text only fits on it in part
+    let fragments = taffy.inline_fragments(after).unwrap();
+    assert_eq!(fragments.len(), 2);
+    assert_eq!(taffy.layout(after).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(fragments[0].location, Point { x: 40.0, y: 0.0 });
+    assert_eq!(fragments[1].location, Point { x: 0.0, y: 10.0 });
+    assert_eq!(taffy.layout(root).unwrap().size.height, 20.0);
+}
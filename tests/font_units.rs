@@ -0,0 +1,113 @@
+use taffy::prelude::*;
+use taffy::FontMetrics;
+
+#[test]
+fn font_units_are_resolved_against_the_font_metrics_of_the_node() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy
+        .new_leaf(Style {
+            size: Size { width: Dimension::em(5.0), height: Dimension::lh(2.0) },
+            margin: Rect { left: LengthPercentageAuto::rem(3.0), right: zero(), top: zero(), bottom: zero() },
+            padding: Rect { left: LengthPercentage::ch(2.0), right: zero(), top: zero(), bottom: zero() },
+            ..Default::default()
+        })
+        .unwrap();
+    let container = taffy
+        .new_with_children(
+            Style {
+                padding: Rect { left: LengthPercentage::em(1.0), right: zero(), top: zero(), bottom: zero() },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style { size: Size { width: length(500.0), height: length(500.0) }, ..Default::default() },
+            &[container],
+        )
+        .unwrap();
+    taffy.set_font_metrics(root, Some(FontMetrics::from_font_size(10.0))).unwrap();
+    taffy.set_font_metrics(container, Some(FontMetrics::from_font_size(20.0))).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The child inherits the font metrics of the container, and rem lengths use the font size of the root
+    assert_eq!(taffy.font_metrics(child).unwrap(), FontMetrics::from_font_size(20.0));
+    assert_eq!(taffy.layout(container).unwrap().padding.left, 20.0);
+    let layout = taffy.layout(child).unwrap();
+    assert_eq!(layout.size, Size { width: 100.0, height: 48.0 });
+    assert_eq!(layout.location.x, 50.0);
+    assert_eq!(layout.padding.left, 20.0);
+}
+
+#[test]
+fn font_units_use_the_default_font_metrics_if_none_are_set() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let root = taffy
+        .new_leaf(Style { size: Size { width: Dimension::em(2.0), height: Dimension::rem(1.0) }, ..Default::default() })
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 32.0, height: 16.0 });
+}
+
+#[test]
+fn changing_font_metrics_invalidates_descendants() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy
+        .new_leaf(Style { size: Size { width: Dimension::em(10.0), height: length(10.0) }, ..Default::default() })
+        .unwrap();
+    let container = taffy
+        .new_with_children(
+            Style { size: Size { width: length(300.0), height: length(10.0) }, ..Default::default() },
+            &[child],
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![NonRepeatedTrackSizingFunction::from(Dimension::rem(10.0)).into()],
+                ..Default::default()
+            },
+            &[container],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().size.width, 160.0);
+    assert_eq!(taffy.layout(root).unwrap().size.width, 160.0);
+
+    taffy.set_font_metrics(container, Some(FontMetrics::from_font_size(20.0))).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().size.width, 200.0);
+
+    taffy.set_font_metrics(root, Some(FontMetrics::from_font_size(12.0))).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().size.width, 200.0);
+    assert_eq!(taffy.layout(root).unwrap().size.width, 120.0);
+}
+
+#[test]
+fn leaf_layouts_resolve_relative_lengths_against_the_relative_length_basis() {
+    use taffy::{compute_leaf_layout, LayoutInput, RelativeLengthBasis, RunMode};
+
+    let style = Style {
+        size: Size { width: Dimension::vw(10.0), height: Dimension::em(2.0) },
+        padding: Rect { left: LengthPercentage::rem(1.0), right: zero(), top: zero(), bottom: zero() },
+        ..Default::default()
+    };
+    let basis = RelativeLengthBasis {
+        viewport_size: Size { width: 500.0, height: 300.0 },
+        font_metrics: FontMetrics::from_font_size(10.0),
+        root_font_size: 20.0,
+    };
+    let inputs = LayoutInput { run_mode: RunMode::PerformLayout, ..LayoutInput::HIDDEN };
+
+    // The calc resolver is only used for calc() values
+    let output = compute_leaf_layout(inputs, &style, basis, |_, _| unreachable!(), |_, _| Size::ZERO);
+
+    assert_eq!(output.size, Size { width: 50.0, height: 20.0 });
+}
@@ -139,7 +139,7 @@ fn block_absolute_resolved_insets__border_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 size: taffy::geometry::Size {
                     width: taffy::style::Dimension::from_length(200f32),
                     height: taffy::style::Dimension::from_length(200f32),
@@ -496,7 +496,7 @@ fn block_absolute_resolved_insets__content_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 size: taffy::geometry::Size {
                     width: taffy::style::Dimension::from_length(200f32),
                     height: taffy::style::Dimension::from_length(200f32),
@@ -16,7 +16,7 @@ fn block_margin_y_collapse_through_blocked_by_overflow_x_scroll__border_box() {
         .new_leaf(taffy::style::Style {
             display: taffy::style::Display::Block,
             overflow: taffy::geometry::Point { x: taffy::style::Overflow::Scroll, y: taffy::style::Overflow::Visible },
-            scrollbar_width: 15f32,
+            scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
             margin: taffy::geometry::Rect { left: zero(), right: zero(), top: length(10f32), bottom: length(10f32) },
             ..Default::default()
         })
@@ -107,7 +107,7 @@ fn block_margin_y_collapse_through_blocked_by_overflow_x_scroll__content_box() {
             display: taffy::style::Display::Block,
             box_sizing: taffy::style::BoxSizing::ContentBox,
             overflow: taffy::geometry::Point { x: taffy::style::Overflow::Scroll, y: taffy::style::Overflow::Visible },
-            scrollbar_width: 15f32,
+            scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
             margin: taffy::geometry::Rect { left: zero(), right: zero(), top: length(10f32), bottom: length(10f32) },
             ..Default::default()
         })
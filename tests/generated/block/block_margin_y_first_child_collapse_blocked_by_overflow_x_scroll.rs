@@ -28,7 +28,7 @@ fn block_margin_y_first_child_collapse_blocked_by_overflow_x_scroll__border_box(
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Visible,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 margin: taffy::geometry::Rect { left: zero(), right: zero(), top: length(10f32), bottom: zero() },
                 ..Default::default()
             },
@@ -126,7 +126,7 @@ fn block_margin_y_first_child_collapse_blocked_by_overflow_x_scroll__content_box
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Visible,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 margin: taffy::geometry::Rect { left: zero(), right: zero(), top: length(10f32), bottom: zero() },
                 ..Default::default()
             },
@@ -28,7 +28,7 @@ fn block_margin_y_last_child_collapse_blocked_by_overflow_y_hidden__border_box()
                     x: taffy::style::Overflow::Visible,
                     y: taffy::style::Overflow::Hidden,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 margin: taffy::geometry::Rect { left: zero(), right: zero(), top: zero(), bottom: length(10f32) },
                 ..Default::default()
             },
@@ -126,7 +126,7 @@ fn block_margin_y_last_child_collapse_blocked_by_overflow_y_hidden__content_box(
                     x: taffy::style::Overflow::Visible,
                     y: taffy::style::Overflow::Hidden,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 margin: taffy::geometry::Rect { left: zero(), right: zero(), top: zero(), bottom: length(10f32) },
                 ..Default::default()
             },
@@ -24,7 +24,7 @@ fn block_overflow_scrollbars_overridden_by_available_space__border_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 ..Default::default()
             },
             &[node00],
@@ -113,7 +113,7 @@ fn block_overflow_scrollbars_overridden_by_available_space__content_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 ..Default::default()
             },
             &[node00],
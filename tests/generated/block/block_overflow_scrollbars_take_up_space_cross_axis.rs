@@ -24,7 +24,7 @@ fn block_overflow_scrollbars_take_up_space_cross_axis__border_box() {
                     x: taffy::style::Overflow::Visible,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 size: taffy::geometry::Size {
                     width: taffy::style::Dimension::from_length(50f32),
                     height: taffy::style::Dimension::from_length(50f32),
@@ -98,7 +98,7 @@ fn block_overflow_scrollbars_take_up_space_cross_axis__content_box() {
                     x: taffy::style::Overflow::Visible,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 size: taffy::geometry::Size {
                     width: taffy::style::Dimension::from_length(50f32),
                     height: taffy::style::Dimension::from_length(50f32),
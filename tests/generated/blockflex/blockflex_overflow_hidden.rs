@@ -12,7 +12,7 @@ fn blockflex_overflow_hidden__border_box() {
                     x: taffy::style::Overflow::Hidden,
                     y: taffy::style::Overflow::Hidden,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 flex_grow: 1f32,
                 ..Default::default()
             },
@@ -95,7 +95,7 @@ fn blockflex_overflow_hidden__content_box() {
                     x: taffy::style::Overflow::Hidden,
                     y: taffy::style::Overflow::Hidden,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 flex_grow: 1f32,
                 ..Default::default()
             },
@@ -20,7 +20,7 @@ fn measure_child_with_flex_shrink_hidden__border_box() {
                     x: taffy::style::Overflow::Hidden,
                     y: taffy::style::Overflow::Hidden,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 ..Default::default()
             },
             crate::TestNodeContext::ahem_text(
@@ -107,7 +107,7 @@ fn measure_child_with_flex_shrink_hidden__content_box() {
                     x: taffy::style::Overflow::Hidden,
                     y: taffy::style::Overflow::Hidden,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 ..Default::default()
             },
             crate::TestNodeContext::ahem_text(
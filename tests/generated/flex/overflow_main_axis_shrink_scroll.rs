@@ -11,7 +11,7 @@ fn overflow_main_axis_shrink_scroll__border_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 flex_shrink: 1f32,
                 ..Default::default()
             },
@@ -80,7 +80,7 @@ fn overflow_main_axis_shrink_scroll__content_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 flex_shrink: 1f32,
                 ..Default::default()
             },
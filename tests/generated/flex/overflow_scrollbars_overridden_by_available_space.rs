@@ -12,7 +12,7 @@ fn overflow_scrollbars_overridden_by_available_space__border_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 flex_grow: 1f32,
                 ..Default::default()
             },
@@ -94,7 +94,7 @@ fn overflow_scrollbars_overridden_by_available_space__content_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 flex_grow: 1f32,
                 ..Default::default()
             },
@@ -21,7 +21,7 @@ fn scroll_size__border_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 align_items: Some(taffy::style::AlignItems::Start),
                 justify_content: Some(taffy::style::JustifyContent::Start),
                 size: taffy::geometry::Size {
@@ -94,7 +94,7 @@ fn scroll_size__content_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 align_items: Some(taffy::style::AlignItems::Start),
                 justify_content: Some(taffy::style::JustifyContent::Start),
                 size: taffy::geometry::Size {
@@ -21,7 +21,7 @@ fn taffy_issue_696__border_box() {
                     x: taffy::style::Overflow::Hidden,
                     y: taffy::style::Overflow::Hidden,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 flex_basis: taffy::style::Dimension::from_length(0f32),
                 min_size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(100f32) },
                 padding: taffy::geometry::Rect {
@@ -113,7 +113,7 @@ fn taffy_issue_696__content_box() {
                     x: taffy::style::Overflow::Hidden,
                     y: taffy::style::Overflow::Hidden,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 flex_basis: taffy::style::Dimension::from_length(0f32),
                 min_size: taffy::geometry::Size { width: auto(), height: taffy::style::Dimension::from_length(100f32) },
                 padding: taffy::geometry::Rect {
@@ -11,7 +11,7 @@ fn grid_fit_content_percent_indefinite_max_content_hidden__border_box() {
                     x: taffy::style::Overflow::Hidden,
                     y: taffy::style::Overflow::Hidden,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 ..Default::default()
             },
             crate::TestNodeContext::ahem_text("HH", crate::WritingMode::Horizontal),
@@ -78,7 +78,7 @@ fn grid_fit_content_percent_indefinite_max_content_hidden__content_box() {
                     x: taffy::style::Overflow::Hidden,
                     y: taffy::style::Overflow::Hidden,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 ..Default::default()
             },
             crate::TestNodeContext::ahem_text("HH", crate::WritingMode::Horizontal),
@@ -11,7 +11,7 @@ fn grid_overflow_inline_axis_scroll__border_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 ..Default::default()
             },
             crate::TestNodeContext::ahem_text("HHHHHHHHHH", crate::WritingMode::Horizontal),
@@ -80,7 +80,7 @@ fn grid_overflow_inline_axis_scroll__content_box() {
                     x: taffy::style::Overflow::Scroll,
                     y: taffy::style::Overflow::Scroll,
                 },
-                scrollbar_width: 15f32,
+                scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
                 ..Default::default()
             },
             crate::TestNodeContext::ahem_text("HHHHHHHHHH", crate::WritingMode::Horizontal),
@@ -7,7 +7,7 @@ fn leaf_overflow_scrollbars_overridden_by_max_size__border_box() {
     let node = taffy
         .new_leaf(taffy::style::Style {
             overflow: taffy::geometry::Point { x: taffy::style::Overflow::Scroll, y: taffy::style::Overflow::Scroll },
-            scrollbar_width: 15f32,
+            scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
             max_size: taffy::geometry::Size {
                 width: taffy::style::Dimension::from_length(2f32),
                 height: taffy::style::Dimension::from_length(4f32),
@@ -55,7 +55,7 @@ fn leaf_overflow_scrollbars_overridden_by_max_size__content_box() {
         .new_leaf(taffy::style::Style {
             box_sizing: taffy::style::BoxSizing::ContentBox,
             overflow: taffy::geometry::Point { x: taffy::style::Overflow::Scroll, y: taffy::style::Overflow::Scroll },
-            scrollbar_width: 15f32,
+            scrollbar_width: taffy::geometry::Size { width: 15f32, height: 15f32 },
             max_size: taffy::geometry::Size {
                 width: taffy::style::Dimension::from_length(2f32),
                 height: taffy::style::Dimension::from_length(4f32),
@@ -0,0 +1,159 @@
+use taffy::prelude::*;
+use taffy::Point;
+use taffy_test_helpers::{new_test_tree, TestNodeContext};
+
+/// A grid node containing a single leaf, whose synthesized baselines are the bottom and right edges of the leaf
+fn boxed_leaf(
+    taffy: &mut TaffyTree<TestNodeContext>,
+    padding: Rect<LengthPercentage>,
+    width: f32,
+    height: f32,
+) -> NodeId {
+    let leaf =
+        taffy.new_leaf(Style { size: Size { width: length(width), height: length(height) }, ..Default::default() });
+    taffy.new_with_children(Style { display: Display::Grid, padding, ..Default::default() }, &[leaf.unwrap()]).unwrap()
+}
+
+#[test]
+fn justify_self_baseline_aligns_the_items_in_each_column() {
+    let mut taffy = new_test_tree();
+    let padded = boxed_leaf(&mut taffy, Rect { left: length(10.0), ..Rect::zero() }, 20.0, 10.0);
+    let unpadded = boxed_leaf(&mut taffy, Rect::zero(), 20.0, 10.0);
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![auto()],
+                justify_items: Some(AlignItems::Baseline),
+                ..Default::default()
+            },
+            &[padded, unpadded],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // Both items' baselines are 30px from the start of the column
+    assert_eq!(taffy.layout(padded).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(unpadded).unwrap().location, Point { x: 10.0, y: 10.0 });
+    assert_eq!(taffy.layout(root).unwrap().size.width, 30.0);
+}
+
+#[test]
+fn only_baseline_aligned_items_are_shimmed() {
+    let mut taffy = new_test_tree();
+    let padded = boxed_leaf(&mut taffy, Rect { top: length(10.0), ..Rect::zero() }, 10.0, 10.0);
+    let unpadded = boxed_leaf(&mut taffy, Rect::zero(), 10.0, 10.0);
+    let start_aligned = taffy
+        .new_leaf(Style {
+            align_self: Some(AlignSelf::Start),
+            size: Size { width: length(10.0), height: length(5.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![auto(), auto(), auto()],
+                align_items: Some(AlignItems::Baseline),
+                ..Default::default()
+            },
+            &[padded, unpadded, start_aligned],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(unpadded).unwrap().location.y, 10.0);
+    assert_eq!(taffy.layout(start_aligned).unwrap().location.y, 0.0);
+    assert_eq!(taffy.layout(root).unwrap().size.height, 20.0);
+}
+
+#[test]
+fn absolutely_positioned_items_are_aligned_to_the_baseline_of_their_row() {
+    let mut taffy = new_test_tree();
+    let in_flow = boxed_leaf(&mut taffy, Rect { top: length(10.0), ..Rect::zero() }, 10.0, 10.0);
+    let absolute = boxed_leaf(&mut taffy, Rect::zero(), 10.0, 5.0);
+    taffy
+        .set_style(
+            absolute,
+            Style {
+                display: Display::Grid,
+                position: Position::Absolute,
+                grid_row: line(1),
+                align_self: Some(AlignSelf::Baseline),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(50.0)],
+                align_items: Some(AlignItems::Baseline),
+                ..Default::default()
+            },
+            &[in_flow, absolute],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The baseline of the row (set by its only in-flow item) is 20px from its top
+    assert_eq!(taffy.layout(absolute).unwrap().location.y, 15.0);
+
+    // Insets take precedence over baseline alignment
+    taffy
+        .set_style(
+            absolute,
+            Style {
+                display: Display::Grid,
+                position: Position::Absolute,
+                grid_row: line(1),
+                align_self: Some(AlignSelf::Baseline),
+                inset: Rect { top: length(2.0), ..Rect::auto() },
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(absolute).unwrap().location.y, 2.0);
+}
+
+#[test]
+fn grid_container_baseline_is_taken_from_its_first_row() {
+    let mut taffy = new_test_tree();
+    let first = boxed_leaf(&mut taffy, Rect { left: length(5.0), top: length(5.0), ..Rect::zero() }, 10.0, 10.0);
+    let second = boxed_leaf(&mut taffy, Rect::zero(), 10.0, 10.0);
+    let inner_grid = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![auto(), auto()],
+                margin: Rect { left: length(3.0), top: length(3.0), ..Rect::zero() },
+                ..Default::default()
+            },
+            &[first, second],
+        )
+        .unwrap();
+    let sibling = boxed_leaf(&mut taffy, Rect::zero(), 40.0, 40.0);
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![auto(), auto()],
+                align_items: Some(AlignItems::Baseline),
+                ..Default::default()
+            },
+            &[inner_grid, sibling],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The inner grid's baseline is the bottom of its first item's leaf (15px), plus its top margin
+    assert_eq!(taffy.layout(inner_grid).unwrap().location.y, 25.0);
+    assert_eq!(taffy.layout(sibling).unwrap().location.y, 0.0);
+}
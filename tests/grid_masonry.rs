@@ -0,0 +1,177 @@
+use taffy::prelude::*;
+use taffy::{DetailedLayoutInfo, Point};
+use taffy_test_helpers::new_test_tree;
+
+fn fixed_height(height: f32) -> Style {
+    Style { size: Size { width: auto(), height: length(height) }, ..Default::default() }
+}
+
+fn masonry_rows(column_count: u16) -> Style {
+    Style {
+        display: Display::Grid,
+        grid_template_columns: (0..column_count).map(|_| length(50.0)).collect(),
+        grid_template_rows: masonry(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn items_are_packed_into_the_shortest_track() {
+    let mut taffy = new_test_tree();
+    let children: Vec<_> =
+        [10.0, 30.0, 20.0, 15.0, 5.0].iter().map(|height| taffy.new_leaf(fixed_height(*height)).unwrap()).collect();
+    let root = taffy
+        .new_with_children(
+            Style { gap: Size { width: length(10.0), height: length(5.0) }, ..masonry_rows(3) },
+            &children,
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let location = |index: usize| taffy.layout(children[index]).unwrap().location;
+    assert_eq!(location(0), Point { x: 0.0, y: 0.0 });
+    assert_eq!(location(1), Point { x: 60.0, y: 0.0 });
+    assert_eq!(location(2), Point { x: 120.0, y: 0.0 });
+    // The first column is the shortest, followed by the third
+    assert_eq!(location(3), Point { x: 0.0, y: 15.0 });
+    assert_eq!(location(4), Point { x: 120.0, y: 25.0 });
+    assert_eq!(taffy.layout(children[0]).unwrap().size.width, 50.0);
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 170.0, height: 30.0 });
+}
+
+#[test]
+fn items_honour_their_placement_and_span_in_the_grid_axis() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(fixed_height(30.0)).unwrap();
+    let second = taffy.new_leaf(fixed_height(10.0)).unwrap();
+    // Placed into the last column even though the middle column is shorter
+    let placed = taffy.new_leaf(Style { grid_column: line(3), ..fixed_height(20.0) }).unwrap();
+    // Packed into the pair of columns whose tallest column is the shortest
+    let spanning = taffy.new_leaf(Style { grid_column: span(2), ..fixed_height(10.0) }).unwrap();
+    let root = taffy.new_with_children(masonry_rows(3), &[first, second, placed, spanning]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let rect = |node| {
+        let layout = taffy.layout(node).unwrap();
+        (layout.location.x, layout.location.y, layout.size.width, layout.size.height)
+    };
+    assert_eq!(rect(placed), (100.0, 0.0, 50.0, 20.0));
+    assert_eq!(rect(spanning), (50.0, 20.0, 100.0, 10.0));
+    assert_eq!(taffy.layout(root).unwrap().size.height, 30.0);
+}
+
+#[test]
+fn items_are_sized_by_the_tracks_of_the_grid_axis() {
+    let mut taffy = new_test_tree();
+    let wide = taffy
+        .new_leaf(Style { size: Size { width: length(80.0), height: length(10.0) }, ..Default::default() })
+        .unwrap();
+    let stretched = taffy.new_leaf(fixed_height(10.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![auto()],
+                grid_template_rows: masonry(),
+                ..Default::default()
+            },
+            &[wide, stretched],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The auto-placed items contribute to the size of the column they could be packed into
+    assert_eq!(taffy.layout(stretched).unwrap().size.width, 80.0);
+    assert_eq!(taffy.layout(stretched).unwrap().location.y, 10.0);
+}
+
+#[test]
+fn align_tracks_aligns_the_items_within_each_track() {
+    let mut taffy = new_test_tree();
+    let children: Vec<_> =
+        [40.0, 10.0, 10.0].iter().map(|height| taffy.new_leaf(fixed_height(*height)).unwrap()).collect();
+    let root = taffy
+        .new_with_children(
+            Style {
+                size: Size { width: auto(), height: length(60.0) },
+                align_tracks: Some(AlignContent::SpaceBetween),
+                ..masonry_rows(2)
+            },
+            &children,
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let y = |index: usize| taffy.layout(children[index]).unwrap().location.y;
+    assert_eq!(y(0), 0.0);
+    assert_eq!(y(1), 0.0);
+    assert_eq!(y(2), 50.0);
+
+    taffy
+        .set_style(
+            root,
+            Style {
+                size: Size { width: auto(), height: length(60.0) },
+                align_tracks: Some(AlignContent::Stretch),
+                ..masonry_rows(2)
+            },
+        )
+        .unwrap();
+    taffy.set_style(children[1], Style::default()).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // Free space is divided between the items in the track, and items with an auto height grow into it
+    assert_eq!(taffy.layout(children[1]).unwrap().size.height, 25.0);
+    assert_eq!(taffy.layout(children[2]).unwrap().location.y, 25.0);
+    assert_eq!(taffy.layout(children[2]).unwrap().size.height, 10.0);
+}
+
+#[test]
+fn columns_can_be_the_masonry_axis() {
+    let mut taffy = new_test_tree();
+    let fixed_width = |width: f32| Style { size: Size { width: length(width), height: auto() }, ..Default::default() };
+    let first = taffy.new_leaf(fixed_width(30.0)).unwrap();
+    let second = taffy.new_leaf(fixed_width(10.0)).unwrap();
+    let third = taffy.new_leaf(fixed_width(20.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                size: Size { width: length(100.0), height: auto() },
+                grid_template_columns: masonry(),
+                grid_template_rows: vec![length(20.0), length(20.0)],
+                justify_tracks: Some(AlignContent::End),
+                ..Default::default()
+            },
+            &[first, second, third],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The third item is packed after the second in the second row, and each row is aligned to the end of the container
+    let location = |node| taffy.layout(node).unwrap().location;
+    assert_eq!(location(first), Point { x: 70.0, y: 0.0 });
+    assert_eq!(location(second), Point { x: 70.0, y: 20.0 });
+    assert_eq!(location(third), Point { x: 80.0, y: 20.0 });
+    assert_eq!(taffy.layout(root).unwrap().size.height, 40.0);
+}
+
+#[test]
+fn detailed_grid_info_reports_the_running_position_of_each_track() {
+    let mut taffy = new_test_tree();
+    let children: Vec<_> =
+        [10.0, 30.0, 20.0, 15.0].iter().map(|height| taffy.new_leaf(fixed_height(*height)).unwrap()).collect();
+    let root = taffy.new_with_children(masonry_rows(3), &children).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let DetailedLayoutInfo::Grid(info) = taffy.detailed_layout_info(root) else { panic!("expected grid info") };
+    assert_eq!(info.masonry_running_positions, Some(vec![25.0, 30.0, 20.0]));
+    assert_eq!(info.columns.sizes, vec![50.0, 50.0, 50.0]);
+    assert_eq!(info.rows.sizes, vec![30.0]);
+}
@@ -0,0 +1,227 @@
+use taffy::prelude::*;
+use taffy::DefaultCheapStr;
+use taffy_test_helpers::new_test_tree;
+
+fn names(names: &[&str]) -> Vec<DefaultCheapStr> {
+    names.iter().map(|&name| name.into()).collect()
+}
+
+#[test]
+fn items_are_placed_into_named_areas() {
+    let mut taffy = new_test_tree();
+    let item_style =
+        |area: &str| Style { grid_row: named_area(area), grid_column: named_area(area), ..Default::default() };
+    let header = taffy.new_leaf(item_style("header")).unwrap();
+    let sidebar = taffy.new_leaf(item_style("sidebar")).unwrap();
+    let main = taffy.new_leaf(item_style("main")).unwrap();
+    let footer = taffy.new_leaf(item_style("footer")).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(100.0), length(200.0)],
+                grid_template_rows: vec![length(10.0), length(20.0), length(30.0)],
+                grid_template_areas: GridTemplateArea::parse(&["header header", "sidebar main", ". footer"]).unwrap(),
+                ..Default::default()
+            },
+            // Children are deliberately out of order so that auto-placement would put them elsewhere
+            &[footer, main, sidebar, header],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let rect = |node| {
+        let layout = taffy.layout(node).unwrap();
+        (layout.location.x, layout.location.y, layout.size.width, layout.size.height)
+    };
+    assert_eq!(rect(header), (0.0, 0.0, 300.0, 10.0));
+    assert_eq!(rect(sidebar), (0.0, 10.0, 100.0, 20.0));
+    assert_eq!(rect(main), (100.0, 10.0, 200.0, 20.0));
+    assert_eq!(rect(footer), (100.0, 30.0, 200.0, 30.0));
+}
+
+#[test]
+fn named_areas_extend_the_explicit_grid() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style { grid_row: named_area("b"), grid_column: named_area("b"), ..Default::default() })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(100.0)],
+                grid_template_rows: vec![length(10.0)],
+                grid_auto_columns: vec![length(50.0)],
+                grid_auto_rows: vec![length(5.0)],
+                grid_template_areas: GridTemplateArea::parse(&["a a .", "a a b"]).unwrap(),
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let layout = taffy.layout(child).unwrap();
+    assert_eq!((layout.location.x, layout.location.y), (150.0, 10.0));
+    assert_eq!((layout.size.width, layout.size.height), (50.0, 5.0));
+    assert_eq!(taffy.layout(root).unwrap().size.width, 200.0);
+}
+
+#[test]
+fn items_are_placed_at_named_lines() {
+    let mut taffy = new_test_tree();
+    let first = taffy
+        .new_leaf(Style {
+            grid_column: Line { start: named_line("gutter", 1), end: named_line("gutter", 2) },
+            ..Default::default()
+        })
+        .unwrap();
+    let last = taffy
+        .new_leaf(Style { grid_column: Line { start: named_line("gutter", -1), end: auto() }, ..Default::default() })
+        .unwrap();
+    let implicit = taffy
+        .new_leaf(Style { grid_column: Line { start: named_line("gutter", 4), end: auto() }, ..Default::default() })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(10.0), length(20.0), length(30.0)],
+                grid_template_column_names: vec![names(&["gutter"]), vec![], names(&["gutter"]), vec![]],
+                grid_auto_columns: vec![length(5.0)],
+                ..Default::default()
+            },
+            &[first, last, implicit],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let column = |node| {
+        let layout = taffy.layout(node).unwrap();
+        (layout.location.x, layout.size.width)
+    };
+    assert_eq!(column(first), (0.0, 30.0));
+    assert_eq!(column(last), (30.0, 30.0));
+    // There are only two lines named "gutter", so the fourth is the second implicit line after the explicit grid
+    assert_eq!(column(implicit), (70.0, 5.0));
+}
+
+#[test]
+fn named_spans_count_lines_with_the_name() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style { grid_column: Line { start: line(1), end: named_span("major", 2) }, ..Default::default() })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(10.0), length(10.0), length(10.0), length(10.0)],
+                grid_template_column_names: vec![
+                    names(&["major"]),
+                    vec![],
+                    names(&["major"]),
+                    vec![],
+                    names(&["major"]),
+                ],
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let layout = taffy.layout(child).unwrap();
+    assert_eq!((layout.location.x, layout.size.width), (0.0, 40.0));
+}
+
+#[test]
+fn line_names_account_for_repeated_tracks() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style { grid_column: Line { start: named_line("end", 1), end: auto() }, ..Default::default() })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(10.0), repeat(3, vec![length(20.0)]), length(30.0)],
+                grid_template_column_names: vec![vec![], vec![], names(&["end"])],
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let layout = taffy.layout(child).unwrap();
+    assert_eq!((layout.location.x, layout.size.width), (70.0, 30.0));
+}
+
+#[test]
+fn absolutely_positioned_items_can_be_placed_into_named_areas() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: zero(), right: zero(), top: zero(), bottom: zero() },
+            grid_row: named_area("main"),
+            grid_column: named_area("main"),
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(100.0), length(200.0)],
+                grid_template_rows: vec![length(10.0), length(20.0)],
+                grid_template_areas: GridTemplateArea::parse(&["top top", "side main"]).unwrap(),
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let layout = taffy.layout(child).unwrap();
+    assert_eq!((layout.location.x, layout.location.y), (100.0, 10.0));
+    assert_eq!((layout.size.width, layout.size.height), (200.0, 20.0));
+}
+
+#[test]
+fn grid_template_areas_parse_rectangular_areas() {
+    let areas = GridTemplateArea::<&str>::parse(&["a a .", "a a b", "... ... b"]).unwrap();
+    assert_eq!(
+        areas,
+        vec![
+            GridTemplateArea { name: "a", row_start: 1, row_end: 3, column_start: 1, column_end: 3 },
+            GridTemplateArea { name: "b", row_start: 2, row_end: 4, column_start: 3, column_end: 4 },
+        ]
+    );
+
+    // Rows with differing numbers of cells
+    assert!(GridTemplateArea::<&str>::parse(&["a a", "b"]).is_err());
+    // Non-rectangular area
+    assert!(GridTemplateArea::<&str>::parse(&["a a", "a b"]).is_err());
+    // Disjoint area
+    assert!(GridTemplateArea::<&str>::parse(&["a b a"]).is_err());
+    // Empty row
+    assert!(GridTemplateArea::<&str>::parse(&[""]).is_err());
+}
+
+#[test]
+fn grid_placements_with_static_names_are_copy() {
+    fn assert_copy<T: Copy>(_: T) {}
+    let placement: Line<GridPlacement<&'static str>> =
+        Line { start: GridPlacement::NamedArea("main"), end: GridPlacement::NamedSpan("main", 2) };
+    assert_copy(placement);
+    assert!(placement.is_definite());
+}
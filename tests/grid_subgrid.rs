@@ -0,0 +1,260 @@
+use taffy::prelude::*;
+use taffy::Point;
+use taffy_test_helpers::{fixed_size, new_test_tree};
+
+fn full_row() -> Line<GridPlacement> {
+    Line { start: line(1), end: line(-1) }
+}
+
+#[test]
+fn subgrid_items_share_the_parents_columns() {
+    let mut taffy = new_test_tree();
+    let mut form_row = |label_width: f32, input_width: f32| {
+        let label = taffy.new_leaf(fixed_size(label_width, 10.0)).unwrap();
+        let input = taffy.new_leaf(fixed_size(input_width, 20.0)).unwrap();
+        let row = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_column: full_row(),
+                    grid_template_columns: subgrid(),
+                    ..Default::default()
+                },
+                &[label, input],
+            )
+            .unwrap();
+        (row, label, input)
+    };
+    let (row0, label0, input0) = form_row(30.0, 100.0);
+    let (row1, label1, input1) = form_row(50.0, 80.0);
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![auto(), auto()],
+                gap: Size { width: length(10.0), height: zero() },
+                ..Default::default()
+            },
+            &[row0, row1],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The label column is as wide as the widest label in either subgrid, and the input column as the widest input
+    assert_eq!(taffy.layout(root).unwrap().size.width, 160.0);
+    for (row, label, input) in [(row0, label0, input0), (row1, label1, input1)] {
+        assert_eq!(taffy.layout(row).unwrap().size.width, 160.0);
+        assert_eq!(taffy.layout(label).unwrap().location.x, 0.0);
+        assert_eq!(taffy.layout(input).unwrap().location.x, 60.0);
+    }
+    assert_eq!(taffy.layout(row0).unwrap().location.y, 0.0);
+    assert_eq!(taffy.layout(row1).unwrap().location.y, 20.0);
+}
+
+#[test]
+fn subgrid_adopts_only_the_tracks_it_spans() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(Style::default()).unwrap();
+    let second = taffy.new_leaf(Style::default()).unwrap();
+    // Placed beyond the two adopted tracks, so clamped into the last of them
+    let clamped =
+        taffy.new_leaf(Style { grid_column: Line { start: line(5), end: auto() }, ..Default::default() }).unwrap();
+    let subgrid_node = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_column: Line { start: line(2), end: span(2) },
+                grid_template_columns: subgrid(),
+                grid_template_rows: vec![length(10.0)],
+                ..Default::default()
+            },
+            &[first, second, clamped],
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(10.0), length(20.0), length(30.0), length(40.0)],
+                gap: Size { width: length(5.0), height: zero() },
+                ..Default::default()
+            },
+            &[subgrid_node],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let column = |node| {
+        let layout = taffy.layout(node).unwrap();
+        (layout.location.x, layout.size.width)
+    };
+    assert_eq!(column(subgrid_node), (15.0, 55.0));
+    assert_eq!(column(first), (0.0, 20.0));
+    assert_eq!(column(second), (25.0, 30.0));
+    assert_eq!(column(clamped), (25.0, 30.0));
+    // Only one row of the subgrid's own template is used: no implicit rows were created for the clamped item
+    assert_eq!(taffy.layout(clamped).unwrap().location.y, 0.0);
+}
+
+#[test]
+fn subgrid_rows_are_sized_by_the_subgrids_items() {
+    let mut taffy = new_test_tree();
+    let sidebar = taffy.new_leaf(fixed_size(20.0, 15.0)).unwrap();
+    let header = taffy.new_leaf(fixed_size(20.0, 30.0)).unwrap();
+    let body = taffy.new_leaf(fixed_size(20.0, 40.0)).unwrap();
+    let card = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_row: Line { start: line(1), end: span(2) },
+                grid_template_rows: subgrid(),
+                ..Default::default()
+            },
+            &[header, body],
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![auto(), auto()],
+                grid_template_rows: vec![auto(), auto()],
+                ..Default::default()
+            },
+            &[card, sidebar],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(root).unwrap().size.height, 70.0);
+    assert_eq!(taffy.layout(card).unwrap().size.height, 70.0);
+    assert_eq!(taffy.layout(body).unwrap().location.y, 30.0);
+}
+
+#[test]
+fn nested_subgrid_items_contribute_to_the_outermost_grid() {
+    let mut taffy = new_test_tree();
+    let leaf = taffy
+        .new_leaf(Style {
+            grid_column: line(2),
+            size: Size { width: length(70.0), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let subgrid_style = || Style {
+        display: Display::Grid,
+        grid_column: full_row(),
+        grid_template_columns: subgrid(),
+        ..Default::default()
+    };
+    let inner = taffy.new_with_children(subgrid_style(), &[leaf]).unwrap();
+    let outer = taffy.new_with_children(subgrid_style(), &[inner]).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style { display: Display::Grid, grid_template_columns: vec![length(10.0), auto()], ..Default::default() },
+            &[outer],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(root).unwrap().size.width, 80.0);
+    assert_eq!(taffy.layout(leaf).unwrap().location.x, 10.0);
+}
+
+#[test]
+fn subgrid_margin_border_and_padding_are_extra_margin_on_its_edge_items() {
+    let mut taffy = new_test_tree();
+    let label = taffy.new_leaf(fixed_size(30.0, 10.0)).unwrap();
+    let input = taffy.new_leaf(fixed_size(100.0, 20.0)).unwrap();
+    let row = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_column: full_row(),
+                grid_template_columns: subgrid(),
+                padding: Rect { left: length(7.0), right: length(9.0), top: zero(), bottom: zero() },
+                border: Rect { left: length(1.0), right: length(1.0), top: length(2.0), bottom: length(2.0) },
+                ..Default::default()
+            },
+            &[label, input],
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![auto(), auto()],
+                gap: Size { width: length(10.0), height: zero() },
+                ..Default::default()
+            },
+            &[row],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The columns grow by the subgrid's border and padding at their edges of the subgrid: 30 + 8 and 100 + 10
+    assert_eq!(taffy.layout(root).unwrap().size.width, 158.0);
+    assert_eq!(taffy.layout(row).unwrap().size, Size { width: 158.0, height: 24.0 });
+    // The subgrid's items line up with the grid's columns, and fit within the subgrid's content box
+    assert_eq!(taffy.layout(label).unwrap().location, Point { x: 8.0, y: 2.0 });
+    assert_eq!(taffy.layout(label).unwrap().size.width, 30.0);
+    assert_eq!(taffy.layout(input).unwrap().location, Point { x: 48.0, y: 2.0 });
+    assert_eq!(taffy.layout(input).unwrap().size.width, 100.0);
+}
+
+#[test]
+fn subgrid_without_a_grid_parent_has_no_explicit_tracks() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(fixed_size(10.0, 10.0)).unwrap();
+    let second = taffy.new_leaf(fixed_size(10.0, 10.0)).unwrap();
+    let grid = taffy
+        .new_with_children(
+            Style { display: Display::Grid, grid_template_columns: subgrid(), ..Default::default() },
+            &[first, second],
+        )
+        .unwrap();
+    let root = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[grid]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The items are auto-placed into a single implicit column, as with `grid_template_columns: vec![]`
+    assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 10.0 });
+}
+
+#[test]
+fn subgrid_tracks_are_discarded_when_the_parent_stops_being_a_grid() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(fixed_size(10.0, 10.0)).unwrap();
+    let second = taffy.new_leaf(fixed_size(10.0, 10.0)).unwrap();
+    let subgrid_node = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_column: full_row(),
+                grid_template_columns: subgrid(),
+                ..Default::default()
+            },
+            &[first, second],
+        )
+        .unwrap();
+    let grid_style =
+        Style { display: Display::Grid, grid_template_columns: vec![length(40.0), length(40.0)], ..Default::default() };
+    let root = taffy.new_with_children(grid_style.clone(), &[subgrid_node]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 40.0, y: 0.0 });
+
+    taffy.set_style(root, Style { display: Display::Block, ..Default::default() }).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 10.0 });
+
+    taffy.set_style(root, grid_style).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 40.0, y: 0.0 });
+}
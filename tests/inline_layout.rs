@@ -0,0 +1,177 @@
+use taffy::prelude::*;
+use taffy::{InlineFragment, Point};
+use taffy_test_helpers::{compute_layout, text};
+
+fn block(width: f32) -> Style {
+    Style { display: Display::Block, size: Size { width: length(width), height: auto() }, ..Default::default() }
+}
+
+#[test]
+fn text_runs_are_broken_into_lines() {
+    let mut taffy = TaffyTree::new();
+    let run = text(&mut taffy, "aaa bbb ccc");
+    let root = taffy.new_with_children(block(100.0), &[run]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 100.0, height: 20.0 });
+    assert_eq!(taffy.layout(run).unwrap().size, Size { width: 70.0, height: 20.0 });
+    assert_eq!(
+        taffy.inline_fragments(run).unwrap(),
+        &[
+            InlineFragment {
+                line: 0,
+                location: Point::ZERO,
+                size: Size { width: 70.0, height: 10.0 },
+                baseline: 8.0,
+                text_start: 0,
+                text_end: 8,
+            },
+            InlineFragment {
+                line: 1,
+                location: Point { x: 0.0, y: 10.0 },
+                size: Size { width: 30.0, height: 10.0 },
+                baseline: 8.0,
+                text_start: 8,
+                text_end: 11,
+            },
+        ]
+    );
+}
+
+#[test]
+fn intrinsic_sizes_of_inline_content() {
+    let mut taffy = TaffyTree::new();
+    let run = text(&mut taffy, "aaa bbb ccc");
+    let root = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[run]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 110.0, height: 10.0 });
+
+    compute_layout(&mut taffy, root, Size::MIN_CONTENT);
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 30.0, height: 30.0 });
+}
+
+#[test]
+fn inline_runs_are_wrapped_in_anonymous_block_boxes() {
+    let mut taffy = TaffyTree::new();
+    let first = text(&mut taffy, "aa");
+    let block_child = taffy
+        .new_leaf(Style { size: Size { width: length(50.0), height: length(10.0) }, ..Default::default() })
+        .unwrap();
+    let last = text(&mut taffy, "bb");
+    let root = taffy
+        .new_with_children(Style { text_align: TextAlign::Center, ..block(100.0) }, &[first, block_child, last])
+        .unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    // Center only aligns inline-level content, so the block-level child stays at the start edge
+    assert_eq!(taffy.layout(first).unwrap().location, Point { x: 40.0, y: 0.0 });
+    assert_eq!(taffy.layout(block_child).unwrap().location, Point { x: 0.0, y: 10.0 });
+    assert_eq!(taffy.layout(last).unwrap().location, Point { x: 40.0, y: 20.0 });
+    assert_eq!(taffy.layout(root).unwrap().size.height, 30.0);
+}
+
+#[test]
+fn atomic_inlines_are_aligned_by_vertical_align() {
+    let mut taffy = TaffyTree::new();
+    let first = text(&mut taffy, "aa");
+    let inline_block = taffy
+        .new_leaf(Style {
+            display: Display::InlineBlock,
+            size: Size { width: length(20.0), height: length(30.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let top = taffy
+        .new_leaf_with_context(
+            Style { display: Display::Inline, vertical_align: VerticalAlign::Top, ..Default::default() },
+            "cc",
+        )
+        .unwrap();
+    let root = taffy.new_with_children(block(100.0), &[first, inline_block, top]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    // The inline-block has no baseline, so its bottom edge sits on the baseline of the line
+    assert_eq!(taffy.layout(root).unwrap().size.height, 32.0);
+    assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 22.0 });
+    assert_eq!(taffy.layout(inline_block).unwrap().location, Point { x: 20.0, y: 0.0 });
+    assert_eq!(taffy.layout(inline_block).unwrap().size, Size { width: 20.0, height: 30.0 });
+    assert_eq!(taffy.layout(top).unwrap().location, Point { x: 40.0, y: 0.0 });
+}
+
+#[test]
+fn inline_boxes_are_fragmented_across_lines() {
+    let mut taffy = TaffyTree::new();
+    let run = text(&mut taffy, "aaa bbb");
+    let inline_box = taffy
+        .new_with_children(
+            Style {
+                display: Display::Inline,
+                padding: Rect { left: length(5.0), right: length(5.0), top: zero(), bottom: zero() },
+                ..Default::default()
+            },
+            &[run],
+        )
+        .unwrap();
+    let root = taffy.new_with_children(block(60.0), &[inline_box]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    let box_layout = taffy.layout(inline_box).unwrap();
+    assert_eq!(box_layout.location, Point::ZERO);
+    assert_eq!(box_layout.size, Size { width: 35.0, height: 20.0 });
+    let box_fragments = taffy.inline_fragments(inline_box).unwrap();
+    assert_eq!(box_fragments.len(), 2);
+    assert_eq!(box_fragments[0].size, Size { width: 35.0, height: 10.0 });
+    assert_eq!(box_fragments[1].location, Point { x: 0.0, y: 10.0 });
+    assert_eq!(box_fragments[1].size, Size { width: 35.0, height: 10.0 });
+
+    // The text is positioned relative to the inline box, and its first fragment follows the box's start padding
+    assert_eq!(taffy.layout(run).unwrap().location, Point::ZERO);
+    let text_fragments = taffy.inline_fragments(run).unwrap();
+    assert_eq!(text_fragments[0].location, Point { x: 5.0, y: 0.0 });
+    assert_eq!(text_fragments[1].location, Point { x: 0.0, y: 10.0 });
+}
+
+#[test]
+fn right_to_left_lines_start_at_the_right_edge() {
+    let mut taffy = TaffyTree::new();
+    let first = text(&mut taffy, "aa");
+    let second = text(&mut taffy, "bbb");
+    let root = taffy.new_with_children(Style { direction: Direction::Rtl, ..block(100.0) }, &[first, second]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(first).unwrap().location, Point { x: 80.0, y: 0.0 });
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 50.0, y: 0.0 });
+}
+
+#[test]
+fn vertical_lines_are_stacked_from_the_right() {
+    let mut taffy = TaffyTree::new();
+    let run = text(&mut taffy, "aaa bbb ccc");
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                writing_mode: WritingMode::VerticalRl,
+                size: Size { width: auto(), height: length(100.0) },
+                ..Default::default()
+            },
+            &[run],
+        )
+        .unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 20.0, height: 100.0 });
+    assert_eq!(taffy.layout(run).unwrap().size, Size { width: 20.0, height: 70.0 });
+    let fragments = taffy.inline_fragments(run).unwrap();
+    assert_eq!(fragments[0].location, Point { x: 10.0, y: 0.0 });
+    assert_eq!(fragments[0].size, Size { width: 10.0, height: 70.0 });
+    assert_eq!(fragments[1].location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(fragments[1].size, Size { width: 10.0, height: 30.0 });
+}
@@ -0,0 +1,214 @@
+use taffy::prelude::*;
+use taffy::{DetailedLayoutInfo, DetailedMulticolInfo, MulticolFragment, Point};
+use taffy_test_helpers::compute_layout;
+
+fn multicol(column_count: Option<u16>, column_width: Dimension, width: Dimension) -> Style {
+    Style {
+        display: Display::Block,
+        column_count,
+        column_width,
+        gap: Size { width: length(10.0), height: zero() },
+        size: Size { width, height: auto() },
+        ..Default::default()
+    }
+}
+
+fn children(taffy: &mut TaffyTree<&'static str>, heights: &[f32]) -> Vec<NodeId> {
+    heights
+        .iter()
+        .map(|&height| {
+            taffy.new_leaf(Style { size: Size { width: auto(), height: length(height) }, ..Default::default() })
+        })
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+fn location(taffy: &TaffyTree<&'static str>, node: NodeId) -> Point<f32> {
+    taffy.layout(node).unwrap().location
+}
+
+fn multicol_info<'a>(taffy: &'a TaffyTree<&'static str>, node: NodeId) -> &'a DetailedMulticolInfo {
+    match taffy.detailed_layout_info(node) {
+        DetailedLayoutInfo::Multicol(info) => info,
+        _ => panic!("expected multi-column info"),
+    }
+}
+
+#[test]
+fn column_count_divides_the_content_box() {
+    let mut taffy = TaffyTree::new();
+    let items = children(&mut taffy, &[20.0; 6]);
+    let root = taffy.new_with_children(multicol(Some(3), auto(), length(320.0)), &items).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 320.0, height: 40.0 });
+    let expected = [(0.0, 0.0), (0.0, 20.0), (110.0, 0.0), (110.0, 20.0), (220.0, 0.0), (220.0, 20.0)];
+    for (item, (x, y)) in items.iter().zip(expected) {
+        assert_eq!(location(&taffy, *item), Point { x, y });
+        assert_eq!(taffy.layout(*item).unwrap().size.width, 100.0);
+    }
+}
+
+#[test]
+fn column_width_determines_the_column_count() {
+    let mut taffy = TaffyTree::new();
+    let items = children(&mut taffy, &[20.0; 3]);
+    let root = taffy.new_with_children(multicol(None, length(90.0), length(320.0)), &items).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    let info = multicol_info(&taffy, root);
+    assert_eq!(info.column_count, 3);
+    assert_eq!(info.column_width, 100.0);
+    assert_eq!(info.column_gap, 10.0);
+    assert_eq!(info.column_height, 20.0);
+    assert_eq!(location(&taffy, items[2]), Point { x: 220.0, y: 0.0 });
+}
+
+#[test]
+fn column_count_limits_the_columns_which_fit_the_column_width() {
+    let mut taffy = TaffyTree::new();
+    let items = children(&mut taffy, &[20.0; 3]);
+    let root = taffy.new_with_children(multicol(Some(2), length(90.0), length(320.0)), &items).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    let info = multicol_info(&taffy, root);
+    assert_eq!(info.column_count, 2);
+    assert_eq!(info.column_width, 155.0);
+    assert_eq!(taffy.layout(root).unwrap().size.height, 40.0);
+    assert_eq!(location(&taffy, items[2]), Point { x: 165.0, y: 0.0 });
+}
+
+#[test]
+fn column_height_is_balanced_between_children() {
+    let mut taffy = TaffyTree::new();
+    let items = children(&mut taffy, &[30.0, 10.0, 10.0, 10.0, 30.0]);
+    let root = taffy.new_with_children(multicol(Some(2), auto(), length(210.0)), &items).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(root).unwrap().size.height, 50.0);
+    let expected = [(0.0, 0.0), (0.0, 30.0), (0.0, 40.0), (110.0, 0.0), (110.0, 10.0)];
+    for (item, (x, y)) in items.iter().zip(expected) {
+        assert_eq!(location(&taffy, *item), Point { x, y });
+    }
+}
+
+#[test]
+fn content_which_does_not_fit_the_height_overflows_into_extra_columns() {
+    let mut taffy = TaffyTree::new();
+    let items = children(&mut taffy, &[20.0; 4]);
+    let style =
+        Style { size: Size { width: length(210.0), height: length(20.0) }, ..multicol(Some(2), auto(), auto()) };
+    let root = taffy.new_with_children(style, &items).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    let info = multicol_info(&taffy, root);
+    assert_eq!(info.column_count, 2);
+    assert_eq!(info.filled_column_count, 4);
+    assert_eq!(info.column_height, 20.0);
+    assert_eq!(location(&taffy, items[3]), Point { x: 330.0, y: 0.0 });
+    assert_eq!(taffy.layout(root).unwrap().content_size, Size { width: 430.0, height: 20.0 });
+}
+
+#[test]
+fn lines_are_broken_across_columns() {
+    let mut taffy = TaffyTree::new();
+    let run = taffy
+        .new_leaf_with_context(Style { display: Display::Inline, ..Default::default() }, "aaa bbb ccc ddd")
+        .unwrap();
+    let root = taffy.new_with_children(multicol(Some(2), auto(), length(70.0)), &[run]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(root).unwrap().size.height, 20.0);
+    let fragments = taffy.inline_fragments(run).unwrap();
+    let locations: Vec<Point<f32>> = fragments.iter().map(|fragment| fragment.location).collect();
+    assert_eq!(
+        locations,
+        [Point { x: 0.0, y: 0.0 }, Point { x: 0.0, y: 10.0 }, Point { x: 40.0, y: 0.0 }, Point { x: 40.0, y: 10.0 }]
+    );
+}
+
+#[test]
+fn children_taller_than_a_column_are_sliced_into_fragments() {
+    let mut taffy = TaffyTree::new();
+    let items = children(&mut taffy, &[60.0, 20.0]);
+    let root = taffy.new_with_children(multicol(Some(2), auto(), length(210.0)), &items).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(root).unwrap().size.height, 40.0);
+    assert_eq!(location(&taffy, items[0]), Point { x: 0.0, y: 0.0 });
+    assert_eq!(location(&taffy, items[1]), Point { x: 110.0, y: 20.0 });
+
+    let info = multicol_info(&taffy, root);
+    assert_eq!(
+        info.items[0].fragments,
+        [
+            MulticolFragment {
+                column: 0,
+                location: Point { x: 0.0, y: 0.0 },
+                size: Size { width: 100.0, height: 40.0 },
+                source_location: Point { x: 0.0, y: 0.0 },
+            },
+            MulticolFragment {
+                column: 1,
+                location: Point { x: 110.0, y: 0.0 },
+                size: Size { width: 100.0, height: 20.0 },
+                source_location: Point { x: 0.0, y: 40.0 },
+            },
+        ]
+    );
+    assert_eq!(info.items[1].fragments.len(), 1);
+    assert_eq!(info.items[1].fragments[0].column, 1);
+}
+
+#[test]
+fn columns_are_placed_from_the_right_in_rtl_containers() {
+    let mut taffy = TaffyTree::new();
+    let items = children(&mut taffy, &[20.0, 20.0]);
+    let style = Style { direction: Direction::Rtl, ..multicol(Some(2), auto(), length(210.0)) };
+    let root = taffy.new_with_children(style, &items).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(location(&taffy, items[0]), Point { x: 110.0, y: 0.0 });
+    assert_eq!(location(&taffy, items[1]), Point { x: 0.0, y: 0.0 });
+}
+
+#[test]
+fn max_content_width_fits_each_column_to_its_content() {
+    let mut taffy = TaffyTree::new();
+    let item_style = Style { size: Size { width: length(50.0), height: length(20.0) }, ..Default::default() };
+    let items = [taffy.new_leaf(item_style.clone()).unwrap(), taffy.new_leaf(item_style).unwrap()];
+    let root = taffy.new_with_children(multicol(Some(2), auto(), auto()), &items).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 110.0, height: 20.0 });
+    assert_eq!(location(&taffy, items[1]), Point { x: 60.0, y: 0.0 });
+}
+
+#[test]
+fn columns_are_stacked_vertically_in_vertical_writing_modes() {
+    let mut taffy = TaffyTree::new();
+    let item_style = Style { size: Size { width: length(20.0), height: auto() }, ..Default::default() };
+    let items = [taffy.new_leaf(item_style.clone()).unwrap(), taffy.new_leaf(item_style).unwrap()];
+    let style = Style {
+        writing_mode: WritingMode::VerticalLr,
+        size: Size { width: auto(), height: length(210.0) },
+        ..multicol(Some(2), auto(), auto())
+    };
+    let root = taffy.new_with_children(style, &items).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 20.0, height: 210.0 });
+    assert_eq!(location(&taffy, items[0]), Point { x: 0.0, y: 0.0 });
+    assert_eq!(location(&taffy, items[1]), Point { x: 0.0, y: 110.0 });
+    assert_eq!(taffy.layout(items[1]).unwrap().size, Size { width: 20.0, height: 100.0 });
+}
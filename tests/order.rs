@@ -0,0 +1,97 @@
+use taffy::prelude::*;
+use taffy_test_helpers::new_test_tree;
+
+#[test]
+fn flex_items_are_laid_out_in_order_modified_document_order() {
+    let mut taffy = new_test_tree();
+    let child_style = |width: f32, order: i32| Style {
+        size: Size { width: length(width), height: auto() },
+        order,
+        ..Default::default()
+    };
+    let child0 = taffy.new_leaf(child_style(10.0, 1)).unwrap();
+    let child1 = taffy.new_leaf(child_style(20.0, -1)).unwrap();
+    let child2 = taffy.new_leaf(child_style(30.0, 0)).unwrap();
+    let child3 = taffy.new_leaf(child_style(40.0, 0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                size: Size { width: length(200.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child0, child1, child2, child3],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child1).unwrap().location.x, 0.0);
+    assert_eq!(taffy.layout(child2).unwrap().location.x, 20.0);
+    assert_eq!(taffy.layout(child3).unwrap().location.x, 50.0);
+    assert_eq!(taffy.layout(child0).unwrap().location.x, 90.0);
+
+    // Paint order is unaffected by the `order` style
+    assert_eq!(taffy.layout(child0).unwrap().order, 0);
+    assert_eq!(taffy.layout(child1).unwrap().order, 1);
+    assert_eq!(taffy.layout(child2).unwrap().order, 2);
+    assert_eq!(taffy.layout(child3).unwrap().order, 3);
+}
+
+#[test]
+fn flex_order_applies_to_wrapped_lines() {
+    let mut taffy = new_test_tree();
+    let child_style =
+        |order: i32| Style { size: Size { width: length(60.0), height: length(10.0) }, order, ..Default::default() };
+    let child0 = taffy.new_leaf(child_style(2)).unwrap();
+    let child1 = taffy.new_leaf(child_style(1)).unwrap();
+    let child2 = taffy.new_leaf(child_style(0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_wrap: FlexWrap::Wrap,
+                align_content: Some(AlignContent::Start),
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child0, child1, child2],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child2).unwrap().location.y, 0.0);
+    assert_eq!(taffy.layout(child1).unwrap().location.y, 10.0);
+    assert_eq!(taffy.layout(child0).unwrap().location.y, 20.0);
+}
+
+#[test]
+fn grid_items_are_auto_placed_in_order_modified_document_order() {
+    let mut taffy = new_test_tree();
+    let child0 = taffy.new_leaf(Style { order: 1, ..Default::default() }).unwrap();
+    let child1 = taffy.new_leaf(Style { order: -1, ..Default::default() }).unwrap();
+    let child2 = taffy.new_leaf(Style::default()).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(40.0), length(40.0), length(40.0)],
+                grid_template_rows: vec![length(40.0)],
+                ..Default::default()
+            },
+            &[child0, child1, child2],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child1).unwrap().location.x, 0.0);
+    assert_eq!(taffy.layout(child2).unwrap().location.x, 40.0);
+    assert_eq!(taffy.layout(child0).unwrap().location.x, 80.0);
+
+    // Paint order is unaffected by the `order` style
+    assert_eq!(taffy.layout(child0).unwrap().order, 0);
+    assert_eq!(taffy.layout(child1).unwrap().order, 1);
+    assert_eq!(taffy.layout(child2).unwrap().order, 2);
+}
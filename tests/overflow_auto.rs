@@ -0,0 +1,76 @@
+use taffy::prelude::*;
+use taffy::{Overflow, Point};
+
+fn container_style(display: Display, overflow: Point<Overflow>) -> Style {
+    Style {
+        display,
+        flex_direction: FlexDirection::Column,
+        overflow,
+        scrollbar_width: Size { width: 10.0, height: 10.0 },
+        size: Size { width: length(100.0), height: length(100.0) },
+        ..Default::default()
+    }
+}
+
+fn child_style(width: Dimension, height: f32) -> Style {
+    Style { size: Size { width, height: length(height) }, flex_shrink: 0.0, ..Default::default() }
+}
+
+fn auto_y() -> Point<Overflow> {
+    Point { x: Overflow::Visible, y: Overflow::Auto }
+}
+
+#[test]
+fn no_scrollbar_is_reserved_if_the_content_fits() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(child_style(auto(), 50.0)).unwrap();
+        let root = taffy.new_with_children(container_style(display, auto_y()), &[child]).unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().scrollbar_size, Size::ZERO, "{display:?}");
+        assert_eq!(taffy.layout(child).unwrap().size.width, 100.0, "{display:?}");
+    }
+}
+
+#[test]
+fn a_scrollbar_is_reserved_if_the_content_overflows() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(child_style(auto(), 200.0)).unwrap();
+        let root = taffy.new_with_children(container_style(display, auto_y()), &[child]).unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().scrollbar_size, Size { width: 10.0, height: 0.0 }, "{display:?}");
+        assert_eq!(taffy.layout(child).unwrap().size.width, 90.0, "{display:?}");
+    }
+}
+
+#[test]
+fn a_scrollbar_in_one_axis_can_cause_overflow_in_the_other() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy.new_leaf(child_style(length(95.0), 200.0)).unwrap();
+    let root = taffy
+        .new_with_children(container_style(Display::Block, Point { x: Overflow::Auto, y: Overflow::Auto }), &[child])
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The vertical scrollbar reduces the width available to the child to 90, so it overflows horizontally too
+    assert_eq!(taffy.layout(root).unwrap().scrollbar_size, Size { width: 10.0, height: 10.0 });
+}
+
+#[test]
+fn scrollbars_of_auto_overflow_items_are_reserved_within_their_parent() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let grandchild = taffy.new_leaf(child_style(auto(), 200.0)).unwrap();
+    let child = taffy.new_with_children(container_style(Display::Flex, auto_y()), &[grandchild]).unwrap();
+    let root = taffy.new_with_children(Style { display: Display::Grid, ..Default::default() }, &[child]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().scrollbar_size, Size { width: 10.0, height: 0.0 });
+    assert_eq!(taffy.layout(grandchild).unwrap().size.width, 90.0);
+}
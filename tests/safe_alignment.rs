@@ -0,0 +1,158 @@
+use taffy::prelude::*;
+use taffy::Point;
+use taffy_test_helpers::{fixed_size, new_test_tree};
+
+#[test]
+fn flex_safe_justify_content_falls_back_to_start_on_overflow() {
+    let mut taffy = new_test_tree();
+    let child = taffy.new_leaf(fixed_size(80.0, 10.0)).unwrap();
+    let root_style = |safety: AlignmentSafety| Style {
+        display: Display::Flex,
+        size: Size { width: length(50.0), height: length(50.0) },
+        justify_content: Some(JustifyContent::Center),
+        justify_content_safety: safety,
+        ..Default::default()
+    };
+    let root = taffy.new_with_children(root_style(AlignmentSafety::Unsafe), &[child]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().location.x, -15.0);
+
+    taffy.set_style(root, root_style(AlignmentSafety::Safe)).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().location.x, 0.0);
+}
+
+#[test]
+fn flex_safe_justify_content_falls_back_to_the_inline_start_edge_in_rtl() {
+    let mut taffy = new_test_tree();
+    let child = taffy.new_leaf(fixed_size(80.0, 10.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                direction: Direction::Rtl,
+                size: Size { width: length(50.0), height: length(50.0) },
+                justify_content: Some(JustifyContent::Center),
+                justify_content_safety: AlignmentSafety::Safe,
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The item overflows the left (inline-end) edge of the container
+    assert_eq!(taffy.layout(child).unwrap().location.x, -30.0);
+}
+
+#[test]
+fn flex_safe_align_items_falls_back_to_start_on_overflow() {
+    let mut taffy = new_test_tree();
+    let inherited = taffy.new_leaf(fixed_size(10.0, 40.0)).unwrap();
+    let overridden =
+        taffy.new_leaf(Style { align_self_safety: Some(AlignmentSafety::Unsafe), ..fixed_size(10.0, 40.0) }).unwrap();
+    let fits = taffy.new_leaf(fixed_size(10.0, 10.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                size: Size { width: length(50.0), height: length(20.0) },
+                align_items: Some(AlignItems::Center),
+                align_items_safety: AlignmentSafety::Safe,
+                ..Default::default()
+            },
+            &[inherited, overridden, fits],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(inherited).unwrap().location.y, 0.0);
+    assert_eq!(taffy.layout(overridden).unwrap().location.y, -10.0);
+    assert_eq!(taffy.layout(fits).unwrap().location.y, 5.0);
+}
+
+#[test]
+fn flex_safe_align_content_falls_back_to_start_on_overflow() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(fixed_size(40.0, 20.0)).unwrap();
+    let second = taffy.new_leaf(fixed_size(40.0, 20.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_wrap: FlexWrap::Wrap,
+                size: Size { width: length(50.0), height: length(30.0) },
+                align_content: Some(AlignContent::End),
+                align_content_safety: AlignmentSafety::Safe,
+                ..Default::default()
+            },
+            &[first, second],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(first).unwrap().location.y, 0.0);
+    assert_eq!(taffy.layout(second).unwrap().location.y, 20.0);
+}
+
+#[test]
+fn grid_safe_content_alignment_falls_back_to_start_on_overflow() {
+    let mut taffy = new_test_tree();
+    let child = taffy.new_leaf(Style::default()).unwrap();
+    let root_style = |safety: AlignmentSafety| Style {
+        display: Display::Grid,
+        size: Size { width: length(50.0), height: length(20.0) },
+        grid_template_columns: vec![length(80.0)],
+        grid_template_rows: vec![length(40.0)],
+        align_content: Some(AlignContent::Center),
+        justify_content: Some(JustifyContent::End),
+        align_content_safety: safety,
+        justify_content_safety: safety,
+        ..Default::default()
+    };
+    let root = taffy.new_with_children(root_style(AlignmentSafety::Unsafe), &[child]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().location, Point { x: -30.0, y: -10.0 });
+
+    taffy.set_style(root, root_style(AlignmentSafety::Safe)).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().location, Point { x: 0.0, y: 0.0 });
+}
+
+#[test]
+fn grid_safe_self_alignment_falls_back_to_start_on_overflow() {
+    let mut taffy = new_test_tree();
+    let inherited = taffy.new_leaf(fixed_size(80.0, 10.0)).unwrap();
+    let overridden = taffy
+        .new_leaf(Style {
+            justify_self: Some(AlignSelf::End),
+            justify_self_safety: Some(AlignmentSafety::Safe),
+            align_self: Some(AlignSelf::Center),
+            ..fixed_size(80.0, 40.0)
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(50.0)],
+                grid_template_rows: vec![length(20.0), length(20.0)],
+                justify_items: Some(AlignItems::Center),
+                justify_items_safety: AlignmentSafety::Safe,
+                ..Default::default()
+            },
+            &[inherited, overridden],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(inherited).unwrap().location.x, 0.0);
+    // Only the item's inline axis alignment is safe
+    assert_eq!(taffy.layout(overridden).unwrap().location, Point { x: 0.0, y: 10.0 });
+}
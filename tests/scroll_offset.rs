@@ -0,0 +1,91 @@
+use taffy::prelude::*;
+use taffy::{Overflow, Point};
+use taffy_test_helpers::fixed_size;
+
+/// A 100x100 scroll container (without scrollbars) whose only child is a 300x250 node
+fn scroller_tree(overflow: Overflow) -> (TaffyTree<()>, NodeId, NodeId) {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy.new_leaf(fixed_size(300.0, 250.0)).unwrap();
+    let scroller = taffy
+        .new_with_children(
+            Style {
+                overflow: Point { x: overflow, y: overflow },
+                scrollbar_width: Size::ZERO,
+                ..fixed_size(100.0, 100.0)
+            },
+            &[child],
+        )
+        .unwrap();
+    (taffy, scroller, child)
+}
+
+#[test]
+fn scroll_offsets_are_clamped_to_the_scrollable_range() {
+    let (mut taffy, scroller, _) = scroller_tree(Overflow::Scroll);
+    taffy.set_scroll_offset(scroller, Point { x: 500.0, y: -10.0 }).unwrap();
+
+    taffy.compute_layout(scroller, Size::MAX_CONTENT).unwrap();
+
+    // The requested offset is kept, and the clamped offset is reported in the layout
+    assert_eq!(taffy.scroll_offset(scroller).unwrap(), Point { x: 500.0, y: -10.0 });
+    assert_eq!(taffy.layout(scroller).unwrap().scroll_offset, Point { x: 200.0, y: 0.0 });
+}
+
+#[test]
+fn children_report_their_scrolled_location() {
+    let (mut taffy, scroller, child) = scroller_tree(Overflow::Scroll);
+    taffy.set_scroll_offset(scroller, Point { x: 20.0, y: 30.0 }).unwrap();
+
+    taffy.compute_layout(scroller, Size::MAX_CONTENT).unwrap();
+
+    let layout = taffy.layout(child).unwrap();
+    assert_eq!(layout.location, Point::ZERO);
+    assert_eq!(layout.scrolled_location, Point { x: -20.0, y: -30.0 });
+}
+
+#[test]
+fn nodes_which_are_not_scroll_containers_are_not_scrolled() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let grandchild = taffy.new_leaf(fixed_size(300.0, 250.0)).unwrap();
+    let child = taffy.new_with_children(fixed_size(100.0, 100.0), &[grandchild]).unwrap();
+    let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+    taffy.set_scroll_offset(child, Point { x: 20.0, y: 30.0 }).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().scroll_offset, Point::ZERO);
+    assert_eq!(taffy.layout(grandchild).unwrap().scrolled_location, Point::ZERO);
+}
+
+#[test]
+fn sticky_offsets_use_the_clamped_scroll_offset() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let header = taffy
+        .new_leaf(Style {
+            position: Position::Sticky,
+            inset: Rect { left: auto(), right: auto(), top: zero(), bottom: auto() },
+            ..fixed_size(50.0, 10.0)
+        })
+        .unwrap();
+    let content = taffy.new_leaf(fixed_size(50.0, 150.0)).unwrap();
+    let scroller = taffy
+        .new_with_children(
+            Style {
+                flex_direction: FlexDirection::Column,
+                overflow: Point { x: Overflow::Hidden, y: Overflow::Scroll },
+                scrollbar_width: Size::ZERO,
+                ..fixed_size(50.0, 100.0)
+            },
+            &[header, content],
+        )
+        .unwrap();
+    taffy.set_scroll_offset(scroller, Point { x: 0.0, y: 1000.0 }).unwrap();
+
+    taffy.compute_layout(scroller, Size::MAX_CONTENT).unwrap();
+
+    // The content can only be scrolled by 60, so the header sticks to the top of the scrollport
+    assert_eq!(taffy.layout(scroller).unwrap().scroll_offset, Point { x: 0.0, y: 60.0 });
+    let layout = taffy.layout(header).unwrap();
+    assert_eq!(layout.sticky_offset, Point { x: 0.0, y: 60.0 });
+    assert_eq!(layout.scrolled_location, Point::ZERO);
+}
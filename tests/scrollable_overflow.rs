@@ -0,0 +1,125 @@
+#![cfg(feature = "content_size")]
+use taffy::prelude::*;
+use taffy::{Overflow, Point};
+use taffy_test_helpers::fixed_size;
+
+fn scrollable_overflow(taffy: &TaffyTree<()>, node: NodeId) -> (Point<f32>, Size<f32>) {
+    let layout = taffy.layout(node).unwrap();
+    (layout.scrollable_overflow_location, layout.scrollable_overflow_size)
+}
+
+#[test]
+fn overflow_past_the_start_edges_is_included() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy
+        .new_leaf(Style {
+            margin: Rect { left: length(-20.0), right: zero(), top: length(-10.0), bottom: zero() },
+            ..fixed_size(50.0, 50.0)
+        })
+        .unwrap();
+    let root = taffy.new_with_children(fixed_size(100.0, 100.0), &[child]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(scrollable_overflow(&taffy, root), (Point { x: -20.0, y: -10.0 }, Size { width: 120.0, height: 110.0 }));
+}
+
+#[test]
+fn row_reverse_overflow_is_included() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy.new_leaf(fixed_size(150.0, 20.0)).unwrap();
+    let root = taffy
+        .new_with_children(Style { flex_direction: FlexDirection::RowReverse, ..fixed_size(100.0, 100.0) }, &[child])
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().location.x, -50.0);
+    assert_eq!(scrollable_overflow(&taffy, root), (Point { x: -50.0, y: 0.0 }, Size { width: 150.0, height: 100.0 }));
+}
+
+#[test]
+fn in_flow_content_of_scroll_containers_is_followed_by_their_end_padding() {
+    let padding = Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: length(10.0) };
+    for (overflow, width) in [(Overflow::Visible, 210.0), (Overflow::Scroll, 220.0)] {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(fixed_size(200.0, 50.0)).unwrap();
+        let node = taffy
+            .new_with_children(
+                Style {
+                    overflow: Point { x: overflow, y: overflow },
+                    scrollbar_width: Size::ZERO,
+                    padding,
+                    ..fixed_size(100.0, 100.0)
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(scrollable_overflow(&taffy, node), (Point::ZERO, Size { width, height: 100.0 }), "{overflow:?}");
+    }
+}
+
+#[test]
+fn clipped_content_does_not_overflow_its_ancestors() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let grandchild = taffy.new_leaf(fixed_size(300.0, 300.0)).unwrap();
+    let child = taffy
+        .new_with_children(
+            Style { overflow: Point { x: Overflow::Hidden, y: Overflow::Visible }, ..fixed_size(50.0, 50.0) },
+            &[grandchild],
+        )
+        .unwrap();
+    let root = taffy.new_with_children(fixed_size(100.0, 100.0), &[child]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(scrollable_overflow(&taffy, root), (Point::ZERO, Size { width: 100.0, height: 300.0 }));
+}
+
+#[test]
+fn absolute_nodes_contribute_to_the_overflow_of_their_containing_block() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let absolute = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: length(-30.0), right: auto(), top: length(0.0), bottom: auto() },
+            ..fixed_size(10.0, 10.0)
+        })
+        .unwrap();
+    let parent =
+        taffy.new_with_children(Style { position: Position::Static, ..fixed_size(50.0, 50.0) }, &[absolute]).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                padding: Rect { left: length(20.0), right: zero(), top: zero(), bottom: zero() },
+                ..fixed_size(100.0, 100.0)
+            },
+            &[parent],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The node is placed 30 to the left of the root's padding box, so it overflows the root but not its parent
+    assert_eq!(scrollable_overflow(&taffy, parent), (Point::ZERO, Size { width: 50.0, height: 50.0 }));
+    assert_eq!(scrollable_overflow(&taffy, root), (Point { x: -30.0, y: 0.0 }, Size { width: 130.0, height: 100.0 }));
+}
+
+#[test]
+fn scrollable_overflow_is_rounded_like_the_border_box() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy
+        .new_leaf(Style {
+            margin: Rect { left: length(-10.4), right: zero(), top: zero(), bottom: zero() },
+            ..fixed_size(50.6, 20.0)
+        })
+        .unwrap();
+    let root = taffy.new_with_children(fixed_size(100.0, 100.0), &[child]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(scrollable_overflow(&taffy, root), (Point { x: -10.0, y: 0.0 }, Size { width: 110.0, height: 100.0 }));
+}
@@ -0,0 +1,88 @@
+use taffy::prelude::*;
+use taffy::{Overflow, Point, ScrollbarGutter};
+
+fn container_style(display: Display, overflow: Overflow, scrollbar_gutter: ScrollbarGutter) -> Style {
+    Style {
+        display,
+        overflow: Point { x: Overflow::Visible, y: overflow },
+        scrollbar_width: Size { width: 10.0, height: 10.0 },
+        scrollbar_gutter,
+        size: Size { width: length(100.0), height: length(100.0) },
+        ..Default::default()
+    }
+}
+
+fn child_style(height: f32) -> Style {
+    Style { size: Size { width: auto(), height: length(height) }, flex_grow: 1.0, ..Default::default() }
+}
+
+#[test]
+fn stable_gutter_is_reserved_whether_or_not_the_content_overflows() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        for height in [50.0, 200.0] {
+            let mut taffy: TaffyTree<()> = TaffyTree::new();
+            let child = taffy.new_leaf(child_style(height)).unwrap();
+            let root = taffy
+                .new_with_children(container_style(display, Overflow::Auto, ScrollbarGutter::Stable), &[child])
+                .unwrap();
+
+            taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+            let scrollbar_width = if height > 100.0 { 10.0 } else { 0.0 };
+            assert_eq!(taffy.layout(root).unwrap().scrollbar_size.width, scrollbar_width, "{display:?}");
+            assert_eq!(taffy.layout(child).unwrap().size.width, 90.0, "{display:?}");
+        }
+    }
+}
+
+#[test]
+fn stable_gutter_is_not_reserved_for_nodes_which_are_not_scroll_containers() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy.new_leaf(child_style(50.0)).unwrap();
+    let root = taffy
+        .new_with_children(container_style(Display::Block, Overflow::Visible, ScrollbarGutter::Stable), &[child])
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().size.width, 100.0);
+}
+
+#[test]
+fn stable_both_edges_gutter_is_reserved_on_both_inline_edges() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(child_style(50.0)).unwrap();
+        let root = taffy
+            .new_with_children(container_style(display, Overflow::Hidden, ScrollbarGutter::StableBothEdges), &[child])
+            .unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().location.x, 10.0, "{display:?}");
+        assert_eq!(taffy.layout(child).unwrap().size.width, 80.0, "{display:?}");
+    }
+}
+
+#[test]
+fn scrollbars_can_have_a_different_width_in_each_axis() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy.new_leaf(Style::default()).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                overflow: Point { x: Overflow::Scroll, y: Overflow::Scroll },
+                scrollbar_width: Size { width: 12.0, height: 4.0 },
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(root).unwrap().scrollbar_size, Size { width: 12.0, height: 4.0 });
+    assert_eq!(taffy.layout(child).unwrap().size, Size { width: 88.0, height: 96.0 });
+}
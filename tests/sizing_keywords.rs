@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod sizing_keywords {
+    use taffy::prelude::*;
+    use taffy::Point;
+    use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext, WritingMode};
+
+    /// Text which is 40 wide at its min-content size and 90 wide at its max-content size
+    const TEXT: TestNodeContext = TestNodeContext::ahem_text("HH\u{200b}HHHH\u{200b}HHH", WritingMode::Horizontal);
+
+    fn text_style(width: Dimension) -> Style {
+        Style { size: Size { width, height: auto() }, ..Default::default() }
+    }
+
+    #[test]
+    fn leaf_widths_are_resolved_by_measuring_content() {
+        let mut taffy = new_test_tree();
+        let min_content = taffy.new_leaf_with_context(text_style(Dimension::min_content()), TEXT).unwrap();
+        let max_content = taffy.new_leaf_with_context(text_style(Dimension::max_content()), TEXT).unwrap();
+        let fit_content = taffy.new_leaf_with_context(text_style(Dimension::fit_content_px(60.0)), TEXT).unwrap();
+        let fit_content_wide =
+            taffy.new_leaf_with_context(text_style(Dimension::fit_content_percent(1.0)), TEXT).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[min_content, max_content, fit_content, fit_content_wide],
+            )
+            .unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(min_content).unwrap().size, Size { width: 40.0, height: 30.0 });
+        assert_eq!(taffy.layout(max_content).unwrap().size, Size { width: 90.0, height: 10.0 });
+        assert_eq!(taffy.layout(fit_content).unwrap().size, Size { width: 60.0, height: 20.0 });
+        assert_eq!(taffy.layout(fit_content_wide).unwrap().size, Size { width: 90.0, height: 10.0 });
+    }
+
+    #[test]
+    fn stretch_fills_the_available_space_minus_margins() {
+        let mut taffy = new_test_tree();
+        let child = taffy
+            .new_leaf(Style {
+                size: Size { width: Dimension::stretch(), height: length(10.0) },
+                margin: Rect { left: length(10.0), right: length(20.0), top: zero(), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    size: Size { width: length(200.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(child).unwrap().location.x, 10.0);
+        assert_eq!(taffy.layout(child).unwrap().size.width, 170.0);
+    }
+
+    #[test]
+    fn flex_items_with_keyword_sizes_are_not_stretched() {
+        let mut taffy = new_test_tree();
+        let row_item = taffy.new_leaf_with_context(text_style(Dimension::max_content()), TEXT).unwrap();
+        let row = taffy
+            .new_with_children(
+                Style { size: Size { width: length(200.0), height: auto() }, ..Default::default() },
+                &[row_item],
+            )
+            .unwrap();
+        let column_item = taffy.new_leaf_with_context(text_style(Dimension::min_content()), TEXT).unwrap();
+        let column = taffy
+            .new_with_children(
+                Style {
+                    flex_direction: FlexDirection::Column,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[column_item],
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { flex_direction: FlexDirection::Column, ..Default::default() }, &[row, column])
+            .unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(row_item).unwrap().size, Size { width: 90.0, height: 10.0 });
+        assert_eq!(taffy.layout(column_item).unwrap().size, Size { width: 40.0, height: 30.0 });
+    }
+
+    #[test]
+    fn grid_items_with_keyword_sizes_are_not_stretched() {
+        let mut taffy = new_test_tree();
+        let child = taffy.new_leaf_with_context(text_style(Dimension::max_content()), TEXT).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size { width: length(200.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        // The auto height is still stretched
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 0.0, y: 0.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 90.0, height: 100.0 });
+    }
+
+    #[test]
+    fn root_width_is_resolved_by_measuring_content() {
+        let mut taffy = new_test_tree();
+        let child = taffy.new_leaf_with_context(Style::default(), TEXT).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: Dimension::fit_content_px(70.0), height: auto() },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy
+            .compute_layout_with_measure(
+                root,
+                Size { width: AvailableSpace::Definite(300.0), height: AvailableSpace::Definite(300.0) },
+                test_measure_function,
+            )
+            .unwrap();
+
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 70.0, height: 20.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 70.0, height: 20.0 });
+    }
+
+    #[test]
+    fn keyword_min_and_max_sizes_clamp_the_size() {
+        let mut taffy = new_test_tree();
+        let min_size = taffy
+            .new_leaf_with_context(
+                Style {
+                    size: Size { width: length(10.0), height: auto() },
+                    min_size: Size { width: Dimension::max_content(), height: auto() },
+                    ..Default::default()
+                },
+                TEXT,
+            )
+            .unwrap();
+        let max_size = taffy
+            .new_leaf_with_context(
+                Style { max_size: Size { width: Dimension::min_content(), height: auto() }, ..Default::default() },
+                TEXT,
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[min_size, max_size],
+            )
+            .unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(min_size).unwrap().size, Size { width: 90.0, height: 10.0 });
+        assert_eq!(taffy.layout(max_size).unwrap().size, Size { width: 40.0, height: 30.0 });
+    }
+
+    #[test]
+    fn absolutely_positioned_nodes_resolve_keyword_sizes() {
+        let mut taffy = new_test_tree();
+        let popover = taffy
+            .new_leaf_with_context(
+                Style {
+                    position: Position::Absolute,
+                    inset: Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: auto() },
+                    size: Size { width: Dimension::max_content(), height: auto() },
+                    ..Default::default()
+                },
+                TEXT,
+            )
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    size: Size { width: length(200.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[popover],
+            )
+            .unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(popover).unwrap().location, Point { x: 10.0, y: 10.0 });
+        assert_eq!(taffy.layout(popover).unwrap().size, Size { width: 90.0, height: 10.0 });
+    }
+}
@@ -0,0 +1,227 @@
+use taffy::prelude::*;
+use taffy::{Overflow, Point};
+
+fn static_parent(margin_left: f32) -> Style {
+    Style {
+        display: Display::Block,
+        position: Position::Static,
+        margin: Rect { left: length(margin_left), right: zero(), top: zero(), bottom: zero() },
+        size: Size { width: length(100.0), height: length(100.0) },
+        ..Default::default()
+    }
+}
+
+fn root_style() -> Style {
+    Style {
+        display: Display::Block,
+        border: Rect { left: length(5.0), right: length(5.0), top: length(5.0), bottom: length(5.0) },
+        padding: Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: length(10.0) },
+        size: Size { width: length(210.0), height: length(210.0) },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn absolute_nodes_are_positioned_against_the_nearest_positioned_ancestor() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let absolute = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: length(10.0), right: auto(), top: auto(), bottom: length(20.0) },
+            size: Size { width: percent(0.5), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let inner = taffy.new_with_children(static_parent(20.0), &[absolute]).unwrap();
+    let outer = taffy.new_with_children(static_parent(30.0), &[inner]).unwrap();
+    let root = taffy.new_with_children(root_style(), &[outer]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The containing block is the padding box of the root, from (5, 5) to (205, 205). The node is placed at (15, 175),
+    // which is reported relative to its parent at (65, 15).
+    let layout = taffy.layout(absolute).unwrap();
+    assert_eq!(layout.size, Size { width: 100.0, height: 10.0 });
+    assert_eq!(layout.location, Point { x: -50.0, y: 160.0 });
+}
+
+#[test]
+fn absolute_nodes_are_positioned_against_a_positioned_parent() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let absolute = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: length(10.0), right: auto(), top: length(20.0), bottom: auto() },
+            size: Size { width: percent(0.5), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let parent =
+        taffy.new_with_children(Style { position: Position::Relative, ..static_parent(20.0) }, &[absolute]).unwrap();
+    let outer = taffy.new_with_children(static_parent(30.0), &[parent]).unwrap();
+    let root = taffy.new_with_children(root_style(), &[outer]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let layout = taffy.layout(absolute).unwrap();
+    assert_eq!(layout.size, Size { width: 50.0, height: 10.0 });
+    assert_eq!(layout.location, Point { x: 10.0, y: 20.0 });
+}
+
+#[test]
+fn absolute_nodes_keep_their_static_position_in_axes_without_insets() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let absolute = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: auto(), right: auto(), top: length(0.0), bottom: auto() },
+            size: Size { width: length(10.0), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let parent = taffy
+        .new_with_children(
+            Style {
+                padding: Rect { left: length(7.0), right: zero(), top: length(7.0), bottom: zero() },
+                ..static_parent(20.0)
+            },
+            &[absolute],
+        )
+        .unwrap();
+    let root = taffy.new_with_children(root_style(), &[parent]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(absolute).unwrap().location, Point { x: 7.0, y: -10.0 });
+}
+
+#[test]
+fn absolute_nodes_are_not_moved_by_scroll_containers_between_them_and_their_containing_block() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let absolute = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: length(0.0), right: auto(), top: length(0.0), bottom: auto() },
+            size: Size { width: length(10.0), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let content = taffy
+        .new_leaf(Style { size: Size { width: length(50.0), height: length(300.0) }, ..Default::default() })
+        .unwrap();
+    let scroller = taffy
+        .new_with_children(
+            Style { overflow: Point { x: Overflow::Scroll, y: Overflow::Scroll }, ..static_parent(0.0) },
+            &[absolute, content],
+        )
+        .unwrap();
+    let root = taffy.new_with_children(root_style(), &[scroller]).unwrap();
+    taffy.set_scroll_offset(scroller, Point { x: 0.0, y: 30.0 }).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The node is placed at the top-left corner of the root's padding box, which the scroll offset does not move
+    let layout = taffy.layout(absolute).unwrap();
+    assert_eq!(layout.location, Point { x: -10.0, y: -10.0 });
+    assert_eq!(layout.scrolled_location, Point { x: -10.0, y: -10.0 });
+}
+
+#[test]
+fn absolute_children_of_static_nodes_are_positioned_by_every_layout_algorithm() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let absolute = taffy
+            .new_leaf(Style {
+                position: Position::Absolute,
+                inset: Rect { left: length(10.0), right: auto(), top: auto(), bottom: length(20.0) },
+                size: Size { width: percent(0.5), height: length(10.0) },
+                ..Default::default()
+            })
+            .unwrap();
+        let inner = taffy.new_with_children(Style { display, ..static_parent(20.0) }, &[absolute]).unwrap();
+        let outer = taffy.new_with_children(Style { display, ..static_parent(30.0) }, &[inner]).unwrap();
+        let root = taffy.new_with_children(root_style(), &[outer]).unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let layout = taffy.layout(absolute).unwrap();
+        assert_eq!(layout.size, Size { width: 100.0, height: 10.0 }, "{display:?}");
+        assert_eq!(layout.location, Point { x: -50.0, y: 160.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn absolute_children_of_static_table_cells_are_positioned_against_the_row() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let absolute = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: auto(), right: length(0.0), top: length(0.0), bottom: auto() },
+            size: Size { width: length(10.0), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let cell_style = Style {
+        display: Display::TableCell,
+        size: Size { width: length(50.0), height: length(30.0) },
+        ..Default::default()
+    };
+    let first = taffy.new_leaf(cell_style.clone()).unwrap();
+    let second = taffy.new_with_children(Style { position: Position::Static, ..cell_style }, &[absolute]).unwrap();
+    let row =
+        taffy.new_with_children(Style { display: Display::TableRow, ..Default::default() }, &[first, second]).unwrap();
+    let table = taffy.new_with_children(Style { display: Display::Table, ..Default::default() }, &[row]).unwrap();
+
+    taffy.compute_layout(table, Size::MAX_CONTENT).unwrap();
+
+    // The row (which is positioned) spans both cells, and the node is placed at its top-right corner
+    let row_layout = *taffy.layout(row).unwrap();
+    let cell_layout = *taffy.layout(second).unwrap();
+    assert_eq!(
+        taffy.layout(absolute).unwrap().location,
+        Point { x: row_layout.size.width - 10.0 - cell_layout.location.x, y: 0.0 }
+    );
+}
+
+#[test]
+#[cfg(feature = "content_size")]
+fn absolute_children_of_static_nodes_do_not_contribute_to_their_content_size() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let absolute = taffy
+        .new_leaf(Style {
+            position: Position::Absolute,
+            inset: Rect { left: length(300.0), right: auto(), top: length(0.0), bottom: auto() },
+            size: Size { width: length(10.0), height: length(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let parent = taffy.new_with_children(static_parent(0.0), &[absolute]).unwrap();
+    let root = taffy.new_with_children(root_style(), &[parent]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The node only overflows its containing block (the root)
+    assert_eq!(taffy.layout(parent).unwrap().content_size, Size::ZERO);
+    assert_eq!(taffy.layout(parent).unwrap().scrollable_overflow_size, Size { width: 100.0, height: 100.0 });
+    assert_eq!(taffy.layout(root).unwrap().scrollable_overflow_size.width, 310.0);
+}
+
+#[test]
+fn static_nodes_ignore_their_insets() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let inset = Rect { left: length(10.0), right: auto(), top: length(10.0), bottom: auto() };
+    let block_child = taffy.new_leaf(Style { position: Position::Static, inset, ..Default::default() }).unwrap();
+    let flex_child = taffy.new_leaf(Style { position: Position::Static, inset, ..Default::default() }).unwrap();
+    let grid_child = taffy.new_leaf(Style { position: Position::Static, inset, ..Default::default() }).unwrap();
+    let block =
+        taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[block_child]).unwrap();
+    let flex = taffy.new_with_children(Style { display: Display::Flex, ..Default::default() }, &[flex_child]).unwrap();
+    let grid = taffy.new_with_children(Style { display: Display::Grid, ..Default::default() }, &[grid_child]).unwrap();
+    let root = taffy.new_with_children(Style::default(), &[block, flex, grid]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    for child in [block_child, flex_child, grid_child] {
+        assert_eq!(taffy.layout(child).unwrap().location, Point::ZERO);
+    }
+}
@@ -0,0 +1,288 @@
+use taffy::prelude::*;
+use taffy::Point;
+use taffy_test_helpers::{compute_layout, text};
+
+/// A cell containing a single text run
+fn cell(taffy: &mut TaffyTree<&'static str>, style: Style, content: &'static str) -> NodeId {
+    let run = text(taffy, content);
+    taffy.new_with_children(Style { display: Display::TableCell, ..style }, &[run]).unwrap()
+}
+
+fn row(taffy: &mut TaffyTree<&'static str>, cells: &[NodeId]) -> NodeId {
+    taffy.new_with_children(Style { display: Display::TableRow, ..Default::default() }, cells).unwrap()
+}
+
+fn table(style: Style) -> Style {
+    Style { display: Display::Table, ..style }
+}
+
+fn width(width: f32) -> Style {
+    Style { size: Size { width: length(width), height: auto() }, ..Default::default() }
+}
+
+#[test]
+fn columns_are_as_wide_as_their_max_content_when_there_is_room() {
+    let mut taffy = TaffyTree::new();
+    let first = cell(&mut taffy, Style::default(), "aa bb");
+    let second = cell(&mut taffy, Style::default(), "c");
+    let first_row = row(&mut taffy, &[first, second]);
+    let root = taffy.new_with_children(table(Style::default()), &[first_row]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 60.0, height: 10.0 });
+    assert_eq!(taffy.layout(first_row).unwrap().size, Size { width: 60.0, height: 10.0 });
+    assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(first).unwrap().size, Size { width: 50.0, height: 10.0 });
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 50.0, y: 0.0 });
+}
+
+#[test]
+fn columns_shrink_between_their_min_content_and_max_content_widths() {
+    let mut taffy = TaffyTree::new();
+    let first = cell(&mut taffy, Style::default(), "aa bb");
+    let second = cell(&mut taffy, Style::default(), "c");
+    let first_row = row(&mut taffy, &[first, second]);
+    let root = taffy.new_with_children(table(width(40.0)), &[first_row]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    // The grid is 10 wider than the sum of the min-content widths (20 + 10), which is a third of the difference
+    // between the sums of the max-content and min-content widths
+    assert_eq!(taffy.layout(first).unwrap().size, Size { width: 30.0, height: 20.0 });
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 30.0, y: 0.0 });
+    assert_eq!(taffy.layout(second).unwrap().size, Size { width: 10.0, height: 20.0 });
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 40.0, height: 20.0 });
+}
+
+#[test]
+fn spanning_cells_widen_the_columns_they_span_in_proportion_to_their_widths() {
+    let mut taffy = TaffyTree::new();
+    let wide = cell(&mut taffy, Style { col_span: 2, ..Default::default() }, "aaaaaa");
+    let narrow = cell(&mut taffy, Style::default(), "a");
+    let medium = cell(&mut taffy, Style::default(), "aaa");
+    let first_row = row(&mut taffy, &[wide]);
+    let second_row = row(&mut taffy, &[narrow, medium]);
+    let root = taffy.new_with_children(table(Style::default()), &[first_row, second_row]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(wide).unwrap().size.width, 60.0);
+    assert_eq!(taffy.layout(narrow).unwrap().size.width, 15.0);
+    assert_eq!(taffy.layout(medium).unwrap().location, Point { x: 15.0, y: 0.0 });
+    assert_eq!(taffy.layout(medium).unwrap().size.width, 45.0);
+}
+
+#[test]
+fn cells_are_placed_beside_cells_spanning_down_from_earlier_rows() {
+    let mut taffy = TaffyTree::new();
+    let tall = cell(&mut taffy, Style { row_span: 2, ..Default::default() }, "a");
+    let top = cell(&mut taffy, Style::default(), "b");
+    let bottom = cell(&mut taffy, Style::default(), "c");
+    let first_row = row(&mut taffy, &[tall, top]);
+    let second_row = row(&mut taffy, &[bottom]);
+    let root = taffy.new_with_children(table(Style::default()), &[first_row, second_row]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(tall).unwrap().size, Size { width: 10.0, height: 20.0 });
+    assert_eq!(taffy.layout(second_row).unwrap().location, Point { x: 0.0, y: 10.0 });
+    assert_eq!(taffy.layout(bottom).unwrap().location, Point { x: 10.0, y: 0.0 });
+}
+
+#[test]
+fn stray_cells_are_wrapped_in_anonymous_rows() {
+    let mut taffy = TaffyTree::new();
+    let first = cell(&mut taffy, Style::default(), "a");
+    let second = cell(&mut taffy, Style::default(), "bb");
+    let group =
+        taffy.new_with_children(Style { display: Display::TableRowGroup, ..Default::default() }, &[first]).unwrap();
+    let root = taffy.new_with_children(table(Style::default()), &[group, second]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    // Each cell is in its own row, so they share a column. Cells in anonymous rows are positioned relative to the
+    // parent of the row.
+    assert_eq!(taffy.layout(group).unwrap().size, Size { width: 20.0, height: 10.0 });
+    assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(first).unwrap().size.width, 20.0);
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 10.0 });
+}
+
+#[test]
+fn border_spacing_separates_cells() {
+    let mut taffy = TaffyTree::new();
+    let first = cell(&mut taffy, Style::default(), "a");
+    let second = cell(&mut taffy, Style::default(), "b");
+    let first_row = row(&mut taffy, &[first]);
+    let second_row = row(&mut taffy, &[second]);
+    let style = Style { border_spacing: Size { width: length(5.0), height: length(3.0) }, ..Default::default() };
+    let root = taffy.new_with_children(table(style), &[first_row, second_row]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 20.0, height: 29.0 });
+    assert_eq!(taffy.layout(first_row).unwrap().location, Point { x: 5.0, y: 3.0 });
+    assert_eq!(taffy.layout(second_row).unwrap().location, Point { x: 5.0, y: 16.0 });
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 0.0 });
+}
+
+#[test]
+fn fixed_layout_uses_the_widths_of_the_first_row() {
+    let mut taffy = TaffyTree::new();
+    let first = cell(&mut taffy, width(30.0), "a");
+    let second = cell(&mut taffy, Style::default(), "b");
+    let wide = cell(&mut taffy, Style::default(), "aaaaaaaaaaaa");
+    let first_row = row(&mut taffy, &[first, second]);
+    let second_row = row(&mut taffy, &[wide]);
+    let style = Style { table_layout: TableLayout::Fixed, ..width(100.0) };
+    let root = taffy.new_with_children(table(style), &[first_row, second_row]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    assert_eq!(taffy.layout(first).unwrap().size.width, 30.0);
+    assert_eq!(taffy.layout(second).unwrap().location.x, 30.0);
+    assert_eq!(taffy.layout(second).unwrap().size.width, 70.0);
+    // The content of later rows does not affect the widths of the columns
+    assert_eq!(taffy.layout(wide).unwrap().size.width, 30.0);
+    assert_eq!(taffy.layout(root).unwrap().size.width, 100.0);
+}
+
+#[test]
+fn collapsed_borders_are_shared_between_adjacent_cells() {
+    let mut taffy = TaffyTree::new();
+    let first = cell(&mut taffy, Style { border: length(4.0), ..Default::default() }, "a");
+    let second = cell(&mut taffy, Style::default(), "b");
+    let first_row = row(&mut taffy, &[first, second]);
+    let style = Style { border_collapse: BorderCollapse::Collapse, border: length(2.0), ..Default::default() };
+    let root = taffy.new_with_children(table(style), &[first_row]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    // The lines between the columns are 4, 4 and 2 wide, and the lines between the rows are both 4 wide
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 30.0, height: 18.0 });
+    assert_eq!(taffy.layout(first_row).unwrap().location, Point { x: 2.0, y: 2.0 });
+    assert_eq!(taffy.layout(first).unwrap().border, Rect { left: 2.0, right: 2.0, top: 2.0, bottom: 2.0 });
+    assert_eq!(taffy.layout(first).unwrap().size, Size { width: 14.0, height: 14.0 });
+    assert_eq!(taffy.layout(second).unwrap().border, Rect { left: 2.0, right: 1.0, top: 2.0, bottom: 2.0 });
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 14.0, y: 0.0 });
+}
+
+#[test]
+fn cell_content_is_vertically_aligned_within_the_row() {
+    let mut taffy = TaffyTree::new();
+    let top = cell(&mut taffy, Style { vertical_align: VerticalAlign::Top, ..Default::default() }, "a");
+    let middle = cell(&mut taffy, Style { vertical_align: VerticalAlign::Middle, ..Default::default() }, "b");
+    let bottom = cell(&mut taffy, Style { vertical_align: VerticalAlign::Bottom, ..Default::default() }, "c");
+    let first_row = taffy
+        .new_with_children(
+            Style {
+                display: Display::TableRow,
+                size: Size { width: auto(), height: length(40.0) },
+                ..Default::default()
+            },
+            &[top, middle, bottom],
+        )
+        .unwrap();
+    let root = taffy.new_with_children(table(Style::default()), &[first_row]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    let content_y = |taffy: &TaffyTree<&'static str>, cell| {
+        let content = taffy.children(cell).unwrap()[0];
+        taffy.layout(content).unwrap().location.y
+    };
+    assert_eq!(taffy.layout(middle).unwrap().size.height, 40.0);
+    assert_eq!(content_y(&taffy, top), 0.0);
+    assert_eq!(content_y(&taffy, middle), 15.0);
+    assert_eq!(content_y(&taffy, bottom), 30.0);
+
+    // Laying out again must not move the content twice
+    taffy.mark_dirty(root).unwrap();
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+    assert_eq!(content_y(&taffy, middle), 15.0);
+}
+
+#[test]
+fn captions_are_placed_above_or_below_the_grid() {
+    let mut taffy = TaffyTree::new();
+    let caption_run = text(&mut taffy, "caption text");
+    let caption = taffy
+        .new_with_children(Style { display: Display::TableCaption, ..Default::default() }, &[caption_run])
+        .unwrap();
+    let only = cell(&mut taffy, Style::default(), "a");
+    let first_row = row(&mut taffy, &[only]);
+    let root = taffy.new_with_children(table(Style::default()), &[caption, first_row]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    // The table is at least as wide as the min-content width of its caption
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 70.0, height: 30.0 });
+    assert_eq!(taffy.layout(caption).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(caption).unwrap().size, Size { width: 70.0, height: 20.0 });
+    assert_eq!(taffy.layout(first_row).unwrap().location, Point { x: 0.0, y: 20.0 });
+    assert_eq!(taffy.layout(only).unwrap().size.width, 70.0);
+
+    taffy
+        .set_style(
+            caption,
+            Style { display: Display::TableCaption, caption_side: CaptionSide::Bottom, ..Default::default() },
+        )
+        .unwrap();
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+    assert_eq!(taffy.layout(first_row).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(caption).unwrap().location, Point { x: 0.0, y: 10.0 });
+}
+
+#[test]
+fn columns_are_placed_right_to_left_in_rtl_tables() {
+    let mut taffy = TaffyTree::new();
+    let first = cell(&mut taffy, Style::default(), "a");
+    let second = cell(&mut taffy, Style::default(), "bbb");
+    let first_row = row(&mut taffy, &[first, second]);
+    let root =
+        taffy.new_with_children(table(Style { direction: Direction::Rtl, ..width(60.0) }), &[first_row]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    // The extra 20 is distributed in proportion to the max-content widths of the columns
+    assert_eq!(taffy.layout(first).unwrap().location.x, 45.0);
+    assert_eq!(taffy.layout(first).unwrap().size.width, 15.0);
+    assert_eq!(taffy.layout(second).unwrap().location.x, 0.0);
+    assert_eq!(taffy.layout(second).unwrap().size.width, 45.0);
+}
+
+#[test]
+fn tables_are_sized_to_fit_within_block_containers() {
+    let mut taffy = TaffyTree::new();
+    let only = cell(&mut taffy, Style::default(), "aaa bbb ccc");
+    let first_row = row(&mut taffy, &[only]);
+    let inner = taffy.new_with_children(table(Style::default()), &[first_row]).unwrap();
+    let root = taffy.new_with_children(Style { display: Display::Block, ..width(200.0) }, &[inner]).unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+    assert_eq!(taffy.layout(inner).unwrap().size, Size { width: 110.0, height: 10.0 });
+
+    taffy.set_style(root, Style { display: Display::Block, ..width(50.0) }).unwrap();
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+    assert_eq!(taffy.layout(inner).unwrap().size, Size { width: 50.0, height: 30.0 });
+}
+
+#[test]
+fn columns_follow_the_inline_axis_of_vertical_tables() {
+    let mut taffy = TaffyTree::new();
+    let first = cell(&mut taffy, Style::default(), "a");
+    let second = cell(&mut taffy, Style::default(), "bb");
+    let first_row = row(&mut taffy, &[first, second]);
+    let root = taffy
+        .new_with_children(table(Style { writing_mode: WritingMode::VerticalLr, ..Default::default() }), &[first_row])
+        .unwrap();
+
+    compute_layout(&mut taffy, root, Size::MAX_CONTENT);
+
+    // The row runs from top to bottom, and is as wide as its widest cell
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 20.0, height: 20.0 });
+    assert_eq!(taffy.layout(first).unwrap().location, Point { x: 0.0, y: 0.0 });
+    assert_eq!(taffy.layout(first).unwrap().size, Size { width: 20.0, height: 10.0 });
+    assert_eq!(taffy.layout(second).unwrap().location, Point { x: 0.0, y: 10.0 });
+}
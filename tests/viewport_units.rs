@@ -0,0 +1,132 @@
+use taffy::prelude::*;
+
+fn definite(width: f32, height: f32) -> Size<AvailableSpace> {
+    Size { width: AvailableSpace::Definite(width), height: AvailableSpace::Definite(height) }
+}
+
+#[test]
+fn viewport_units_are_resolved_against_the_available_space() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy
+        .new_leaf(Style {
+            size: Size { width: Dimension::vw(50.0), height: Dimension::vh(10.0) },
+            margin: Rect { left: LengthPercentageAuto::vmin(10.0), right: zero(), top: zero(), bottom: zero() },
+            padding: Rect { left: LengthPercentage::vmax(1.0), right: zero(), top: zero(), bottom: zero() },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style { size: Size { width: length(1000.0), height: length(1000.0) }, ..Default::default() },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, definite(800.0, 600.0)).unwrap();
+
+    let layout = taffy.layout(child).unwrap();
+    assert_eq!(layout.size, Size { width: 400.0, height: 60.0 });
+    assert_eq!(layout.location.x, 60.0);
+    assert_eq!(layout.padding.left, 8.0);
+}
+
+#[test]
+fn resizing_the_viewport_only_requires_a_recompute() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy
+        .new_leaf(Style { size: Size { width: Dimension::vw(25.0), height: length(10.0) }, ..Default::default() })
+        .unwrap();
+    // The container and the root have fixed sizes, so their layout inputs do not change when the viewport is resized
+    let container = taffy
+        .new_with_children(
+            Style { size: Size { width: length(300.0), height: length(10.0) }, ..Default::default() },
+            &[child],
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(500.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[container],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, definite(800.0, 600.0)).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().size.width, 200.0);
+
+    taffy.compute_layout(root, definite(400.0, 600.0)).unwrap();
+    assert_eq!(taffy.layout(child).unwrap().size.width, 100.0);
+}
+
+#[test]
+fn viewport_units_size_grid_tracks() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy.new_leaf(Style::default()).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![NonRepeatedTrackSizingFunction::from(Dimension::vw(20.0)).into(), fr(1.0)],
+                size: Size { width: length(300.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, definite(1000.0, 500.0)).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().size.width, 200.0);
+}
+
+#[test]
+fn viewport_units_are_resolved_against_the_viewport_size() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy
+        .new_leaf(Style {
+            size: Size { width: Dimension::vw(50.0), height: Dimension::vh(10.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    let root = taffy.new_with_children(Style::default(), &[child]).unwrap();
+    taffy.set_viewport_size(Some(Size { width: 800.0, height: 600.0 }));
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child).unwrap().size, Size { width: 400.0, height: 60.0 });
+}
+
+#[test]
+fn resizing_the_viewport_only_invalidates_nodes_which_use_viewport_units() {
+    use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext};
+
+    let mut taffy = new_test_tree();
+    let measured = taffy.new_leaf_with_context(Style::default(), TestNodeContext::fixed(50.0, 50.0)).unwrap();
+    let sized = taffy
+        .new_leaf(Style { size: Size { width: Dimension::vw(25.0), height: length(10.0) }, ..Default::default() })
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                flex_direction: FlexDirection::Column,
+                align_items: Some(AlignItems::Start),
+                size: Size { width: length(500.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[measured, sized],
+        )
+        .unwrap();
+
+    taffy.set_viewport_size(Some(Size { width: 800.0, height: 600.0 }));
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    let measure_count = taffy.get_node_context(measured).unwrap().count;
+
+    taffy.set_viewport_size(Some(Size { width: 400.0, height: 600.0 }));
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    assert_eq!(taffy.layout(sized).unwrap().size.width, 100.0);
+    assert_eq!(taffy.get_node_context(measured).unwrap().count, measure_count);
+}
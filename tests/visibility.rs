@@ -0,0 +1,85 @@
+use taffy::prelude::*;
+use taffy::Point;
+use taffy_test_helpers::{fixed_size, new_test_tree};
+
+fn collapsed(style: Style) -> Style {
+    Style { visibility: Visibility::Collapse, ..style }
+}
+
+#[test]
+fn collapsed_flex_items_leave_a_strut_in_their_line() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(fixed_size(20.0, 40.0)).unwrap();
+    let collapsed_item = taffy.new_leaf(collapsed(fixed_size(30.0, 60.0))).unwrap();
+    let last = taffy.new_leaf(fixed_size(20.0, 10.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style { display: Display::Flex, gap: Size { width: length(5.0), height: zero() }, ..Default::default() },
+            &[first, collapsed_item, last],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The collapsed item takes up no space in the main axis, but the line keeps its cross size
+    assert_eq!(taffy.layout(root).unwrap().size, Size { width: 45.0, height: 60.0 });
+    assert_eq!(taffy.layout(last).unwrap().location, Point { x: 25.0, y: 0.0 });
+    assert_eq!(taffy.layout(last).unwrap().size.height, 10.0);
+
+    let collapsed_layout = taffy.layout(collapsed_item).unwrap();
+    assert_eq!(collapsed_layout.size, Size::ZERO);
+    assert_eq!(collapsed_layout.visibility, Visibility::Collapse);
+}
+
+#[test]
+fn collapsed_flex_items_are_struts_in_the_line_of_the_preceding_item() {
+    let mut taffy = new_test_tree();
+    let first = taffy.new_leaf(fixed_size(30.0, 10.0)).unwrap();
+    let collapsed_item = taffy.new_leaf(collapsed(fixed_size(30.0, 20.0))).unwrap();
+    let last = taffy.new_leaf(fixed_size(30.0, 30.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_wrap: FlexWrap::Wrap,
+                align_items: Some(AlignItems::Start),
+                size: Size { width: length(50.0), height: auto() },
+                ..Default::default()
+            },
+            &[first, collapsed_item, last],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The collapsed item was in a line of its own, so its strut is the height of that line
+    assert_eq!(taffy.layout(first).unwrap().size.height, 10.0);
+    assert_eq!(taffy.layout(last).unwrap().location, Point { x: 0.0, y: 20.0 });
+    assert_eq!(taffy.layout(root).unwrap().size.height, 50.0);
+}
+
+#[test]
+fn hidden_nodes_are_laid_out_like_visible_nodes() {
+    let mut taffy = new_test_tree();
+    let hidden_style = Style { visibility: Visibility::Hidden, ..fixed_size(20.0, 10.0) };
+    let flex_hidden = taffy.new_leaf(hidden_style.clone()).unwrap();
+    let flex_visible = taffy.new_leaf(fixed_size(20.0, 10.0)).unwrap();
+    let flex_root = taffy.new_with_children(Style::default(), &[flex_hidden, flex_visible]).unwrap();
+    let grid_hidden = taffy.new_leaf(hidden_style.clone()).unwrap();
+    let grid_root =
+        taffy.new_with_children(Style { display: Display::Grid, ..Default::default() }, &[grid_hidden]).unwrap();
+    let block_hidden = taffy.new_leaf(hidden_style).unwrap();
+    let block_root =
+        taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[block_hidden]).unwrap();
+    let root = taffy.new_with_children(Style::default(), &[flex_root, grid_root, block_root]).unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(flex_visible).unwrap().location.x, 20.0);
+    assert_eq!(taffy.layout(flex_visible).unwrap().visibility, Visibility::Visible);
+    for node in [flex_hidden, grid_hidden, block_hidden] {
+        let layout = taffy.layout(node).unwrap();
+        assert_eq!(layout.size, Size { width: 20.0, height: 10.0 });
+        assert_eq!(layout.visibility, Visibility::Hidden);
+    }
+}
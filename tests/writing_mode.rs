@@ -0,0 +1,192 @@
+use taffy::prelude::*;
+use taffy::{LogicalRect, LogicalSize};
+use taffy_test_helpers::new_test_tree;
+
+#[test]
+fn block_vertical_lr_stacks_children_from_the_left() {
+    let mut taffy = new_test_tree();
+    let child_style = |width: f32| Style { size: Size { width: length(width), height: auto() }, ..Default::default() };
+    let child0 = taffy.new_leaf(child_style(10.0)).unwrap();
+    let child1 = taffy.new_leaf(child_style(20.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                writing_mode: WritingMode::VerticalLr,
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child0, child1],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let layout0 = taffy.layout(child0).unwrap();
+    let layout1 = taffy.layout(child1).unwrap();
+    assert_eq!((layout0.location.x, layout0.location.y), (0.0, 0.0));
+    assert_eq!((layout0.size.width, layout0.size.height), (10.0, 100.0));
+    assert_eq!((layout1.location.x, layout1.location.y), (10.0, 0.0));
+    assert_eq!((layout1.size.width, layout1.size.height), (20.0, 100.0));
+}
+
+#[test]
+fn block_vertical_rl_stacks_children_from_the_right() {
+    let mut taffy = new_test_tree();
+    let child_style = |width: f32| Style { size: Size { width: length(width), height: auto() }, ..Default::default() };
+    let child0 = taffy.new_leaf(child_style(10.0)).unwrap();
+    let child1 = taffy.new_leaf(child_style(20.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                writing_mode: WritingMode::VerticalRl,
+                size: Size { width: length(100.0), height: length(100.0) },
+                padding: Rect { left: zero(), right: length(5.0), top: zero(), bottom: zero() },
+                ..Default::default()
+            },
+            &[child0, child1],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let layout0 = taffy.layout(child0).unwrap();
+    let layout1 = taffy.layout(child1).unwrap();
+    assert_eq!(layout0.location.x, 85.0);
+    assert_eq!(layout1.location.x, 65.0);
+    assert_eq!(layout1.size.height, 100.0);
+}
+
+#[test]
+fn flex_row_vertical_lr_lays_out_items_from_the_top() {
+    let mut taffy = new_test_tree();
+    let child_style =
+        |height: f32| Style { size: Size { width: auto(), height: length(height) }, ..Default::default() };
+    let child0 = taffy.new_leaf(child_style(10.0)).unwrap();
+    let child1 = taffy.new_leaf(child_style(20.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                writing_mode: WritingMode::VerticalLr,
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child0, child1],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let layout0 = taffy.layout(child0).unwrap();
+    let layout1 = taffy.layout(child1).unwrap();
+    assert_eq!((layout0.location.x, layout0.location.y), (0.0, 0.0));
+    assert_eq!((layout0.size.width, layout0.size.height), (100.0, 10.0));
+    assert_eq!((layout1.location.x, layout1.location.y), (0.0, 10.0));
+}
+
+#[test]
+fn flex_row_vertical_lr_rtl_lays_out_items_from_the_bottom() {
+    let mut taffy = new_test_tree();
+    let child_style =
+        |height: f32| Style { size: Size { width: auto(), height: length(height) }, ..Default::default() };
+    let child0 = taffy.new_leaf(child_style(10.0)).unwrap();
+    let child1 = taffy.new_leaf(child_style(20.0)).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                writing_mode: WritingMode::VerticalLr,
+                direction: Direction::Rtl,
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child0, child1],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.layout(child0).unwrap().location.y, 90.0);
+    assert_eq!(taffy.layout(child1).unwrap().location.y, 70.0);
+}
+
+#[test]
+fn grid_vertical_rl_places_columns_vertically() {
+    let mut taffy = new_test_tree();
+    let child0 = taffy.new_leaf(Style::default()).unwrap();
+    let child1 = taffy.new_leaf(Style::default()).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                writing_mode: WritingMode::VerticalRl,
+                size: Size { width: length(100.0), height: length(100.0) },
+                grid_template_columns: vec![length(10.0), length(20.0)],
+                grid_template_rows: vec![length(40.0)],
+                ..Default::default()
+            },
+            &[child0, child1],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let layout0 = taffy.layout(child0).unwrap();
+    let layout1 = taffy.layout(child1).unwrap();
+    assert_eq!((layout0.location.x, layout0.location.y), (60.0, 0.0));
+    assert_eq!((layout0.size.width, layout0.size.height), (40.0, 10.0));
+    assert_eq!((layout1.location.x, layout1.location.y), (60.0, 10.0));
+    assert_eq!((layout1.size.width, layout1.size.height), (40.0, 20.0));
+}
+
+#[test]
+fn horizontal_child_of_vertical_parent_is_sized_by_its_content() {
+    let mut taffy = new_test_tree();
+    let grandchild = taffy
+        .new_leaf(Style { size: Size { width: length(30.0), height: length(20.0) }, ..Default::default() })
+        .unwrap();
+    let child =
+        taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[grandchild]).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                writing_mode: WritingMode::VerticalLr,
+                size: Size { width: auto(), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let root_layout = taffy.layout(root).unwrap();
+    let child_layout = taffy.layout(child).unwrap();
+    assert_eq!((root_layout.size.width, root_layout.size.height), (30.0, 100.0));
+    assert_eq!((child_layout.size.width, child_layout.size.height), (30.0, 100.0));
+    assert_eq!(taffy.layout(grandchild).unwrap().size.width, 30.0);
+}
+
+#[test]
+fn logical_properties_resolve_against_writing_mode_and_direction() {
+    let mut style = Style { writing_mode: WritingMode::VerticalRl, direction: Direction::Rtl, ..Default::default() };
+    let margin = LogicalRect {
+        inline_start: length(1.0),
+        inline_end: length(2.0),
+        block_start: length(3.0),
+        block_end: length(4.0),
+    };
+    style.set_logical_margin(margin);
+    assert_eq!(style.margin, Rect { left: length(4.0), right: length(3.0), top: length(2.0), bottom: length(1.0) });
+    assert_eq!(style.logical_margin(), margin);
+
+    style.set_logical_size(LogicalSize { inline: length(10.0), block: length(20.0) });
+    assert_eq!(style.size, Size { width: length(20.0), height: length(10.0) });
+
+    style.writing_mode = WritingMode::HorizontalTb;
+    assert_eq!(style.logical_size(), LogicalSize { inline: length(20.0), block: length(10.0) });
+    assert_eq!(style.logical_margin().inline_start, length(3.0));
+}